@@ -0,0 +1,291 @@
+//! The case conversion of `const_str::convert_case!` and `const_str::convert_ascii_case!`,
+//! with the default `CaseOptions`.
+//!
+//! The rules are ported from `const_str::ascii_case::Boundaries` and must be kept in sync.
+
+use crate::unicode_case;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    NonAscii,
+    Lower,
+    Upper,
+    Digit,
+    Dot,
+    Other,
+}
+
+impl TokenKind {
+    fn new(ch: char, unicode: bool) -> Self {
+        match ch {
+            'a'..='z' => TokenKind::Lower,
+            'A'..='Z' => TokenKind::Upper,
+            '0'..='9' => TokenKind::Digit,
+            '.' => TokenKind::Dot,
+            _ if ch.is_ascii() => TokenKind::Other,
+            _ if !unicode => TokenKind::NonAscii,
+            _ if unicode_case::is_uppercase(ch) => TokenKind::Upper,
+            _ if unicode_case::is_lowercase(ch) => TokenKind::Lower,
+            _ if unicode_case::is_word(ch) => TokenKind::NonAscii,
+            _ => TokenKind::Other,
+        }
+    }
+}
+
+/// Returns the byte offsets of the word boundaries.
+fn boundaries(s: &str, unicode: bool) -> Vec<usize> {
+    use TokenKind::*;
+
+    let mut ans = Vec::new();
+    let mut k1: Option<TokenKind> = None;
+    let mut k2: Option<TokenKind> = None;
+    let mut last = 0;
+    let mut dot_start = 0;
+
+    for (i, ch) in s.char_indices() {
+        let k0 = TokenKind::new(ch, unicode);
+        if k0 == Dot && k1 != Some(Dot) {
+            dot_start = i;
+        }
+        match k1 {
+            None => ans.push(i),
+            Some(k1) if k1 != k0 => match (k1, k0) {
+                (Upper, Lower) => ans.push(last),
+                (Lower, Upper) => ans.push(i),
+                (NonAscii | Lower | Upper | Digit, NonAscii | Lower | Upper | Digit) => {}
+                (_, Dot) => {}
+                (Dot, _) => {
+                    if k2.is_some() {
+                        // runs of `.` are separators in `convert_case!`
+                        ans.push(if unicode { dot_start } else { last });
+                    }
+                    ans.push(i);
+                }
+                _ => ans.push(i),
+            },
+            Some(_) => {}
+        }
+        k2 = k1;
+        k1 = Some(k0);
+        last = i;
+    }
+
+    // so is `.` at the end
+    if unicode && k1 == Some(Dot) && dot_start > 0 {
+        ans.push(dot_start);
+    }
+    ans.push(s.len());
+    ans
+}
+
+/// Returns the words of `s`, skipping separators.
+fn words(s: &str, unicode: bool) -> Vec<&str> {
+    let is_separator = |ch: char| {
+        matches!(
+            TokenKind::new(ch, unicode),
+            TokenKind::Other | TokenKind::Dot
+        )
+    };
+    boundaries(s, unicode)
+        .windows(2)
+        .map(|w| &s[w[0]..w[1]])
+        .filter(|word| !word.chars().all(is_separator))
+        .collect()
+}
+
+/// Target cases of `const_str::convert_case!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AsciiCase {
+    Lower,
+    Upper,
+    LowerCamel,
+    UpperCamel,
+    Title,
+    Train,
+    Snake,
+    Kebab,
+    ShoutySnake,
+    ShoutyKebab,
+    Dot,
+    Path,
+    Sentence,
+    Ada,
+    Flat,
+    UpperFlat,
+}
+
+impl AsciiCase {
+    pub fn from_name(name: &str) -> Option<Self> {
+        let case = match name {
+            "lower" => Self::Lower,
+            "upper" => Self::Upper,
+            "lower_camel" => Self::LowerCamel,
+            "upper_camel" => Self::UpperCamel,
+            "title" => Self::Title,
+            "train" => Self::Train,
+            "snake" => Self::Snake,
+            "kebab" => Self::Kebab,
+            "shouty_snake" => Self::ShoutySnake,
+            "shouty_kebab" | "cobol" => Self::ShoutyKebab,
+            "dot" => Self::Dot,
+            "path" => Self::Path,
+            "sentence" => Self::Sentence,
+            "ada" => Self::Ada,
+            "flat" => Self::Flat,
+            "upper_flat" => Self::UpperFlat,
+            _ => return None,
+        };
+        Some(case)
+    }
+
+    fn separator(self) -> Option<char> {
+        match self {
+            Self::Title | Self::Sentence => Some(' '),
+            Self::Snake | Self::ShoutySnake | Self::Ada => Some('_'),
+            Self::Train | Self::Kebab | Self::ShoutyKebab => Some('-'),
+            Self::Dot => Some('.'),
+            Self::Path => Some('/'),
+            _ => None,
+        }
+    }
+
+    fn is_upper(self) -> bool {
+        matches!(
+            self,
+            Self::Upper | Self::ShoutySnake | Self::ShoutyKebab | Self::UpperFlat
+        )
+    }
+
+    fn is_title_at(self, is_first_word: bool, j: usize) -> bool {
+        match self {
+            Self::LowerCamel => !is_first_word && j == 0,
+            Self::Sentence => is_first_word && j == 0,
+            Self::UpperCamel | Self::Title | Self::Train | Self::Ada => j == 0,
+            _ => false,
+        }
+    }
+
+    /// Converts `s` like `const_str::convert_case!` if `unicode` is true,
+    /// otherwise like `const_str::convert_ascii_case!`.
+    pub fn convert(self, s: &str, unicode: bool) -> String {
+        let words = match self {
+            Self::Lower | Self::Upper => vec![s],
+            _ => words(s, unicode),
+        };
+
+        let mut ans = String::with_capacity(s.len());
+        for (i, word) in words.into_iter().enumerate() {
+            if i > 0 {
+                ans.extend(self.separator());
+            }
+            if unicode {
+                self.push_unicode_word(&mut ans, word, i == 0);
+                continue;
+            }
+            for (j, ch) in word.char_indices() {
+                let ch = if self.is_upper() || self.is_title_at(i == 0, j) {
+                    ch.to_ascii_uppercase()
+                } else {
+                    ch.to_ascii_lowercase()
+                };
+                ans.push(ch);
+            }
+        }
+        ans
+    }
+
+    fn push_unicode_word(self, ans: &mut String, word: &str, is_first_word: bool) {
+        let mut cased_before = false;
+        for (j, ch) in word.char_indices() {
+            let chars = if self.is_upper() {
+                unicode_case::to_upper(ch)
+            } else if self.is_title_at(is_first_word, j) {
+                unicode_case::to_title(ch)
+            } else if ch == 'Σ' && cased_before && is_final_sigma(&word[j + ch.len_utf8()..]) {
+                ['ς', '\0', '\0']
+            } else {
+                unicode_case::to_lower(ch)
+            };
+            if !unicode_case::is_case_ignorable(ch) {
+                cased_before = unicode_case::is_cased(ch);
+            }
+
+            ans.push(chars[0]);
+            ans.extend(chars[1..].iter().filter(|&&c| c != '\0'));
+        }
+    }
+}
+
+/// Checks the `Final_Sigma` condition after a `'Σ'`, which follows a cased letter.
+fn is_final_sigma(rest: &str) -> bool {
+    match rest
+        .chars()
+        .find(|&ch| !unicode_case::is_case_ignorable(ch))
+    {
+        Some(ch) => !unicode_case::is_cased(ch),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AsciiCase;
+
+    #[test]
+    fn test_convert_case() {
+        macro_rules! testcase {
+            ($s: literal, [$($case: tt),+]) => {$(
+                let case = AsciiCase::from_name(stringify!($case)).unwrap();
+                assert_eq!(
+                    case.convert($s, true),
+                    const_str::convert_case!($case, $s),
+                    "convert_case!({}, {:?})", stringify!($case), $s,
+                );
+                assert_eq!(
+                    case.convert($s, false),
+                    const_str::convert_ascii_case!($case, $s),
+                    "convert_ascii_case!({}, {:?})", stringify!($case), $s,
+                );
+            )+};
+        }
+
+        macro_rules! corpus {
+            ($($s: literal),+) => {$(
+                testcase!($s, [
+                    lower, upper, lower_camel, upper_camel, title, train,
+                    snake, kebab, shouty_snake, shouty_kebab, cobol,
+                    dot, path, sentence, ada, flat, upper_flat
+                ]);
+            )+};
+        }
+
+        corpus!(
+            "",
+            "_",
+            "hello world",
+            "XMLHttpRequest",
+            "HTTP2Server",
+            "getHTTPResponseCode",
+            "IOError",
+            "version2 alpha",
+            "Hello World123!XMLHttp4t5.c6.7b.8",
+            "1.2E3",
+            "a..b",
+            "a.b.",
+            "1..2...",
+            "ab..CD",
+            "__a__b-c__d__",
+            "ΌΣΟΣ Σ ΑΣ'Σ ΣΑ.Σ",
+            "ΌροςΚαιΛΟΓΟΣ2",
+            "straße ﬁx ŉ İstanbul",
+            "ǅemal ǆemal ǄEMAL",
+            "ÉcoleNormale—Supérieure",
+            "我的Name2",
+            "e\u{301}Clair",
+            "Größe_MAẞ3ter",
+            "журналПРОВЕРКА42",
+            "ᾳᾼ ΐ Ԁ Ꙁ",
+            "ꟊꟋ Ᲊᲊ"
+        );
+    }
+}
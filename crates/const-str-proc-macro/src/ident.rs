@@ -0,0 +1,215 @@
+use crate::conv_case::AsciiCase;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Delimiter, Group, Span, TokenTree};
+use quote::ToTokens;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::{Ident, Lit, Macro, Token};
+
+enum Expr {
+    Value(String),
+    Concat(Vec<Expr>),
+    ConvertCase(AsciiCase, bool, Box<Expr>),
+    Replace(Box<[Expr; 3]>),
+}
+
+impl Expr {
+    fn eval(&self) -> String {
+        match self {
+            Expr::Value(s) => s.clone(),
+            Expr::Concat(xs) => xs.iter().map(Expr::eval).collect(),
+            Expr::ConvertCase(case, unicode, x) => case.convert(&x.eval(), *unicode),
+            Expr::Replace(args) => {
+                let [x, from, to] = &**args;
+                x.eval().replace(&from.eval(), &to.eval())
+            }
+        }
+    }
+}
+
+/// Parses expressions and records the spans of the first identifier and literal tokens.
+struct ExprParser {
+    ident_span: Option<Span>,
+    lit_span: Option<Span>,
+}
+
+impl ExprParser {
+    fn parse_expr(&mut self, input: ParseStream) -> syn::Result<Expr> {
+        if input.fork().parse::<Macro>().is_ok() {
+            let m = input.parse::<Macro>()?;
+            return self.parse_macro(&m);
+        }
+
+        if input.peek(Ident::peek_any) {
+            let ident = input.call(Ident::parse_any)?;
+            self.ident_span.get_or_insert(ident.span());
+            return Ok(Expr::Value(ident.unraw().to_string()));
+        }
+
+        let lit = input.parse::<Lit>()?;
+        self.lit_span.get_or_insert(lit.span());
+        let s = match &lit {
+            Lit::Str(x) => x.value(),
+            Lit::Char(x) => x.value().to_string(),
+            Lit::Int(x) => x.base10_digits().to_owned(),
+            Lit::Bool(x) => x.value.to_string(),
+            _ => return Err(syn::Error::new(lit.span(), "unsupported literal")),
+        };
+        Ok(Expr::Value(s))
+    }
+
+    fn parse_args(&mut self, input: ParseStream) -> syn::Result<Vec<Expr>> {
+        let mut args = Vec::new();
+        while !input.is_empty() {
+            args.push(self.parse_expr(input)?);
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+        Ok(args)
+    }
+
+    fn parse_macro(&mut self, m: &Macro) -> syn::Result<Expr> {
+        let name = m.path.segments.last().map(|seg| seg.ident.to_string());
+
+        match name.as_deref() {
+            Some("concat") => {
+                let args = (|input: ParseStream| self.parse_args(input)).parse2(m.tokens.clone())?;
+                Ok(Expr::Concat(args))
+            }
+            Some(name @ ("convert_case" | "convert_ascii_case")) => {
+                let unicode = name == "convert_case";
+                let parser = |input: ParseStream| {
+                    let ident = input.parse::<Ident>()?;
+                    let case = AsciiCase::from_name(&ident.to_string())
+                        .ok_or_else(|| syn::Error::new(ident.span(), "unsupported case"))?;
+                    input.parse::<Token![,]>()?;
+                    let x = self.parse_expr(input)?;
+                    input.parse::<Option<Token![,]>>()?;
+                    Ok(Expr::ConvertCase(case, unicode, Box::new(x)))
+                };
+                parser.parse2(m.tokens.clone())
+            }
+            Some("replace") => {
+                let args = (|input: ParseStream| self.parse_args(input)).parse2(m.tokens.clone())?;
+                match <[Expr; 3]>::try_from(args) {
+                    Ok(args) => Ok(Expr::Replace(Box::new(args))),
+                    Err(_) => Err(syn::Error::new_spanned(
+                        m,
+                        "expected three arguments: the input, the pattern and the replacement",
+                    )),
+                }
+            }
+            _ => Err(syn::Error::new_spanned(
+                &m.path,
+                "unsupported macro, expected `concat!`, `convert_case!`, `convert_ascii_case!` or `replace!`",
+            )),
+        }
+    }
+}
+
+pub struct ConstIdent {
+    expr: Expr,
+    span: Span,
+}
+
+impl Parse for ConstIdent {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut parser = ExprParser {
+            ident_span: None,
+            lit_span: None,
+        };
+        let expr = parser.parse_expr(input)?;
+        input.parse::<Option<Token![,]>>()?;
+        let span = parser
+            .ident_span
+            .or(parser.lit_span)
+            .unwrap_or_else(Span::call_site);
+        Ok(Self { expr, span })
+    }
+}
+
+impl ConstIdent {
+    fn to_ident(&self) -> syn::Result<Ident> {
+        let s = self.expr.eval();
+        match syn::parse_str::<Ident>(&s) {
+            Ok(mut ident) => {
+                ident.set_span(self.span);
+                Ok(ident)
+            }
+            Err(_) => {
+                let msg = format!("`{s}` is not a valid identifier");
+                Err(syn::Error::new(self.span, msg))
+            }
+        }
+    }
+
+    pub fn eval(&self) -> TokenStream {
+        match self.to_ident() {
+            Ok(ident) => ident.into_token_stream().into(),
+            Err(e) => e.to_compile_error().into(),
+        }
+    }
+}
+
+/// Returns the arguments if the tokens start with `ident!(...)`.
+fn nested_call(tts: &[TokenTree]) -> Option<&Group> {
+    match tts {
+        [TokenTree::Ident(name), TokenTree::Punct(bang), TokenTree::Group(args), ..]
+            if name == "ident" && bang.as_char() == '!' && args.delimiter() != Delimiter::None =>
+        {
+            Some(args)
+        }
+        _ => None,
+    }
+}
+
+fn contains_nested_call(tokens: &proc_macro2::TokenStream) -> bool {
+    let tts: Vec<TokenTree> = tokens.clone().into_iter().collect();
+    (0..tts.len()).any(|i| match &tts[i] {
+        TokenTree::Group(g) => contains_nested_call(&g.stream()),
+        _ => nested_call(&tts[i..]).is_some(),
+    })
+}
+
+fn expand_nested_calls(tokens: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+    let tts: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut output = proc_macro2::TokenStream::new();
+    let mut i = 0;
+    while i < tts.len() {
+        if let Some(args) = nested_call(&tts[i..]) {
+            let ident = syn::parse2::<ConstIdent>(args.stream())?.to_ident()?;
+            output.extend([TokenTree::Ident(ident)]);
+            i += 3;
+            continue;
+        }
+        match &tts[i] {
+            TokenTree::Group(g) => {
+                let mut group = Group::new(g.delimiter(), expand_nested_calls(g.stream())?);
+                group.set_span(g.span());
+                output.extend([TokenTree::Group(group)]);
+            }
+            tt => output.extend([tt.clone()]),
+        }
+        i += 1;
+    }
+    Ok(output)
+}
+
+/// The tokens with nested `ident!(...)` calls, which are replaced by their identifiers.
+pub struct ConstIdentItems(proc_macro2::TokenStream);
+
+impl ConstIdentItems {
+    pub fn new(tokens: proc_macro2::TokenStream) -> Option<Self> {
+        contains_nested_call(&tokens).then_some(Self(tokens))
+    }
+
+    pub fn eval(self) -> TokenStream {
+        match expand_nested_calls(self.0) {
+            Ok(tokens) => tokens.into(),
+            Err(e) => e.to_compile_error().into(),
+        }
+    }
+}
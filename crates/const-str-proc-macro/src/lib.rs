@@ -15,9 +15,16 @@ macro_rules! proc_error {
     };
 }
 
-mod case;
 mod fmt;
 
+// The case conversion is also compiled in tests, so that its tests run without the `ident` feature.
+#[cfg(any(feature = "ident", test))]
+mod conv_case;
+#[cfg(feature = "ident")]
+mod ident;
+#[cfg(any(feature = "ident", test))]
+mod unicode_case;
+
 #[cfg(feature = "regex")]
 mod regex;
 
@@ -51,11 +58,15 @@ pub fn format_parts(input: TokenStream) -> TokenStream {
     m.eval()
 }
 
-/// Converts a string literal to a specified case.
+/// Returns an identifier built from string literals and identifiers.
+#[cfg(feature = "ident")]
 #[proc_macro]
-pub fn convert_case(input: TokenStream) -> TokenStream {
-    use crate::case::ConvertCase;
-    let m = parse_macro_input!(input as ConvertCase);
+pub fn ident(input: TokenStream) -> TokenStream {
+    use crate::ident::{ConstIdent, ConstIdentItems};
+    if let Some(m) = ConstIdentItems::new(input.clone().into()) {
+        return m.eval();
+    }
+    let m = parse_macro_input!(input as ConstIdent);
     m.eval()
 }
 
@@ -93,3 +104,30 @@ pub fn regex_assert_match(input: TokenStream) -> TokenStream {
     let m = parse_macro_input!(input as RegexAssertMatch);
     m.eval()
 }
+
+/// Returns the capture groups of the first match in the string literal.
+#[cfg(feature = "regex")]
+#[proc_macro]
+pub fn regex_captures(input: TokenStream) -> TokenStream {
+    use crate::regex::RegexCaptures;
+    let m = parse_macro_input!(input as RegexCaptures);
+    m.eval()
+}
+
+/// Replaces all matches of the pattern in the string literal.
+#[cfg(feature = "regex")]
+#[proc_macro]
+pub fn regex_replace(input: TokenStream) -> TokenStream {
+    use crate::regex::RegexReplace;
+    let m = parse_macro_input!(input as RegexReplace);
+    m.eval()
+}
+
+#[doc(hidden)]
+#[cfg(feature = "regex")]
+#[proc_macro]
+pub fn regex_dfa_parts(input: TokenStream) -> TokenStream {
+    use crate::regex::RegexDfaParts;
+    let m = parse_macro_input!(input as RegexDfaParts);
+    m.eval()
+}
@@ -34,3 +34,262 @@ impl RegexAssertMatch {
         TokenStream::new()
     }
 }
+
+pub struct RegexReplace {
+    re: LitStr,
+    text: LitStr,
+    rep: LitStr,
+}
+
+fn parse_lit_str(input: ParseStream, what: &str) -> syn::Result<LitStr> {
+    if input.peek(LitStr) {
+        return input.parse::<LitStr>();
+    }
+    let msg = format!(
+        "expected a string literal as the {what}, \
+        because it is evaluated by a proc macro which cannot see the values of constants"
+    );
+    Err(input.error(msg))
+}
+
+impl Parse for RegexReplace {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let re = parse_lit_str(input, "pattern")?;
+        let _ = input.parse::<Token![,]>()?;
+        let text = parse_lit_str(input, "input")?;
+        let _ = input.parse::<Token![,]>()?;
+        let rep = parse_lit_str(input, "replacement")?;
+        let _ = input.parse::<Option<Token![,]>>()?;
+        Ok(Self { re, text, rep })
+    }
+}
+
+impl RegexReplace {
+    pub fn eval(&self) -> TokenStream {
+        use quote::ToTokens;
+        use regex::Regex;
+
+        let re: Regex = match Regex::new(&self.re.value()) {
+            Ok(re) => re,
+            Err(e) => return proc_error!(self.re, e.to_string()),
+        };
+
+        let text = self.text.value();
+        let rep = self.rep.value();
+        let dst = re.replace_all(&text, rep.as_str());
+
+        let dst_token = LitStr::new(&dst, self.text.span());
+        dst_token.into_token_stream().into()
+    }
+}
+
+pub struct RegexCaptures {
+    re: LitStr,
+    text: LitStr,
+}
+
+impl Parse for RegexCaptures {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let re = parse_lit_str(input, "pattern")?;
+        let _ = input.parse::<Token![,]>()?;
+        let text = parse_lit_str(input, "input")?;
+        let _ = input.parse::<Option<Token![,]>>()?;
+        Ok(Self { re, text })
+    }
+}
+
+/// Marks the capture groups which may not participate in a match.
+fn mark_optional_groups(hir: &regex_syntax::hir::Hir, optional: bool, output: &mut [bool]) {
+    use regex_syntax::hir::HirKind;
+
+    match hir.kind() {
+        HirKind::Empty | HirKind::Literal(_) | HirKind::Class(_) | HirKind::Look(_) => {}
+        HirKind::Repetition(rep) => {
+            mark_optional_groups(&rep.sub, optional || rep.min == 0, output)
+        }
+        HirKind::Capture(cap) => {
+            output[cap.index as usize] = optional;
+            mark_optional_groups(&cap.sub, optional, output);
+        }
+        HirKind::Concat(subs) => {
+            for sub in subs {
+                mark_optional_groups(sub, optional, output);
+            }
+        }
+        HirKind::Alternation(subs) => {
+            for sub in subs {
+                mark_optional_groups(sub, true, output);
+            }
+        }
+    }
+}
+
+impl RegexCaptures {
+    pub fn eval(&self) -> TokenStream {
+        use proc_macro2::TokenStream as TokenStream2;
+        use quote::quote;
+        use regex::Regex;
+        use syn::Ident;
+
+        let re: Regex = match Regex::new(&self.re.value()) {
+            Ok(re) => re,
+            Err(e) => return proc_error!(self.re, e.to_string()),
+        };
+
+        let hir = match regex_syntax::Parser::new().parse(&self.re.value()) {
+            Ok(hir) => hir,
+            Err(e) => return proc_error!(self.re, e.to_string()),
+        };
+        let mut optional = vec![false; re.captures_len()];
+        mark_optional_groups(&hir, false, &mut optional);
+
+        let text = self.text.value();
+
+        let caps = match re.captures(&text) {
+            Some(caps) => caps,
+            None => return proc_error!(self.text, "the string literal does not match the pattern"),
+        };
+
+        // (type, value) of each group
+        let group = |i: usize| -> (TokenStream2, TokenStream2) {
+            let lit = |s: &str| LitStr::new(s, self.text.span());
+            match (optional[i], caps.get(i)) {
+                (false, m) => {
+                    let value = lit(m.map_or("", |m| m.as_str()));
+                    (quote!(&'static str), quote!(#value))
+                }
+                (true, Some(m)) => {
+                    let value = lit(m.as_str());
+                    (quote!(Option<&'static str>), quote!(Some(#value)))
+                }
+                (true, None) => (quote!(Option<&'static str>), quote!(None::<&'static str>)),
+            }
+        };
+
+        let has_names = re.capture_names().any(|name| name.is_some());
+
+        let tokens = if has_names {
+            let mut names: Vec<Ident> = Vec::new();
+            let mut types: Vec<TokenStream2> = Vec::new();
+            let mut values: Vec<TokenStream2> = Vec::new();
+            for (i, name) in re.capture_names().enumerate().skip(1) {
+                let Some(name) = name else {
+                    let msg = format!(
+                        "the group {i} is unnamed, \
+                        name all groups or use `(?:...)` for a non-capturing group"
+                    );
+                    return proc_error!(self.re, msg);
+                };
+                let ident = match syn::parse_str::<Ident>(name) {
+                    Ok(ident) => ident,
+                    Err(_) => {
+                        let msg = format!("the group name `{name}` is not a valid identifier");
+                        return proc_error!(self.re, msg);
+                    }
+                };
+                let (ty, value) = group(i);
+                names.push(ident);
+                types.push(ty);
+                values.push(value);
+            }
+            quote! {{
+                #[derive(Debug, Clone, Copy)]
+                struct Captures {
+                    #(pub #names: #types,)*
+                }
+                Captures { #(#names: #values,)* }
+            }}
+        } else {
+            let values: Vec<TokenStream2> = (1..caps.len()).map(|i| group(i).1).collect();
+            quote! { (#(#values,)*) }
+        };
+
+        tokens.into()
+    }
+}
+
+pub struct RegexDfaParts {
+    re: LitStr,
+}
+
+impl Parse for RegexDfaParts {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let re = input.parse::<LitStr>()?;
+        Ok(Self { re })
+    }
+}
+
+impl RegexDfaParts {
+    pub fn eval(&self) -> TokenStream {
+        use regex_automata::dfa::{dense, Automaton, StartKind};
+        use regex_automata::util::primitives::StateID;
+        use regex_automata::Input;
+
+        use std::collections::HashMap;
+
+        let config = dense::Config::new()
+            .start_kind(StartKind::Unanchored)
+            .minimize(true);
+
+        let dfa = match dense::Builder::new()
+            .configure(config)
+            .build(&self.re.value())
+        {
+            Ok(dfa) => dfa,
+            Err(e) => return proc_error!(self.re, e.to_string()),
+        };
+
+        let start = match dfa.start_state_forward(&Input::new("")) {
+            Ok(id) => id,
+            Err(e) => return proc_error!(self.re, e.to_string()),
+        };
+
+        // one representative byte for each equivalence class
+        let mut classes = [0u8; 256];
+        let mut representatives: Vec<u8> = Vec::new();
+        for b in 0..=255u8 {
+            let class = dfa.byte_classes().get(b);
+            classes[b as usize] = class;
+            if class as usize == representatives.len() {
+                representatives.push(b);
+            }
+        }
+        let stride = representatives.len();
+
+        // renumber the reachable states so that the start state is `0`
+        let mut ids: HashMap<StateID, u32> = HashMap::new();
+        let mut states: Vec<StateID> = Vec::new();
+
+        ids.insert(start, 0);
+        states.push(start);
+
+        let mut trans: Vec<u32> = Vec::new();
+        let mut eoi: Vec<u32> = Vec::new();
+        let mut accept: Vec<bool> = Vec::new();
+
+        let mut i = 0;
+        while i < states.len() {
+            let id = states[i];
+
+            let mut visit = |next: StateID| -> u32 {
+                *ids.entry(next).or_insert_with(|| {
+                    states.push(next);
+                    (states.len() - 1) as u32
+                })
+            };
+
+            for &b in &representatives {
+                trans.push(visit(dfa.next_state(id, b)));
+            }
+            eoi.push(visit(dfa.next_eoi_state(id)));
+            accept.push(dfa.is_match_state(id));
+
+            i += 1;
+        }
+
+        let tokens = quote::quote! {
+            (&[#(#classes),*], #stride, &[#(#trans),*], &[#(#eoi),*], &[#(#accept),*])
+        };
+        tokens.into()
+    }
+}
@@ -0,0 +1,66 @@
+// The tables are generated by `just gen-unicode-case`, see `tools/gen-unicode-case`.
+// They are the same as the tables of `const-str`, so the proc macros convert cases
+// by the same Unicode data as the const implementation.
+
+#[allow(dead_code)] // `FOLD` is only used by `const-str`
+mod tables;
+
+use self::tables::*;
+
+fn find_map<T: Copy>(table: &[(char, T)], ch: char) -> Option<T> {
+    let i = table.binary_search_by_key(&ch, |&(key, _)| key).ok()?;
+    Some(table[i].1)
+}
+
+fn find_range(table: &[(u32, u32)], ch: char) -> bool {
+    let x = ch as u32;
+    let i = table.partition_point(|&(_, end)| end < x);
+    i < table.len() && table[i].0 <= x
+}
+
+fn mapping(single: &[(char, char)], multi: &[(char, [char; 3])], ch: char) -> [char; 3] {
+    if let Some(c) = find_map(single, ch) {
+        return [c, '\0', '\0'];
+    }
+    find_map(multi, ch).unwrap_or([ch, '\0', '\0'])
+}
+
+/// Returns the full lowercase mapping of `ch`, padded with `'\0'`.
+pub fn to_lower(ch: char) -> [char; 3] {
+    mapping(TO_LOWER, TO_LOWER_MULTI, ch)
+}
+
+/// Returns the full uppercase mapping of `ch`, padded with `'\0'`.
+pub fn to_upper(ch: char) -> [char; 3] {
+    mapping(TO_UPPER, TO_UPPER_MULTI, ch)
+}
+
+/// Returns the full titlecase mapping of `ch`, padded with `'\0'`.
+pub fn to_title(ch: char) -> [char; 3] {
+    if let Some(c) = find_map(TO_TITLE, ch) {
+        return [c, '\0', '\0'];
+    }
+    find_map(TO_TITLE_MULTI, ch).unwrap_or_else(|| to_upper(ch))
+}
+
+pub fn is_lowercase(ch: char) -> bool {
+    find_range(LOWERCASE, ch)
+}
+
+/// Returns true if `ch` is uppercase or titlecase.
+pub fn is_uppercase(ch: char) -> bool {
+    find_range(UPPERCASE, ch) || find_range(TITLECASE, ch)
+}
+
+pub fn is_cased(ch: char) -> bool {
+    is_lowercase(ch) || is_uppercase(ch)
+}
+
+pub fn is_case_ignorable(ch: char) -> bool {
+    find_range(CASE_IGNORABLE, ch)
+}
+
+/// Returns true if `ch` can be a part of a word.
+pub fn is_word(ch: char) -> bool {
+    find_range(WORD, ch)
+}
@@ -0,0 +1,1933 @@
+// DO NOT EDIT THIS FILE. It is generated by `just gen-unicode-case`.
+//
+// Unicode version: 17.0.0, the same as `char::UNICODE_VERSION` of the generating toolchain.
+//
+// + full case mappings (UnicodeData.txt and the unconditional part of SpecialCasing.txt)
+// + simple case folding (the C and S entries of CaseFolding.txt)
+// + the derived properties Lowercase, Uppercase and Case_Ignorable
+// + General_Category Lt
+// + the word characters (alphabetic, numeric, cased or combining marks)
+
+#[cfg(test)]
+pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+#[rustfmt::skip]
+pub const TO_LOWER: &[(char, char)] = &[
+    ('A', 'a'), ('B', 'b'), ('C', 'c'), ('D', 'd'),
+    ('E', 'e'), ('F', 'f'), ('G', 'g'), ('H', 'h'),
+    ('I', 'i'), ('J', 'j'), ('K', 'k'), ('L', 'l'),
+    ('M', 'm'), ('N', 'n'), ('O', 'o'), ('P', 'p'),
+    ('Q', 'q'), ('R', 'r'), ('S', 's'), ('T', 't'),
+    ('U', 'u'), ('V', 'v'), ('W', 'w'), ('X', 'x'),
+    ('Y', 'y'), ('Z', 'z'), ('\u{c0}', '\u{e0}'), ('\u{c1}', '\u{e1}'),
+    ('\u{c2}', '\u{e2}'), ('\u{c3}', '\u{e3}'), ('\u{c4}', '\u{e4}'), ('\u{c5}', '\u{e5}'),
+    ('\u{c6}', '\u{e6}'), ('\u{c7}', '\u{e7}'), ('\u{c8}', '\u{e8}'), ('\u{c9}', '\u{e9}'),
+    ('\u{ca}', '\u{ea}'), ('\u{cb}', '\u{eb}'), ('\u{cc}', '\u{ec}'), ('\u{cd}', '\u{ed}'),
+    ('\u{ce}', '\u{ee}'), ('\u{cf}', '\u{ef}'), ('\u{d0}', '\u{f0}'), ('\u{d1}', '\u{f1}'),
+    ('\u{d2}', '\u{f2}'), ('\u{d3}', '\u{f3}'), ('\u{d4}', '\u{f4}'), ('\u{d5}', '\u{f5}'),
+    ('\u{d6}', '\u{f6}'), ('\u{d8}', '\u{f8}'), ('\u{d9}', '\u{f9}'), ('\u{da}', '\u{fa}'),
+    ('\u{db}', '\u{fb}'), ('\u{dc}', '\u{fc}'), ('\u{dd}', '\u{fd}'), ('\u{de}', '\u{fe}'),
+    ('\u{100}', '\u{101}'), ('\u{102}', '\u{103}'), ('\u{104}', '\u{105}'), ('\u{106}', '\u{107}'),
+    ('\u{108}', '\u{109}'), ('\u{10a}', '\u{10b}'), ('\u{10c}', '\u{10d}'), ('\u{10e}', '\u{10f}'),
+    ('\u{110}', '\u{111}'), ('\u{112}', '\u{113}'), ('\u{114}', '\u{115}'), ('\u{116}', '\u{117}'),
+    ('\u{118}', '\u{119}'), ('\u{11a}', '\u{11b}'), ('\u{11c}', '\u{11d}'), ('\u{11e}', '\u{11f}'),
+    ('\u{120}', '\u{121}'), ('\u{122}', '\u{123}'), ('\u{124}', '\u{125}'), ('\u{126}', '\u{127}'),
+    ('\u{128}', '\u{129}'), ('\u{12a}', '\u{12b}'), ('\u{12c}', '\u{12d}'), ('\u{12e}', '\u{12f}'),
+    ('\u{132}', '\u{133}'), ('\u{134}', '\u{135}'), ('\u{136}', '\u{137}'), ('\u{139}', '\u{13a}'),
+    ('\u{13b}', '\u{13c}'), ('\u{13d}', '\u{13e}'), ('\u{13f}', '\u{140}'), ('\u{141}', '\u{142}'),
+    ('\u{143}', '\u{144}'), ('\u{145}', '\u{146}'), ('\u{147}', '\u{148}'), ('\u{14a}', '\u{14b}'),
+    ('\u{14c}', '\u{14d}'), ('\u{14e}', '\u{14f}'), ('\u{150}', '\u{151}'), ('\u{152}', '\u{153}'),
+    ('\u{154}', '\u{155}'), ('\u{156}', '\u{157}'), ('\u{158}', '\u{159}'), ('\u{15a}', '\u{15b}'),
+    ('\u{15c}', '\u{15d}'), ('\u{15e}', '\u{15f}'), ('\u{160}', '\u{161}'), ('\u{162}', '\u{163}'),
+    ('\u{164}', '\u{165}'), ('\u{166}', '\u{167}'), ('\u{168}', '\u{169}'), ('\u{16a}', '\u{16b}'),
+    ('\u{16c}', '\u{16d}'), ('\u{16e}', '\u{16f}'), ('\u{170}', '\u{171}'), ('\u{172}', '\u{173}'),
+    ('\u{174}', '\u{175}'), ('\u{176}', '\u{177}'), ('\u{178}', '\u{ff}'), ('\u{179}', '\u{17a}'),
+    ('\u{17b}', '\u{17c}'), ('\u{17d}', '\u{17e}'), ('\u{181}', '\u{253}'), ('\u{182}', '\u{183}'),
+    ('\u{184}', '\u{185}'), ('\u{186}', '\u{254}'), ('\u{187}', '\u{188}'), ('\u{189}', '\u{256}'),
+    ('\u{18a}', '\u{257}'), ('\u{18b}', '\u{18c}'), ('\u{18e}', '\u{1dd}'), ('\u{18f}', '\u{259}'),
+    ('\u{190}', '\u{25b}'), ('\u{191}', '\u{192}'), ('\u{193}', '\u{260}'), ('\u{194}', '\u{263}'),
+    ('\u{196}', '\u{269}'), ('\u{197}', '\u{268}'), ('\u{198}', '\u{199}'), ('\u{19c}', '\u{26f}'),
+    ('\u{19d}', '\u{272}'), ('\u{19f}', '\u{275}'), ('\u{1a0}', '\u{1a1}'), ('\u{1a2}', '\u{1a3}'),
+    ('\u{1a4}', '\u{1a5}'), ('\u{1a6}', '\u{280}'), ('\u{1a7}', '\u{1a8}'), ('\u{1a9}', '\u{283}'),
+    ('\u{1ac}', '\u{1ad}'), ('\u{1ae}', '\u{288}'), ('\u{1af}', '\u{1b0}'), ('\u{1b1}', '\u{28a}'),
+    ('\u{1b2}', '\u{28b}'), ('\u{1b3}', '\u{1b4}'), ('\u{1b5}', '\u{1b6}'), ('\u{1b7}', '\u{292}'),
+    ('\u{1b8}', '\u{1b9}'), ('\u{1bc}', '\u{1bd}'), ('\u{1c4}', '\u{1c6}'), ('\u{1c5}', '\u{1c6}'),
+    ('\u{1c7}', '\u{1c9}'), ('\u{1c8}', '\u{1c9}'), ('\u{1ca}', '\u{1cc}'), ('\u{1cb}', '\u{1cc}'),
+    ('\u{1cd}', '\u{1ce}'), ('\u{1cf}', '\u{1d0}'), ('\u{1d1}', '\u{1d2}'), ('\u{1d3}', '\u{1d4}'),
+    ('\u{1d5}', '\u{1d6}'), ('\u{1d7}', '\u{1d8}'), ('\u{1d9}', '\u{1da}'), ('\u{1db}', '\u{1dc}'),
+    ('\u{1de}', '\u{1df}'), ('\u{1e0}', '\u{1e1}'), ('\u{1e2}', '\u{1e3}'), ('\u{1e4}', '\u{1e5}'),
+    ('\u{1e6}', '\u{1e7}'), ('\u{1e8}', '\u{1e9}'), ('\u{1ea}', '\u{1eb}'), ('\u{1ec}', '\u{1ed}'),
+    ('\u{1ee}', '\u{1ef}'), ('\u{1f1}', '\u{1f3}'), ('\u{1f2}', '\u{1f3}'), ('\u{1f4}', '\u{1f5}'),
+    ('\u{1f6}', '\u{195}'), ('\u{1f7}', '\u{1bf}'), ('\u{1f8}', '\u{1f9}'), ('\u{1fa}', '\u{1fb}'),
+    ('\u{1fc}', '\u{1fd}'), ('\u{1fe}', '\u{1ff}'), ('\u{200}', '\u{201}'), ('\u{202}', '\u{203}'),
+    ('\u{204}', '\u{205}'), ('\u{206}', '\u{207}'), ('\u{208}', '\u{209}'), ('\u{20a}', '\u{20b}'),
+    ('\u{20c}', '\u{20d}'), ('\u{20e}', '\u{20f}'), ('\u{210}', '\u{211}'), ('\u{212}', '\u{213}'),
+    ('\u{214}', '\u{215}'), ('\u{216}', '\u{217}'), ('\u{218}', '\u{219}'), ('\u{21a}', '\u{21b}'),
+    ('\u{21c}', '\u{21d}'), ('\u{21e}', '\u{21f}'), ('\u{220}', '\u{19e}'), ('\u{222}', '\u{223}'),
+    ('\u{224}', '\u{225}'), ('\u{226}', '\u{227}'), ('\u{228}', '\u{229}'), ('\u{22a}', '\u{22b}'),
+    ('\u{22c}', '\u{22d}'), ('\u{22e}', '\u{22f}'), ('\u{230}', '\u{231}'), ('\u{232}', '\u{233}'),
+    ('\u{23a}', '\u{2c65}'), ('\u{23b}', '\u{23c}'), ('\u{23d}', '\u{19a}'), ('\u{23e}', '\u{2c66}'),
+    ('\u{241}', '\u{242}'), ('\u{243}', '\u{180}'), ('\u{244}', '\u{289}'), ('\u{245}', '\u{28c}'),
+    ('\u{246}', '\u{247}'), ('\u{248}', '\u{249}'), ('\u{24a}', '\u{24b}'), ('\u{24c}', '\u{24d}'),
+    ('\u{24e}', '\u{24f}'), ('\u{370}', '\u{371}'), ('\u{372}', '\u{373}'), ('\u{376}', '\u{377}'),
+    ('\u{37f}', '\u{3f3}'), ('\u{386}', '\u{3ac}'), ('\u{388}', '\u{3ad}'), ('\u{389}', '\u{3ae}'),
+    ('\u{38a}', '\u{3af}'), ('\u{38c}', '\u{3cc}'), ('\u{38e}', '\u{3cd}'), ('\u{38f}', '\u{3ce}'),
+    ('\u{391}', '\u{3b1}'), ('\u{392}', '\u{3b2}'), ('\u{393}', '\u{3b3}'), ('\u{394}', '\u{3b4}'),
+    ('\u{395}', '\u{3b5}'), ('\u{396}', '\u{3b6}'), ('\u{397}', '\u{3b7}'), ('\u{398}', '\u{3b8}'),
+    ('\u{399}', '\u{3b9}'), ('\u{39a}', '\u{3ba}'), ('\u{39b}', '\u{3bb}'), ('\u{39c}', '\u{3bc}'),
+    ('\u{39d}', '\u{3bd}'), ('\u{39e}', '\u{3be}'), ('\u{39f}', '\u{3bf}'), ('\u{3a0}', '\u{3c0}'),
+    ('\u{3a1}', '\u{3c1}'), ('\u{3a3}', '\u{3c3}'), ('\u{3a4}', '\u{3c4}'), ('\u{3a5}', '\u{3c5}'),
+    ('\u{3a6}', '\u{3c6}'), ('\u{3a7}', '\u{3c7}'), ('\u{3a8}', '\u{3c8}'), ('\u{3a9}', '\u{3c9}'),
+    ('\u{3aa}', '\u{3ca}'), ('\u{3ab}', '\u{3cb}'), ('\u{3cf}', '\u{3d7}'), ('\u{3d8}', '\u{3d9}'),
+    ('\u{3da}', '\u{3db}'), ('\u{3dc}', '\u{3dd}'), ('\u{3de}', '\u{3df}'), ('\u{3e0}', '\u{3e1}'),
+    ('\u{3e2}', '\u{3e3}'), ('\u{3e4}', '\u{3e5}'), ('\u{3e6}', '\u{3e7}'), ('\u{3e8}', '\u{3e9}'),
+    ('\u{3ea}', '\u{3eb}'), ('\u{3ec}', '\u{3ed}'), ('\u{3ee}', '\u{3ef}'), ('\u{3f4}', '\u{3b8}'),
+    ('\u{3f7}', '\u{3f8}'), ('\u{3f9}', '\u{3f2}'), ('\u{3fa}', '\u{3fb}'), ('\u{3fd}', '\u{37b}'),
+    ('\u{3fe}', '\u{37c}'), ('\u{3ff}', '\u{37d}'), ('\u{400}', '\u{450}'), ('\u{401}', '\u{451}'),
+    ('\u{402}', '\u{452}'), ('\u{403}', '\u{453}'), ('\u{404}', '\u{454}'), ('\u{405}', '\u{455}'),
+    ('\u{406}', '\u{456}'), ('\u{407}', '\u{457}'), ('\u{408}', '\u{458}'), ('\u{409}', '\u{459}'),
+    ('\u{40a}', '\u{45a}'), ('\u{40b}', '\u{45b}'), ('\u{40c}', '\u{45c}'), ('\u{40d}', '\u{45d}'),
+    ('\u{40e}', '\u{45e}'), ('\u{40f}', '\u{45f}'), ('\u{410}', '\u{430}'), ('\u{411}', '\u{431}'),
+    ('\u{412}', '\u{432}'), ('\u{413}', '\u{433}'), ('\u{414}', '\u{434}'), ('\u{415}', '\u{435}'),
+    ('\u{416}', '\u{436}'), ('\u{417}', '\u{437}'), ('\u{418}', '\u{438}'), ('\u{419}', '\u{439}'),
+    ('\u{41a}', '\u{43a}'), ('\u{41b}', '\u{43b}'), ('\u{41c}', '\u{43c}'), ('\u{41d}', '\u{43d}'),
+    ('\u{41e}', '\u{43e}'), ('\u{41f}', '\u{43f}'), ('\u{420}', '\u{440}'), ('\u{421}', '\u{441}'),
+    ('\u{422}', '\u{442}'), ('\u{423}', '\u{443}'), ('\u{424}', '\u{444}'), ('\u{425}', '\u{445}'),
+    ('\u{426}', '\u{446}'), ('\u{427}', '\u{447}'), ('\u{428}', '\u{448}'), ('\u{429}', '\u{449}'),
+    ('\u{42a}', '\u{44a}'), ('\u{42b}', '\u{44b}'), ('\u{42c}', '\u{44c}'), ('\u{42d}', '\u{44d}'),
+    ('\u{42e}', '\u{44e}'), ('\u{42f}', '\u{44f}'), ('\u{460}', '\u{461}'), ('\u{462}', '\u{463}'),
+    ('\u{464}', '\u{465}'), ('\u{466}', '\u{467}'), ('\u{468}', '\u{469}'), ('\u{46a}', '\u{46b}'),
+    ('\u{46c}', '\u{46d}'), ('\u{46e}', '\u{46f}'), ('\u{470}', '\u{471}'), ('\u{472}', '\u{473}'),
+    ('\u{474}', '\u{475}'), ('\u{476}', '\u{477}'), ('\u{478}', '\u{479}'), ('\u{47a}', '\u{47b}'),
+    ('\u{47c}', '\u{47d}'), ('\u{47e}', '\u{47f}'), ('\u{480}', '\u{481}'), ('\u{48a}', '\u{48b}'),
+    ('\u{48c}', '\u{48d}'), ('\u{48e}', '\u{48f}'), ('\u{490}', '\u{491}'), ('\u{492}', '\u{493}'),
+    ('\u{494}', '\u{495}'), ('\u{496}', '\u{497}'), ('\u{498}', '\u{499}'), ('\u{49a}', '\u{49b}'),
+    ('\u{49c}', '\u{49d}'), ('\u{49e}', '\u{49f}'), ('\u{4a0}', '\u{4a1}'), ('\u{4a2}', '\u{4a3}'),
+    ('\u{4a4}', '\u{4a5}'), ('\u{4a6}', '\u{4a7}'), ('\u{4a8}', '\u{4a9}'), ('\u{4aa}', '\u{4ab}'),
+    ('\u{4ac}', '\u{4ad}'), ('\u{4ae}', '\u{4af}'), ('\u{4b0}', '\u{4b1}'), ('\u{4b2}', '\u{4b3}'),
+    ('\u{4b4}', '\u{4b5}'), ('\u{4b6}', '\u{4b7}'), ('\u{4b8}', '\u{4b9}'), ('\u{4ba}', '\u{4bb}'),
+    ('\u{4bc}', '\u{4bd}'), ('\u{4be}', '\u{4bf}'), ('\u{4c0}', '\u{4cf}'), ('\u{4c1}', '\u{4c2}'),
+    ('\u{4c3}', '\u{4c4}'), ('\u{4c5}', '\u{4c6}'), ('\u{4c7}', '\u{4c8}'), ('\u{4c9}', '\u{4ca}'),
+    ('\u{4cb}', '\u{4cc}'), ('\u{4cd}', '\u{4ce}'), ('\u{4d0}', '\u{4d1}'), ('\u{4d2}', '\u{4d3}'),
+    ('\u{4d4}', '\u{4d5}'), ('\u{4d6}', '\u{4d7}'), ('\u{4d8}', '\u{4d9}'), ('\u{4da}', '\u{4db}'),
+    ('\u{4dc}', '\u{4dd}'), ('\u{4de}', '\u{4df}'), ('\u{4e0}', '\u{4e1}'), ('\u{4e2}', '\u{4e3}'),
+    ('\u{4e4}', '\u{4e5}'), ('\u{4e6}', '\u{4e7}'), ('\u{4e8}', '\u{4e9}'), ('\u{4ea}', '\u{4eb}'),
+    ('\u{4ec}', '\u{4ed}'), ('\u{4ee}', '\u{4ef}'), ('\u{4f0}', '\u{4f1}'), ('\u{4f2}', '\u{4f3}'),
+    ('\u{4f4}', '\u{4f5}'), ('\u{4f6}', '\u{4f7}'), ('\u{4f8}', '\u{4f9}'), ('\u{4fa}', '\u{4fb}'),
+    ('\u{4fc}', '\u{4fd}'), ('\u{4fe}', '\u{4ff}'), ('\u{500}', '\u{501}'), ('\u{502}', '\u{503}'),
+    ('\u{504}', '\u{505}'), ('\u{506}', '\u{507}'), ('\u{508}', '\u{509}'), ('\u{50a}', '\u{50b}'),
+    ('\u{50c}', '\u{50d}'), ('\u{50e}', '\u{50f}'), ('\u{510}', '\u{511}'), ('\u{512}', '\u{513}'),
+    ('\u{514}', '\u{515}'), ('\u{516}', '\u{517}'), ('\u{518}', '\u{519}'), ('\u{51a}', '\u{51b}'),
+    ('\u{51c}', '\u{51d}'), ('\u{51e}', '\u{51f}'), ('\u{520}', '\u{521}'), ('\u{522}', '\u{523}'),
+    ('\u{524}', '\u{525}'), ('\u{526}', '\u{527}'), ('\u{528}', '\u{529}'), ('\u{52a}', '\u{52b}'),
+    ('\u{52c}', '\u{52d}'), ('\u{52e}', '\u{52f}'), ('\u{531}', '\u{561}'), ('\u{532}', '\u{562}'),
+    ('\u{533}', '\u{563}'), ('\u{534}', '\u{564}'), ('\u{535}', '\u{565}'), ('\u{536}', '\u{566}'),
+    ('\u{537}', '\u{567}'), ('\u{538}', '\u{568}'), ('\u{539}', '\u{569}'), ('\u{53a}', '\u{56a}'),
+    ('\u{53b}', '\u{56b}'), ('\u{53c}', '\u{56c}'), ('\u{53d}', '\u{56d}'), ('\u{53e}', '\u{56e}'),
+    ('\u{53f}', '\u{56f}'), ('\u{540}', '\u{570}'), ('\u{541}', '\u{571}'), ('\u{542}', '\u{572}'),
+    ('\u{543}', '\u{573}'), ('\u{544}', '\u{574}'), ('\u{545}', '\u{575}'), ('\u{546}', '\u{576}'),
+    ('\u{547}', '\u{577}'), ('\u{548}', '\u{578}'), ('\u{549}', '\u{579}'), ('\u{54a}', '\u{57a}'),
+    ('\u{54b}', '\u{57b}'), ('\u{54c}', '\u{57c}'), ('\u{54d}', '\u{57d}'), ('\u{54e}', '\u{57e}'),
+    ('\u{54f}', '\u{57f}'), ('\u{550}', '\u{580}'), ('\u{551}', '\u{581}'), ('\u{552}', '\u{582}'),
+    ('\u{553}', '\u{583}'), ('\u{554}', '\u{584}'), ('\u{555}', '\u{585}'), ('\u{556}', '\u{586}'),
+    ('\u{10a0}', '\u{2d00}'), ('\u{10a1}', '\u{2d01}'), ('\u{10a2}', '\u{2d02}'), ('\u{10a3}', '\u{2d03}'),
+    ('\u{10a4}', '\u{2d04}'), ('\u{10a5}', '\u{2d05}'), ('\u{10a6}', '\u{2d06}'), ('\u{10a7}', '\u{2d07}'),
+    ('\u{10a8}', '\u{2d08}'), ('\u{10a9}', '\u{2d09}'), ('\u{10aa}', '\u{2d0a}'), ('\u{10ab}', '\u{2d0b}'),
+    ('\u{10ac}', '\u{2d0c}'), ('\u{10ad}', '\u{2d0d}'), ('\u{10ae}', '\u{2d0e}'), ('\u{10af}', '\u{2d0f}'),
+    ('\u{10b0}', '\u{2d10}'), ('\u{10b1}', '\u{2d11}'), ('\u{10b2}', '\u{2d12}'), ('\u{10b3}', '\u{2d13}'),
+    ('\u{10b4}', '\u{2d14}'), ('\u{10b5}', '\u{2d15}'), ('\u{10b6}', '\u{2d16}'), ('\u{10b7}', '\u{2d17}'),
+    ('\u{10b8}', '\u{2d18}'), ('\u{10b9}', '\u{2d19}'), ('\u{10ba}', '\u{2d1a}'), ('\u{10bb}', '\u{2d1b}'),
+    ('\u{10bc}', '\u{2d1c}'), ('\u{10bd}', '\u{2d1d}'), ('\u{10be}', '\u{2d1e}'), ('\u{10bf}', '\u{2d1f}'),
+    ('\u{10c0}', '\u{2d20}'), ('\u{10c1}', '\u{2d21}'), ('\u{10c2}', '\u{2d22}'), ('\u{10c3}', '\u{2d23}'),
+    ('\u{10c4}', '\u{2d24}'), ('\u{10c5}', '\u{2d25}'), ('\u{10c7}', '\u{2d27}'), ('\u{10cd}', '\u{2d2d}'),
+    ('\u{13a0}', '\u{ab70}'), ('\u{13a1}', '\u{ab71}'), ('\u{13a2}', '\u{ab72}'), ('\u{13a3}', '\u{ab73}'),
+    ('\u{13a4}', '\u{ab74}'), ('\u{13a5}', '\u{ab75}'), ('\u{13a6}', '\u{ab76}'), ('\u{13a7}', '\u{ab77}'),
+    ('\u{13a8}', '\u{ab78}'), ('\u{13a9}', '\u{ab79}'), ('\u{13aa}', '\u{ab7a}'), ('\u{13ab}', '\u{ab7b}'),
+    ('\u{13ac}', '\u{ab7c}'), ('\u{13ad}', '\u{ab7d}'), ('\u{13ae}', '\u{ab7e}'), ('\u{13af}', '\u{ab7f}'),
+    ('\u{13b0}', '\u{ab80}'), ('\u{13b1}', '\u{ab81}'), ('\u{13b2}', '\u{ab82}'), ('\u{13b3}', '\u{ab83}'),
+    ('\u{13b4}', '\u{ab84}'), ('\u{13b5}', '\u{ab85}'), ('\u{13b6}', '\u{ab86}'), ('\u{13b7}', '\u{ab87}'),
+    ('\u{13b8}', '\u{ab88}'), ('\u{13b9}', '\u{ab89}'), ('\u{13ba}', '\u{ab8a}'), ('\u{13bb}', '\u{ab8b}'),
+    ('\u{13bc}', '\u{ab8c}'), ('\u{13bd}', '\u{ab8d}'), ('\u{13be}', '\u{ab8e}'), ('\u{13bf}', '\u{ab8f}'),
+    ('\u{13c0}', '\u{ab90}'), ('\u{13c1}', '\u{ab91}'), ('\u{13c2}', '\u{ab92}'), ('\u{13c3}', '\u{ab93}'),
+    ('\u{13c4}', '\u{ab94}'), ('\u{13c5}', '\u{ab95}'), ('\u{13c6}', '\u{ab96}'), ('\u{13c7}', '\u{ab97}'),
+    ('\u{13c8}', '\u{ab98}'), ('\u{13c9}', '\u{ab99}'), ('\u{13ca}', '\u{ab9a}'), ('\u{13cb}', '\u{ab9b}'),
+    ('\u{13cc}', '\u{ab9c}'), ('\u{13cd}', '\u{ab9d}'), ('\u{13ce}', '\u{ab9e}'), ('\u{13cf}', '\u{ab9f}'),
+    ('\u{13d0}', '\u{aba0}'), ('\u{13d1}', '\u{aba1}'), ('\u{13d2}', '\u{aba2}'), ('\u{13d3}', '\u{aba3}'),
+    ('\u{13d4}', '\u{aba4}'), ('\u{13d5}', '\u{aba5}'), ('\u{13d6}', '\u{aba6}'), ('\u{13d7}', '\u{aba7}'),
+    ('\u{13d8}', '\u{aba8}'), ('\u{13d9}', '\u{aba9}'), ('\u{13da}', '\u{abaa}'), ('\u{13db}', '\u{abab}'),
+    ('\u{13dc}', '\u{abac}'), ('\u{13dd}', '\u{abad}'), ('\u{13de}', '\u{abae}'), ('\u{13df}', '\u{abaf}'),
+    ('\u{13e0}', '\u{abb0}'), ('\u{13e1}', '\u{abb1}'), ('\u{13e2}', '\u{abb2}'), ('\u{13e3}', '\u{abb3}'),
+    ('\u{13e4}', '\u{abb4}'), ('\u{13e5}', '\u{abb5}'), ('\u{13e6}', '\u{abb6}'), ('\u{13e7}', '\u{abb7}'),
+    ('\u{13e8}', '\u{abb8}'), ('\u{13e9}', '\u{abb9}'), ('\u{13ea}', '\u{abba}'), ('\u{13eb}', '\u{abbb}'),
+    ('\u{13ec}', '\u{abbc}'), ('\u{13ed}', '\u{abbd}'), ('\u{13ee}', '\u{abbe}'), ('\u{13ef}', '\u{abbf}'),
+    ('\u{13f0}', '\u{13f8}'), ('\u{13f1}', '\u{13f9}'), ('\u{13f2}', '\u{13fa}'), ('\u{13f3}', '\u{13fb}'),
+    ('\u{13f4}', '\u{13fc}'), ('\u{13f5}', '\u{13fd}'), ('\u{1c89}', '\u{1c8a}'), ('\u{1c90}', '\u{10d0}'),
+    ('\u{1c91}', '\u{10d1}'), ('\u{1c92}', '\u{10d2}'), ('\u{1c93}', '\u{10d3}'), ('\u{1c94}', '\u{10d4}'),
+    ('\u{1c95}', '\u{10d5}'), ('\u{1c96}', '\u{10d6}'), ('\u{1c97}', '\u{10d7}'), ('\u{1c98}', '\u{10d8}'),
+    ('\u{1c99}', '\u{10d9}'), ('\u{1c9a}', '\u{10da}'), ('\u{1c9b}', '\u{10db}'), ('\u{1c9c}', '\u{10dc}'),
+    ('\u{1c9d}', '\u{10dd}'), ('\u{1c9e}', '\u{10de}'), ('\u{1c9f}', '\u{10df}'), ('\u{1ca0}', '\u{10e0}'),
+    ('\u{1ca1}', '\u{10e1}'), ('\u{1ca2}', '\u{10e2}'), ('\u{1ca3}', '\u{10e3}'), ('\u{1ca4}', '\u{10e4}'),
+    ('\u{1ca5}', '\u{10e5}'), ('\u{1ca6}', '\u{10e6}'), ('\u{1ca7}', '\u{10e7}'), ('\u{1ca8}', '\u{10e8}'),
+    ('\u{1ca9}', '\u{10e9}'), ('\u{1caa}', '\u{10ea}'), ('\u{1cab}', '\u{10eb}'), ('\u{1cac}', '\u{10ec}'),
+    ('\u{1cad}', '\u{10ed}'), ('\u{1cae}', '\u{10ee}'), ('\u{1caf}', '\u{10ef}'), ('\u{1cb0}', '\u{10f0}'),
+    ('\u{1cb1}', '\u{10f1}'), ('\u{1cb2}', '\u{10f2}'), ('\u{1cb3}', '\u{10f3}'), ('\u{1cb4}', '\u{10f4}'),
+    ('\u{1cb5}', '\u{10f5}'), ('\u{1cb6}', '\u{10f6}'), ('\u{1cb7}', '\u{10f7}'), ('\u{1cb8}', '\u{10f8}'),
+    ('\u{1cb9}', '\u{10f9}'), ('\u{1cba}', '\u{10fa}'), ('\u{1cbd}', '\u{10fd}'), ('\u{1cbe}', '\u{10fe}'),
+    ('\u{1cbf}', '\u{10ff}'), ('\u{1e00}', '\u{1e01}'), ('\u{1e02}', '\u{1e03}'), ('\u{1e04}', '\u{1e05}'),
+    ('\u{1e06}', '\u{1e07}'), ('\u{1e08}', '\u{1e09}'), ('\u{1e0a}', '\u{1e0b}'), ('\u{1e0c}', '\u{1e0d}'),
+    ('\u{1e0e}', '\u{1e0f}'), ('\u{1e10}', '\u{1e11}'), ('\u{1e12}', '\u{1e13}'), ('\u{1e14}', '\u{1e15}'),
+    ('\u{1e16}', '\u{1e17}'), ('\u{1e18}', '\u{1e19}'), ('\u{1e1a}', '\u{1e1b}'), ('\u{1e1c}', '\u{1e1d}'),
+    ('\u{1e1e}', '\u{1e1f}'), ('\u{1e20}', '\u{1e21}'), ('\u{1e22}', '\u{1e23}'), ('\u{1e24}', '\u{1e25}'),
+    ('\u{1e26}', '\u{1e27}'), ('\u{1e28}', '\u{1e29}'), ('\u{1e2a}', '\u{1e2b}'), ('\u{1e2c}', '\u{1e2d}'),
+    ('\u{1e2e}', '\u{1e2f}'), ('\u{1e30}', '\u{1e31}'), ('\u{1e32}', '\u{1e33}'), ('\u{1e34}', '\u{1e35}'),
+    ('\u{1e36}', '\u{1e37}'), ('\u{1e38}', '\u{1e39}'), ('\u{1e3a}', '\u{1e3b}'), ('\u{1e3c}', '\u{1e3d}'),
+    ('\u{1e3e}', '\u{1e3f}'), ('\u{1e40}', '\u{1e41}'), ('\u{1e42}', '\u{1e43}'), ('\u{1e44}', '\u{1e45}'),
+    ('\u{1e46}', '\u{1e47}'), ('\u{1e48}', '\u{1e49}'), ('\u{1e4a}', '\u{1e4b}'), ('\u{1e4c}', '\u{1e4d}'),
+    ('\u{1e4e}', '\u{1e4f}'), ('\u{1e50}', '\u{1e51}'), ('\u{1e52}', '\u{1e53}'), ('\u{1e54}', '\u{1e55}'),
+    ('\u{1e56}', '\u{1e57}'), ('\u{1e58}', '\u{1e59}'), ('\u{1e5a}', '\u{1e5b}'), ('\u{1e5c}', '\u{1e5d}'),
+    ('\u{1e5e}', '\u{1e5f}'), ('\u{1e60}', '\u{1e61}'), ('\u{1e62}', '\u{1e63}'), ('\u{1e64}', '\u{1e65}'),
+    ('\u{1e66}', '\u{1e67}'), ('\u{1e68}', '\u{1e69}'), ('\u{1e6a}', '\u{1e6b}'), ('\u{1e6c}', '\u{1e6d}'),
+    ('\u{1e6e}', '\u{1e6f}'), ('\u{1e70}', '\u{1e71}'), ('\u{1e72}', '\u{1e73}'), ('\u{1e74}', '\u{1e75}'),
+    ('\u{1e76}', '\u{1e77}'), ('\u{1e78}', '\u{1e79}'), ('\u{1e7a}', '\u{1e7b}'), ('\u{1e7c}', '\u{1e7d}'),
+    ('\u{1e7e}', '\u{1e7f}'), ('\u{1e80}', '\u{1e81}'), ('\u{1e82}', '\u{1e83}'), ('\u{1e84}', '\u{1e85}'),
+    ('\u{1e86}', '\u{1e87}'), ('\u{1e88}', '\u{1e89}'), ('\u{1e8a}', '\u{1e8b}'), ('\u{1e8c}', '\u{1e8d}'),
+    ('\u{1e8e}', '\u{1e8f}'), ('\u{1e90}', '\u{1e91}'), ('\u{1e92}', '\u{1e93}'), ('\u{1e94}', '\u{1e95}'),
+    ('\u{1e9e}', '\u{df}'), ('\u{1ea0}', '\u{1ea1}'), ('\u{1ea2}', '\u{1ea3}'), ('\u{1ea4}', '\u{1ea5}'),
+    ('\u{1ea6}', '\u{1ea7}'), ('\u{1ea8}', '\u{1ea9}'), ('\u{1eaa}', '\u{1eab}'), ('\u{1eac}', '\u{1ead}'),
+    ('\u{1eae}', '\u{1eaf}'), ('\u{1eb0}', '\u{1eb1}'), ('\u{1eb2}', '\u{1eb3}'), ('\u{1eb4}', '\u{1eb5}'),
+    ('\u{1eb6}', '\u{1eb7}'), ('\u{1eb8}', '\u{1eb9}'), ('\u{1eba}', '\u{1ebb}'), ('\u{1ebc}', '\u{1ebd}'),
+    ('\u{1ebe}', '\u{1ebf}'), ('\u{1ec0}', '\u{1ec1}'), ('\u{1ec2}', '\u{1ec3}'), ('\u{1ec4}', '\u{1ec5}'),
+    ('\u{1ec6}', '\u{1ec7}'), ('\u{1ec8}', '\u{1ec9}'), ('\u{1eca}', '\u{1ecb}'), ('\u{1ecc}', '\u{1ecd}'),
+    ('\u{1ece}', '\u{1ecf}'), ('\u{1ed0}', '\u{1ed1}'), ('\u{1ed2}', '\u{1ed3}'), ('\u{1ed4}', '\u{1ed5}'),
+    ('\u{1ed6}', '\u{1ed7}'), ('\u{1ed8}', '\u{1ed9}'), ('\u{1eda}', '\u{1edb}'), ('\u{1edc}', '\u{1edd}'),
+    ('\u{1ede}', '\u{1edf}'), ('\u{1ee0}', '\u{1ee1}'), ('\u{1ee2}', '\u{1ee3}'), ('\u{1ee4}', '\u{1ee5}'),
+    ('\u{1ee6}', '\u{1ee7}'), ('\u{1ee8}', '\u{1ee9}'), ('\u{1eea}', '\u{1eeb}'), ('\u{1eec}', '\u{1eed}'),
+    ('\u{1eee}', '\u{1eef}'), ('\u{1ef0}', '\u{1ef1}'), ('\u{1ef2}', '\u{1ef3}'), ('\u{1ef4}', '\u{1ef5}'),
+    ('\u{1ef6}', '\u{1ef7}'), ('\u{1ef8}', '\u{1ef9}'), ('\u{1efa}', '\u{1efb}'), ('\u{1efc}', '\u{1efd}'),
+    ('\u{1efe}', '\u{1eff}'), ('\u{1f08}', '\u{1f00}'), ('\u{1f09}', '\u{1f01}'), ('\u{1f0a}', '\u{1f02}'),
+    ('\u{1f0b}', '\u{1f03}'), ('\u{1f0c}', '\u{1f04}'), ('\u{1f0d}', '\u{1f05}'), ('\u{1f0e}', '\u{1f06}'),
+    ('\u{1f0f}', '\u{1f07}'), ('\u{1f18}', '\u{1f10}'), ('\u{1f19}', '\u{1f11}'), ('\u{1f1a}', '\u{1f12}'),
+    ('\u{1f1b}', '\u{1f13}'), ('\u{1f1c}', '\u{1f14}'), ('\u{1f1d}', '\u{1f15}'), ('\u{1f28}', '\u{1f20}'),
+    ('\u{1f29}', '\u{1f21}'), ('\u{1f2a}', '\u{1f22}'), ('\u{1f2b}', '\u{1f23}'), ('\u{1f2c}', '\u{1f24}'),
+    ('\u{1f2d}', '\u{1f25}'), ('\u{1f2e}', '\u{1f26}'), ('\u{1f2f}', '\u{1f27}'), ('\u{1f38}', '\u{1f30}'),
+    ('\u{1f39}', '\u{1f31}'), ('\u{1f3a}', '\u{1f32}'), ('\u{1f3b}', '\u{1f33}'), ('\u{1f3c}', '\u{1f34}'),
+    ('\u{1f3d}', '\u{1f35}'), ('\u{1f3e}', '\u{1f36}'), ('\u{1f3f}', '\u{1f37}'), ('\u{1f48}', '\u{1f40}'),
+    ('\u{1f49}', '\u{1f41}'), ('\u{1f4a}', '\u{1f42}'), ('\u{1f4b}', '\u{1f43}'), ('\u{1f4c}', '\u{1f44}'),
+    ('\u{1f4d}', '\u{1f45}'), ('\u{1f59}', '\u{1f51}'), ('\u{1f5b}', '\u{1f53}'), ('\u{1f5d}', '\u{1f55}'),
+    ('\u{1f5f}', '\u{1f57}'), ('\u{1f68}', '\u{1f60}'), ('\u{1f69}', '\u{1f61}'), ('\u{1f6a}', '\u{1f62}'),
+    ('\u{1f6b}', '\u{1f63}'), ('\u{1f6c}', '\u{1f64}'), ('\u{1f6d}', '\u{1f65}'), ('\u{1f6e}', '\u{1f66}'),
+    ('\u{1f6f}', '\u{1f67}'), ('\u{1f88}', '\u{1f80}'), ('\u{1f89}', '\u{1f81}'), ('\u{1f8a}', '\u{1f82}'),
+    ('\u{1f8b}', '\u{1f83}'), ('\u{1f8c}', '\u{1f84}'), ('\u{1f8d}', '\u{1f85}'), ('\u{1f8e}', '\u{1f86}'),
+    ('\u{1f8f}', '\u{1f87}'), ('\u{1f98}', '\u{1f90}'), ('\u{1f99}', '\u{1f91}'), ('\u{1f9a}', '\u{1f92}'),
+    ('\u{1f9b}', '\u{1f93}'), ('\u{1f9c}', '\u{1f94}'), ('\u{1f9d}', '\u{1f95}'), ('\u{1f9e}', '\u{1f96}'),
+    ('\u{1f9f}', '\u{1f97}'), ('\u{1fa8}', '\u{1fa0}'), ('\u{1fa9}', '\u{1fa1}'), ('\u{1faa}', '\u{1fa2}'),
+    ('\u{1fab}', '\u{1fa3}'), ('\u{1fac}', '\u{1fa4}'), ('\u{1fad}', '\u{1fa5}'), ('\u{1fae}', '\u{1fa6}'),
+    ('\u{1faf}', '\u{1fa7}'), ('\u{1fb8}', '\u{1fb0}'), ('\u{1fb9}', '\u{1fb1}'), ('\u{1fba}', '\u{1f70}'),
+    ('\u{1fbb}', '\u{1f71}'), ('\u{1fbc}', '\u{1fb3}'), ('\u{1fc8}', '\u{1f72}'), ('\u{1fc9}', '\u{1f73}'),
+    ('\u{1fca}', '\u{1f74}'), ('\u{1fcb}', '\u{1f75}'), ('\u{1fcc}', '\u{1fc3}'), ('\u{1fd8}', '\u{1fd0}'),
+    ('\u{1fd9}', '\u{1fd1}'), ('\u{1fda}', '\u{1f76}'), ('\u{1fdb}', '\u{1f77}'), ('\u{1fe8}', '\u{1fe0}'),
+    ('\u{1fe9}', '\u{1fe1}'), ('\u{1fea}', '\u{1f7a}'), ('\u{1feb}', '\u{1f7b}'), ('\u{1fec}', '\u{1fe5}'),
+    ('\u{1ff8}', '\u{1f78}'), ('\u{1ff9}', '\u{1f79}'), ('\u{1ffa}', '\u{1f7c}'), ('\u{1ffb}', '\u{1f7d}'),
+    ('\u{1ffc}', '\u{1ff3}'), ('\u{2126}', '\u{3c9}'), ('\u{212a}', 'k'), ('\u{212b}', '\u{e5}'),
+    ('\u{2132}', '\u{214e}'), ('\u{2160}', '\u{2170}'), ('\u{2161}', '\u{2171}'), ('\u{2162}', '\u{2172}'),
+    ('\u{2163}', '\u{2173}'), ('\u{2164}', '\u{2174}'), ('\u{2165}', '\u{2175}'), ('\u{2166}', '\u{2176}'),
+    ('\u{2167}', '\u{2177}'), ('\u{2168}', '\u{2178}'), ('\u{2169}', '\u{2179}'), ('\u{216a}', '\u{217a}'),
+    ('\u{216b}', '\u{217b}'), ('\u{216c}', '\u{217c}'), ('\u{216d}', '\u{217d}'), ('\u{216e}', '\u{217e}'),
+    ('\u{216f}', '\u{217f}'), ('\u{2183}', '\u{2184}'), ('\u{24b6}', '\u{24d0}'), ('\u{24b7}', '\u{24d1}'),
+    ('\u{24b8}', '\u{24d2}'), ('\u{24b9}', '\u{24d3}'), ('\u{24ba}', '\u{24d4}'), ('\u{24bb}', '\u{24d5}'),
+    ('\u{24bc}', '\u{24d6}'), ('\u{24bd}', '\u{24d7}'), ('\u{24be}', '\u{24d8}'), ('\u{24bf}', '\u{24d9}'),
+    ('\u{24c0}', '\u{24da}'), ('\u{24c1}', '\u{24db}'), ('\u{24c2}', '\u{24dc}'), ('\u{24c3}', '\u{24dd}'),
+    ('\u{24c4}', '\u{24de}'), ('\u{24c5}', '\u{24df}'), ('\u{24c6}', '\u{24e0}'), ('\u{24c7}', '\u{24e1}'),
+    ('\u{24c8}', '\u{24e2}'), ('\u{24c9}', '\u{24e3}'), ('\u{24ca}', '\u{24e4}'), ('\u{24cb}', '\u{24e5}'),
+    ('\u{24cc}', '\u{24e6}'), ('\u{24cd}', '\u{24e7}'), ('\u{24ce}', '\u{24e8}'), ('\u{24cf}', '\u{24e9}'),
+    ('\u{2c00}', '\u{2c30}'), ('\u{2c01}', '\u{2c31}'), ('\u{2c02}', '\u{2c32}'), ('\u{2c03}', '\u{2c33}'),
+    ('\u{2c04}', '\u{2c34}'), ('\u{2c05}', '\u{2c35}'), ('\u{2c06}', '\u{2c36}'), ('\u{2c07}', '\u{2c37}'),
+    ('\u{2c08}', '\u{2c38}'), ('\u{2c09}', '\u{2c39}'), ('\u{2c0a}', '\u{2c3a}'), ('\u{2c0b}', '\u{2c3b}'),
+    ('\u{2c0c}', '\u{2c3c}'), ('\u{2c0d}', '\u{2c3d}'), ('\u{2c0e}', '\u{2c3e}'), ('\u{2c0f}', '\u{2c3f}'),
+    ('\u{2c10}', '\u{2c40}'), ('\u{2c11}', '\u{2c41}'), ('\u{2c12}', '\u{2c42}'), ('\u{2c13}', '\u{2c43}'),
+    ('\u{2c14}', '\u{2c44}'), ('\u{2c15}', '\u{2c45}'), ('\u{2c16}', '\u{2c46}'), ('\u{2c17}', '\u{2c47}'),
+    ('\u{2c18}', '\u{2c48}'), ('\u{2c19}', '\u{2c49}'), ('\u{2c1a}', '\u{2c4a}'), ('\u{2c1b}', '\u{2c4b}'),
+    ('\u{2c1c}', '\u{2c4c}'), ('\u{2c1d}', '\u{2c4d}'), ('\u{2c1e}', '\u{2c4e}'), ('\u{2c1f}', '\u{2c4f}'),
+    ('\u{2c20}', '\u{2c50}'), ('\u{2c21}', '\u{2c51}'), ('\u{2c22}', '\u{2c52}'), ('\u{2c23}', '\u{2c53}'),
+    ('\u{2c24}', '\u{2c54}'), ('\u{2c25}', '\u{2c55}'), ('\u{2c26}', '\u{2c56}'), ('\u{2c27}', '\u{2c57}'),
+    ('\u{2c28}', '\u{2c58}'), ('\u{2c29}', '\u{2c59}'), ('\u{2c2a}', '\u{2c5a}'), ('\u{2c2b}', '\u{2c5b}'),
+    ('\u{2c2c}', '\u{2c5c}'), ('\u{2c2d}', '\u{2c5d}'), ('\u{2c2e}', '\u{2c5e}'), ('\u{2c2f}', '\u{2c5f}'),
+    ('\u{2c60}', '\u{2c61}'), ('\u{2c62}', '\u{26b}'), ('\u{2c63}', '\u{1d7d}'), ('\u{2c64}', '\u{27d}'),
+    ('\u{2c67}', '\u{2c68}'), ('\u{2c69}', '\u{2c6a}'), ('\u{2c6b}', '\u{2c6c}'), ('\u{2c6d}', '\u{251}'),
+    ('\u{2c6e}', '\u{271}'), ('\u{2c6f}', '\u{250}'), ('\u{2c70}', '\u{252}'), ('\u{2c72}', '\u{2c73}'),
+    ('\u{2c75}', '\u{2c76}'), ('\u{2c7e}', '\u{23f}'), ('\u{2c7f}', '\u{240}'), ('\u{2c80}', '\u{2c81}'),
+    ('\u{2c82}', '\u{2c83}'), ('\u{2c84}', '\u{2c85}'), ('\u{2c86}', '\u{2c87}'), ('\u{2c88}', '\u{2c89}'),
+    ('\u{2c8a}', '\u{2c8b}'), ('\u{2c8c}', '\u{2c8d}'), ('\u{2c8e}', '\u{2c8f}'), ('\u{2c90}', '\u{2c91}'),
+    ('\u{2c92}', '\u{2c93}'), ('\u{2c94}', '\u{2c95}'), ('\u{2c96}', '\u{2c97}'), ('\u{2c98}', '\u{2c99}'),
+    ('\u{2c9a}', '\u{2c9b}'), ('\u{2c9c}', '\u{2c9d}'), ('\u{2c9e}', '\u{2c9f}'), ('\u{2ca0}', '\u{2ca1}'),
+    ('\u{2ca2}', '\u{2ca3}'), ('\u{2ca4}', '\u{2ca5}'), ('\u{2ca6}', '\u{2ca7}'), ('\u{2ca8}', '\u{2ca9}'),
+    ('\u{2caa}', '\u{2cab}'), ('\u{2cac}', '\u{2cad}'), ('\u{2cae}', '\u{2caf}'), ('\u{2cb0}', '\u{2cb1}'),
+    ('\u{2cb2}', '\u{2cb3}'), ('\u{2cb4}', '\u{2cb5}'), ('\u{2cb6}', '\u{2cb7}'), ('\u{2cb8}', '\u{2cb9}'),
+    ('\u{2cba}', '\u{2cbb}'), ('\u{2cbc}', '\u{2cbd}'), ('\u{2cbe}', '\u{2cbf}'), ('\u{2cc0}', '\u{2cc1}'),
+    ('\u{2cc2}', '\u{2cc3}'), ('\u{2cc4}', '\u{2cc5}'), ('\u{2cc6}', '\u{2cc7}'), ('\u{2cc8}', '\u{2cc9}'),
+    ('\u{2cca}', '\u{2ccb}'), ('\u{2ccc}', '\u{2ccd}'), ('\u{2cce}', '\u{2ccf}'), ('\u{2cd0}', '\u{2cd1}'),
+    ('\u{2cd2}', '\u{2cd3}'), ('\u{2cd4}', '\u{2cd5}'), ('\u{2cd6}', '\u{2cd7}'), ('\u{2cd8}', '\u{2cd9}'),
+    ('\u{2cda}', '\u{2cdb}'), ('\u{2cdc}', '\u{2cdd}'), ('\u{2cde}', '\u{2cdf}'), ('\u{2ce0}', '\u{2ce1}'),
+    ('\u{2ce2}', '\u{2ce3}'), ('\u{2ceb}', '\u{2cec}'), ('\u{2ced}', '\u{2cee}'), ('\u{2cf2}', '\u{2cf3}'),
+    ('\u{a640}', '\u{a641}'), ('\u{a642}', '\u{a643}'), ('\u{a644}', '\u{a645}'), ('\u{a646}', '\u{a647}'),
+    ('\u{a648}', '\u{a649}'), ('\u{a64a}', '\u{a64b}'), ('\u{a64c}', '\u{a64d}'), ('\u{a64e}', '\u{a64f}'),
+    ('\u{a650}', '\u{a651}'), ('\u{a652}', '\u{a653}'), ('\u{a654}', '\u{a655}'), ('\u{a656}', '\u{a657}'),
+    ('\u{a658}', '\u{a659}'), ('\u{a65a}', '\u{a65b}'), ('\u{a65c}', '\u{a65d}'), ('\u{a65e}', '\u{a65f}'),
+    ('\u{a660}', '\u{a661}'), ('\u{a662}', '\u{a663}'), ('\u{a664}', '\u{a665}'), ('\u{a666}', '\u{a667}'),
+    ('\u{a668}', '\u{a669}'), ('\u{a66a}', '\u{a66b}'), ('\u{a66c}', '\u{a66d}'), ('\u{a680}', '\u{a681}'),
+    ('\u{a682}', '\u{a683}'), ('\u{a684}', '\u{a685}'), ('\u{a686}', '\u{a687}'), ('\u{a688}', '\u{a689}'),
+    ('\u{a68a}', '\u{a68b}'), ('\u{a68c}', '\u{a68d}'), ('\u{a68e}', '\u{a68f}'), ('\u{a690}', '\u{a691}'),
+    ('\u{a692}', '\u{a693}'), ('\u{a694}', '\u{a695}'), ('\u{a696}', '\u{a697}'), ('\u{a698}', '\u{a699}'),
+    ('\u{a69a}', '\u{a69b}'), ('\u{a722}', '\u{a723}'), ('\u{a724}', '\u{a725}'), ('\u{a726}', '\u{a727}'),
+    ('\u{a728}', '\u{a729}'), ('\u{a72a}', '\u{a72b}'), ('\u{a72c}', '\u{a72d}'), ('\u{a72e}', '\u{a72f}'),
+    ('\u{a732}', '\u{a733}'), ('\u{a734}', '\u{a735}'), ('\u{a736}', '\u{a737}'), ('\u{a738}', '\u{a739}'),
+    ('\u{a73a}', '\u{a73b}'), ('\u{a73c}', '\u{a73d}'), ('\u{a73e}', '\u{a73f}'), ('\u{a740}', '\u{a741}'),
+    ('\u{a742}', '\u{a743}'), ('\u{a744}', '\u{a745}'), ('\u{a746}', '\u{a747}'), ('\u{a748}', '\u{a749}'),
+    ('\u{a74a}', '\u{a74b}'), ('\u{a74c}', '\u{a74d}'), ('\u{a74e}', '\u{a74f}'), ('\u{a750}', '\u{a751}'),
+    ('\u{a752}', '\u{a753}'), ('\u{a754}', '\u{a755}'), ('\u{a756}', '\u{a757}'), ('\u{a758}', '\u{a759}'),
+    ('\u{a75a}', '\u{a75b}'), ('\u{a75c}', '\u{a75d}'), ('\u{a75e}', '\u{a75f}'), ('\u{a760}', '\u{a761}'),
+    ('\u{a762}', '\u{a763}'), ('\u{a764}', '\u{a765}'), ('\u{a766}', '\u{a767}'), ('\u{a768}', '\u{a769}'),
+    ('\u{a76a}', '\u{a76b}'), ('\u{a76c}', '\u{a76d}'), ('\u{a76e}', '\u{a76f}'), ('\u{a779}', '\u{a77a}'),
+    ('\u{a77b}', '\u{a77c}'), ('\u{a77d}', '\u{1d79}'), ('\u{a77e}', '\u{a77f}'), ('\u{a780}', '\u{a781}'),
+    ('\u{a782}', '\u{a783}'), ('\u{a784}', '\u{a785}'), ('\u{a786}', '\u{a787}'), ('\u{a78b}', '\u{a78c}'),
+    ('\u{a78d}', '\u{265}'), ('\u{a790}', '\u{a791}'), ('\u{a792}', '\u{a793}'), ('\u{a796}', '\u{a797}'),
+    ('\u{a798}', '\u{a799}'), ('\u{a79a}', '\u{a79b}'), ('\u{a79c}', '\u{a79d}'), ('\u{a79e}', '\u{a79f}'),
+    ('\u{a7a0}', '\u{a7a1}'), ('\u{a7a2}', '\u{a7a3}'), ('\u{a7a4}', '\u{a7a5}'), ('\u{a7a6}', '\u{a7a7}'),
+    ('\u{a7a8}', '\u{a7a9}'), ('\u{a7aa}', '\u{266}'), ('\u{a7ab}', '\u{25c}'), ('\u{a7ac}', '\u{261}'),
+    ('\u{a7ad}', '\u{26c}'), ('\u{a7ae}', '\u{26a}'), ('\u{a7b0}', '\u{29e}'), ('\u{a7b1}', '\u{287}'),
+    ('\u{a7b2}', '\u{29d}'), ('\u{a7b3}', '\u{ab53}'), ('\u{a7b4}', '\u{a7b5}'), ('\u{a7b6}', '\u{a7b7}'),
+    ('\u{a7b8}', '\u{a7b9}'), ('\u{a7ba}', '\u{a7bb}'), ('\u{a7bc}', '\u{a7bd}'), ('\u{a7be}', '\u{a7bf}'),
+    ('\u{a7c0}', '\u{a7c1}'), ('\u{a7c2}', '\u{a7c3}'), ('\u{a7c4}', '\u{a794}'), ('\u{a7c5}', '\u{282}'),
+    ('\u{a7c6}', '\u{1d8e}'), ('\u{a7c7}', '\u{a7c8}'), ('\u{a7c9}', '\u{a7ca}'), ('\u{a7cb}', '\u{264}'),
+    ('\u{a7cc}', '\u{a7cd}'), ('\u{a7ce}', '\u{a7cf}'), ('\u{a7d0}', '\u{a7d1}'), ('\u{a7d2}', '\u{a7d3}'),
+    ('\u{a7d4}', '\u{a7d5}'), ('\u{a7d6}', '\u{a7d7}'), ('\u{a7d8}', '\u{a7d9}'), ('\u{a7da}', '\u{a7db}'),
+    ('\u{a7dc}', '\u{19b}'), ('\u{a7f5}', '\u{a7f6}'), ('\u{ff21}', '\u{ff41}'), ('\u{ff22}', '\u{ff42}'),
+    ('\u{ff23}', '\u{ff43}'), ('\u{ff24}', '\u{ff44}'), ('\u{ff25}', '\u{ff45}'), ('\u{ff26}', '\u{ff46}'),
+    ('\u{ff27}', '\u{ff47}'), ('\u{ff28}', '\u{ff48}'), ('\u{ff29}', '\u{ff49}'), ('\u{ff2a}', '\u{ff4a}'),
+    ('\u{ff2b}', '\u{ff4b}'), ('\u{ff2c}', '\u{ff4c}'), ('\u{ff2d}', '\u{ff4d}'), ('\u{ff2e}', '\u{ff4e}'),
+    ('\u{ff2f}', '\u{ff4f}'), ('\u{ff30}', '\u{ff50}'), ('\u{ff31}', '\u{ff51}'), ('\u{ff32}', '\u{ff52}'),
+    ('\u{ff33}', '\u{ff53}'), ('\u{ff34}', '\u{ff54}'), ('\u{ff35}', '\u{ff55}'), ('\u{ff36}', '\u{ff56}'),
+    ('\u{ff37}', '\u{ff57}'), ('\u{ff38}', '\u{ff58}'), ('\u{ff39}', '\u{ff59}'), ('\u{ff3a}', '\u{ff5a}'),
+    ('\u{10400}', '\u{10428}'), ('\u{10401}', '\u{10429}'), ('\u{10402}', '\u{1042a}'), ('\u{10403}', '\u{1042b}'),
+    ('\u{10404}', '\u{1042c}'), ('\u{10405}', '\u{1042d}'), ('\u{10406}', '\u{1042e}'), ('\u{10407}', '\u{1042f}'),
+    ('\u{10408}', '\u{10430}'), ('\u{10409}', '\u{10431}'), ('\u{1040a}', '\u{10432}'), ('\u{1040b}', '\u{10433}'),
+    ('\u{1040c}', '\u{10434}'), ('\u{1040d}', '\u{10435}'), ('\u{1040e}', '\u{10436}'), ('\u{1040f}', '\u{10437}'),
+    ('\u{10410}', '\u{10438}'), ('\u{10411}', '\u{10439}'), ('\u{10412}', '\u{1043a}'), ('\u{10413}', '\u{1043b}'),
+    ('\u{10414}', '\u{1043c}'), ('\u{10415}', '\u{1043d}'), ('\u{10416}', '\u{1043e}'), ('\u{10417}', '\u{1043f}'),
+    ('\u{10418}', '\u{10440}'), ('\u{10419}', '\u{10441}'), ('\u{1041a}', '\u{10442}'), ('\u{1041b}', '\u{10443}'),
+    ('\u{1041c}', '\u{10444}'), ('\u{1041d}', '\u{10445}'), ('\u{1041e}', '\u{10446}'), ('\u{1041f}', '\u{10447}'),
+    ('\u{10420}', '\u{10448}'), ('\u{10421}', '\u{10449}'), ('\u{10422}', '\u{1044a}'), ('\u{10423}', '\u{1044b}'),
+    ('\u{10424}', '\u{1044c}'), ('\u{10425}', '\u{1044d}'), ('\u{10426}', '\u{1044e}'), ('\u{10427}', '\u{1044f}'),
+    ('\u{104b0}', '\u{104d8}'), ('\u{104b1}', '\u{104d9}'), ('\u{104b2}', '\u{104da}'), ('\u{104b3}', '\u{104db}'),
+    ('\u{104b4}', '\u{104dc}'), ('\u{104b5}', '\u{104dd}'), ('\u{104b6}', '\u{104de}'), ('\u{104b7}', '\u{104df}'),
+    ('\u{104b8}', '\u{104e0}'), ('\u{104b9}', '\u{104e1}'), ('\u{104ba}', '\u{104e2}'), ('\u{104bb}', '\u{104e3}'),
+    ('\u{104bc}', '\u{104e4}'), ('\u{104bd}', '\u{104e5}'), ('\u{104be}', '\u{104e6}'), ('\u{104bf}', '\u{104e7}'),
+    ('\u{104c0}', '\u{104e8}'), ('\u{104c1}', '\u{104e9}'), ('\u{104c2}', '\u{104ea}'), ('\u{104c3}', '\u{104eb}'),
+    ('\u{104c4}', '\u{104ec}'), ('\u{104c5}', '\u{104ed}'), ('\u{104c6}', '\u{104ee}'), ('\u{104c7}', '\u{104ef}'),
+    ('\u{104c8}', '\u{104f0}'), ('\u{104c9}', '\u{104f1}'), ('\u{104ca}', '\u{104f2}'), ('\u{104cb}', '\u{104f3}'),
+    ('\u{104cc}', '\u{104f4}'), ('\u{104cd}', '\u{104f5}'), ('\u{104ce}', '\u{104f6}'), ('\u{104cf}', '\u{104f7}'),
+    ('\u{104d0}', '\u{104f8}'), ('\u{104d1}', '\u{104f9}'), ('\u{104d2}', '\u{104fa}'), ('\u{104d3}', '\u{104fb}'),
+    ('\u{10570}', '\u{10597}'), ('\u{10571}', '\u{10598}'), ('\u{10572}', '\u{10599}'), ('\u{10573}', '\u{1059a}'),
+    ('\u{10574}', '\u{1059b}'), ('\u{10575}', '\u{1059c}'), ('\u{10576}', '\u{1059d}'), ('\u{10577}', '\u{1059e}'),
+    ('\u{10578}', '\u{1059f}'), ('\u{10579}', '\u{105a0}'), ('\u{1057a}', '\u{105a1}'), ('\u{1057c}', '\u{105a3}'),
+    ('\u{1057d}', '\u{105a4}'), ('\u{1057e}', '\u{105a5}'), ('\u{1057f}', '\u{105a6}'), ('\u{10580}', '\u{105a7}'),
+    ('\u{10581}', '\u{105a8}'), ('\u{10582}', '\u{105a9}'), ('\u{10583}', '\u{105aa}'), ('\u{10584}', '\u{105ab}'),
+    ('\u{10585}', '\u{105ac}'), ('\u{10586}', '\u{105ad}'), ('\u{10587}', '\u{105ae}'), ('\u{10588}', '\u{105af}'),
+    ('\u{10589}', '\u{105b0}'), ('\u{1058a}', '\u{105b1}'), ('\u{1058c}', '\u{105b3}'), ('\u{1058d}', '\u{105b4}'),
+    ('\u{1058e}', '\u{105b5}'), ('\u{1058f}', '\u{105b6}'), ('\u{10590}', '\u{105b7}'), ('\u{10591}', '\u{105b8}'),
+    ('\u{10592}', '\u{105b9}'), ('\u{10594}', '\u{105bb}'), ('\u{10595}', '\u{105bc}'), ('\u{10c80}', '\u{10cc0}'),
+    ('\u{10c81}', '\u{10cc1}'), ('\u{10c82}', '\u{10cc2}'), ('\u{10c83}', '\u{10cc3}'), ('\u{10c84}', '\u{10cc4}'),
+    ('\u{10c85}', '\u{10cc5}'), ('\u{10c86}', '\u{10cc6}'), ('\u{10c87}', '\u{10cc7}'), ('\u{10c88}', '\u{10cc8}'),
+    ('\u{10c89}', '\u{10cc9}'), ('\u{10c8a}', '\u{10cca}'), ('\u{10c8b}', '\u{10ccb}'), ('\u{10c8c}', '\u{10ccc}'),
+    ('\u{10c8d}', '\u{10ccd}'), ('\u{10c8e}', '\u{10cce}'), ('\u{10c8f}', '\u{10ccf}'), ('\u{10c90}', '\u{10cd0}'),
+    ('\u{10c91}', '\u{10cd1}'), ('\u{10c92}', '\u{10cd2}'), ('\u{10c93}', '\u{10cd3}'), ('\u{10c94}', '\u{10cd4}'),
+    ('\u{10c95}', '\u{10cd5}'), ('\u{10c96}', '\u{10cd6}'), ('\u{10c97}', '\u{10cd7}'), ('\u{10c98}', '\u{10cd8}'),
+    ('\u{10c99}', '\u{10cd9}'), ('\u{10c9a}', '\u{10cda}'), ('\u{10c9b}', '\u{10cdb}'), ('\u{10c9c}', '\u{10cdc}'),
+    ('\u{10c9d}', '\u{10cdd}'), ('\u{10c9e}', '\u{10cde}'), ('\u{10c9f}', '\u{10cdf}'), ('\u{10ca0}', '\u{10ce0}'),
+    ('\u{10ca1}', '\u{10ce1}'), ('\u{10ca2}', '\u{10ce2}'), ('\u{10ca3}', '\u{10ce3}'), ('\u{10ca4}', '\u{10ce4}'),
+    ('\u{10ca5}', '\u{10ce5}'), ('\u{10ca6}', '\u{10ce6}'), ('\u{10ca7}', '\u{10ce7}'), ('\u{10ca8}', '\u{10ce8}'),
+    ('\u{10ca9}', '\u{10ce9}'), ('\u{10caa}', '\u{10cea}'), ('\u{10cab}', '\u{10ceb}'), ('\u{10cac}', '\u{10cec}'),
+    ('\u{10cad}', '\u{10ced}'), ('\u{10cae}', '\u{10cee}'), ('\u{10caf}', '\u{10cef}'), ('\u{10cb0}', '\u{10cf0}'),
+    ('\u{10cb1}', '\u{10cf1}'), ('\u{10cb2}', '\u{10cf2}'), ('\u{10d50}', '\u{10d70}'), ('\u{10d51}', '\u{10d71}'),
+    ('\u{10d52}', '\u{10d72}'), ('\u{10d53}', '\u{10d73}'), ('\u{10d54}', '\u{10d74}'), ('\u{10d55}', '\u{10d75}'),
+    ('\u{10d56}', '\u{10d76}'), ('\u{10d57}', '\u{10d77}'), ('\u{10d58}', '\u{10d78}'), ('\u{10d59}', '\u{10d79}'),
+    ('\u{10d5a}', '\u{10d7a}'), ('\u{10d5b}', '\u{10d7b}'), ('\u{10d5c}', '\u{10d7c}'), ('\u{10d5d}', '\u{10d7d}'),
+    ('\u{10d5e}', '\u{10d7e}'), ('\u{10d5f}', '\u{10d7f}'), ('\u{10d60}', '\u{10d80}'), ('\u{10d61}', '\u{10d81}'),
+    ('\u{10d62}', '\u{10d82}'), ('\u{10d63}', '\u{10d83}'), ('\u{10d64}', '\u{10d84}'), ('\u{10d65}', '\u{10d85}'),
+    ('\u{118a0}', '\u{118c0}'), ('\u{118a1}', '\u{118c1}'), ('\u{118a2}', '\u{118c2}'), ('\u{118a3}', '\u{118c3}'),
+    ('\u{118a4}', '\u{118c4}'), ('\u{118a5}', '\u{118c5}'), ('\u{118a6}', '\u{118c6}'), ('\u{118a7}', '\u{118c7}'),
+    ('\u{118a8}', '\u{118c8}'), ('\u{118a9}', '\u{118c9}'), ('\u{118aa}', '\u{118ca}'), ('\u{118ab}', '\u{118cb}'),
+    ('\u{118ac}', '\u{118cc}'), ('\u{118ad}', '\u{118cd}'), ('\u{118ae}', '\u{118ce}'), ('\u{118af}', '\u{118cf}'),
+    ('\u{118b0}', '\u{118d0}'), ('\u{118b1}', '\u{118d1}'), ('\u{118b2}', '\u{118d2}'), ('\u{118b3}', '\u{118d3}'),
+    ('\u{118b4}', '\u{118d4}'), ('\u{118b5}', '\u{118d5}'), ('\u{118b6}', '\u{118d6}'), ('\u{118b7}', '\u{118d7}'),
+    ('\u{118b8}', '\u{118d8}'), ('\u{118b9}', '\u{118d9}'), ('\u{118ba}', '\u{118da}'), ('\u{118bb}', '\u{118db}'),
+    ('\u{118bc}', '\u{118dc}'), ('\u{118bd}', '\u{118dd}'), ('\u{118be}', '\u{118de}'), ('\u{118bf}', '\u{118df}'),
+    ('\u{16e40}', '\u{16e60}'), ('\u{16e41}', '\u{16e61}'), ('\u{16e42}', '\u{16e62}'), ('\u{16e43}', '\u{16e63}'),
+    ('\u{16e44}', '\u{16e64}'), ('\u{16e45}', '\u{16e65}'), ('\u{16e46}', '\u{16e66}'), ('\u{16e47}', '\u{16e67}'),
+    ('\u{16e48}', '\u{16e68}'), ('\u{16e49}', '\u{16e69}'), ('\u{16e4a}', '\u{16e6a}'), ('\u{16e4b}', '\u{16e6b}'),
+    ('\u{16e4c}', '\u{16e6c}'), ('\u{16e4d}', '\u{16e6d}'), ('\u{16e4e}', '\u{16e6e}'), ('\u{16e4f}', '\u{16e6f}'),
+    ('\u{16e50}', '\u{16e70}'), ('\u{16e51}', '\u{16e71}'), ('\u{16e52}', '\u{16e72}'), ('\u{16e53}', '\u{16e73}'),
+    ('\u{16e54}', '\u{16e74}'), ('\u{16e55}', '\u{16e75}'), ('\u{16e56}', '\u{16e76}'), ('\u{16e57}', '\u{16e77}'),
+    ('\u{16e58}', '\u{16e78}'), ('\u{16e59}', '\u{16e79}'), ('\u{16e5a}', '\u{16e7a}'), ('\u{16e5b}', '\u{16e7b}'),
+    ('\u{16e5c}', '\u{16e7c}'), ('\u{16e5d}', '\u{16e7d}'), ('\u{16e5e}', '\u{16e7e}'), ('\u{16e5f}', '\u{16e7f}'),
+    ('\u{16ea0}', '\u{16ebb}'), ('\u{16ea1}', '\u{16ebc}'), ('\u{16ea2}', '\u{16ebd}'), ('\u{16ea3}', '\u{16ebe}'),
+    ('\u{16ea4}', '\u{16ebf}'), ('\u{16ea5}', '\u{16ec0}'), ('\u{16ea6}', '\u{16ec1}'), ('\u{16ea7}', '\u{16ec2}'),
+    ('\u{16ea8}', '\u{16ec3}'), ('\u{16ea9}', '\u{16ec4}'), ('\u{16eaa}', '\u{16ec5}'), ('\u{16eab}', '\u{16ec6}'),
+    ('\u{16eac}', '\u{16ec7}'), ('\u{16ead}', '\u{16ec8}'), ('\u{16eae}', '\u{16ec9}'), ('\u{16eaf}', '\u{16eca}'),
+    ('\u{16eb0}', '\u{16ecb}'), ('\u{16eb1}', '\u{16ecc}'), ('\u{16eb2}', '\u{16ecd}'), ('\u{16eb3}', '\u{16ece}'),
+    ('\u{16eb4}', '\u{16ecf}'), ('\u{16eb5}', '\u{16ed0}'), ('\u{16eb6}', '\u{16ed1}'), ('\u{16eb7}', '\u{16ed2}'),
+    ('\u{16eb8}', '\u{16ed3}'), ('\u{1e900}', '\u{1e922}'), ('\u{1e901}', '\u{1e923}'), ('\u{1e902}', '\u{1e924}'),
+    ('\u{1e903}', '\u{1e925}'), ('\u{1e904}', '\u{1e926}'), ('\u{1e905}', '\u{1e927}'), ('\u{1e906}', '\u{1e928}'),
+    ('\u{1e907}', '\u{1e929}'), ('\u{1e908}', '\u{1e92a}'), ('\u{1e909}', '\u{1e92b}'), ('\u{1e90a}', '\u{1e92c}'),
+    ('\u{1e90b}', '\u{1e92d}'), ('\u{1e90c}', '\u{1e92e}'), ('\u{1e90d}', '\u{1e92f}'), ('\u{1e90e}', '\u{1e930}'),
+    ('\u{1e90f}', '\u{1e931}'), ('\u{1e910}', '\u{1e932}'), ('\u{1e911}', '\u{1e933}'), ('\u{1e912}', '\u{1e934}'),
+    ('\u{1e913}', '\u{1e935}'), ('\u{1e914}', '\u{1e936}'), ('\u{1e915}', '\u{1e937}'), ('\u{1e916}', '\u{1e938}'),
+    ('\u{1e917}', '\u{1e939}'), ('\u{1e918}', '\u{1e93a}'), ('\u{1e919}', '\u{1e93b}'), ('\u{1e91a}', '\u{1e93c}'),
+    ('\u{1e91b}', '\u{1e93d}'), ('\u{1e91c}', '\u{1e93e}'), ('\u{1e91d}', '\u{1e93f}'), ('\u{1e91e}', '\u{1e940}'),
+    ('\u{1e91f}', '\u{1e941}'), ('\u{1e920}', '\u{1e942}'), ('\u{1e921}', '\u{1e943}'),
+];
+
+#[rustfmt::skip]
+pub const TO_LOWER_MULTI: &[(char, [char; 3])] = &[
+    ('\u{130}', ['i', '\u{307}', '\u{0}']),
+];
+
+#[rustfmt::skip]
+pub const TO_UPPER: &[(char, char)] = &[
+    ('a', 'A'), ('b', 'B'), ('c', 'C'), ('d', 'D'),
+    ('e', 'E'), ('f', 'F'), ('g', 'G'), ('h', 'H'),
+    ('i', 'I'), ('j', 'J'), ('k', 'K'), ('l', 'L'),
+    ('m', 'M'), ('n', 'N'), ('o', 'O'), ('p', 'P'),
+    ('q', 'Q'), ('r', 'R'), ('s', 'S'), ('t', 'T'),
+    ('u', 'U'), ('v', 'V'), ('w', 'W'), ('x', 'X'),
+    ('y', 'Y'), ('z', 'Z'), ('\u{b5}', '\u{39c}'), ('\u{e0}', '\u{c0}'),
+    ('\u{e1}', '\u{c1}'), ('\u{e2}', '\u{c2}'), ('\u{e3}', '\u{c3}'), ('\u{e4}', '\u{c4}'),
+    ('\u{e5}', '\u{c5}'), ('\u{e6}', '\u{c6}'), ('\u{e7}', '\u{c7}'), ('\u{e8}', '\u{c8}'),
+    ('\u{e9}', '\u{c9}'), ('\u{ea}', '\u{ca}'), ('\u{eb}', '\u{cb}'), ('\u{ec}', '\u{cc}'),
+    ('\u{ed}', '\u{cd}'), ('\u{ee}', '\u{ce}'), ('\u{ef}', '\u{cf}'), ('\u{f0}', '\u{d0}'),
+    ('\u{f1}', '\u{d1}'), ('\u{f2}', '\u{d2}'), ('\u{f3}', '\u{d3}'), ('\u{f4}', '\u{d4}'),
+    ('\u{f5}', '\u{d5}'), ('\u{f6}', '\u{d6}'), ('\u{f8}', '\u{d8}'), ('\u{f9}', '\u{d9}'),
+    ('\u{fa}', '\u{da}'), ('\u{fb}', '\u{db}'), ('\u{fc}', '\u{dc}'), ('\u{fd}', '\u{dd}'),
+    ('\u{fe}', '\u{de}'), ('\u{ff}', '\u{178}'), ('\u{101}', '\u{100}'), ('\u{103}', '\u{102}'),
+    ('\u{105}', '\u{104}'), ('\u{107}', '\u{106}'), ('\u{109}', '\u{108}'), ('\u{10b}', '\u{10a}'),
+    ('\u{10d}', '\u{10c}'), ('\u{10f}', '\u{10e}'), ('\u{111}', '\u{110}'), ('\u{113}', '\u{112}'),
+    ('\u{115}', '\u{114}'), ('\u{117}', '\u{116}'), ('\u{119}', '\u{118}'), ('\u{11b}', '\u{11a}'),
+    ('\u{11d}', '\u{11c}'), ('\u{11f}', '\u{11e}'), ('\u{121}', '\u{120}'), ('\u{123}', '\u{122}'),
+    ('\u{125}', '\u{124}'), ('\u{127}', '\u{126}'), ('\u{129}', '\u{128}'), ('\u{12b}', '\u{12a}'),
+    ('\u{12d}', '\u{12c}'), ('\u{12f}', '\u{12e}'), ('\u{131}', 'I'), ('\u{133}', '\u{132}'),
+    ('\u{135}', '\u{134}'), ('\u{137}', '\u{136}'), ('\u{13a}', '\u{139}'), ('\u{13c}', '\u{13b}'),
+    ('\u{13e}', '\u{13d}'), ('\u{140}', '\u{13f}'), ('\u{142}', '\u{141}'), ('\u{144}', '\u{143}'),
+    ('\u{146}', '\u{145}'), ('\u{148}', '\u{147}'), ('\u{14b}', '\u{14a}'), ('\u{14d}', '\u{14c}'),
+    ('\u{14f}', '\u{14e}'), ('\u{151}', '\u{150}'), ('\u{153}', '\u{152}'), ('\u{155}', '\u{154}'),
+    ('\u{157}', '\u{156}'), ('\u{159}', '\u{158}'), ('\u{15b}', '\u{15a}'), ('\u{15d}', '\u{15c}'),
+    ('\u{15f}', '\u{15e}'), ('\u{161}', '\u{160}'), ('\u{163}', '\u{162}'), ('\u{165}', '\u{164}'),
+    ('\u{167}', '\u{166}'), ('\u{169}', '\u{168}'), ('\u{16b}', '\u{16a}'), ('\u{16d}', '\u{16c}'),
+    ('\u{16f}', '\u{16e}'), ('\u{171}', '\u{170}'), ('\u{173}', '\u{172}'), ('\u{175}', '\u{174}'),
+    ('\u{177}', '\u{176}'), ('\u{17a}', '\u{179}'), ('\u{17c}', '\u{17b}'), ('\u{17e}', '\u{17d}'),
+    ('\u{17f}', 'S'), ('\u{180}', '\u{243}'), ('\u{183}', '\u{182}'), ('\u{185}', '\u{184}'),
+    ('\u{188}', '\u{187}'), ('\u{18c}', '\u{18b}'), ('\u{192}', '\u{191}'), ('\u{195}', '\u{1f6}'),
+    ('\u{199}', '\u{198}'), ('\u{19a}', '\u{23d}'), ('\u{19b}', '\u{a7dc}'), ('\u{19e}', '\u{220}'),
+    ('\u{1a1}', '\u{1a0}'), ('\u{1a3}', '\u{1a2}'), ('\u{1a5}', '\u{1a4}'), ('\u{1a8}', '\u{1a7}'),
+    ('\u{1ad}', '\u{1ac}'), ('\u{1b0}', '\u{1af}'), ('\u{1b4}', '\u{1b3}'), ('\u{1b6}', '\u{1b5}'),
+    ('\u{1b9}', '\u{1b8}'), ('\u{1bd}', '\u{1bc}'), ('\u{1bf}', '\u{1f7}'), ('\u{1c5}', '\u{1c4}'),
+    ('\u{1c6}', '\u{1c4}'), ('\u{1c8}', '\u{1c7}'), ('\u{1c9}', '\u{1c7}'), ('\u{1cb}', '\u{1ca}'),
+    ('\u{1cc}', '\u{1ca}'), ('\u{1ce}', '\u{1cd}'), ('\u{1d0}', '\u{1cf}'), ('\u{1d2}', '\u{1d1}'),
+    ('\u{1d4}', '\u{1d3}'), ('\u{1d6}', '\u{1d5}'), ('\u{1d8}', '\u{1d7}'), ('\u{1da}', '\u{1d9}'),
+    ('\u{1dc}', '\u{1db}'), ('\u{1dd}', '\u{18e}'), ('\u{1df}', '\u{1de}'), ('\u{1e1}', '\u{1e0}'),
+    ('\u{1e3}', '\u{1e2}'), ('\u{1e5}', '\u{1e4}'), ('\u{1e7}', '\u{1e6}'), ('\u{1e9}', '\u{1e8}'),
+    ('\u{1eb}', '\u{1ea}'), ('\u{1ed}', '\u{1ec}'), ('\u{1ef}', '\u{1ee}'), ('\u{1f2}', '\u{1f1}'),
+    ('\u{1f3}', '\u{1f1}'), ('\u{1f5}', '\u{1f4}'), ('\u{1f9}', '\u{1f8}'), ('\u{1fb}', '\u{1fa}'),
+    ('\u{1fd}', '\u{1fc}'), ('\u{1ff}', '\u{1fe}'), ('\u{201}', '\u{200}'), ('\u{203}', '\u{202}'),
+    ('\u{205}', '\u{204}'), ('\u{207}', '\u{206}'), ('\u{209}', '\u{208}'), ('\u{20b}', '\u{20a}'),
+    ('\u{20d}', '\u{20c}'), ('\u{20f}', '\u{20e}'), ('\u{211}', '\u{210}'), ('\u{213}', '\u{212}'),
+    ('\u{215}', '\u{214}'), ('\u{217}', '\u{216}'), ('\u{219}', '\u{218}'), ('\u{21b}', '\u{21a}'),
+    ('\u{21d}', '\u{21c}'), ('\u{21f}', '\u{21e}'), ('\u{223}', '\u{222}'), ('\u{225}', '\u{224}'),
+    ('\u{227}', '\u{226}'), ('\u{229}', '\u{228}'), ('\u{22b}', '\u{22a}'), ('\u{22d}', '\u{22c}'),
+    ('\u{22f}', '\u{22e}'), ('\u{231}', '\u{230}'), ('\u{233}', '\u{232}'), ('\u{23c}', '\u{23b}'),
+    ('\u{23f}', '\u{2c7e}'), ('\u{240}', '\u{2c7f}'), ('\u{242}', '\u{241}'), ('\u{247}', '\u{246}'),
+    ('\u{249}', '\u{248}'), ('\u{24b}', '\u{24a}'), ('\u{24d}', '\u{24c}'), ('\u{24f}', '\u{24e}'),
+    ('\u{250}', '\u{2c6f}'), ('\u{251}', '\u{2c6d}'), ('\u{252}', '\u{2c70}'), ('\u{253}', '\u{181}'),
+    ('\u{254}', '\u{186}'), ('\u{256}', '\u{189}'), ('\u{257}', '\u{18a}'), ('\u{259}', '\u{18f}'),
+    ('\u{25b}', '\u{190}'), ('\u{25c}', '\u{a7ab}'), ('\u{260}', '\u{193}'), ('\u{261}', '\u{a7ac}'),
+    ('\u{263}', '\u{194}'), ('\u{264}', '\u{a7cb}'), ('\u{265}', '\u{a78d}'), ('\u{266}', '\u{a7aa}'),
+    ('\u{268}', '\u{197}'), ('\u{269}', '\u{196}'), ('\u{26a}', '\u{a7ae}'), ('\u{26b}', '\u{2c62}'),
+    ('\u{26c}', '\u{a7ad}'), ('\u{26f}', '\u{19c}'), ('\u{271}', '\u{2c6e}'), ('\u{272}', '\u{19d}'),
+    ('\u{275}', '\u{19f}'), ('\u{27d}', '\u{2c64}'), ('\u{280}', '\u{1a6}'), ('\u{282}', '\u{a7c5}'),
+    ('\u{283}', '\u{1a9}'), ('\u{287}', '\u{a7b1}'), ('\u{288}', '\u{1ae}'), ('\u{289}', '\u{244}'),
+    ('\u{28a}', '\u{1b1}'), ('\u{28b}', '\u{1b2}'), ('\u{28c}', '\u{245}'), ('\u{292}', '\u{1b7}'),
+    ('\u{29d}', '\u{a7b2}'), ('\u{29e}', '\u{a7b0}'), ('\u{345}', '\u{399}'), ('\u{371}', '\u{370}'),
+    ('\u{373}', '\u{372}'), ('\u{377}', '\u{376}'), ('\u{37b}', '\u{3fd}'), ('\u{37c}', '\u{3fe}'),
+    ('\u{37d}', '\u{3ff}'), ('\u{3ac}', '\u{386}'), ('\u{3ad}', '\u{388}'), ('\u{3ae}', '\u{389}'),
+    ('\u{3af}', '\u{38a}'), ('\u{3b1}', '\u{391}'), ('\u{3b2}', '\u{392}'), ('\u{3b3}', '\u{393}'),
+    ('\u{3b4}', '\u{394}'), ('\u{3b5}', '\u{395}'), ('\u{3b6}', '\u{396}'), ('\u{3b7}', '\u{397}'),
+    ('\u{3b8}', '\u{398}'), ('\u{3b9}', '\u{399}'), ('\u{3ba}', '\u{39a}'), ('\u{3bb}', '\u{39b}'),
+    ('\u{3bc}', '\u{39c}'), ('\u{3bd}', '\u{39d}'), ('\u{3be}', '\u{39e}'), ('\u{3bf}', '\u{39f}'),
+    ('\u{3c0}', '\u{3a0}'), ('\u{3c1}', '\u{3a1}'), ('\u{3c2}', '\u{3a3}'), ('\u{3c3}', '\u{3a3}'),
+    ('\u{3c4}', '\u{3a4}'), ('\u{3c5}', '\u{3a5}'), ('\u{3c6}', '\u{3a6}'), ('\u{3c7}', '\u{3a7}'),
+    ('\u{3c8}', '\u{3a8}'), ('\u{3c9}', '\u{3a9}'), ('\u{3ca}', '\u{3aa}'), ('\u{3cb}', '\u{3ab}'),
+    ('\u{3cc}', '\u{38c}'), ('\u{3cd}', '\u{38e}'), ('\u{3ce}', '\u{38f}'), ('\u{3d0}', '\u{392}'),
+    ('\u{3d1}', '\u{398}'), ('\u{3d5}', '\u{3a6}'), ('\u{3d6}', '\u{3a0}'), ('\u{3d7}', '\u{3cf}'),
+    ('\u{3d9}', '\u{3d8}'), ('\u{3db}', '\u{3da}'), ('\u{3dd}', '\u{3dc}'), ('\u{3df}', '\u{3de}'),
+    ('\u{3e1}', '\u{3e0}'), ('\u{3e3}', '\u{3e2}'), ('\u{3e5}', '\u{3e4}'), ('\u{3e7}', '\u{3e6}'),
+    ('\u{3e9}', '\u{3e8}'), ('\u{3eb}', '\u{3ea}'), ('\u{3ed}', '\u{3ec}'), ('\u{3ef}', '\u{3ee}'),
+    ('\u{3f0}', '\u{39a}'), ('\u{3f1}', '\u{3a1}'), ('\u{3f2}', '\u{3f9}'), ('\u{3f3}', '\u{37f}'),
+    ('\u{3f5}', '\u{395}'), ('\u{3f8}', '\u{3f7}'), ('\u{3fb}', '\u{3fa}'), ('\u{430}', '\u{410}'),
+    ('\u{431}', '\u{411}'), ('\u{432}', '\u{412}'), ('\u{433}', '\u{413}'), ('\u{434}', '\u{414}'),
+    ('\u{435}', '\u{415}'), ('\u{436}', '\u{416}'), ('\u{437}', '\u{417}'), ('\u{438}', '\u{418}'),
+    ('\u{439}', '\u{419}'), ('\u{43a}', '\u{41a}'), ('\u{43b}', '\u{41b}'), ('\u{43c}', '\u{41c}'),
+    ('\u{43d}', '\u{41d}'), ('\u{43e}', '\u{41e}'), ('\u{43f}', '\u{41f}'), ('\u{440}', '\u{420}'),
+    ('\u{441}', '\u{421}'), ('\u{442}', '\u{422}'), ('\u{443}', '\u{423}'), ('\u{444}', '\u{424}'),
+    ('\u{445}', '\u{425}'), ('\u{446}', '\u{426}'), ('\u{447}', '\u{427}'), ('\u{448}', '\u{428}'),
+    ('\u{449}', '\u{429}'), ('\u{44a}', '\u{42a}'), ('\u{44b}', '\u{42b}'), ('\u{44c}', '\u{42c}'),
+    ('\u{44d}', '\u{42d}'), ('\u{44e}', '\u{42e}'), ('\u{44f}', '\u{42f}'), ('\u{450}', '\u{400}'),
+    ('\u{451}', '\u{401}'), ('\u{452}', '\u{402}'), ('\u{453}', '\u{403}'), ('\u{454}', '\u{404}'),
+    ('\u{455}', '\u{405}'), ('\u{456}', '\u{406}'), ('\u{457}', '\u{407}'), ('\u{458}', '\u{408}'),
+    ('\u{459}', '\u{409}'), ('\u{45a}', '\u{40a}'), ('\u{45b}', '\u{40b}'), ('\u{45c}', '\u{40c}'),
+    ('\u{45d}', '\u{40d}'), ('\u{45e}', '\u{40e}'), ('\u{45f}', '\u{40f}'), ('\u{461}', '\u{460}'),
+    ('\u{463}', '\u{462}'), ('\u{465}', '\u{464}'), ('\u{467}', '\u{466}'), ('\u{469}', '\u{468}'),
+    ('\u{46b}', '\u{46a}'), ('\u{46d}', '\u{46c}'), ('\u{46f}', '\u{46e}'), ('\u{471}', '\u{470}'),
+    ('\u{473}', '\u{472}'), ('\u{475}', '\u{474}'), ('\u{477}', '\u{476}'), ('\u{479}', '\u{478}'),
+    ('\u{47b}', '\u{47a}'), ('\u{47d}', '\u{47c}'), ('\u{47f}', '\u{47e}'), ('\u{481}', '\u{480}'),
+    ('\u{48b}', '\u{48a}'), ('\u{48d}', '\u{48c}'), ('\u{48f}', '\u{48e}'), ('\u{491}', '\u{490}'),
+    ('\u{493}', '\u{492}'), ('\u{495}', '\u{494}'), ('\u{497}', '\u{496}'), ('\u{499}', '\u{498}'),
+    ('\u{49b}', '\u{49a}'), ('\u{49d}', '\u{49c}'), ('\u{49f}', '\u{49e}'), ('\u{4a1}', '\u{4a0}'),
+    ('\u{4a3}', '\u{4a2}'), ('\u{4a5}', '\u{4a4}'), ('\u{4a7}', '\u{4a6}'), ('\u{4a9}', '\u{4a8}'),
+    ('\u{4ab}', '\u{4aa}'), ('\u{4ad}', '\u{4ac}'), ('\u{4af}', '\u{4ae}'), ('\u{4b1}', '\u{4b0}'),
+    ('\u{4b3}', '\u{4b2}'), ('\u{4b5}', '\u{4b4}'), ('\u{4b7}', '\u{4b6}'), ('\u{4b9}', '\u{4b8}'),
+    ('\u{4bb}', '\u{4ba}'), ('\u{4bd}', '\u{4bc}'), ('\u{4bf}', '\u{4be}'), ('\u{4c2}', '\u{4c1}'),
+    ('\u{4c4}', '\u{4c3}'), ('\u{4c6}', '\u{4c5}'), ('\u{4c8}', '\u{4c7}'), ('\u{4ca}', '\u{4c9}'),
+    ('\u{4cc}', '\u{4cb}'), ('\u{4ce}', '\u{4cd}'), ('\u{4cf}', '\u{4c0}'), ('\u{4d1}', '\u{4d0}'),
+    ('\u{4d3}', '\u{4d2}'), ('\u{4d5}', '\u{4d4}'), ('\u{4d7}', '\u{4d6}'), ('\u{4d9}', '\u{4d8}'),
+    ('\u{4db}', '\u{4da}'), ('\u{4dd}', '\u{4dc}'), ('\u{4df}', '\u{4de}'), ('\u{4e1}', '\u{4e0}'),
+    ('\u{4e3}', '\u{4e2}'), ('\u{4e5}', '\u{4e4}'), ('\u{4e7}', '\u{4e6}'), ('\u{4e9}', '\u{4e8}'),
+    ('\u{4eb}', '\u{4ea}'), ('\u{4ed}', '\u{4ec}'), ('\u{4ef}', '\u{4ee}'), ('\u{4f1}', '\u{4f0}'),
+    ('\u{4f3}', '\u{4f2}'), ('\u{4f5}', '\u{4f4}'), ('\u{4f7}', '\u{4f6}'), ('\u{4f9}', '\u{4f8}'),
+    ('\u{4fb}', '\u{4fa}'), ('\u{4fd}', '\u{4fc}'), ('\u{4ff}', '\u{4fe}'), ('\u{501}', '\u{500}'),
+    ('\u{503}', '\u{502}'), ('\u{505}', '\u{504}'), ('\u{507}', '\u{506}'), ('\u{509}', '\u{508}'),
+    ('\u{50b}', '\u{50a}'), ('\u{50d}', '\u{50c}'), ('\u{50f}', '\u{50e}'), ('\u{511}', '\u{510}'),
+    ('\u{513}', '\u{512}'), ('\u{515}', '\u{514}'), ('\u{517}', '\u{516}'), ('\u{519}', '\u{518}'),
+    ('\u{51b}', '\u{51a}'), ('\u{51d}', '\u{51c}'), ('\u{51f}', '\u{51e}'), ('\u{521}', '\u{520}'),
+    ('\u{523}', '\u{522}'), ('\u{525}', '\u{524}'), ('\u{527}', '\u{526}'), ('\u{529}', '\u{528}'),
+    ('\u{52b}', '\u{52a}'), ('\u{52d}', '\u{52c}'), ('\u{52f}', '\u{52e}'), ('\u{561}', '\u{531}'),
+    ('\u{562}', '\u{532}'), ('\u{563}', '\u{533}'), ('\u{564}', '\u{534}'), ('\u{565}', '\u{535}'),
+    ('\u{566}', '\u{536}'), ('\u{567}', '\u{537}'), ('\u{568}', '\u{538}'), ('\u{569}', '\u{539}'),
+    ('\u{56a}', '\u{53a}'), ('\u{56b}', '\u{53b}'), ('\u{56c}', '\u{53c}'), ('\u{56d}', '\u{53d}'),
+    ('\u{56e}', '\u{53e}'), ('\u{56f}', '\u{53f}'), ('\u{570}', '\u{540}'), ('\u{571}', '\u{541}'),
+    ('\u{572}', '\u{542}'), ('\u{573}', '\u{543}'), ('\u{574}', '\u{544}'), ('\u{575}', '\u{545}'),
+    ('\u{576}', '\u{546}'), ('\u{577}', '\u{547}'), ('\u{578}', '\u{548}'), ('\u{579}', '\u{549}'),
+    ('\u{57a}', '\u{54a}'), ('\u{57b}', '\u{54b}'), ('\u{57c}', '\u{54c}'), ('\u{57d}', '\u{54d}'),
+    ('\u{57e}', '\u{54e}'), ('\u{57f}', '\u{54f}'), ('\u{580}', '\u{550}'), ('\u{581}', '\u{551}'),
+    ('\u{582}', '\u{552}'), ('\u{583}', '\u{553}'), ('\u{584}', '\u{554}'), ('\u{585}', '\u{555}'),
+    ('\u{586}', '\u{556}'), ('\u{10d0}', '\u{1c90}'), ('\u{10d1}', '\u{1c91}'), ('\u{10d2}', '\u{1c92}'),
+    ('\u{10d3}', '\u{1c93}'), ('\u{10d4}', '\u{1c94}'), ('\u{10d5}', '\u{1c95}'), ('\u{10d6}', '\u{1c96}'),
+    ('\u{10d7}', '\u{1c97}'), ('\u{10d8}', '\u{1c98}'), ('\u{10d9}', '\u{1c99}'), ('\u{10da}', '\u{1c9a}'),
+    ('\u{10db}', '\u{1c9b}'), ('\u{10dc}', '\u{1c9c}'), ('\u{10dd}', '\u{1c9d}'), ('\u{10de}', '\u{1c9e}'),
+    ('\u{10df}', '\u{1c9f}'), ('\u{10e0}', '\u{1ca0}'), ('\u{10e1}', '\u{1ca1}'), ('\u{10e2}', '\u{1ca2}'),
+    ('\u{10e3}', '\u{1ca3}'), ('\u{10e4}', '\u{1ca4}'), ('\u{10e5}', '\u{1ca5}'), ('\u{10e6}', '\u{1ca6}'),
+    ('\u{10e7}', '\u{1ca7}'), ('\u{10e8}', '\u{1ca8}'), ('\u{10e9}', '\u{1ca9}'), ('\u{10ea}', '\u{1caa}'),
+    ('\u{10eb}', '\u{1cab}'), ('\u{10ec}', '\u{1cac}'), ('\u{10ed}', '\u{1cad}'), ('\u{10ee}', '\u{1cae}'),
+    ('\u{10ef}', '\u{1caf}'), ('\u{10f0}', '\u{1cb0}'), ('\u{10f1}', '\u{1cb1}'), ('\u{10f2}', '\u{1cb2}'),
+    ('\u{10f3}', '\u{1cb3}'), ('\u{10f4}', '\u{1cb4}'), ('\u{10f5}', '\u{1cb5}'), ('\u{10f6}', '\u{1cb6}'),
+    ('\u{10f7}', '\u{1cb7}'), ('\u{10f8}', '\u{1cb8}'), ('\u{10f9}', '\u{1cb9}'), ('\u{10fa}', '\u{1cba}'),
+    ('\u{10fd}', '\u{1cbd}'), ('\u{10fe}', '\u{1cbe}'), ('\u{10ff}', '\u{1cbf}'), ('\u{13f8}', '\u{13f0}'),
+    ('\u{13f9}', '\u{13f1}'), ('\u{13fa}', '\u{13f2}'), ('\u{13fb}', '\u{13f3}'), ('\u{13fc}', '\u{13f4}'),
+    ('\u{13fd}', '\u{13f5}'), ('\u{1c80}', '\u{412}'), ('\u{1c81}', '\u{414}'), ('\u{1c82}', '\u{41e}'),
+    ('\u{1c83}', '\u{421}'), ('\u{1c84}', '\u{422}'), ('\u{1c85}', '\u{422}'), ('\u{1c86}', '\u{42a}'),
+    ('\u{1c87}', '\u{462}'), ('\u{1c88}', '\u{a64a}'), ('\u{1c8a}', '\u{1c89}'), ('\u{1d79}', '\u{a77d}'),
+    ('\u{1d7d}', '\u{2c63}'), ('\u{1d8e}', '\u{a7c6}'), ('\u{1e01}', '\u{1e00}'), ('\u{1e03}', '\u{1e02}'),
+    ('\u{1e05}', '\u{1e04}'), ('\u{1e07}', '\u{1e06}'), ('\u{1e09}', '\u{1e08}'), ('\u{1e0b}', '\u{1e0a}'),
+    ('\u{1e0d}', '\u{1e0c}'), ('\u{1e0f}', '\u{1e0e}'), ('\u{1e11}', '\u{1e10}'), ('\u{1e13}', '\u{1e12}'),
+    ('\u{1e15}', '\u{1e14}'), ('\u{1e17}', '\u{1e16}'), ('\u{1e19}', '\u{1e18}'), ('\u{1e1b}', '\u{1e1a}'),
+    ('\u{1e1d}', '\u{1e1c}'), ('\u{1e1f}', '\u{1e1e}'), ('\u{1e21}', '\u{1e20}'), ('\u{1e23}', '\u{1e22}'),
+    ('\u{1e25}', '\u{1e24}'), ('\u{1e27}', '\u{1e26}'), ('\u{1e29}', '\u{1e28}'), ('\u{1e2b}', '\u{1e2a}'),
+    ('\u{1e2d}', '\u{1e2c}'), ('\u{1e2f}', '\u{1e2e}'), ('\u{1e31}', '\u{1e30}'), ('\u{1e33}', '\u{1e32}'),
+    ('\u{1e35}', '\u{1e34}'), ('\u{1e37}', '\u{1e36}'), ('\u{1e39}', '\u{1e38}'), ('\u{1e3b}', '\u{1e3a}'),
+    ('\u{1e3d}', '\u{1e3c}'), ('\u{1e3f}', '\u{1e3e}'), ('\u{1e41}', '\u{1e40}'), ('\u{1e43}', '\u{1e42}'),
+    ('\u{1e45}', '\u{1e44}'), ('\u{1e47}', '\u{1e46}'), ('\u{1e49}', '\u{1e48}'), ('\u{1e4b}', '\u{1e4a}'),
+    ('\u{1e4d}', '\u{1e4c}'), ('\u{1e4f}', '\u{1e4e}'), ('\u{1e51}', '\u{1e50}'), ('\u{1e53}', '\u{1e52}'),
+    ('\u{1e55}', '\u{1e54}'), ('\u{1e57}', '\u{1e56}'), ('\u{1e59}', '\u{1e58}'), ('\u{1e5b}', '\u{1e5a}'),
+    ('\u{1e5d}', '\u{1e5c}'), ('\u{1e5f}', '\u{1e5e}'), ('\u{1e61}', '\u{1e60}'), ('\u{1e63}', '\u{1e62}'),
+    ('\u{1e65}', '\u{1e64}'), ('\u{1e67}', '\u{1e66}'), ('\u{1e69}', '\u{1e68}'), ('\u{1e6b}', '\u{1e6a}'),
+    ('\u{1e6d}', '\u{1e6c}'), ('\u{1e6f}', '\u{1e6e}'), ('\u{1e71}', '\u{1e70}'), ('\u{1e73}', '\u{1e72}'),
+    ('\u{1e75}', '\u{1e74}'), ('\u{1e77}', '\u{1e76}'), ('\u{1e79}', '\u{1e78}'), ('\u{1e7b}', '\u{1e7a}'),
+    ('\u{1e7d}', '\u{1e7c}'), ('\u{1e7f}', '\u{1e7e}'), ('\u{1e81}', '\u{1e80}'), ('\u{1e83}', '\u{1e82}'),
+    ('\u{1e85}', '\u{1e84}'), ('\u{1e87}', '\u{1e86}'), ('\u{1e89}', '\u{1e88}'), ('\u{1e8b}', '\u{1e8a}'),
+    ('\u{1e8d}', '\u{1e8c}'), ('\u{1e8f}', '\u{1e8e}'), ('\u{1e91}', '\u{1e90}'), ('\u{1e93}', '\u{1e92}'),
+    ('\u{1e95}', '\u{1e94}'), ('\u{1e9b}', '\u{1e60}'), ('\u{1ea1}', '\u{1ea0}'), ('\u{1ea3}', '\u{1ea2}'),
+    ('\u{1ea5}', '\u{1ea4}'), ('\u{1ea7}', '\u{1ea6}'), ('\u{1ea9}', '\u{1ea8}'), ('\u{1eab}', '\u{1eaa}'),
+    ('\u{1ead}', '\u{1eac}'), ('\u{1eaf}', '\u{1eae}'), ('\u{1eb1}', '\u{1eb0}'), ('\u{1eb3}', '\u{1eb2}'),
+    ('\u{1eb5}', '\u{1eb4}'), ('\u{1eb7}', '\u{1eb6}'), ('\u{1eb9}', '\u{1eb8}'), ('\u{1ebb}', '\u{1eba}'),
+    ('\u{1ebd}', '\u{1ebc}'), ('\u{1ebf}', '\u{1ebe}'), ('\u{1ec1}', '\u{1ec0}'), ('\u{1ec3}', '\u{1ec2}'),
+    ('\u{1ec5}', '\u{1ec4}'), ('\u{1ec7}', '\u{1ec6}'), ('\u{1ec9}', '\u{1ec8}'), ('\u{1ecb}', '\u{1eca}'),
+    ('\u{1ecd}', '\u{1ecc}'), ('\u{1ecf}', '\u{1ece}'), ('\u{1ed1}', '\u{1ed0}'), ('\u{1ed3}', '\u{1ed2}'),
+    ('\u{1ed5}', '\u{1ed4}'), ('\u{1ed7}', '\u{1ed6}'), ('\u{1ed9}', '\u{1ed8}'), ('\u{1edb}', '\u{1eda}'),
+    ('\u{1edd}', '\u{1edc}'), ('\u{1edf}', '\u{1ede}'), ('\u{1ee1}', '\u{1ee0}'), ('\u{1ee3}', '\u{1ee2}'),
+    ('\u{1ee5}', '\u{1ee4}'), ('\u{1ee7}', '\u{1ee6}'), ('\u{1ee9}', '\u{1ee8}'), ('\u{1eeb}', '\u{1eea}'),
+    ('\u{1eed}', '\u{1eec}'), ('\u{1eef}', '\u{1eee}'), ('\u{1ef1}', '\u{1ef0}'), ('\u{1ef3}', '\u{1ef2}'),
+    ('\u{1ef5}', '\u{1ef4}'), ('\u{1ef7}', '\u{1ef6}'), ('\u{1ef9}', '\u{1ef8}'), ('\u{1efb}', '\u{1efa}'),
+    ('\u{1efd}', '\u{1efc}'), ('\u{1eff}', '\u{1efe}'), ('\u{1f00}', '\u{1f08}'), ('\u{1f01}', '\u{1f09}'),
+    ('\u{1f02}', '\u{1f0a}'), ('\u{1f03}', '\u{1f0b}'), ('\u{1f04}', '\u{1f0c}'), ('\u{1f05}', '\u{1f0d}'),
+    ('\u{1f06}', '\u{1f0e}'), ('\u{1f07}', '\u{1f0f}'), ('\u{1f10}', '\u{1f18}'), ('\u{1f11}', '\u{1f19}'),
+    ('\u{1f12}', '\u{1f1a}'), ('\u{1f13}', '\u{1f1b}'), ('\u{1f14}', '\u{1f1c}'), ('\u{1f15}', '\u{1f1d}'),
+    ('\u{1f20}', '\u{1f28}'), ('\u{1f21}', '\u{1f29}'), ('\u{1f22}', '\u{1f2a}'), ('\u{1f23}', '\u{1f2b}'),
+    ('\u{1f24}', '\u{1f2c}'), ('\u{1f25}', '\u{1f2d}'), ('\u{1f26}', '\u{1f2e}'), ('\u{1f27}', '\u{1f2f}'),
+    ('\u{1f30}', '\u{1f38}'), ('\u{1f31}', '\u{1f39}'), ('\u{1f32}', '\u{1f3a}'), ('\u{1f33}', '\u{1f3b}'),
+    ('\u{1f34}', '\u{1f3c}'), ('\u{1f35}', '\u{1f3d}'), ('\u{1f36}', '\u{1f3e}'), ('\u{1f37}', '\u{1f3f}'),
+    ('\u{1f40}', '\u{1f48}'), ('\u{1f41}', '\u{1f49}'), ('\u{1f42}', '\u{1f4a}'), ('\u{1f43}', '\u{1f4b}'),
+    ('\u{1f44}', '\u{1f4c}'), ('\u{1f45}', '\u{1f4d}'), ('\u{1f51}', '\u{1f59}'), ('\u{1f53}', '\u{1f5b}'),
+    ('\u{1f55}', '\u{1f5d}'), ('\u{1f57}', '\u{1f5f}'), ('\u{1f60}', '\u{1f68}'), ('\u{1f61}', '\u{1f69}'),
+    ('\u{1f62}', '\u{1f6a}'), ('\u{1f63}', '\u{1f6b}'), ('\u{1f64}', '\u{1f6c}'), ('\u{1f65}', '\u{1f6d}'),
+    ('\u{1f66}', '\u{1f6e}'), ('\u{1f67}', '\u{1f6f}'), ('\u{1f70}', '\u{1fba}'), ('\u{1f71}', '\u{1fbb}'),
+    ('\u{1f72}', '\u{1fc8}'), ('\u{1f73}', '\u{1fc9}'), ('\u{1f74}', '\u{1fca}'), ('\u{1f75}', '\u{1fcb}'),
+    ('\u{1f76}', '\u{1fda}'), ('\u{1f77}', '\u{1fdb}'), ('\u{1f78}', '\u{1ff8}'), ('\u{1f79}', '\u{1ff9}'),
+    ('\u{1f7a}', '\u{1fea}'), ('\u{1f7b}', '\u{1feb}'), ('\u{1f7c}', '\u{1ffa}'), ('\u{1f7d}', '\u{1ffb}'),
+    ('\u{1fb0}', '\u{1fb8}'), ('\u{1fb1}', '\u{1fb9}'), ('\u{1fbe}', '\u{399}'), ('\u{1fd0}', '\u{1fd8}'),
+    ('\u{1fd1}', '\u{1fd9}'), ('\u{1fe0}', '\u{1fe8}'), ('\u{1fe1}', '\u{1fe9}'), ('\u{1fe5}', '\u{1fec}'),
+    ('\u{214e}', '\u{2132}'), ('\u{2170}', '\u{2160}'), ('\u{2171}', '\u{2161}'), ('\u{2172}', '\u{2162}'),
+    ('\u{2173}', '\u{2163}'), ('\u{2174}', '\u{2164}'), ('\u{2175}', '\u{2165}'), ('\u{2176}', '\u{2166}'),
+    ('\u{2177}', '\u{2167}'), ('\u{2178}', '\u{2168}'), ('\u{2179}', '\u{2169}'), ('\u{217a}', '\u{216a}'),
+    ('\u{217b}', '\u{216b}'), ('\u{217c}', '\u{216c}'), ('\u{217d}', '\u{216d}'), ('\u{217e}', '\u{216e}'),
+    ('\u{217f}', '\u{216f}'), ('\u{2184}', '\u{2183}'), ('\u{24d0}', '\u{24b6}'), ('\u{24d1}', '\u{24b7}'),
+    ('\u{24d2}', '\u{24b8}'), ('\u{24d3}', '\u{24b9}'), ('\u{24d4}', '\u{24ba}'), ('\u{24d5}', '\u{24bb}'),
+    ('\u{24d6}', '\u{24bc}'), ('\u{24d7}', '\u{24bd}'), ('\u{24d8}', '\u{24be}'), ('\u{24d9}', '\u{24bf}'),
+    ('\u{24da}', '\u{24c0}'), ('\u{24db}', '\u{24c1}'), ('\u{24dc}', '\u{24c2}'), ('\u{24dd}', '\u{24c3}'),
+    ('\u{24de}', '\u{24c4}'), ('\u{24df}', '\u{24c5}'), ('\u{24e0}', '\u{24c6}'), ('\u{24e1}', '\u{24c7}'),
+    ('\u{24e2}', '\u{24c8}'), ('\u{24e3}', '\u{24c9}'), ('\u{24e4}', '\u{24ca}'), ('\u{24e5}', '\u{24cb}'),
+    ('\u{24e6}', '\u{24cc}'), ('\u{24e7}', '\u{24cd}'), ('\u{24e8}', '\u{24ce}'), ('\u{24e9}', '\u{24cf}'),
+    ('\u{2c30}', '\u{2c00}'), ('\u{2c31}', '\u{2c01}'), ('\u{2c32}', '\u{2c02}'), ('\u{2c33}', '\u{2c03}'),
+    ('\u{2c34}', '\u{2c04}'), ('\u{2c35}', '\u{2c05}'), ('\u{2c36}', '\u{2c06}'), ('\u{2c37}', '\u{2c07}'),
+    ('\u{2c38}', '\u{2c08}'), ('\u{2c39}', '\u{2c09}'), ('\u{2c3a}', '\u{2c0a}'), ('\u{2c3b}', '\u{2c0b}'),
+    ('\u{2c3c}', '\u{2c0c}'), ('\u{2c3d}', '\u{2c0d}'), ('\u{2c3e}', '\u{2c0e}'), ('\u{2c3f}', '\u{2c0f}'),
+    ('\u{2c40}', '\u{2c10}'), ('\u{2c41}', '\u{2c11}'), ('\u{2c42}', '\u{2c12}'), ('\u{2c43}', '\u{2c13}'),
+    ('\u{2c44}', '\u{2c14}'), ('\u{2c45}', '\u{2c15}'), ('\u{2c46}', '\u{2c16}'), ('\u{2c47}', '\u{2c17}'),
+    ('\u{2c48}', '\u{2c18}'), ('\u{2c49}', '\u{2c19}'), ('\u{2c4a}', '\u{2c1a}'), ('\u{2c4b}', '\u{2c1b}'),
+    ('\u{2c4c}', '\u{2c1c}'), ('\u{2c4d}', '\u{2c1d}'), ('\u{2c4e}', '\u{2c1e}'), ('\u{2c4f}', '\u{2c1f}'),
+    ('\u{2c50}', '\u{2c20}'), ('\u{2c51}', '\u{2c21}'), ('\u{2c52}', '\u{2c22}'), ('\u{2c53}', '\u{2c23}'),
+    ('\u{2c54}', '\u{2c24}'), ('\u{2c55}', '\u{2c25}'), ('\u{2c56}', '\u{2c26}'), ('\u{2c57}', '\u{2c27}'),
+    ('\u{2c58}', '\u{2c28}'), ('\u{2c59}', '\u{2c29}'), ('\u{2c5a}', '\u{2c2a}'), ('\u{2c5b}', '\u{2c2b}'),
+    ('\u{2c5c}', '\u{2c2c}'), ('\u{2c5d}', '\u{2c2d}'), ('\u{2c5e}', '\u{2c2e}'), ('\u{2c5f}', '\u{2c2f}'),
+    ('\u{2c61}', '\u{2c60}'), ('\u{2c65}', '\u{23a}'), ('\u{2c66}', '\u{23e}'), ('\u{2c68}', '\u{2c67}'),
+    ('\u{2c6a}', '\u{2c69}'), ('\u{2c6c}', '\u{2c6b}'), ('\u{2c73}', '\u{2c72}'), ('\u{2c76}', '\u{2c75}'),
+    ('\u{2c81}', '\u{2c80}'), ('\u{2c83}', '\u{2c82}'), ('\u{2c85}', '\u{2c84}'), ('\u{2c87}', '\u{2c86}'),
+    ('\u{2c89}', '\u{2c88}'), ('\u{2c8b}', '\u{2c8a}'), ('\u{2c8d}', '\u{2c8c}'), ('\u{2c8f}', '\u{2c8e}'),
+    ('\u{2c91}', '\u{2c90}'), ('\u{2c93}', '\u{2c92}'), ('\u{2c95}', '\u{2c94}'), ('\u{2c97}', '\u{2c96}'),
+    ('\u{2c99}', '\u{2c98}'), ('\u{2c9b}', '\u{2c9a}'), ('\u{2c9d}', '\u{2c9c}'), ('\u{2c9f}', '\u{2c9e}'),
+    ('\u{2ca1}', '\u{2ca0}'), ('\u{2ca3}', '\u{2ca2}'), ('\u{2ca5}', '\u{2ca4}'), ('\u{2ca7}', '\u{2ca6}'),
+    ('\u{2ca9}', '\u{2ca8}'), ('\u{2cab}', '\u{2caa}'), ('\u{2cad}', '\u{2cac}'), ('\u{2caf}', '\u{2cae}'),
+    ('\u{2cb1}', '\u{2cb0}'), ('\u{2cb3}', '\u{2cb2}'), ('\u{2cb5}', '\u{2cb4}'), ('\u{2cb7}', '\u{2cb6}'),
+    ('\u{2cb9}', '\u{2cb8}'), ('\u{2cbb}', '\u{2cba}'), ('\u{2cbd}', '\u{2cbc}'), ('\u{2cbf}', '\u{2cbe}'),
+    ('\u{2cc1}', '\u{2cc0}'), ('\u{2cc3}', '\u{2cc2}'), ('\u{2cc5}', '\u{2cc4}'), ('\u{2cc7}', '\u{2cc6}'),
+    ('\u{2cc9}', '\u{2cc8}'), ('\u{2ccb}', '\u{2cca}'), ('\u{2ccd}', '\u{2ccc}'), ('\u{2ccf}', '\u{2cce}'),
+    ('\u{2cd1}', '\u{2cd0}'), ('\u{2cd3}', '\u{2cd2}'), ('\u{2cd5}', '\u{2cd4}'), ('\u{2cd7}', '\u{2cd6}'),
+    ('\u{2cd9}', '\u{2cd8}'), ('\u{2cdb}', '\u{2cda}'), ('\u{2cdd}', '\u{2cdc}'), ('\u{2cdf}', '\u{2cde}'),
+    ('\u{2ce1}', '\u{2ce0}'), ('\u{2ce3}', '\u{2ce2}'), ('\u{2cec}', '\u{2ceb}'), ('\u{2cee}', '\u{2ced}'),
+    ('\u{2cf3}', '\u{2cf2}'), ('\u{2d00}', '\u{10a0}'), ('\u{2d01}', '\u{10a1}'), ('\u{2d02}', '\u{10a2}'),
+    ('\u{2d03}', '\u{10a3}'), ('\u{2d04}', '\u{10a4}'), ('\u{2d05}', '\u{10a5}'), ('\u{2d06}', '\u{10a6}'),
+    ('\u{2d07}', '\u{10a7}'), ('\u{2d08}', '\u{10a8}'), ('\u{2d09}', '\u{10a9}'), ('\u{2d0a}', '\u{10aa}'),
+    ('\u{2d0b}', '\u{10ab}'), ('\u{2d0c}', '\u{10ac}'), ('\u{2d0d}', '\u{10ad}'), ('\u{2d0e}', '\u{10ae}'),
+    ('\u{2d0f}', '\u{10af}'), ('\u{2d10}', '\u{10b0}'), ('\u{2d11}', '\u{10b1}'), ('\u{2d12}', '\u{10b2}'),
+    ('\u{2d13}', '\u{10b3}'), ('\u{2d14}', '\u{10b4}'), ('\u{2d15}', '\u{10b5}'), ('\u{2d16}', '\u{10b6}'),
+    ('\u{2d17}', '\u{10b7}'), ('\u{2d18}', '\u{10b8}'), ('\u{2d19}', '\u{10b9}'), ('\u{2d1a}', '\u{10ba}'),
+    ('\u{2d1b}', '\u{10bb}'), ('\u{2d1c}', '\u{10bc}'), ('\u{2d1d}', '\u{10bd}'), ('\u{2d1e}', '\u{10be}'),
+    ('\u{2d1f}', '\u{10bf}'), ('\u{2d20}', '\u{10c0}'), ('\u{2d21}', '\u{10c1}'), ('\u{2d22}', '\u{10c2}'),
+    ('\u{2d23}', '\u{10c3}'), ('\u{2d24}', '\u{10c4}'), ('\u{2d25}', '\u{10c5}'), ('\u{2d27}', '\u{10c7}'),
+    ('\u{2d2d}', '\u{10cd}'), ('\u{a641}', '\u{a640}'), ('\u{a643}', '\u{a642}'), ('\u{a645}', '\u{a644}'),
+    ('\u{a647}', '\u{a646}'), ('\u{a649}', '\u{a648}'), ('\u{a64b}', '\u{a64a}'), ('\u{a64d}', '\u{a64c}'),
+    ('\u{a64f}', '\u{a64e}'), ('\u{a651}', '\u{a650}'), ('\u{a653}', '\u{a652}'), ('\u{a655}', '\u{a654}'),
+    ('\u{a657}', '\u{a656}'), ('\u{a659}', '\u{a658}'), ('\u{a65b}', '\u{a65a}'), ('\u{a65d}', '\u{a65c}'),
+    ('\u{a65f}', '\u{a65e}'), ('\u{a661}', '\u{a660}'), ('\u{a663}', '\u{a662}'), ('\u{a665}', '\u{a664}'),
+    ('\u{a667}', '\u{a666}'), ('\u{a669}', '\u{a668}'), ('\u{a66b}', '\u{a66a}'), ('\u{a66d}', '\u{a66c}'),
+    ('\u{a681}', '\u{a680}'), ('\u{a683}', '\u{a682}'), ('\u{a685}', '\u{a684}'), ('\u{a687}', '\u{a686}'),
+    ('\u{a689}', '\u{a688}'), ('\u{a68b}', '\u{a68a}'), ('\u{a68d}', '\u{a68c}'), ('\u{a68f}', '\u{a68e}'),
+    ('\u{a691}', '\u{a690}'), ('\u{a693}', '\u{a692}'), ('\u{a695}', '\u{a694}'), ('\u{a697}', '\u{a696}'),
+    ('\u{a699}', '\u{a698}'), ('\u{a69b}', '\u{a69a}'), ('\u{a723}', '\u{a722}'), ('\u{a725}', '\u{a724}'),
+    ('\u{a727}', '\u{a726}'), ('\u{a729}', '\u{a728}'), ('\u{a72b}', '\u{a72a}'), ('\u{a72d}', '\u{a72c}'),
+    ('\u{a72f}', '\u{a72e}'), ('\u{a733}', '\u{a732}'), ('\u{a735}', '\u{a734}'), ('\u{a737}', '\u{a736}'),
+    ('\u{a739}', '\u{a738}'), ('\u{a73b}', '\u{a73a}'), ('\u{a73d}', '\u{a73c}'), ('\u{a73f}', '\u{a73e}'),
+    ('\u{a741}', '\u{a740}'), ('\u{a743}', '\u{a742}'), ('\u{a745}', '\u{a744}'), ('\u{a747}', '\u{a746}'),
+    ('\u{a749}', '\u{a748}'), ('\u{a74b}', '\u{a74a}'), ('\u{a74d}', '\u{a74c}'), ('\u{a74f}', '\u{a74e}'),
+    ('\u{a751}', '\u{a750}'), ('\u{a753}', '\u{a752}'), ('\u{a755}', '\u{a754}'), ('\u{a757}', '\u{a756}'),
+    ('\u{a759}', '\u{a758}'), ('\u{a75b}', '\u{a75a}'), ('\u{a75d}', '\u{a75c}'), ('\u{a75f}', '\u{a75e}'),
+    ('\u{a761}', '\u{a760}'), ('\u{a763}', '\u{a762}'), ('\u{a765}', '\u{a764}'), ('\u{a767}', '\u{a766}'),
+    ('\u{a769}', '\u{a768}'), ('\u{a76b}', '\u{a76a}'), ('\u{a76d}', '\u{a76c}'), ('\u{a76f}', '\u{a76e}'),
+    ('\u{a77a}', '\u{a779}'), ('\u{a77c}', '\u{a77b}'), ('\u{a77f}', '\u{a77e}'), ('\u{a781}', '\u{a780}'),
+    ('\u{a783}', '\u{a782}'), ('\u{a785}', '\u{a784}'), ('\u{a787}', '\u{a786}'), ('\u{a78c}', '\u{a78b}'),
+    ('\u{a791}', '\u{a790}'), ('\u{a793}', '\u{a792}'), ('\u{a794}', '\u{a7c4}'), ('\u{a797}', '\u{a796}'),
+    ('\u{a799}', '\u{a798}'), ('\u{a79b}', '\u{a79a}'), ('\u{a79d}', '\u{a79c}'), ('\u{a79f}', '\u{a79e}'),
+    ('\u{a7a1}', '\u{a7a0}'), ('\u{a7a3}', '\u{a7a2}'), ('\u{a7a5}', '\u{a7a4}'), ('\u{a7a7}', '\u{a7a6}'),
+    ('\u{a7a9}', '\u{a7a8}'), ('\u{a7b5}', '\u{a7b4}'), ('\u{a7b7}', '\u{a7b6}'), ('\u{a7b9}', '\u{a7b8}'),
+    ('\u{a7bb}', '\u{a7ba}'), ('\u{a7bd}', '\u{a7bc}'), ('\u{a7bf}', '\u{a7be}'), ('\u{a7c1}', '\u{a7c0}'),
+    ('\u{a7c3}', '\u{a7c2}'), ('\u{a7c8}', '\u{a7c7}'), ('\u{a7ca}', '\u{a7c9}'), ('\u{a7cd}', '\u{a7cc}'),
+    ('\u{a7cf}', '\u{a7ce}'), ('\u{a7d1}', '\u{a7d0}'), ('\u{a7d3}', '\u{a7d2}'), ('\u{a7d5}', '\u{a7d4}'),
+    ('\u{a7d7}', '\u{a7d6}'), ('\u{a7d9}', '\u{a7d8}'), ('\u{a7db}', '\u{a7da}'), ('\u{a7f6}', '\u{a7f5}'),
+    ('\u{ab53}', '\u{a7b3}'), ('\u{ab70}', '\u{13a0}'), ('\u{ab71}', '\u{13a1}'), ('\u{ab72}', '\u{13a2}'),
+    ('\u{ab73}', '\u{13a3}'), ('\u{ab74}', '\u{13a4}'), ('\u{ab75}', '\u{13a5}'), ('\u{ab76}', '\u{13a6}'),
+    ('\u{ab77}', '\u{13a7}'), ('\u{ab78}', '\u{13a8}'), ('\u{ab79}', '\u{13a9}'), ('\u{ab7a}', '\u{13aa}'),
+    ('\u{ab7b}', '\u{13ab}'), ('\u{ab7c}', '\u{13ac}'), ('\u{ab7d}', '\u{13ad}'), ('\u{ab7e}', '\u{13ae}'),
+    ('\u{ab7f}', '\u{13af}'), ('\u{ab80}', '\u{13b0}'), ('\u{ab81}', '\u{13b1}'), ('\u{ab82}', '\u{13b2}'),
+    ('\u{ab83}', '\u{13b3}'), ('\u{ab84}', '\u{13b4}'), ('\u{ab85}', '\u{13b5}'), ('\u{ab86}', '\u{13b6}'),
+    ('\u{ab87}', '\u{13b7}'), ('\u{ab88}', '\u{13b8}'), ('\u{ab89}', '\u{13b9}'), ('\u{ab8a}', '\u{13ba}'),
+    ('\u{ab8b}', '\u{13bb}'), ('\u{ab8c}', '\u{13bc}'), ('\u{ab8d}', '\u{13bd}'), ('\u{ab8e}', '\u{13be}'),
+    ('\u{ab8f}', '\u{13bf}'), ('\u{ab90}', '\u{13c0}'), ('\u{ab91}', '\u{13c1}'), ('\u{ab92}', '\u{13c2}'),
+    ('\u{ab93}', '\u{13c3}'), ('\u{ab94}', '\u{13c4}'), ('\u{ab95}', '\u{13c5}'), ('\u{ab96}', '\u{13c6}'),
+    ('\u{ab97}', '\u{13c7}'), ('\u{ab98}', '\u{13c8}'), ('\u{ab99}', '\u{13c9}'), ('\u{ab9a}', '\u{13ca}'),
+    ('\u{ab9b}', '\u{13cb}'), ('\u{ab9c}', '\u{13cc}'), ('\u{ab9d}', '\u{13cd}'), ('\u{ab9e}', '\u{13ce}'),
+    ('\u{ab9f}', '\u{13cf}'), ('\u{aba0}', '\u{13d0}'), ('\u{aba1}', '\u{13d1}'), ('\u{aba2}', '\u{13d2}'),
+    ('\u{aba3}', '\u{13d3}'), ('\u{aba4}', '\u{13d4}'), ('\u{aba5}', '\u{13d5}'), ('\u{aba6}', '\u{13d6}'),
+    ('\u{aba7}', '\u{13d7}'), ('\u{aba8}', '\u{13d8}'), ('\u{aba9}', '\u{13d9}'), ('\u{abaa}', '\u{13da}'),
+    ('\u{abab}', '\u{13db}'), ('\u{abac}', '\u{13dc}'), ('\u{abad}', '\u{13dd}'), ('\u{abae}', '\u{13de}'),
+    ('\u{abaf}', '\u{13df}'), ('\u{abb0}', '\u{13e0}'), ('\u{abb1}', '\u{13e1}'), ('\u{abb2}', '\u{13e2}'),
+    ('\u{abb3}', '\u{13e3}'), ('\u{abb4}', '\u{13e4}'), ('\u{abb5}', '\u{13e5}'), ('\u{abb6}', '\u{13e6}'),
+    ('\u{abb7}', '\u{13e7}'), ('\u{abb8}', '\u{13e8}'), ('\u{abb9}', '\u{13e9}'), ('\u{abba}', '\u{13ea}'),
+    ('\u{abbb}', '\u{13eb}'), ('\u{abbc}', '\u{13ec}'), ('\u{abbd}', '\u{13ed}'), ('\u{abbe}', '\u{13ee}'),
+    ('\u{abbf}', '\u{13ef}'), ('\u{ff41}', '\u{ff21}'), ('\u{ff42}', '\u{ff22}'), ('\u{ff43}', '\u{ff23}'),
+    ('\u{ff44}', '\u{ff24}'), ('\u{ff45}', '\u{ff25}'), ('\u{ff46}', '\u{ff26}'), ('\u{ff47}', '\u{ff27}'),
+    ('\u{ff48}', '\u{ff28}'), ('\u{ff49}', '\u{ff29}'), ('\u{ff4a}', '\u{ff2a}'), ('\u{ff4b}', '\u{ff2b}'),
+    ('\u{ff4c}', '\u{ff2c}'), ('\u{ff4d}', '\u{ff2d}'), ('\u{ff4e}', '\u{ff2e}'), ('\u{ff4f}', '\u{ff2f}'),
+    ('\u{ff50}', '\u{ff30}'), ('\u{ff51}', '\u{ff31}'), ('\u{ff52}', '\u{ff32}'), ('\u{ff53}', '\u{ff33}'),
+    ('\u{ff54}', '\u{ff34}'), ('\u{ff55}', '\u{ff35}'), ('\u{ff56}', '\u{ff36}'), ('\u{ff57}', '\u{ff37}'),
+    ('\u{ff58}', '\u{ff38}'), ('\u{ff59}', '\u{ff39}'), ('\u{ff5a}', '\u{ff3a}'), ('\u{10428}', '\u{10400}'),
+    ('\u{10429}', '\u{10401}'), ('\u{1042a}', '\u{10402}'), ('\u{1042b}', '\u{10403}'), ('\u{1042c}', '\u{10404}'),
+    ('\u{1042d}', '\u{10405}'), ('\u{1042e}', '\u{10406}'), ('\u{1042f}', '\u{10407}'), ('\u{10430}', '\u{10408}'),
+    ('\u{10431}', '\u{10409}'), ('\u{10432}', '\u{1040a}'), ('\u{10433}', '\u{1040b}'), ('\u{10434}', '\u{1040c}'),
+    ('\u{10435}', '\u{1040d}'), ('\u{10436}', '\u{1040e}'), ('\u{10437}', '\u{1040f}'), ('\u{10438}', '\u{10410}'),
+    ('\u{10439}', '\u{10411}'), ('\u{1043a}', '\u{10412}'), ('\u{1043b}', '\u{10413}'), ('\u{1043c}', '\u{10414}'),
+    ('\u{1043d}', '\u{10415}'), ('\u{1043e}', '\u{10416}'), ('\u{1043f}', '\u{10417}'), ('\u{10440}', '\u{10418}'),
+    ('\u{10441}', '\u{10419}'), ('\u{10442}', '\u{1041a}'), ('\u{10443}', '\u{1041b}'), ('\u{10444}', '\u{1041c}'),
+    ('\u{10445}', '\u{1041d}'), ('\u{10446}', '\u{1041e}'), ('\u{10447}', '\u{1041f}'), ('\u{10448}', '\u{10420}'),
+    ('\u{10449}', '\u{10421}'), ('\u{1044a}', '\u{10422}'), ('\u{1044b}', '\u{10423}'), ('\u{1044c}', '\u{10424}'),
+    ('\u{1044d}', '\u{10425}'), ('\u{1044e}', '\u{10426}'), ('\u{1044f}', '\u{10427}'), ('\u{104d8}', '\u{104b0}'),
+    ('\u{104d9}', '\u{104b1}'), ('\u{104da}', '\u{104b2}'), ('\u{104db}', '\u{104b3}'), ('\u{104dc}', '\u{104b4}'),
+    ('\u{104dd}', '\u{104b5}'), ('\u{104de}', '\u{104b6}'), ('\u{104df}', '\u{104b7}'), ('\u{104e0}', '\u{104b8}'),
+    ('\u{104e1}', '\u{104b9}'), ('\u{104e2}', '\u{104ba}'), ('\u{104e3}', '\u{104bb}'), ('\u{104e4}', '\u{104bc}'),
+    ('\u{104e5}', '\u{104bd}'), ('\u{104e6}', '\u{104be}'), ('\u{104e7}', '\u{104bf}'), ('\u{104e8}', '\u{104c0}'),
+    ('\u{104e9}', '\u{104c1}'), ('\u{104ea}', '\u{104c2}'), ('\u{104eb}', '\u{104c3}'), ('\u{104ec}', '\u{104c4}'),
+    ('\u{104ed}', '\u{104c5}'), ('\u{104ee}', '\u{104c6}'), ('\u{104ef}', '\u{104c7}'), ('\u{104f0}', '\u{104c8}'),
+    ('\u{104f1}', '\u{104c9}'), ('\u{104f2}', '\u{104ca}'), ('\u{104f3}', '\u{104cb}'), ('\u{104f4}', '\u{104cc}'),
+    ('\u{104f5}', '\u{104cd}'), ('\u{104f6}', '\u{104ce}'), ('\u{104f7}', '\u{104cf}'), ('\u{104f8}', '\u{104d0}'),
+    ('\u{104f9}', '\u{104d1}'), ('\u{104fa}', '\u{104d2}'), ('\u{104fb}', '\u{104d3}'), ('\u{10597}', '\u{10570}'),
+    ('\u{10598}', '\u{10571}'), ('\u{10599}', '\u{10572}'), ('\u{1059a}', '\u{10573}'), ('\u{1059b}', '\u{10574}'),
+    ('\u{1059c}', '\u{10575}'), ('\u{1059d}', '\u{10576}'), ('\u{1059e}', '\u{10577}'), ('\u{1059f}', '\u{10578}'),
+    ('\u{105a0}', '\u{10579}'), ('\u{105a1}', '\u{1057a}'), ('\u{105a3}', '\u{1057c}'), ('\u{105a4}', '\u{1057d}'),
+    ('\u{105a5}', '\u{1057e}'), ('\u{105a6}', '\u{1057f}'), ('\u{105a7}', '\u{10580}'), ('\u{105a8}', '\u{10581}'),
+    ('\u{105a9}', '\u{10582}'), ('\u{105aa}', '\u{10583}'), ('\u{105ab}', '\u{10584}'), ('\u{105ac}', '\u{10585}'),
+    ('\u{105ad}', '\u{10586}'), ('\u{105ae}', '\u{10587}'), ('\u{105af}', '\u{10588}'), ('\u{105b0}', '\u{10589}'),
+    ('\u{105b1}', '\u{1058a}'), ('\u{105b3}', '\u{1058c}'), ('\u{105b4}', '\u{1058d}'), ('\u{105b5}', '\u{1058e}'),
+    ('\u{105b6}', '\u{1058f}'), ('\u{105b7}', '\u{10590}'), ('\u{105b8}', '\u{10591}'), ('\u{105b9}', '\u{10592}'),
+    ('\u{105bb}', '\u{10594}'), ('\u{105bc}', '\u{10595}'), ('\u{10cc0}', '\u{10c80}'), ('\u{10cc1}', '\u{10c81}'),
+    ('\u{10cc2}', '\u{10c82}'), ('\u{10cc3}', '\u{10c83}'), ('\u{10cc4}', '\u{10c84}'), ('\u{10cc5}', '\u{10c85}'),
+    ('\u{10cc6}', '\u{10c86}'), ('\u{10cc7}', '\u{10c87}'), ('\u{10cc8}', '\u{10c88}'), ('\u{10cc9}', '\u{10c89}'),
+    ('\u{10cca}', '\u{10c8a}'), ('\u{10ccb}', '\u{10c8b}'), ('\u{10ccc}', '\u{10c8c}'), ('\u{10ccd}', '\u{10c8d}'),
+    ('\u{10cce}', '\u{10c8e}'), ('\u{10ccf}', '\u{10c8f}'), ('\u{10cd0}', '\u{10c90}'), ('\u{10cd1}', '\u{10c91}'),
+    ('\u{10cd2}', '\u{10c92}'), ('\u{10cd3}', '\u{10c93}'), ('\u{10cd4}', '\u{10c94}'), ('\u{10cd5}', '\u{10c95}'),
+    ('\u{10cd6}', '\u{10c96}'), ('\u{10cd7}', '\u{10c97}'), ('\u{10cd8}', '\u{10c98}'), ('\u{10cd9}', '\u{10c99}'),
+    ('\u{10cda}', '\u{10c9a}'), ('\u{10cdb}', '\u{10c9b}'), ('\u{10cdc}', '\u{10c9c}'), ('\u{10cdd}', '\u{10c9d}'),
+    ('\u{10cde}', '\u{10c9e}'), ('\u{10cdf}', '\u{10c9f}'), ('\u{10ce0}', '\u{10ca0}'), ('\u{10ce1}', '\u{10ca1}'),
+    ('\u{10ce2}', '\u{10ca2}'), ('\u{10ce3}', '\u{10ca3}'), ('\u{10ce4}', '\u{10ca4}'), ('\u{10ce5}', '\u{10ca5}'),
+    ('\u{10ce6}', '\u{10ca6}'), ('\u{10ce7}', '\u{10ca7}'), ('\u{10ce8}', '\u{10ca8}'), ('\u{10ce9}', '\u{10ca9}'),
+    ('\u{10cea}', '\u{10caa}'), ('\u{10ceb}', '\u{10cab}'), ('\u{10cec}', '\u{10cac}'), ('\u{10ced}', '\u{10cad}'),
+    ('\u{10cee}', '\u{10cae}'), ('\u{10cef}', '\u{10caf}'), ('\u{10cf0}', '\u{10cb0}'), ('\u{10cf1}', '\u{10cb1}'),
+    ('\u{10cf2}', '\u{10cb2}'), ('\u{10d70}', '\u{10d50}'), ('\u{10d71}', '\u{10d51}'), ('\u{10d72}', '\u{10d52}'),
+    ('\u{10d73}', '\u{10d53}'), ('\u{10d74}', '\u{10d54}'), ('\u{10d75}', '\u{10d55}'), ('\u{10d76}', '\u{10d56}'),
+    ('\u{10d77}', '\u{10d57}'), ('\u{10d78}', '\u{10d58}'), ('\u{10d79}', '\u{10d59}'), ('\u{10d7a}', '\u{10d5a}'),
+    ('\u{10d7b}', '\u{10d5b}'), ('\u{10d7c}', '\u{10d5c}'), ('\u{10d7d}', '\u{10d5d}'), ('\u{10d7e}', '\u{10d5e}'),
+    ('\u{10d7f}', '\u{10d5f}'), ('\u{10d80}', '\u{10d60}'), ('\u{10d81}', '\u{10d61}'), ('\u{10d82}', '\u{10d62}'),
+    ('\u{10d83}', '\u{10d63}'), ('\u{10d84}', '\u{10d64}'), ('\u{10d85}', '\u{10d65}'), ('\u{118c0}', '\u{118a0}'),
+    ('\u{118c1}', '\u{118a1}'), ('\u{118c2}', '\u{118a2}'), ('\u{118c3}', '\u{118a3}'), ('\u{118c4}', '\u{118a4}'),
+    ('\u{118c5}', '\u{118a5}'), ('\u{118c6}', '\u{118a6}'), ('\u{118c7}', '\u{118a7}'), ('\u{118c8}', '\u{118a8}'),
+    ('\u{118c9}', '\u{118a9}'), ('\u{118ca}', '\u{118aa}'), ('\u{118cb}', '\u{118ab}'), ('\u{118cc}', '\u{118ac}'),
+    ('\u{118cd}', '\u{118ad}'), ('\u{118ce}', '\u{118ae}'), ('\u{118cf}', '\u{118af}'), ('\u{118d0}', '\u{118b0}'),
+    ('\u{118d1}', '\u{118b1}'), ('\u{118d2}', '\u{118b2}'), ('\u{118d3}', '\u{118b3}'), ('\u{118d4}', '\u{118b4}'),
+    ('\u{118d5}', '\u{118b5}'), ('\u{118d6}', '\u{118b6}'), ('\u{118d7}', '\u{118b7}'), ('\u{118d8}', '\u{118b8}'),
+    ('\u{118d9}', '\u{118b9}'), ('\u{118da}', '\u{118ba}'), ('\u{118db}', '\u{118bb}'), ('\u{118dc}', '\u{118bc}'),
+    ('\u{118dd}', '\u{118bd}'), ('\u{118de}', '\u{118be}'), ('\u{118df}', '\u{118bf}'), ('\u{16e60}', '\u{16e40}'),
+    ('\u{16e61}', '\u{16e41}'), ('\u{16e62}', '\u{16e42}'), ('\u{16e63}', '\u{16e43}'), ('\u{16e64}', '\u{16e44}'),
+    ('\u{16e65}', '\u{16e45}'), ('\u{16e66}', '\u{16e46}'), ('\u{16e67}', '\u{16e47}'), ('\u{16e68}', '\u{16e48}'),
+    ('\u{16e69}', '\u{16e49}'), ('\u{16e6a}', '\u{16e4a}'), ('\u{16e6b}', '\u{16e4b}'), ('\u{16e6c}', '\u{16e4c}'),
+    ('\u{16e6d}', '\u{16e4d}'), ('\u{16e6e}', '\u{16e4e}'), ('\u{16e6f}', '\u{16e4f}'), ('\u{16e70}', '\u{16e50}'),
+    ('\u{16e71}', '\u{16e51}'), ('\u{16e72}', '\u{16e52}'), ('\u{16e73}', '\u{16e53}'), ('\u{16e74}', '\u{16e54}'),
+    ('\u{16e75}', '\u{16e55}'), ('\u{16e76}', '\u{16e56}'), ('\u{16e77}', '\u{16e57}'), ('\u{16e78}', '\u{16e58}'),
+    ('\u{16e79}', '\u{16e59}'), ('\u{16e7a}', '\u{16e5a}'), ('\u{16e7b}', '\u{16e5b}'), ('\u{16e7c}', '\u{16e5c}'),
+    ('\u{16e7d}', '\u{16e5d}'), ('\u{16e7e}', '\u{16e5e}'), ('\u{16e7f}', '\u{16e5f}'), ('\u{16ebb}', '\u{16ea0}'),
+    ('\u{16ebc}', '\u{16ea1}'), ('\u{16ebd}', '\u{16ea2}'), ('\u{16ebe}', '\u{16ea3}'), ('\u{16ebf}', '\u{16ea4}'),
+    ('\u{16ec0}', '\u{16ea5}'), ('\u{16ec1}', '\u{16ea6}'), ('\u{16ec2}', '\u{16ea7}'), ('\u{16ec3}', '\u{16ea8}'),
+    ('\u{16ec4}', '\u{16ea9}'), ('\u{16ec5}', '\u{16eaa}'), ('\u{16ec6}', '\u{16eab}'), ('\u{16ec7}', '\u{16eac}'),
+    ('\u{16ec8}', '\u{16ead}'), ('\u{16ec9}', '\u{16eae}'), ('\u{16eca}', '\u{16eaf}'), ('\u{16ecb}', '\u{16eb0}'),
+    ('\u{16ecc}', '\u{16eb1}'), ('\u{16ecd}', '\u{16eb2}'), ('\u{16ece}', '\u{16eb3}'), ('\u{16ecf}', '\u{16eb4}'),
+    ('\u{16ed0}', '\u{16eb5}'), ('\u{16ed1}', '\u{16eb6}'), ('\u{16ed2}', '\u{16eb7}'), ('\u{16ed3}', '\u{16eb8}'),
+    ('\u{1e922}', '\u{1e900}'), ('\u{1e923}', '\u{1e901}'), ('\u{1e924}', '\u{1e902}'), ('\u{1e925}', '\u{1e903}'),
+    ('\u{1e926}', '\u{1e904}'), ('\u{1e927}', '\u{1e905}'), ('\u{1e928}', '\u{1e906}'), ('\u{1e929}', '\u{1e907}'),
+    ('\u{1e92a}', '\u{1e908}'), ('\u{1e92b}', '\u{1e909}'), ('\u{1e92c}', '\u{1e90a}'), ('\u{1e92d}', '\u{1e90b}'),
+    ('\u{1e92e}', '\u{1e90c}'), ('\u{1e92f}', '\u{1e90d}'), ('\u{1e930}', '\u{1e90e}'), ('\u{1e931}', '\u{1e90f}'),
+    ('\u{1e932}', '\u{1e910}'), ('\u{1e933}', '\u{1e911}'), ('\u{1e934}', '\u{1e912}'), ('\u{1e935}', '\u{1e913}'),
+    ('\u{1e936}', '\u{1e914}'), ('\u{1e937}', '\u{1e915}'), ('\u{1e938}', '\u{1e916}'), ('\u{1e939}', '\u{1e917}'),
+    ('\u{1e93a}', '\u{1e918}'), ('\u{1e93b}', '\u{1e919}'), ('\u{1e93c}', '\u{1e91a}'), ('\u{1e93d}', '\u{1e91b}'),
+    ('\u{1e93e}', '\u{1e91c}'), ('\u{1e93f}', '\u{1e91d}'), ('\u{1e940}', '\u{1e91e}'), ('\u{1e941}', '\u{1e91f}'),
+    ('\u{1e942}', '\u{1e920}'), ('\u{1e943}', '\u{1e921}'),
+];
+
+#[rustfmt::skip]
+pub const TO_UPPER_MULTI: &[(char, [char; 3])] = &[
+    ('\u{df}', ['S', 'S', '\u{0}']), ('\u{149}', ['\u{2bc}', 'N', '\u{0}']),
+    ('\u{1f0}', ['J', '\u{30c}', '\u{0}']), ('\u{390}', ['\u{399}', '\u{308}', '\u{301}']),
+    ('\u{3b0}', ['\u{3a5}', '\u{308}', '\u{301}']), ('\u{587}', ['\u{535}', '\u{552}', '\u{0}']),
+    ('\u{1e96}', ['H', '\u{331}', '\u{0}']), ('\u{1e97}', ['T', '\u{308}', '\u{0}']),
+    ('\u{1e98}', ['W', '\u{30a}', '\u{0}']), ('\u{1e99}', ['Y', '\u{30a}', '\u{0}']),
+    ('\u{1e9a}', ['A', '\u{2be}', '\u{0}']), ('\u{1f50}', ['\u{3a5}', '\u{313}', '\u{0}']),
+    ('\u{1f52}', ['\u{3a5}', '\u{313}', '\u{300}']), ('\u{1f54}', ['\u{3a5}', '\u{313}', '\u{301}']),
+    ('\u{1f56}', ['\u{3a5}', '\u{313}', '\u{342}']), ('\u{1f80}', ['\u{1f08}', '\u{399}', '\u{0}']),
+    ('\u{1f81}', ['\u{1f09}', '\u{399}', '\u{0}']), ('\u{1f82}', ['\u{1f0a}', '\u{399}', '\u{0}']),
+    ('\u{1f83}', ['\u{1f0b}', '\u{399}', '\u{0}']), ('\u{1f84}', ['\u{1f0c}', '\u{399}', '\u{0}']),
+    ('\u{1f85}', ['\u{1f0d}', '\u{399}', '\u{0}']), ('\u{1f86}', ['\u{1f0e}', '\u{399}', '\u{0}']),
+    ('\u{1f87}', ['\u{1f0f}', '\u{399}', '\u{0}']), ('\u{1f88}', ['\u{1f08}', '\u{399}', '\u{0}']),
+    ('\u{1f89}', ['\u{1f09}', '\u{399}', '\u{0}']), ('\u{1f8a}', ['\u{1f0a}', '\u{399}', '\u{0}']),
+    ('\u{1f8b}', ['\u{1f0b}', '\u{399}', '\u{0}']), ('\u{1f8c}', ['\u{1f0c}', '\u{399}', '\u{0}']),
+    ('\u{1f8d}', ['\u{1f0d}', '\u{399}', '\u{0}']), ('\u{1f8e}', ['\u{1f0e}', '\u{399}', '\u{0}']),
+    ('\u{1f8f}', ['\u{1f0f}', '\u{399}', '\u{0}']), ('\u{1f90}', ['\u{1f28}', '\u{399}', '\u{0}']),
+    ('\u{1f91}', ['\u{1f29}', '\u{399}', '\u{0}']), ('\u{1f92}', ['\u{1f2a}', '\u{399}', '\u{0}']),
+    ('\u{1f93}', ['\u{1f2b}', '\u{399}', '\u{0}']), ('\u{1f94}', ['\u{1f2c}', '\u{399}', '\u{0}']),
+    ('\u{1f95}', ['\u{1f2d}', '\u{399}', '\u{0}']), ('\u{1f96}', ['\u{1f2e}', '\u{399}', '\u{0}']),
+    ('\u{1f97}', ['\u{1f2f}', '\u{399}', '\u{0}']), ('\u{1f98}', ['\u{1f28}', '\u{399}', '\u{0}']),
+    ('\u{1f99}', ['\u{1f29}', '\u{399}', '\u{0}']), ('\u{1f9a}', ['\u{1f2a}', '\u{399}', '\u{0}']),
+    ('\u{1f9b}', ['\u{1f2b}', '\u{399}', '\u{0}']), ('\u{1f9c}', ['\u{1f2c}', '\u{399}', '\u{0}']),
+    ('\u{1f9d}', ['\u{1f2d}', '\u{399}', '\u{0}']), ('\u{1f9e}', ['\u{1f2e}', '\u{399}', '\u{0}']),
+    ('\u{1f9f}', ['\u{1f2f}', '\u{399}', '\u{0}']), ('\u{1fa0}', ['\u{1f68}', '\u{399}', '\u{0}']),
+    ('\u{1fa1}', ['\u{1f69}', '\u{399}', '\u{0}']), ('\u{1fa2}', ['\u{1f6a}', '\u{399}', '\u{0}']),
+    ('\u{1fa3}', ['\u{1f6b}', '\u{399}', '\u{0}']), ('\u{1fa4}', ['\u{1f6c}', '\u{399}', '\u{0}']),
+    ('\u{1fa5}', ['\u{1f6d}', '\u{399}', '\u{0}']), ('\u{1fa6}', ['\u{1f6e}', '\u{399}', '\u{0}']),
+    ('\u{1fa7}', ['\u{1f6f}', '\u{399}', '\u{0}']), ('\u{1fa8}', ['\u{1f68}', '\u{399}', '\u{0}']),
+    ('\u{1fa9}', ['\u{1f69}', '\u{399}', '\u{0}']), ('\u{1faa}', ['\u{1f6a}', '\u{399}', '\u{0}']),
+    ('\u{1fab}', ['\u{1f6b}', '\u{399}', '\u{0}']), ('\u{1fac}', ['\u{1f6c}', '\u{399}', '\u{0}']),
+    ('\u{1fad}', ['\u{1f6d}', '\u{399}', '\u{0}']), ('\u{1fae}', ['\u{1f6e}', '\u{399}', '\u{0}']),
+    ('\u{1faf}', ['\u{1f6f}', '\u{399}', '\u{0}']), ('\u{1fb2}', ['\u{1fba}', '\u{399}', '\u{0}']),
+    ('\u{1fb3}', ['\u{391}', '\u{399}', '\u{0}']), ('\u{1fb4}', ['\u{386}', '\u{399}', '\u{0}']),
+    ('\u{1fb6}', ['\u{391}', '\u{342}', '\u{0}']), ('\u{1fb7}', ['\u{391}', '\u{342}', '\u{399}']),
+    ('\u{1fbc}', ['\u{391}', '\u{399}', '\u{0}']), ('\u{1fc2}', ['\u{1fca}', '\u{399}', '\u{0}']),
+    ('\u{1fc3}', ['\u{397}', '\u{399}', '\u{0}']), ('\u{1fc4}', ['\u{389}', '\u{399}', '\u{0}']),
+    ('\u{1fc6}', ['\u{397}', '\u{342}', '\u{0}']), ('\u{1fc7}', ['\u{397}', '\u{342}', '\u{399}']),
+    ('\u{1fcc}', ['\u{397}', '\u{399}', '\u{0}']), ('\u{1fd2}', ['\u{399}', '\u{308}', '\u{300}']),
+    ('\u{1fd3}', ['\u{399}', '\u{308}', '\u{301}']), ('\u{1fd6}', ['\u{399}', '\u{342}', '\u{0}']),
+    ('\u{1fd7}', ['\u{399}', '\u{308}', '\u{342}']), ('\u{1fe2}', ['\u{3a5}', '\u{308}', '\u{300}']),
+    ('\u{1fe3}', ['\u{3a5}', '\u{308}', '\u{301}']), ('\u{1fe4}', ['\u{3a1}', '\u{313}', '\u{0}']),
+    ('\u{1fe6}', ['\u{3a5}', '\u{342}', '\u{0}']), ('\u{1fe7}', ['\u{3a5}', '\u{308}', '\u{342}']),
+    ('\u{1ff2}', ['\u{1ffa}', '\u{399}', '\u{0}']), ('\u{1ff3}', ['\u{3a9}', '\u{399}', '\u{0}']),
+    ('\u{1ff4}', ['\u{38f}', '\u{399}', '\u{0}']), ('\u{1ff6}', ['\u{3a9}', '\u{342}', '\u{0}']),
+    ('\u{1ff7}', ['\u{3a9}', '\u{342}', '\u{399}']), ('\u{1ffc}', ['\u{3a9}', '\u{399}', '\u{0}']),
+    ('\u{fb00}', ['F', 'F', '\u{0}']), ('\u{fb01}', ['F', 'I', '\u{0}']),
+    ('\u{fb02}', ['F', 'L', '\u{0}']), ('\u{fb03}', ['F', 'F', 'I']),
+    ('\u{fb04}', ['F', 'F', 'L']), ('\u{fb05}', ['S', 'T', '\u{0}']),
+    ('\u{fb06}', ['S', 'T', '\u{0}']), ('\u{fb13}', ['\u{544}', '\u{546}', '\u{0}']),
+    ('\u{fb14}', ['\u{544}', '\u{535}', '\u{0}']), ('\u{fb15}', ['\u{544}', '\u{53b}', '\u{0}']),
+    ('\u{fb16}', ['\u{54e}', '\u{546}', '\u{0}']), ('\u{fb17}', ['\u{544}', '\u{53d}', '\u{0}']),
+];
+
+#[rustfmt::skip]
+pub const TO_TITLE: &[(char, char)] = &[
+    ('\u{1c4}', '\u{1c5}'), ('\u{1c5}', '\u{1c5}'), ('\u{1c6}', '\u{1c5}'), ('\u{1c7}', '\u{1c8}'),
+    ('\u{1c8}', '\u{1c8}'), ('\u{1c9}', '\u{1c8}'), ('\u{1ca}', '\u{1cb}'), ('\u{1cb}', '\u{1cb}'),
+    ('\u{1cc}', '\u{1cb}'), ('\u{1f1}', '\u{1f2}'), ('\u{1f2}', '\u{1f2}'), ('\u{1f3}', '\u{1f2}'),
+    ('\u{10d0}', '\u{10d0}'), ('\u{10d1}', '\u{10d1}'), ('\u{10d2}', '\u{10d2}'), ('\u{10d3}', '\u{10d3}'),
+    ('\u{10d4}', '\u{10d4}'), ('\u{10d5}', '\u{10d5}'), ('\u{10d6}', '\u{10d6}'), ('\u{10d7}', '\u{10d7}'),
+    ('\u{10d8}', '\u{10d8}'), ('\u{10d9}', '\u{10d9}'), ('\u{10da}', '\u{10da}'), ('\u{10db}', '\u{10db}'),
+    ('\u{10dc}', '\u{10dc}'), ('\u{10dd}', '\u{10dd}'), ('\u{10de}', '\u{10de}'), ('\u{10df}', '\u{10df}'),
+    ('\u{10e0}', '\u{10e0}'), ('\u{10e1}', '\u{10e1}'), ('\u{10e2}', '\u{10e2}'), ('\u{10e3}', '\u{10e3}'),
+    ('\u{10e4}', '\u{10e4}'), ('\u{10e5}', '\u{10e5}'), ('\u{10e6}', '\u{10e6}'), ('\u{10e7}', '\u{10e7}'),
+    ('\u{10e8}', '\u{10e8}'), ('\u{10e9}', '\u{10e9}'), ('\u{10ea}', '\u{10ea}'), ('\u{10eb}', '\u{10eb}'),
+    ('\u{10ec}', '\u{10ec}'), ('\u{10ed}', '\u{10ed}'), ('\u{10ee}', '\u{10ee}'), ('\u{10ef}', '\u{10ef}'),
+    ('\u{10f0}', '\u{10f0}'), ('\u{10f1}', '\u{10f1}'), ('\u{10f2}', '\u{10f2}'), ('\u{10f3}', '\u{10f3}'),
+    ('\u{10f4}', '\u{10f4}'), ('\u{10f5}', '\u{10f5}'), ('\u{10f6}', '\u{10f6}'), ('\u{10f7}', '\u{10f7}'),
+    ('\u{10f8}', '\u{10f8}'), ('\u{10f9}', '\u{10f9}'), ('\u{10fa}', '\u{10fa}'), ('\u{10fd}', '\u{10fd}'),
+    ('\u{10fe}', '\u{10fe}'), ('\u{10ff}', '\u{10ff}'), ('\u{1f80}', '\u{1f88}'), ('\u{1f81}', '\u{1f89}'),
+    ('\u{1f82}', '\u{1f8a}'), ('\u{1f83}', '\u{1f8b}'), ('\u{1f84}', '\u{1f8c}'), ('\u{1f85}', '\u{1f8d}'),
+    ('\u{1f86}', '\u{1f8e}'), ('\u{1f87}', '\u{1f8f}'), ('\u{1f88}', '\u{1f88}'), ('\u{1f89}', '\u{1f89}'),
+    ('\u{1f8a}', '\u{1f8a}'), ('\u{1f8b}', '\u{1f8b}'), ('\u{1f8c}', '\u{1f8c}'), ('\u{1f8d}', '\u{1f8d}'),
+    ('\u{1f8e}', '\u{1f8e}'), ('\u{1f8f}', '\u{1f8f}'), ('\u{1f90}', '\u{1f98}'), ('\u{1f91}', '\u{1f99}'),
+    ('\u{1f92}', '\u{1f9a}'), ('\u{1f93}', '\u{1f9b}'), ('\u{1f94}', '\u{1f9c}'), ('\u{1f95}', '\u{1f9d}'),
+    ('\u{1f96}', '\u{1f9e}'), ('\u{1f97}', '\u{1f9f}'), ('\u{1f98}', '\u{1f98}'), ('\u{1f99}', '\u{1f99}'),
+    ('\u{1f9a}', '\u{1f9a}'), ('\u{1f9b}', '\u{1f9b}'), ('\u{1f9c}', '\u{1f9c}'), ('\u{1f9d}', '\u{1f9d}'),
+    ('\u{1f9e}', '\u{1f9e}'), ('\u{1f9f}', '\u{1f9f}'), ('\u{1fa0}', '\u{1fa8}'), ('\u{1fa1}', '\u{1fa9}'),
+    ('\u{1fa2}', '\u{1faa}'), ('\u{1fa3}', '\u{1fab}'), ('\u{1fa4}', '\u{1fac}'), ('\u{1fa5}', '\u{1fad}'),
+    ('\u{1fa6}', '\u{1fae}'), ('\u{1fa7}', '\u{1faf}'), ('\u{1fa8}', '\u{1fa8}'), ('\u{1fa9}', '\u{1fa9}'),
+    ('\u{1faa}', '\u{1faa}'), ('\u{1fab}', '\u{1fab}'), ('\u{1fac}', '\u{1fac}'), ('\u{1fad}', '\u{1fad}'),
+    ('\u{1fae}', '\u{1fae}'), ('\u{1faf}', '\u{1faf}'), ('\u{1fb3}', '\u{1fbc}'), ('\u{1fbc}', '\u{1fbc}'),
+    ('\u{1fc3}', '\u{1fcc}'), ('\u{1fcc}', '\u{1fcc}'), ('\u{1ff3}', '\u{1ffc}'), ('\u{1ffc}', '\u{1ffc}'),
+];
+
+#[rustfmt::skip]
+pub const TO_TITLE_MULTI: &[(char, [char; 3])] = &[
+    ('\u{df}', ['S', 's', '\u{0}']), ('\u{587}', ['\u{535}', '\u{582}', '\u{0}']),
+    ('\u{1fb2}', ['\u{1fba}', '\u{345}', '\u{0}']), ('\u{1fb4}', ['\u{386}', '\u{345}', '\u{0}']),
+    ('\u{1fb7}', ['\u{391}', '\u{342}', '\u{345}']), ('\u{1fc2}', ['\u{1fca}', '\u{345}', '\u{0}']),
+    ('\u{1fc4}', ['\u{389}', '\u{345}', '\u{0}']), ('\u{1fc7}', ['\u{397}', '\u{342}', '\u{345}']),
+    ('\u{1ff2}', ['\u{1ffa}', '\u{345}', '\u{0}']), ('\u{1ff4}', ['\u{38f}', '\u{345}', '\u{0}']),
+    ('\u{1ff7}', ['\u{3a9}', '\u{342}', '\u{345}']), ('\u{fb00}', ['F', 'f', '\u{0}']),
+    ('\u{fb01}', ['F', 'i', '\u{0}']), ('\u{fb02}', ['F', 'l', '\u{0}']),
+    ('\u{fb03}', ['F', 'f', 'i']), ('\u{fb04}', ['F', 'f', 'l']),
+    ('\u{fb05}', ['S', 't', '\u{0}']), ('\u{fb06}', ['S', 't', '\u{0}']),
+    ('\u{fb13}', ['\u{544}', '\u{576}', '\u{0}']), ('\u{fb14}', ['\u{544}', '\u{565}', '\u{0}']),
+    ('\u{fb15}', ['\u{544}', '\u{56b}', '\u{0}']), ('\u{fb16}', ['\u{54e}', '\u{576}', '\u{0}']),
+    ('\u{fb17}', ['\u{544}', '\u{56d}', '\u{0}']),
+];
+
+#[rustfmt::skip]
+pub const FOLD: &[(char, char)] = &[
+    ('A', 'a'), ('B', 'b'), ('C', 'c'), ('D', 'd'),
+    ('E', 'e'), ('F', 'f'), ('G', 'g'), ('H', 'h'),
+    ('I', 'i'), ('J', 'j'), ('K', 'k'), ('L', 'l'),
+    ('M', 'm'), ('N', 'n'), ('O', 'o'), ('P', 'p'),
+    ('Q', 'q'), ('R', 'r'), ('S', 's'), ('T', 't'),
+    ('U', 'u'), ('V', 'v'), ('W', 'w'), ('X', 'x'),
+    ('Y', 'y'), ('Z', 'z'), ('\u{b5}', '\u{3bc}'), ('\u{c0}', '\u{e0}'),
+    ('\u{c1}', '\u{e1}'), ('\u{c2}', '\u{e2}'), ('\u{c3}', '\u{e3}'), ('\u{c4}', '\u{e4}'),
+    ('\u{c5}', '\u{e5}'), ('\u{c6}', '\u{e6}'), ('\u{c7}', '\u{e7}'), ('\u{c8}', '\u{e8}'),
+    ('\u{c9}', '\u{e9}'), ('\u{ca}', '\u{ea}'), ('\u{cb}', '\u{eb}'), ('\u{cc}', '\u{ec}'),
+    ('\u{cd}', '\u{ed}'), ('\u{ce}', '\u{ee}'), ('\u{cf}', '\u{ef}'), ('\u{d0}', '\u{f0}'),
+    ('\u{d1}', '\u{f1}'), ('\u{d2}', '\u{f2}'), ('\u{d3}', '\u{f3}'), ('\u{d4}', '\u{f4}'),
+    ('\u{d5}', '\u{f5}'), ('\u{d6}', '\u{f6}'), ('\u{d8}', '\u{f8}'), ('\u{d9}', '\u{f9}'),
+    ('\u{da}', '\u{fa}'), ('\u{db}', '\u{fb}'), ('\u{dc}', '\u{fc}'), ('\u{dd}', '\u{fd}'),
+    ('\u{de}', '\u{fe}'), ('\u{100}', '\u{101}'), ('\u{102}', '\u{103}'), ('\u{104}', '\u{105}'),
+    ('\u{106}', '\u{107}'), ('\u{108}', '\u{109}'), ('\u{10a}', '\u{10b}'), ('\u{10c}', '\u{10d}'),
+    ('\u{10e}', '\u{10f}'), ('\u{110}', '\u{111}'), ('\u{112}', '\u{113}'), ('\u{114}', '\u{115}'),
+    ('\u{116}', '\u{117}'), ('\u{118}', '\u{119}'), ('\u{11a}', '\u{11b}'), ('\u{11c}', '\u{11d}'),
+    ('\u{11e}', '\u{11f}'), ('\u{120}', '\u{121}'), ('\u{122}', '\u{123}'), ('\u{124}', '\u{125}'),
+    ('\u{126}', '\u{127}'), ('\u{128}', '\u{129}'), ('\u{12a}', '\u{12b}'), ('\u{12c}', '\u{12d}'),
+    ('\u{12e}', '\u{12f}'), ('\u{132}', '\u{133}'), ('\u{134}', '\u{135}'), ('\u{136}', '\u{137}'),
+    ('\u{139}', '\u{13a}'), ('\u{13b}', '\u{13c}'), ('\u{13d}', '\u{13e}'), ('\u{13f}', '\u{140}'),
+    ('\u{141}', '\u{142}'), ('\u{143}', '\u{144}'), ('\u{145}', '\u{146}'), ('\u{147}', '\u{148}'),
+    ('\u{14a}', '\u{14b}'), ('\u{14c}', '\u{14d}'), ('\u{14e}', '\u{14f}'), ('\u{150}', '\u{151}'),
+    ('\u{152}', '\u{153}'), ('\u{154}', '\u{155}'), ('\u{156}', '\u{157}'), ('\u{158}', '\u{159}'),
+    ('\u{15a}', '\u{15b}'), ('\u{15c}', '\u{15d}'), ('\u{15e}', '\u{15f}'), ('\u{160}', '\u{161}'),
+    ('\u{162}', '\u{163}'), ('\u{164}', '\u{165}'), ('\u{166}', '\u{167}'), ('\u{168}', '\u{169}'),
+    ('\u{16a}', '\u{16b}'), ('\u{16c}', '\u{16d}'), ('\u{16e}', '\u{16f}'), ('\u{170}', '\u{171}'),
+    ('\u{172}', '\u{173}'), ('\u{174}', '\u{175}'), ('\u{176}', '\u{177}'), ('\u{178}', '\u{ff}'),
+    ('\u{179}', '\u{17a}'), ('\u{17b}', '\u{17c}'), ('\u{17d}', '\u{17e}'), ('\u{17f}', 's'),
+    ('\u{181}', '\u{253}'), ('\u{182}', '\u{183}'), ('\u{184}', '\u{185}'), ('\u{186}', '\u{254}'),
+    ('\u{187}', '\u{188}'), ('\u{189}', '\u{256}'), ('\u{18a}', '\u{257}'), ('\u{18b}', '\u{18c}'),
+    ('\u{18e}', '\u{1dd}'), ('\u{18f}', '\u{259}'), ('\u{190}', '\u{25b}'), ('\u{191}', '\u{192}'),
+    ('\u{193}', '\u{260}'), ('\u{194}', '\u{263}'), ('\u{196}', '\u{269}'), ('\u{197}', '\u{268}'),
+    ('\u{198}', '\u{199}'), ('\u{19c}', '\u{26f}'), ('\u{19d}', '\u{272}'), ('\u{19f}', '\u{275}'),
+    ('\u{1a0}', '\u{1a1}'), ('\u{1a2}', '\u{1a3}'), ('\u{1a4}', '\u{1a5}'), ('\u{1a6}', '\u{280}'),
+    ('\u{1a7}', '\u{1a8}'), ('\u{1a9}', '\u{283}'), ('\u{1ac}', '\u{1ad}'), ('\u{1ae}', '\u{288}'),
+    ('\u{1af}', '\u{1b0}'), ('\u{1b1}', '\u{28a}'), ('\u{1b2}', '\u{28b}'), ('\u{1b3}', '\u{1b4}'),
+    ('\u{1b5}', '\u{1b6}'), ('\u{1b7}', '\u{292}'), ('\u{1b8}', '\u{1b9}'), ('\u{1bc}', '\u{1bd}'),
+    ('\u{1c4}', '\u{1c6}'), ('\u{1c5}', '\u{1c6}'), ('\u{1c7}', '\u{1c9}'), ('\u{1c8}', '\u{1c9}'),
+    ('\u{1ca}', '\u{1cc}'), ('\u{1cb}', '\u{1cc}'), ('\u{1cd}', '\u{1ce}'), ('\u{1cf}', '\u{1d0}'),
+    ('\u{1d1}', '\u{1d2}'), ('\u{1d3}', '\u{1d4}'), ('\u{1d5}', '\u{1d6}'), ('\u{1d7}', '\u{1d8}'),
+    ('\u{1d9}', '\u{1da}'), ('\u{1db}', '\u{1dc}'), ('\u{1de}', '\u{1df}'), ('\u{1e0}', '\u{1e1}'),
+    ('\u{1e2}', '\u{1e3}'), ('\u{1e4}', '\u{1e5}'), ('\u{1e6}', '\u{1e7}'), ('\u{1e8}', '\u{1e9}'),
+    ('\u{1ea}', '\u{1eb}'), ('\u{1ec}', '\u{1ed}'), ('\u{1ee}', '\u{1ef}'), ('\u{1f1}', '\u{1f3}'),
+    ('\u{1f2}', '\u{1f3}'), ('\u{1f4}', '\u{1f5}'), ('\u{1f6}', '\u{195}'), ('\u{1f7}', '\u{1bf}'),
+    ('\u{1f8}', '\u{1f9}'), ('\u{1fa}', '\u{1fb}'), ('\u{1fc}', '\u{1fd}'), ('\u{1fe}', '\u{1ff}'),
+    ('\u{200}', '\u{201}'), ('\u{202}', '\u{203}'), ('\u{204}', '\u{205}'), ('\u{206}', '\u{207}'),
+    ('\u{208}', '\u{209}'), ('\u{20a}', '\u{20b}'), ('\u{20c}', '\u{20d}'), ('\u{20e}', '\u{20f}'),
+    ('\u{210}', '\u{211}'), ('\u{212}', '\u{213}'), ('\u{214}', '\u{215}'), ('\u{216}', '\u{217}'),
+    ('\u{218}', '\u{219}'), ('\u{21a}', '\u{21b}'), ('\u{21c}', '\u{21d}'), ('\u{21e}', '\u{21f}'),
+    ('\u{220}', '\u{19e}'), ('\u{222}', '\u{223}'), ('\u{224}', '\u{225}'), ('\u{226}', '\u{227}'),
+    ('\u{228}', '\u{229}'), ('\u{22a}', '\u{22b}'), ('\u{22c}', '\u{22d}'), ('\u{22e}', '\u{22f}'),
+    ('\u{230}', '\u{231}'), ('\u{232}', '\u{233}'), ('\u{23a}', '\u{2c65}'), ('\u{23b}', '\u{23c}'),
+    ('\u{23d}', '\u{19a}'), ('\u{23e}', '\u{2c66}'), ('\u{241}', '\u{242}'), ('\u{243}', '\u{180}'),
+    ('\u{244}', '\u{289}'), ('\u{245}', '\u{28c}'), ('\u{246}', '\u{247}'), ('\u{248}', '\u{249}'),
+    ('\u{24a}', '\u{24b}'), ('\u{24c}', '\u{24d}'), ('\u{24e}', '\u{24f}'), ('\u{345}', '\u{3b9}'),
+    ('\u{370}', '\u{371}'), ('\u{372}', '\u{373}'), ('\u{376}', '\u{377}'), ('\u{37f}', '\u{3f3}'),
+    ('\u{386}', '\u{3ac}'), ('\u{388}', '\u{3ad}'), ('\u{389}', '\u{3ae}'), ('\u{38a}', '\u{3af}'),
+    ('\u{38c}', '\u{3cc}'), ('\u{38e}', '\u{3cd}'), ('\u{38f}', '\u{3ce}'), ('\u{391}', '\u{3b1}'),
+    ('\u{392}', '\u{3b2}'), ('\u{393}', '\u{3b3}'), ('\u{394}', '\u{3b4}'), ('\u{395}', '\u{3b5}'),
+    ('\u{396}', '\u{3b6}'), ('\u{397}', '\u{3b7}'), ('\u{398}', '\u{3b8}'), ('\u{399}', '\u{3b9}'),
+    ('\u{39a}', '\u{3ba}'), ('\u{39b}', '\u{3bb}'), ('\u{39c}', '\u{3bc}'), ('\u{39d}', '\u{3bd}'),
+    ('\u{39e}', '\u{3be}'), ('\u{39f}', '\u{3bf}'), ('\u{3a0}', '\u{3c0}'), ('\u{3a1}', '\u{3c1}'),
+    ('\u{3a3}', '\u{3c3}'), ('\u{3a4}', '\u{3c4}'), ('\u{3a5}', '\u{3c5}'), ('\u{3a6}', '\u{3c6}'),
+    ('\u{3a7}', '\u{3c7}'), ('\u{3a8}', '\u{3c8}'), ('\u{3a9}', '\u{3c9}'), ('\u{3aa}', '\u{3ca}'),
+    ('\u{3ab}', '\u{3cb}'), ('\u{3c2}', '\u{3c3}'), ('\u{3cf}', '\u{3d7}'), ('\u{3d0}', '\u{3b2}'),
+    ('\u{3d1}', '\u{3b8}'), ('\u{3d5}', '\u{3c6}'), ('\u{3d6}', '\u{3c0}'), ('\u{3d8}', '\u{3d9}'),
+    ('\u{3da}', '\u{3db}'), ('\u{3dc}', '\u{3dd}'), ('\u{3de}', '\u{3df}'), ('\u{3e0}', '\u{3e1}'),
+    ('\u{3e2}', '\u{3e3}'), ('\u{3e4}', '\u{3e5}'), ('\u{3e6}', '\u{3e7}'), ('\u{3e8}', '\u{3e9}'),
+    ('\u{3ea}', '\u{3eb}'), ('\u{3ec}', '\u{3ed}'), ('\u{3ee}', '\u{3ef}'), ('\u{3f0}', '\u{3ba}'),
+    ('\u{3f1}', '\u{3c1}'), ('\u{3f4}', '\u{3b8}'), ('\u{3f5}', '\u{3b5}'), ('\u{3f7}', '\u{3f8}'),
+    ('\u{3f9}', '\u{3f2}'), ('\u{3fa}', '\u{3fb}'), ('\u{3fd}', '\u{37b}'), ('\u{3fe}', '\u{37c}'),
+    ('\u{3ff}', '\u{37d}'), ('\u{400}', '\u{450}'), ('\u{401}', '\u{451}'), ('\u{402}', '\u{452}'),
+    ('\u{403}', '\u{453}'), ('\u{404}', '\u{454}'), ('\u{405}', '\u{455}'), ('\u{406}', '\u{456}'),
+    ('\u{407}', '\u{457}'), ('\u{408}', '\u{458}'), ('\u{409}', '\u{459}'), ('\u{40a}', '\u{45a}'),
+    ('\u{40b}', '\u{45b}'), ('\u{40c}', '\u{45c}'), ('\u{40d}', '\u{45d}'), ('\u{40e}', '\u{45e}'),
+    ('\u{40f}', '\u{45f}'), ('\u{410}', '\u{430}'), ('\u{411}', '\u{431}'), ('\u{412}', '\u{432}'),
+    ('\u{413}', '\u{433}'), ('\u{414}', '\u{434}'), ('\u{415}', '\u{435}'), ('\u{416}', '\u{436}'),
+    ('\u{417}', '\u{437}'), ('\u{418}', '\u{438}'), ('\u{419}', '\u{439}'), ('\u{41a}', '\u{43a}'),
+    ('\u{41b}', '\u{43b}'), ('\u{41c}', '\u{43c}'), ('\u{41d}', '\u{43d}'), ('\u{41e}', '\u{43e}'),
+    ('\u{41f}', '\u{43f}'), ('\u{420}', '\u{440}'), ('\u{421}', '\u{441}'), ('\u{422}', '\u{442}'),
+    ('\u{423}', '\u{443}'), ('\u{424}', '\u{444}'), ('\u{425}', '\u{445}'), ('\u{426}', '\u{446}'),
+    ('\u{427}', '\u{447}'), ('\u{428}', '\u{448}'), ('\u{429}', '\u{449}'), ('\u{42a}', '\u{44a}'),
+    ('\u{42b}', '\u{44b}'), ('\u{42c}', '\u{44c}'), ('\u{42d}', '\u{44d}'), ('\u{42e}', '\u{44e}'),
+    ('\u{42f}', '\u{44f}'), ('\u{460}', '\u{461}'), ('\u{462}', '\u{463}'), ('\u{464}', '\u{465}'),
+    ('\u{466}', '\u{467}'), ('\u{468}', '\u{469}'), ('\u{46a}', '\u{46b}'), ('\u{46c}', '\u{46d}'),
+    ('\u{46e}', '\u{46f}'), ('\u{470}', '\u{471}'), ('\u{472}', '\u{473}'), ('\u{474}', '\u{475}'),
+    ('\u{476}', '\u{477}'), ('\u{478}', '\u{479}'), ('\u{47a}', '\u{47b}'), ('\u{47c}', '\u{47d}'),
+    ('\u{47e}', '\u{47f}'), ('\u{480}', '\u{481}'), ('\u{48a}', '\u{48b}'), ('\u{48c}', '\u{48d}'),
+    ('\u{48e}', '\u{48f}'), ('\u{490}', '\u{491}'), ('\u{492}', '\u{493}'), ('\u{494}', '\u{495}'),
+    ('\u{496}', '\u{497}'), ('\u{498}', '\u{499}'), ('\u{49a}', '\u{49b}'), ('\u{49c}', '\u{49d}'),
+    ('\u{49e}', '\u{49f}'), ('\u{4a0}', '\u{4a1}'), ('\u{4a2}', '\u{4a3}'), ('\u{4a4}', '\u{4a5}'),
+    ('\u{4a6}', '\u{4a7}'), ('\u{4a8}', '\u{4a9}'), ('\u{4aa}', '\u{4ab}'), ('\u{4ac}', '\u{4ad}'),
+    ('\u{4ae}', '\u{4af}'), ('\u{4b0}', '\u{4b1}'), ('\u{4b2}', '\u{4b3}'), ('\u{4b4}', '\u{4b5}'),
+    ('\u{4b6}', '\u{4b7}'), ('\u{4b8}', '\u{4b9}'), ('\u{4ba}', '\u{4bb}'), ('\u{4bc}', '\u{4bd}'),
+    ('\u{4be}', '\u{4bf}'), ('\u{4c0}', '\u{4cf}'), ('\u{4c1}', '\u{4c2}'), ('\u{4c3}', '\u{4c4}'),
+    ('\u{4c5}', '\u{4c6}'), ('\u{4c7}', '\u{4c8}'), ('\u{4c9}', '\u{4ca}'), ('\u{4cb}', '\u{4cc}'),
+    ('\u{4cd}', '\u{4ce}'), ('\u{4d0}', '\u{4d1}'), ('\u{4d2}', '\u{4d3}'), ('\u{4d4}', '\u{4d5}'),
+    ('\u{4d6}', '\u{4d7}'), ('\u{4d8}', '\u{4d9}'), ('\u{4da}', '\u{4db}'), ('\u{4dc}', '\u{4dd}'),
+    ('\u{4de}', '\u{4df}'), ('\u{4e0}', '\u{4e1}'), ('\u{4e2}', '\u{4e3}'), ('\u{4e4}', '\u{4e5}'),
+    ('\u{4e6}', '\u{4e7}'), ('\u{4e8}', '\u{4e9}'), ('\u{4ea}', '\u{4eb}'), ('\u{4ec}', '\u{4ed}'),
+    ('\u{4ee}', '\u{4ef}'), ('\u{4f0}', '\u{4f1}'), ('\u{4f2}', '\u{4f3}'), ('\u{4f4}', '\u{4f5}'),
+    ('\u{4f6}', '\u{4f7}'), ('\u{4f8}', '\u{4f9}'), ('\u{4fa}', '\u{4fb}'), ('\u{4fc}', '\u{4fd}'),
+    ('\u{4fe}', '\u{4ff}'), ('\u{500}', '\u{501}'), ('\u{502}', '\u{503}'), ('\u{504}', '\u{505}'),
+    ('\u{506}', '\u{507}'), ('\u{508}', '\u{509}'), ('\u{50a}', '\u{50b}'), ('\u{50c}', '\u{50d}'),
+    ('\u{50e}', '\u{50f}'), ('\u{510}', '\u{511}'), ('\u{512}', '\u{513}'), ('\u{514}', '\u{515}'),
+    ('\u{516}', '\u{517}'), ('\u{518}', '\u{519}'), ('\u{51a}', '\u{51b}'), ('\u{51c}', '\u{51d}'),
+    ('\u{51e}', '\u{51f}'), ('\u{520}', '\u{521}'), ('\u{522}', '\u{523}'), ('\u{524}', '\u{525}'),
+    ('\u{526}', '\u{527}'), ('\u{528}', '\u{529}'), ('\u{52a}', '\u{52b}'), ('\u{52c}', '\u{52d}'),
+    ('\u{52e}', '\u{52f}'), ('\u{531}', '\u{561}'), ('\u{532}', '\u{562}'), ('\u{533}', '\u{563}'),
+    ('\u{534}', '\u{564}'), ('\u{535}', '\u{565}'), ('\u{536}', '\u{566}'), ('\u{537}', '\u{567}'),
+    ('\u{538}', '\u{568}'), ('\u{539}', '\u{569}'), ('\u{53a}', '\u{56a}'), ('\u{53b}', '\u{56b}'),
+    ('\u{53c}', '\u{56c}'), ('\u{53d}', '\u{56d}'), ('\u{53e}', '\u{56e}'), ('\u{53f}', '\u{56f}'),
+    ('\u{540}', '\u{570}'), ('\u{541}', '\u{571}'), ('\u{542}', '\u{572}'), ('\u{543}', '\u{573}'),
+    ('\u{544}', '\u{574}'), ('\u{545}', '\u{575}'), ('\u{546}', '\u{576}'), ('\u{547}', '\u{577}'),
+    ('\u{548}', '\u{578}'), ('\u{549}', '\u{579}'), ('\u{54a}', '\u{57a}'), ('\u{54b}', '\u{57b}'),
+    ('\u{54c}', '\u{57c}'), ('\u{54d}', '\u{57d}'), ('\u{54e}', '\u{57e}'), ('\u{54f}', '\u{57f}'),
+    ('\u{550}', '\u{580}'), ('\u{551}', '\u{581}'), ('\u{552}', '\u{582}'), ('\u{553}', '\u{583}'),
+    ('\u{554}', '\u{584}'), ('\u{555}', '\u{585}'), ('\u{556}', '\u{586}'), ('\u{10a0}', '\u{2d00}'),
+    ('\u{10a1}', '\u{2d01}'), ('\u{10a2}', '\u{2d02}'), ('\u{10a3}', '\u{2d03}'), ('\u{10a4}', '\u{2d04}'),
+    ('\u{10a5}', '\u{2d05}'), ('\u{10a6}', '\u{2d06}'), ('\u{10a7}', '\u{2d07}'), ('\u{10a8}', '\u{2d08}'),
+    ('\u{10a9}', '\u{2d09}'), ('\u{10aa}', '\u{2d0a}'), ('\u{10ab}', '\u{2d0b}'), ('\u{10ac}', '\u{2d0c}'),
+    ('\u{10ad}', '\u{2d0d}'), ('\u{10ae}', '\u{2d0e}'), ('\u{10af}', '\u{2d0f}'), ('\u{10b0}', '\u{2d10}'),
+    ('\u{10b1}', '\u{2d11}'), ('\u{10b2}', '\u{2d12}'), ('\u{10b3}', '\u{2d13}'), ('\u{10b4}', '\u{2d14}'),
+    ('\u{10b5}', '\u{2d15}'), ('\u{10b6}', '\u{2d16}'), ('\u{10b7}', '\u{2d17}'), ('\u{10b8}', '\u{2d18}'),
+    ('\u{10b9}', '\u{2d19}'), ('\u{10ba}', '\u{2d1a}'), ('\u{10bb}', '\u{2d1b}'), ('\u{10bc}', '\u{2d1c}'),
+    ('\u{10bd}', '\u{2d1d}'), ('\u{10be}', '\u{2d1e}'), ('\u{10bf}', '\u{2d1f}'), ('\u{10c0}', '\u{2d20}'),
+    ('\u{10c1}', '\u{2d21}'), ('\u{10c2}', '\u{2d22}'), ('\u{10c3}', '\u{2d23}'), ('\u{10c4}', '\u{2d24}'),
+    ('\u{10c5}', '\u{2d25}'), ('\u{10c7}', '\u{2d27}'), ('\u{10cd}', '\u{2d2d}'), ('\u{13f8}', '\u{13f0}'),
+    ('\u{13f9}', '\u{13f1}'), ('\u{13fa}', '\u{13f2}'), ('\u{13fb}', '\u{13f3}'), ('\u{13fc}', '\u{13f4}'),
+    ('\u{13fd}', '\u{13f5}'), ('\u{1c80}', '\u{432}'), ('\u{1c81}', '\u{434}'), ('\u{1c82}', '\u{43e}'),
+    ('\u{1c83}', '\u{441}'), ('\u{1c84}', '\u{442}'), ('\u{1c85}', '\u{442}'), ('\u{1c86}', '\u{44a}'),
+    ('\u{1c87}', '\u{463}'), ('\u{1c88}', '\u{a64b}'), ('\u{1c89}', '\u{1c8a}'), ('\u{1c90}', '\u{10d0}'),
+    ('\u{1c91}', '\u{10d1}'), ('\u{1c92}', '\u{10d2}'), ('\u{1c93}', '\u{10d3}'), ('\u{1c94}', '\u{10d4}'),
+    ('\u{1c95}', '\u{10d5}'), ('\u{1c96}', '\u{10d6}'), ('\u{1c97}', '\u{10d7}'), ('\u{1c98}', '\u{10d8}'),
+    ('\u{1c99}', '\u{10d9}'), ('\u{1c9a}', '\u{10da}'), ('\u{1c9b}', '\u{10db}'), ('\u{1c9c}', '\u{10dc}'),
+    ('\u{1c9d}', '\u{10dd}'), ('\u{1c9e}', '\u{10de}'), ('\u{1c9f}', '\u{10df}'), ('\u{1ca0}', '\u{10e0}'),
+    ('\u{1ca1}', '\u{10e1}'), ('\u{1ca2}', '\u{10e2}'), ('\u{1ca3}', '\u{10e3}'), ('\u{1ca4}', '\u{10e4}'),
+    ('\u{1ca5}', '\u{10e5}'), ('\u{1ca6}', '\u{10e6}'), ('\u{1ca7}', '\u{10e7}'), ('\u{1ca8}', '\u{10e8}'),
+    ('\u{1ca9}', '\u{10e9}'), ('\u{1caa}', '\u{10ea}'), ('\u{1cab}', '\u{10eb}'), ('\u{1cac}', '\u{10ec}'),
+    ('\u{1cad}', '\u{10ed}'), ('\u{1cae}', '\u{10ee}'), ('\u{1caf}', '\u{10ef}'), ('\u{1cb0}', '\u{10f0}'),
+    ('\u{1cb1}', '\u{10f1}'), ('\u{1cb2}', '\u{10f2}'), ('\u{1cb3}', '\u{10f3}'), ('\u{1cb4}', '\u{10f4}'),
+    ('\u{1cb5}', '\u{10f5}'), ('\u{1cb6}', '\u{10f6}'), ('\u{1cb7}', '\u{10f7}'), ('\u{1cb8}', '\u{10f8}'),
+    ('\u{1cb9}', '\u{10f9}'), ('\u{1cba}', '\u{10fa}'), ('\u{1cbd}', '\u{10fd}'), ('\u{1cbe}', '\u{10fe}'),
+    ('\u{1cbf}', '\u{10ff}'), ('\u{1e00}', '\u{1e01}'), ('\u{1e02}', '\u{1e03}'), ('\u{1e04}', '\u{1e05}'),
+    ('\u{1e06}', '\u{1e07}'), ('\u{1e08}', '\u{1e09}'), ('\u{1e0a}', '\u{1e0b}'), ('\u{1e0c}', '\u{1e0d}'),
+    ('\u{1e0e}', '\u{1e0f}'), ('\u{1e10}', '\u{1e11}'), ('\u{1e12}', '\u{1e13}'), ('\u{1e14}', '\u{1e15}'),
+    ('\u{1e16}', '\u{1e17}'), ('\u{1e18}', '\u{1e19}'), ('\u{1e1a}', '\u{1e1b}'), ('\u{1e1c}', '\u{1e1d}'),
+    ('\u{1e1e}', '\u{1e1f}'), ('\u{1e20}', '\u{1e21}'), ('\u{1e22}', '\u{1e23}'), ('\u{1e24}', '\u{1e25}'),
+    ('\u{1e26}', '\u{1e27}'), ('\u{1e28}', '\u{1e29}'), ('\u{1e2a}', '\u{1e2b}'), ('\u{1e2c}', '\u{1e2d}'),
+    ('\u{1e2e}', '\u{1e2f}'), ('\u{1e30}', '\u{1e31}'), ('\u{1e32}', '\u{1e33}'), ('\u{1e34}', '\u{1e35}'),
+    ('\u{1e36}', '\u{1e37}'), ('\u{1e38}', '\u{1e39}'), ('\u{1e3a}', '\u{1e3b}'), ('\u{1e3c}', '\u{1e3d}'),
+    ('\u{1e3e}', '\u{1e3f}'), ('\u{1e40}', '\u{1e41}'), ('\u{1e42}', '\u{1e43}'), ('\u{1e44}', '\u{1e45}'),
+    ('\u{1e46}', '\u{1e47}'), ('\u{1e48}', '\u{1e49}'), ('\u{1e4a}', '\u{1e4b}'), ('\u{1e4c}', '\u{1e4d}'),
+    ('\u{1e4e}', '\u{1e4f}'), ('\u{1e50}', '\u{1e51}'), ('\u{1e52}', '\u{1e53}'), ('\u{1e54}', '\u{1e55}'),
+    ('\u{1e56}', '\u{1e57}'), ('\u{1e58}', '\u{1e59}'), ('\u{1e5a}', '\u{1e5b}'), ('\u{1e5c}', '\u{1e5d}'),
+    ('\u{1e5e}', '\u{1e5f}'), ('\u{1e60}', '\u{1e61}'), ('\u{1e62}', '\u{1e63}'), ('\u{1e64}', '\u{1e65}'),
+    ('\u{1e66}', '\u{1e67}'), ('\u{1e68}', '\u{1e69}'), ('\u{1e6a}', '\u{1e6b}'), ('\u{1e6c}', '\u{1e6d}'),
+    ('\u{1e6e}', '\u{1e6f}'), ('\u{1e70}', '\u{1e71}'), ('\u{1e72}', '\u{1e73}'), ('\u{1e74}', '\u{1e75}'),
+    ('\u{1e76}', '\u{1e77}'), ('\u{1e78}', '\u{1e79}'), ('\u{1e7a}', '\u{1e7b}'), ('\u{1e7c}', '\u{1e7d}'),
+    ('\u{1e7e}', '\u{1e7f}'), ('\u{1e80}', '\u{1e81}'), ('\u{1e82}', '\u{1e83}'), ('\u{1e84}', '\u{1e85}'),
+    ('\u{1e86}', '\u{1e87}'), ('\u{1e88}', '\u{1e89}'), ('\u{1e8a}', '\u{1e8b}'), ('\u{1e8c}', '\u{1e8d}'),
+    ('\u{1e8e}', '\u{1e8f}'), ('\u{1e90}', '\u{1e91}'), ('\u{1e92}', '\u{1e93}'), ('\u{1e94}', '\u{1e95}'),
+    ('\u{1e9b}', '\u{1e61}'), ('\u{1e9e}', '\u{df}'), ('\u{1ea0}', '\u{1ea1}'), ('\u{1ea2}', '\u{1ea3}'),
+    ('\u{1ea4}', '\u{1ea5}'), ('\u{1ea6}', '\u{1ea7}'), ('\u{1ea8}', '\u{1ea9}'), ('\u{1eaa}', '\u{1eab}'),
+    ('\u{1eac}', '\u{1ead}'), ('\u{1eae}', '\u{1eaf}'), ('\u{1eb0}', '\u{1eb1}'), ('\u{1eb2}', '\u{1eb3}'),
+    ('\u{1eb4}', '\u{1eb5}'), ('\u{1eb6}', '\u{1eb7}'), ('\u{1eb8}', '\u{1eb9}'), ('\u{1eba}', '\u{1ebb}'),
+    ('\u{1ebc}', '\u{1ebd}'), ('\u{1ebe}', '\u{1ebf}'), ('\u{1ec0}', '\u{1ec1}'), ('\u{1ec2}', '\u{1ec3}'),
+    ('\u{1ec4}', '\u{1ec5}'), ('\u{1ec6}', '\u{1ec7}'), ('\u{1ec8}', '\u{1ec9}'), ('\u{1eca}', '\u{1ecb}'),
+    ('\u{1ecc}', '\u{1ecd}'), ('\u{1ece}', '\u{1ecf}'), ('\u{1ed0}', '\u{1ed1}'), ('\u{1ed2}', '\u{1ed3}'),
+    ('\u{1ed4}', '\u{1ed5}'), ('\u{1ed6}', '\u{1ed7}'), ('\u{1ed8}', '\u{1ed9}'), ('\u{1eda}', '\u{1edb}'),
+    ('\u{1edc}', '\u{1edd}'), ('\u{1ede}', '\u{1edf}'), ('\u{1ee0}', '\u{1ee1}'), ('\u{1ee2}', '\u{1ee3}'),
+    ('\u{1ee4}', '\u{1ee5}'), ('\u{1ee6}', '\u{1ee7}'), ('\u{1ee8}', '\u{1ee9}'), ('\u{1eea}', '\u{1eeb}'),
+    ('\u{1eec}', '\u{1eed}'), ('\u{1eee}', '\u{1eef}'), ('\u{1ef0}', '\u{1ef1}'), ('\u{1ef2}', '\u{1ef3}'),
+    ('\u{1ef4}', '\u{1ef5}'), ('\u{1ef6}', '\u{1ef7}'), ('\u{1ef8}', '\u{1ef9}'), ('\u{1efa}', '\u{1efb}'),
+    ('\u{1efc}', '\u{1efd}'), ('\u{1efe}', '\u{1eff}'), ('\u{1f08}', '\u{1f00}'), ('\u{1f09}', '\u{1f01}'),
+    ('\u{1f0a}', '\u{1f02}'), ('\u{1f0b}', '\u{1f03}'), ('\u{1f0c}', '\u{1f04}'), ('\u{1f0d}', '\u{1f05}'),
+    ('\u{1f0e}', '\u{1f06}'), ('\u{1f0f}', '\u{1f07}'), ('\u{1f18}', '\u{1f10}'), ('\u{1f19}', '\u{1f11}'),
+    ('\u{1f1a}', '\u{1f12}'), ('\u{1f1b}', '\u{1f13}'), ('\u{1f1c}', '\u{1f14}'), ('\u{1f1d}', '\u{1f15}'),
+    ('\u{1f28}', '\u{1f20}'), ('\u{1f29}', '\u{1f21}'), ('\u{1f2a}', '\u{1f22}'), ('\u{1f2b}', '\u{1f23}'),
+    ('\u{1f2c}', '\u{1f24}'), ('\u{1f2d}', '\u{1f25}'), ('\u{1f2e}', '\u{1f26}'), ('\u{1f2f}', '\u{1f27}'),
+    ('\u{1f38}', '\u{1f30}'), ('\u{1f39}', '\u{1f31}'), ('\u{1f3a}', '\u{1f32}'), ('\u{1f3b}', '\u{1f33}'),
+    ('\u{1f3c}', '\u{1f34}'), ('\u{1f3d}', '\u{1f35}'), ('\u{1f3e}', '\u{1f36}'), ('\u{1f3f}', '\u{1f37}'),
+    ('\u{1f48}', '\u{1f40}'), ('\u{1f49}', '\u{1f41}'), ('\u{1f4a}', '\u{1f42}'), ('\u{1f4b}', '\u{1f43}'),
+    ('\u{1f4c}', '\u{1f44}'), ('\u{1f4d}', '\u{1f45}'), ('\u{1f59}', '\u{1f51}'), ('\u{1f5b}', '\u{1f53}'),
+    ('\u{1f5d}', '\u{1f55}'), ('\u{1f5f}', '\u{1f57}'), ('\u{1f68}', '\u{1f60}'), ('\u{1f69}', '\u{1f61}'),
+    ('\u{1f6a}', '\u{1f62}'), ('\u{1f6b}', '\u{1f63}'), ('\u{1f6c}', '\u{1f64}'), ('\u{1f6d}', '\u{1f65}'),
+    ('\u{1f6e}', '\u{1f66}'), ('\u{1f6f}', '\u{1f67}'), ('\u{1f88}', '\u{1f80}'), ('\u{1f89}', '\u{1f81}'),
+    ('\u{1f8a}', '\u{1f82}'), ('\u{1f8b}', '\u{1f83}'), ('\u{1f8c}', '\u{1f84}'), ('\u{1f8d}', '\u{1f85}'),
+    ('\u{1f8e}', '\u{1f86}'), ('\u{1f8f}', '\u{1f87}'), ('\u{1f98}', '\u{1f90}'), ('\u{1f99}', '\u{1f91}'),
+    ('\u{1f9a}', '\u{1f92}'), ('\u{1f9b}', '\u{1f93}'), ('\u{1f9c}', '\u{1f94}'), ('\u{1f9d}', '\u{1f95}'),
+    ('\u{1f9e}', '\u{1f96}'), ('\u{1f9f}', '\u{1f97}'), ('\u{1fa8}', '\u{1fa0}'), ('\u{1fa9}', '\u{1fa1}'),
+    ('\u{1faa}', '\u{1fa2}'), ('\u{1fab}', '\u{1fa3}'), ('\u{1fac}', '\u{1fa4}'), ('\u{1fad}', '\u{1fa5}'),
+    ('\u{1fae}', '\u{1fa6}'), ('\u{1faf}', '\u{1fa7}'), ('\u{1fb8}', '\u{1fb0}'), ('\u{1fb9}', '\u{1fb1}'),
+    ('\u{1fba}', '\u{1f70}'), ('\u{1fbb}', '\u{1f71}'), ('\u{1fbc}', '\u{1fb3}'), ('\u{1fbe}', '\u{3b9}'),
+    ('\u{1fc8}', '\u{1f72}'), ('\u{1fc9}', '\u{1f73}'), ('\u{1fca}', '\u{1f74}'), ('\u{1fcb}', '\u{1f75}'),
+    ('\u{1fcc}', '\u{1fc3}'), ('\u{1fd3}', '\u{390}'), ('\u{1fd8}', '\u{1fd0}'), ('\u{1fd9}', '\u{1fd1}'),
+    ('\u{1fda}', '\u{1f76}'), ('\u{1fdb}', '\u{1f77}'), ('\u{1fe3}', '\u{3b0}'), ('\u{1fe8}', '\u{1fe0}'),
+    ('\u{1fe9}', '\u{1fe1}'), ('\u{1fea}', '\u{1f7a}'), ('\u{1feb}', '\u{1f7b}'), ('\u{1fec}', '\u{1fe5}'),
+    ('\u{1ff8}', '\u{1f78}'), ('\u{1ff9}', '\u{1f79}'), ('\u{1ffa}', '\u{1f7c}'), ('\u{1ffb}', '\u{1f7d}'),
+    ('\u{1ffc}', '\u{1ff3}'), ('\u{2126}', '\u{3c9}'), ('\u{212a}', 'k'), ('\u{212b}', '\u{e5}'),
+    ('\u{2132}', '\u{214e}'), ('\u{2160}', '\u{2170}'), ('\u{2161}', '\u{2171}'), ('\u{2162}', '\u{2172}'),
+    ('\u{2163}', '\u{2173}'), ('\u{2164}', '\u{2174}'), ('\u{2165}', '\u{2175}'), ('\u{2166}', '\u{2176}'),
+    ('\u{2167}', '\u{2177}'), ('\u{2168}', '\u{2178}'), ('\u{2169}', '\u{2179}'), ('\u{216a}', '\u{217a}'),
+    ('\u{216b}', '\u{217b}'), ('\u{216c}', '\u{217c}'), ('\u{216d}', '\u{217d}'), ('\u{216e}', '\u{217e}'),
+    ('\u{216f}', '\u{217f}'), ('\u{2183}', '\u{2184}'), ('\u{24b6}', '\u{24d0}'), ('\u{24b7}', '\u{24d1}'),
+    ('\u{24b8}', '\u{24d2}'), ('\u{24b9}', '\u{24d3}'), ('\u{24ba}', '\u{24d4}'), ('\u{24bb}', '\u{24d5}'),
+    ('\u{24bc}', '\u{24d6}'), ('\u{24bd}', '\u{24d7}'), ('\u{24be}', '\u{24d8}'), ('\u{24bf}', '\u{24d9}'),
+    ('\u{24c0}', '\u{24da}'), ('\u{24c1}', '\u{24db}'), ('\u{24c2}', '\u{24dc}'), ('\u{24c3}', '\u{24dd}'),
+    ('\u{24c4}', '\u{24de}'), ('\u{24c5}', '\u{24df}'), ('\u{24c6}', '\u{24e0}'), ('\u{24c7}', '\u{24e1}'),
+    ('\u{24c8}', '\u{24e2}'), ('\u{24c9}', '\u{24e3}'), ('\u{24ca}', '\u{24e4}'), ('\u{24cb}', '\u{24e5}'),
+    ('\u{24cc}', '\u{24e6}'), ('\u{24cd}', '\u{24e7}'), ('\u{24ce}', '\u{24e8}'), ('\u{24cf}', '\u{24e9}'),
+    ('\u{2c00}', '\u{2c30}'), ('\u{2c01}', '\u{2c31}'), ('\u{2c02}', '\u{2c32}'), ('\u{2c03}', '\u{2c33}'),
+    ('\u{2c04}', '\u{2c34}'), ('\u{2c05}', '\u{2c35}'), ('\u{2c06}', '\u{2c36}'), ('\u{2c07}', '\u{2c37}'),
+    ('\u{2c08}', '\u{2c38}'), ('\u{2c09}', '\u{2c39}'), ('\u{2c0a}', '\u{2c3a}'), ('\u{2c0b}', '\u{2c3b}'),
+    ('\u{2c0c}', '\u{2c3c}'), ('\u{2c0d}', '\u{2c3d}'), ('\u{2c0e}', '\u{2c3e}'), ('\u{2c0f}', '\u{2c3f}'),
+    ('\u{2c10}', '\u{2c40}'), ('\u{2c11}', '\u{2c41}'), ('\u{2c12}', '\u{2c42}'), ('\u{2c13}', '\u{2c43}'),
+    ('\u{2c14}', '\u{2c44}'), ('\u{2c15}', '\u{2c45}'), ('\u{2c16}', '\u{2c46}'), ('\u{2c17}', '\u{2c47}'),
+    ('\u{2c18}', '\u{2c48}'), ('\u{2c19}', '\u{2c49}'), ('\u{2c1a}', '\u{2c4a}'), ('\u{2c1b}', '\u{2c4b}'),
+    ('\u{2c1c}', '\u{2c4c}'), ('\u{2c1d}', '\u{2c4d}'), ('\u{2c1e}', '\u{2c4e}'), ('\u{2c1f}', '\u{2c4f}'),
+    ('\u{2c20}', '\u{2c50}'), ('\u{2c21}', '\u{2c51}'), ('\u{2c22}', '\u{2c52}'), ('\u{2c23}', '\u{2c53}'),
+    ('\u{2c24}', '\u{2c54}'), ('\u{2c25}', '\u{2c55}'), ('\u{2c26}', '\u{2c56}'), ('\u{2c27}', '\u{2c57}'),
+    ('\u{2c28}', '\u{2c58}'), ('\u{2c29}', '\u{2c59}'), ('\u{2c2a}', '\u{2c5a}'), ('\u{2c2b}', '\u{2c5b}'),
+    ('\u{2c2c}', '\u{2c5c}'), ('\u{2c2d}', '\u{2c5d}'), ('\u{2c2e}', '\u{2c5e}'), ('\u{2c2f}', '\u{2c5f}'),
+    ('\u{2c60}', '\u{2c61}'), ('\u{2c62}', '\u{26b}'), ('\u{2c63}', '\u{1d7d}'), ('\u{2c64}', '\u{27d}'),
+    ('\u{2c67}', '\u{2c68}'), ('\u{2c69}', '\u{2c6a}'), ('\u{2c6b}', '\u{2c6c}'), ('\u{2c6d}', '\u{251}'),
+    ('\u{2c6e}', '\u{271}'), ('\u{2c6f}', '\u{250}'), ('\u{2c70}', '\u{252}'), ('\u{2c72}', '\u{2c73}'),
+    ('\u{2c75}', '\u{2c76}'), ('\u{2c7e}', '\u{23f}'), ('\u{2c7f}', '\u{240}'), ('\u{2c80}', '\u{2c81}'),
+    ('\u{2c82}', '\u{2c83}'), ('\u{2c84}', '\u{2c85}'), ('\u{2c86}', '\u{2c87}'), ('\u{2c88}', '\u{2c89}'),
+    ('\u{2c8a}', '\u{2c8b}'), ('\u{2c8c}', '\u{2c8d}'), ('\u{2c8e}', '\u{2c8f}'), ('\u{2c90}', '\u{2c91}'),
+    ('\u{2c92}', '\u{2c93}'), ('\u{2c94}', '\u{2c95}'), ('\u{2c96}', '\u{2c97}'), ('\u{2c98}', '\u{2c99}'),
+    ('\u{2c9a}', '\u{2c9b}'), ('\u{2c9c}', '\u{2c9d}'), ('\u{2c9e}', '\u{2c9f}'), ('\u{2ca0}', '\u{2ca1}'),
+    ('\u{2ca2}', '\u{2ca3}'), ('\u{2ca4}', '\u{2ca5}'), ('\u{2ca6}', '\u{2ca7}'), ('\u{2ca8}', '\u{2ca9}'),
+    ('\u{2caa}', '\u{2cab}'), ('\u{2cac}', '\u{2cad}'), ('\u{2cae}', '\u{2caf}'), ('\u{2cb0}', '\u{2cb1}'),
+    ('\u{2cb2}', '\u{2cb3}'), ('\u{2cb4}', '\u{2cb5}'), ('\u{2cb6}', '\u{2cb7}'), ('\u{2cb8}', '\u{2cb9}'),
+    ('\u{2cba}', '\u{2cbb}'), ('\u{2cbc}', '\u{2cbd}'), ('\u{2cbe}', '\u{2cbf}'), ('\u{2cc0}', '\u{2cc1}'),
+    ('\u{2cc2}', '\u{2cc3}'), ('\u{2cc4}', '\u{2cc5}'), ('\u{2cc6}', '\u{2cc7}'), ('\u{2cc8}', '\u{2cc9}'),
+    ('\u{2cca}', '\u{2ccb}'), ('\u{2ccc}', '\u{2ccd}'), ('\u{2cce}', '\u{2ccf}'), ('\u{2cd0}', '\u{2cd1}'),
+    ('\u{2cd2}', '\u{2cd3}'), ('\u{2cd4}', '\u{2cd5}'), ('\u{2cd6}', '\u{2cd7}'), ('\u{2cd8}', '\u{2cd9}'),
+    ('\u{2cda}', '\u{2cdb}'), ('\u{2cdc}', '\u{2cdd}'), ('\u{2cde}', '\u{2cdf}'), ('\u{2ce0}', '\u{2ce1}'),
+    ('\u{2ce2}', '\u{2ce3}'), ('\u{2ceb}', '\u{2cec}'), ('\u{2ced}', '\u{2cee}'), ('\u{2cf2}', '\u{2cf3}'),
+    ('\u{a640}', '\u{a641}'), ('\u{a642}', '\u{a643}'), ('\u{a644}', '\u{a645}'), ('\u{a646}', '\u{a647}'),
+    ('\u{a648}', '\u{a649}'), ('\u{a64a}', '\u{a64b}'), ('\u{a64c}', '\u{a64d}'), ('\u{a64e}', '\u{a64f}'),
+    ('\u{a650}', '\u{a651}'), ('\u{a652}', '\u{a653}'), ('\u{a654}', '\u{a655}'), ('\u{a656}', '\u{a657}'),
+    ('\u{a658}', '\u{a659}'), ('\u{a65a}', '\u{a65b}'), ('\u{a65c}', '\u{a65d}'), ('\u{a65e}', '\u{a65f}'),
+    ('\u{a660}', '\u{a661}'), ('\u{a662}', '\u{a663}'), ('\u{a664}', '\u{a665}'), ('\u{a666}', '\u{a667}'),
+    ('\u{a668}', '\u{a669}'), ('\u{a66a}', '\u{a66b}'), ('\u{a66c}', '\u{a66d}'), ('\u{a680}', '\u{a681}'),
+    ('\u{a682}', '\u{a683}'), ('\u{a684}', '\u{a685}'), ('\u{a686}', '\u{a687}'), ('\u{a688}', '\u{a689}'),
+    ('\u{a68a}', '\u{a68b}'), ('\u{a68c}', '\u{a68d}'), ('\u{a68e}', '\u{a68f}'), ('\u{a690}', '\u{a691}'),
+    ('\u{a692}', '\u{a693}'), ('\u{a694}', '\u{a695}'), ('\u{a696}', '\u{a697}'), ('\u{a698}', '\u{a699}'),
+    ('\u{a69a}', '\u{a69b}'), ('\u{a722}', '\u{a723}'), ('\u{a724}', '\u{a725}'), ('\u{a726}', '\u{a727}'),
+    ('\u{a728}', '\u{a729}'), ('\u{a72a}', '\u{a72b}'), ('\u{a72c}', '\u{a72d}'), ('\u{a72e}', '\u{a72f}'),
+    ('\u{a732}', '\u{a733}'), ('\u{a734}', '\u{a735}'), ('\u{a736}', '\u{a737}'), ('\u{a738}', '\u{a739}'),
+    ('\u{a73a}', '\u{a73b}'), ('\u{a73c}', '\u{a73d}'), ('\u{a73e}', '\u{a73f}'), ('\u{a740}', '\u{a741}'),
+    ('\u{a742}', '\u{a743}'), ('\u{a744}', '\u{a745}'), ('\u{a746}', '\u{a747}'), ('\u{a748}', '\u{a749}'),
+    ('\u{a74a}', '\u{a74b}'), ('\u{a74c}', '\u{a74d}'), ('\u{a74e}', '\u{a74f}'), ('\u{a750}', '\u{a751}'),
+    ('\u{a752}', '\u{a753}'), ('\u{a754}', '\u{a755}'), ('\u{a756}', '\u{a757}'), ('\u{a758}', '\u{a759}'),
+    ('\u{a75a}', '\u{a75b}'), ('\u{a75c}', '\u{a75d}'), ('\u{a75e}', '\u{a75f}'), ('\u{a760}', '\u{a761}'),
+    ('\u{a762}', '\u{a763}'), ('\u{a764}', '\u{a765}'), ('\u{a766}', '\u{a767}'), ('\u{a768}', '\u{a769}'),
+    ('\u{a76a}', '\u{a76b}'), ('\u{a76c}', '\u{a76d}'), ('\u{a76e}', '\u{a76f}'), ('\u{a779}', '\u{a77a}'),
+    ('\u{a77b}', '\u{a77c}'), ('\u{a77d}', '\u{1d79}'), ('\u{a77e}', '\u{a77f}'), ('\u{a780}', '\u{a781}'),
+    ('\u{a782}', '\u{a783}'), ('\u{a784}', '\u{a785}'), ('\u{a786}', '\u{a787}'), ('\u{a78b}', '\u{a78c}'),
+    ('\u{a78d}', '\u{265}'), ('\u{a790}', '\u{a791}'), ('\u{a792}', '\u{a793}'), ('\u{a796}', '\u{a797}'),
+    ('\u{a798}', '\u{a799}'), ('\u{a79a}', '\u{a79b}'), ('\u{a79c}', '\u{a79d}'), ('\u{a79e}', '\u{a79f}'),
+    ('\u{a7a0}', '\u{a7a1}'), ('\u{a7a2}', '\u{a7a3}'), ('\u{a7a4}', '\u{a7a5}'), ('\u{a7a6}', '\u{a7a7}'),
+    ('\u{a7a8}', '\u{a7a9}'), ('\u{a7aa}', '\u{266}'), ('\u{a7ab}', '\u{25c}'), ('\u{a7ac}', '\u{261}'),
+    ('\u{a7ad}', '\u{26c}'), ('\u{a7ae}', '\u{26a}'), ('\u{a7b0}', '\u{29e}'), ('\u{a7b1}', '\u{287}'),
+    ('\u{a7b2}', '\u{29d}'), ('\u{a7b3}', '\u{ab53}'), ('\u{a7b4}', '\u{a7b5}'), ('\u{a7b6}', '\u{a7b7}'),
+    ('\u{a7b8}', '\u{a7b9}'), ('\u{a7ba}', '\u{a7bb}'), ('\u{a7bc}', '\u{a7bd}'), ('\u{a7be}', '\u{a7bf}'),
+    ('\u{a7c0}', '\u{a7c1}'), ('\u{a7c2}', '\u{a7c3}'), ('\u{a7c4}', '\u{a794}'), ('\u{a7c5}', '\u{282}'),
+    ('\u{a7c6}', '\u{1d8e}'), ('\u{a7c7}', '\u{a7c8}'), ('\u{a7c9}', '\u{a7ca}'), ('\u{a7cb}', '\u{264}'),
+    ('\u{a7cc}', '\u{a7cd}'), ('\u{a7ce}', '\u{a7cf}'), ('\u{a7d0}', '\u{a7d1}'), ('\u{a7d2}', '\u{a7d3}'),
+    ('\u{a7d4}', '\u{a7d5}'), ('\u{a7d6}', '\u{a7d7}'), ('\u{a7d8}', '\u{a7d9}'), ('\u{a7da}', '\u{a7db}'),
+    ('\u{a7dc}', '\u{19b}'), ('\u{a7f5}', '\u{a7f6}'), ('\u{ab70}', '\u{13a0}'), ('\u{ab71}', '\u{13a1}'),
+    ('\u{ab72}', '\u{13a2}'), ('\u{ab73}', '\u{13a3}'), ('\u{ab74}', '\u{13a4}'), ('\u{ab75}', '\u{13a5}'),
+    ('\u{ab76}', '\u{13a6}'), ('\u{ab77}', '\u{13a7}'), ('\u{ab78}', '\u{13a8}'), ('\u{ab79}', '\u{13a9}'),
+    ('\u{ab7a}', '\u{13aa}'), ('\u{ab7b}', '\u{13ab}'), ('\u{ab7c}', '\u{13ac}'), ('\u{ab7d}', '\u{13ad}'),
+    ('\u{ab7e}', '\u{13ae}'), ('\u{ab7f}', '\u{13af}'), ('\u{ab80}', '\u{13b0}'), ('\u{ab81}', '\u{13b1}'),
+    ('\u{ab82}', '\u{13b2}'), ('\u{ab83}', '\u{13b3}'), ('\u{ab84}', '\u{13b4}'), ('\u{ab85}', '\u{13b5}'),
+    ('\u{ab86}', '\u{13b6}'), ('\u{ab87}', '\u{13b7}'), ('\u{ab88}', '\u{13b8}'), ('\u{ab89}', '\u{13b9}'),
+    ('\u{ab8a}', '\u{13ba}'), ('\u{ab8b}', '\u{13bb}'), ('\u{ab8c}', '\u{13bc}'), ('\u{ab8d}', '\u{13bd}'),
+    ('\u{ab8e}', '\u{13be}'), ('\u{ab8f}', '\u{13bf}'), ('\u{ab90}', '\u{13c0}'), ('\u{ab91}', '\u{13c1}'),
+    ('\u{ab92}', '\u{13c2}'), ('\u{ab93}', '\u{13c3}'), ('\u{ab94}', '\u{13c4}'), ('\u{ab95}', '\u{13c5}'),
+    ('\u{ab96}', '\u{13c6}'), ('\u{ab97}', '\u{13c7}'), ('\u{ab98}', '\u{13c8}'), ('\u{ab99}', '\u{13c9}'),
+    ('\u{ab9a}', '\u{13ca}'), ('\u{ab9b}', '\u{13cb}'), ('\u{ab9c}', '\u{13cc}'), ('\u{ab9d}', '\u{13cd}'),
+    ('\u{ab9e}', '\u{13ce}'), ('\u{ab9f}', '\u{13cf}'), ('\u{aba0}', '\u{13d0}'), ('\u{aba1}', '\u{13d1}'),
+    ('\u{aba2}', '\u{13d2}'), ('\u{aba3}', '\u{13d3}'), ('\u{aba4}', '\u{13d4}'), ('\u{aba5}', '\u{13d5}'),
+    ('\u{aba6}', '\u{13d6}'), ('\u{aba7}', '\u{13d7}'), ('\u{aba8}', '\u{13d8}'), ('\u{aba9}', '\u{13d9}'),
+    ('\u{abaa}', '\u{13da}'), ('\u{abab}', '\u{13db}'), ('\u{abac}', '\u{13dc}'), ('\u{abad}', '\u{13dd}'),
+    ('\u{abae}', '\u{13de}'), ('\u{abaf}', '\u{13df}'), ('\u{abb0}', '\u{13e0}'), ('\u{abb1}', '\u{13e1}'),
+    ('\u{abb2}', '\u{13e2}'), ('\u{abb3}', '\u{13e3}'), ('\u{abb4}', '\u{13e4}'), ('\u{abb5}', '\u{13e5}'),
+    ('\u{abb6}', '\u{13e6}'), ('\u{abb7}', '\u{13e7}'), ('\u{abb8}', '\u{13e8}'), ('\u{abb9}', '\u{13e9}'),
+    ('\u{abba}', '\u{13ea}'), ('\u{abbb}', '\u{13eb}'), ('\u{abbc}', '\u{13ec}'), ('\u{abbd}', '\u{13ed}'),
+    ('\u{abbe}', '\u{13ee}'), ('\u{abbf}', '\u{13ef}'), ('\u{fb05}', '\u{fb06}'), ('\u{ff21}', '\u{ff41}'),
+    ('\u{ff22}', '\u{ff42}'), ('\u{ff23}', '\u{ff43}'), ('\u{ff24}', '\u{ff44}'), ('\u{ff25}', '\u{ff45}'),
+    ('\u{ff26}', '\u{ff46}'), ('\u{ff27}', '\u{ff47}'), ('\u{ff28}', '\u{ff48}'), ('\u{ff29}', '\u{ff49}'),
+    ('\u{ff2a}', '\u{ff4a}'), ('\u{ff2b}', '\u{ff4b}'), ('\u{ff2c}', '\u{ff4c}'), ('\u{ff2d}', '\u{ff4d}'),
+    ('\u{ff2e}', '\u{ff4e}'), ('\u{ff2f}', '\u{ff4f}'), ('\u{ff30}', '\u{ff50}'), ('\u{ff31}', '\u{ff51}'),
+    ('\u{ff32}', '\u{ff52}'), ('\u{ff33}', '\u{ff53}'), ('\u{ff34}', '\u{ff54}'), ('\u{ff35}', '\u{ff55}'),
+    ('\u{ff36}', '\u{ff56}'), ('\u{ff37}', '\u{ff57}'), ('\u{ff38}', '\u{ff58}'), ('\u{ff39}', '\u{ff59}'),
+    ('\u{ff3a}', '\u{ff5a}'), ('\u{10400}', '\u{10428}'), ('\u{10401}', '\u{10429}'), ('\u{10402}', '\u{1042a}'),
+    ('\u{10403}', '\u{1042b}'), ('\u{10404}', '\u{1042c}'), ('\u{10405}', '\u{1042d}'), ('\u{10406}', '\u{1042e}'),
+    ('\u{10407}', '\u{1042f}'), ('\u{10408}', '\u{10430}'), ('\u{10409}', '\u{10431}'), ('\u{1040a}', '\u{10432}'),
+    ('\u{1040b}', '\u{10433}'), ('\u{1040c}', '\u{10434}'), ('\u{1040d}', '\u{10435}'), ('\u{1040e}', '\u{10436}'),
+    ('\u{1040f}', '\u{10437}'), ('\u{10410}', '\u{10438}'), ('\u{10411}', '\u{10439}'), ('\u{10412}', '\u{1043a}'),
+    ('\u{10413}', '\u{1043b}'), ('\u{10414}', '\u{1043c}'), ('\u{10415}', '\u{1043d}'), ('\u{10416}', '\u{1043e}'),
+    ('\u{10417}', '\u{1043f}'), ('\u{10418}', '\u{10440}'), ('\u{10419}', '\u{10441}'), ('\u{1041a}', '\u{10442}'),
+    ('\u{1041b}', '\u{10443}'), ('\u{1041c}', '\u{10444}'), ('\u{1041d}', '\u{10445}'), ('\u{1041e}', '\u{10446}'),
+    ('\u{1041f}', '\u{10447}'), ('\u{10420}', '\u{10448}'), ('\u{10421}', '\u{10449}'), ('\u{10422}', '\u{1044a}'),
+    ('\u{10423}', '\u{1044b}'), ('\u{10424}', '\u{1044c}'), ('\u{10425}', '\u{1044d}'), ('\u{10426}', '\u{1044e}'),
+    ('\u{10427}', '\u{1044f}'), ('\u{104b0}', '\u{104d8}'), ('\u{104b1}', '\u{104d9}'), ('\u{104b2}', '\u{104da}'),
+    ('\u{104b3}', '\u{104db}'), ('\u{104b4}', '\u{104dc}'), ('\u{104b5}', '\u{104dd}'), ('\u{104b6}', '\u{104de}'),
+    ('\u{104b7}', '\u{104df}'), ('\u{104b8}', '\u{104e0}'), ('\u{104b9}', '\u{104e1}'), ('\u{104ba}', '\u{104e2}'),
+    ('\u{104bb}', '\u{104e3}'), ('\u{104bc}', '\u{104e4}'), ('\u{104bd}', '\u{104e5}'), ('\u{104be}', '\u{104e6}'),
+    ('\u{104bf}', '\u{104e7}'), ('\u{104c0}', '\u{104e8}'), ('\u{104c1}', '\u{104e9}'), ('\u{104c2}', '\u{104ea}'),
+    ('\u{104c3}', '\u{104eb}'), ('\u{104c4}', '\u{104ec}'), ('\u{104c5}', '\u{104ed}'), ('\u{104c6}', '\u{104ee}'),
+    ('\u{104c7}', '\u{104ef}'), ('\u{104c8}', '\u{104f0}'), ('\u{104c9}', '\u{104f1}'), ('\u{104ca}', '\u{104f2}'),
+    ('\u{104cb}', '\u{104f3}'), ('\u{104cc}', '\u{104f4}'), ('\u{104cd}', '\u{104f5}'), ('\u{104ce}', '\u{104f6}'),
+    ('\u{104cf}', '\u{104f7}'), ('\u{104d0}', '\u{104f8}'), ('\u{104d1}', '\u{104f9}'), ('\u{104d2}', '\u{104fa}'),
+    ('\u{104d3}', '\u{104fb}'), ('\u{10570}', '\u{10597}'), ('\u{10571}', '\u{10598}'), ('\u{10572}', '\u{10599}'),
+    ('\u{10573}', '\u{1059a}'), ('\u{10574}', '\u{1059b}'), ('\u{10575}', '\u{1059c}'), ('\u{10576}', '\u{1059d}'),
+    ('\u{10577}', '\u{1059e}'), ('\u{10578}', '\u{1059f}'), ('\u{10579}', '\u{105a0}'), ('\u{1057a}', '\u{105a1}'),
+    ('\u{1057c}', '\u{105a3}'), ('\u{1057d}', '\u{105a4}'), ('\u{1057e}', '\u{105a5}'), ('\u{1057f}', '\u{105a6}'),
+    ('\u{10580}', '\u{105a7}'), ('\u{10581}', '\u{105a8}'), ('\u{10582}', '\u{105a9}'), ('\u{10583}', '\u{105aa}'),
+    ('\u{10584}', '\u{105ab}'), ('\u{10585}', '\u{105ac}'), ('\u{10586}', '\u{105ad}'), ('\u{10587}', '\u{105ae}'),
+    ('\u{10588}', '\u{105af}'), ('\u{10589}', '\u{105b0}'), ('\u{1058a}', '\u{105b1}'), ('\u{1058c}', '\u{105b3}'),
+    ('\u{1058d}', '\u{105b4}'), ('\u{1058e}', '\u{105b5}'), ('\u{1058f}', '\u{105b6}'), ('\u{10590}', '\u{105b7}'),
+    ('\u{10591}', '\u{105b8}'), ('\u{10592}', '\u{105b9}'), ('\u{10594}', '\u{105bb}'), ('\u{10595}', '\u{105bc}'),
+    ('\u{10c80}', '\u{10cc0}'), ('\u{10c81}', '\u{10cc1}'), ('\u{10c82}', '\u{10cc2}'), ('\u{10c83}', '\u{10cc3}'),
+    ('\u{10c84}', '\u{10cc4}'), ('\u{10c85}', '\u{10cc5}'), ('\u{10c86}', '\u{10cc6}'), ('\u{10c87}', '\u{10cc7}'),
+    ('\u{10c88}', '\u{10cc8}'), ('\u{10c89}', '\u{10cc9}'), ('\u{10c8a}', '\u{10cca}'), ('\u{10c8b}', '\u{10ccb}'),
+    ('\u{10c8c}', '\u{10ccc}'), ('\u{10c8d}', '\u{10ccd}'), ('\u{10c8e}', '\u{10cce}'), ('\u{10c8f}', '\u{10ccf}'),
+    ('\u{10c90}', '\u{10cd0}'), ('\u{10c91}', '\u{10cd1}'), ('\u{10c92}', '\u{10cd2}'), ('\u{10c93}', '\u{10cd3}'),
+    ('\u{10c94}', '\u{10cd4}'), ('\u{10c95}', '\u{10cd5}'), ('\u{10c96}', '\u{10cd6}'), ('\u{10c97}', '\u{10cd7}'),
+    ('\u{10c98}', '\u{10cd8}'), ('\u{10c99}', '\u{10cd9}'), ('\u{10c9a}', '\u{10cda}'), ('\u{10c9b}', '\u{10cdb}'),
+    ('\u{10c9c}', '\u{10cdc}'), ('\u{10c9d}', '\u{10cdd}'), ('\u{10c9e}', '\u{10cde}'), ('\u{10c9f}', '\u{10cdf}'),
+    ('\u{10ca0}', '\u{10ce0}'), ('\u{10ca1}', '\u{10ce1}'), ('\u{10ca2}', '\u{10ce2}'), ('\u{10ca3}', '\u{10ce3}'),
+    ('\u{10ca4}', '\u{10ce4}'), ('\u{10ca5}', '\u{10ce5}'), ('\u{10ca6}', '\u{10ce6}'), ('\u{10ca7}', '\u{10ce7}'),
+    ('\u{10ca8}', '\u{10ce8}'), ('\u{10ca9}', '\u{10ce9}'), ('\u{10caa}', '\u{10cea}'), ('\u{10cab}', '\u{10ceb}'),
+    ('\u{10cac}', '\u{10cec}'), ('\u{10cad}', '\u{10ced}'), ('\u{10cae}', '\u{10cee}'), ('\u{10caf}', '\u{10cef}'),
+    ('\u{10cb0}', '\u{10cf0}'), ('\u{10cb1}', '\u{10cf1}'), ('\u{10cb2}', '\u{10cf2}'), ('\u{10d50}', '\u{10d70}'),
+    ('\u{10d51}', '\u{10d71}'), ('\u{10d52}', '\u{10d72}'), ('\u{10d53}', '\u{10d73}'), ('\u{10d54}', '\u{10d74}'),
+    ('\u{10d55}', '\u{10d75}'), ('\u{10d56}', '\u{10d76}'), ('\u{10d57}', '\u{10d77}'), ('\u{10d58}', '\u{10d78}'),
+    ('\u{10d59}', '\u{10d79}'), ('\u{10d5a}', '\u{10d7a}'), ('\u{10d5b}', '\u{10d7b}'), ('\u{10d5c}', '\u{10d7c}'),
+    ('\u{10d5d}', '\u{10d7d}'), ('\u{10d5e}', '\u{10d7e}'), ('\u{10d5f}', '\u{10d7f}'), ('\u{10d60}', '\u{10d80}'),
+    ('\u{10d61}', '\u{10d81}'), ('\u{10d62}', '\u{10d82}'), ('\u{10d63}', '\u{10d83}'), ('\u{10d64}', '\u{10d84}'),
+    ('\u{10d65}', '\u{10d85}'), ('\u{118a0}', '\u{118c0}'), ('\u{118a1}', '\u{118c1}'), ('\u{118a2}', '\u{118c2}'),
+    ('\u{118a3}', '\u{118c3}'), ('\u{118a4}', '\u{118c4}'), ('\u{118a5}', '\u{118c5}'), ('\u{118a6}', '\u{118c6}'),
+    ('\u{118a7}', '\u{118c7}'), ('\u{118a8}', '\u{118c8}'), ('\u{118a9}', '\u{118c9}'), ('\u{118aa}', '\u{118ca}'),
+    ('\u{118ab}', '\u{118cb}'), ('\u{118ac}', '\u{118cc}'), ('\u{118ad}', '\u{118cd}'), ('\u{118ae}', '\u{118ce}'),
+    ('\u{118af}', '\u{118cf}'), ('\u{118b0}', '\u{118d0}'), ('\u{118b1}', '\u{118d1}'), ('\u{118b2}', '\u{118d2}'),
+    ('\u{118b3}', '\u{118d3}'), ('\u{118b4}', '\u{118d4}'), ('\u{118b5}', '\u{118d5}'), ('\u{118b6}', '\u{118d6}'),
+    ('\u{118b7}', '\u{118d7}'), ('\u{118b8}', '\u{118d8}'), ('\u{118b9}', '\u{118d9}'), ('\u{118ba}', '\u{118da}'),
+    ('\u{118bb}', '\u{118db}'), ('\u{118bc}', '\u{118dc}'), ('\u{118bd}', '\u{118dd}'), ('\u{118be}', '\u{118de}'),
+    ('\u{118bf}', '\u{118df}'), ('\u{16e40}', '\u{16e60}'), ('\u{16e41}', '\u{16e61}'), ('\u{16e42}', '\u{16e62}'),
+    ('\u{16e43}', '\u{16e63}'), ('\u{16e44}', '\u{16e64}'), ('\u{16e45}', '\u{16e65}'), ('\u{16e46}', '\u{16e66}'),
+    ('\u{16e47}', '\u{16e67}'), ('\u{16e48}', '\u{16e68}'), ('\u{16e49}', '\u{16e69}'), ('\u{16e4a}', '\u{16e6a}'),
+    ('\u{16e4b}', '\u{16e6b}'), ('\u{16e4c}', '\u{16e6c}'), ('\u{16e4d}', '\u{16e6d}'), ('\u{16e4e}', '\u{16e6e}'),
+    ('\u{16e4f}', '\u{16e6f}'), ('\u{16e50}', '\u{16e70}'), ('\u{16e51}', '\u{16e71}'), ('\u{16e52}', '\u{16e72}'),
+    ('\u{16e53}', '\u{16e73}'), ('\u{16e54}', '\u{16e74}'), ('\u{16e55}', '\u{16e75}'), ('\u{16e56}', '\u{16e76}'),
+    ('\u{16e57}', '\u{16e77}'), ('\u{16e58}', '\u{16e78}'), ('\u{16e59}', '\u{16e79}'), ('\u{16e5a}', '\u{16e7a}'),
+    ('\u{16e5b}', '\u{16e7b}'), ('\u{16e5c}', '\u{16e7c}'), ('\u{16e5d}', '\u{16e7d}'), ('\u{16e5e}', '\u{16e7e}'),
+    ('\u{16e5f}', '\u{16e7f}'), ('\u{16ea0}', '\u{16ebb}'), ('\u{16ea1}', '\u{16ebc}'), ('\u{16ea2}', '\u{16ebd}'),
+    ('\u{16ea3}', '\u{16ebe}'), ('\u{16ea4}', '\u{16ebf}'), ('\u{16ea5}', '\u{16ec0}'), ('\u{16ea6}', '\u{16ec1}'),
+    ('\u{16ea7}', '\u{16ec2}'), ('\u{16ea8}', '\u{16ec3}'), ('\u{16ea9}', '\u{16ec4}'), ('\u{16eaa}', '\u{16ec5}'),
+    ('\u{16eab}', '\u{16ec6}'), ('\u{16eac}', '\u{16ec7}'), ('\u{16ead}', '\u{16ec8}'), ('\u{16eae}', '\u{16ec9}'),
+    ('\u{16eaf}', '\u{16eca}'), ('\u{16eb0}', '\u{16ecb}'), ('\u{16eb1}', '\u{16ecc}'), ('\u{16eb2}', '\u{16ecd}'),
+    ('\u{16eb3}', '\u{16ece}'), ('\u{16eb4}', '\u{16ecf}'), ('\u{16eb5}', '\u{16ed0}'), ('\u{16eb6}', '\u{16ed1}'),
+    ('\u{16eb7}', '\u{16ed2}'), ('\u{16eb8}', '\u{16ed3}'), ('\u{1e900}', '\u{1e922}'), ('\u{1e901}', '\u{1e923}'),
+    ('\u{1e902}', '\u{1e924}'), ('\u{1e903}', '\u{1e925}'), ('\u{1e904}', '\u{1e926}'), ('\u{1e905}', '\u{1e927}'),
+    ('\u{1e906}', '\u{1e928}'), ('\u{1e907}', '\u{1e929}'), ('\u{1e908}', '\u{1e92a}'), ('\u{1e909}', '\u{1e92b}'),
+    ('\u{1e90a}', '\u{1e92c}'), ('\u{1e90b}', '\u{1e92d}'), ('\u{1e90c}', '\u{1e92e}'), ('\u{1e90d}', '\u{1e92f}'),
+    ('\u{1e90e}', '\u{1e930}'), ('\u{1e90f}', '\u{1e931}'), ('\u{1e910}', '\u{1e932}'), ('\u{1e911}', '\u{1e933}'),
+    ('\u{1e912}', '\u{1e934}'), ('\u{1e913}', '\u{1e935}'), ('\u{1e914}', '\u{1e936}'), ('\u{1e915}', '\u{1e937}'),
+    ('\u{1e916}', '\u{1e938}'), ('\u{1e917}', '\u{1e939}'), ('\u{1e918}', '\u{1e93a}'), ('\u{1e919}', '\u{1e93b}'),
+    ('\u{1e91a}', '\u{1e93c}'), ('\u{1e91b}', '\u{1e93d}'), ('\u{1e91c}', '\u{1e93e}'), ('\u{1e91d}', '\u{1e93f}'),
+    ('\u{1e91e}', '\u{1e940}'), ('\u{1e91f}', '\u{1e941}'), ('\u{1e920}', '\u{1e942}'), ('\u{1e921}', '\u{1e943}'),
+];
+
+#[rustfmt::skip]
+pub const LOWERCASE: &[(u32, u32)] = &[
+    (0x00061, 0x0007a), (0x000aa, 0x000aa), (0x000b5, 0x000b5), (0x000ba, 0x000ba),
+    (0x000df, 0x000f6), (0x000f8, 0x000ff), (0x00101, 0x00101), (0x00103, 0x00103),
+    (0x00105, 0x00105), (0x00107, 0x00107), (0x00109, 0x00109), (0x0010b, 0x0010b),
+    (0x0010d, 0x0010d), (0x0010f, 0x0010f), (0x00111, 0x00111), (0x00113, 0x00113),
+    (0x00115, 0x00115), (0x00117, 0x00117), (0x00119, 0x00119), (0x0011b, 0x0011b),
+    (0x0011d, 0x0011d), (0x0011f, 0x0011f), (0x00121, 0x00121), (0x00123, 0x00123),
+    (0x00125, 0x00125), (0x00127, 0x00127), (0x00129, 0x00129), (0x0012b, 0x0012b),
+    (0x0012d, 0x0012d), (0x0012f, 0x0012f), (0x00131, 0x00131), (0x00133, 0x00133),
+    (0x00135, 0x00135), (0x00137, 0x00138), (0x0013a, 0x0013a), (0x0013c, 0x0013c),
+    (0x0013e, 0x0013e), (0x00140, 0x00140), (0x00142, 0x00142), (0x00144, 0x00144),
+    (0x00146, 0x00146), (0x00148, 0x00149), (0x0014b, 0x0014b), (0x0014d, 0x0014d),
+    (0x0014f, 0x0014f), (0x00151, 0x00151), (0x00153, 0x00153), (0x00155, 0x00155),
+    (0x00157, 0x00157), (0x00159, 0x00159), (0x0015b, 0x0015b), (0x0015d, 0x0015d),
+    (0x0015f, 0x0015f), (0x00161, 0x00161), (0x00163, 0x00163), (0x00165, 0x00165),
+    (0x00167, 0x00167), (0x00169, 0x00169), (0x0016b, 0x0016b), (0x0016d, 0x0016d),
+    (0x0016f, 0x0016f), (0x00171, 0x00171), (0x00173, 0x00173), (0x00175, 0x00175),
+    (0x00177, 0x00177), (0x0017a, 0x0017a), (0x0017c, 0x0017c), (0x0017e, 0x00180),
+    (0x00183, 0x00183), (0x00185, 0x00185), (0x00188, 0x00188), (0x0018c, 0x0018d),
+    (0x00192, 0x00192), (0x00195, 0x00195), (0x00199, 0x0019b), (0x0019e, 0x0019e),
+    (0x001a1, 0x001a1), (0x001a3, 0x001a3), (0x001a5, 0x001a5), (0x001a8, 0x001a8),
+    (0x001aa, 0x001ab), (0x001ad, 0x001ad), (0x001b0, 0x001b0), (0x001b4, 0x001b4),
+    (0x001b6, 0x001b6), (0x001b9, 0x001ba), (0x001bd, 0x001bf), (0x001c6, 0x001c6),
+    (0x001c9, 0x001c9), (0x001cc, 0x001cc), (0x001ce, 0x001ce), (0x001d0, 0x001d0),
+    (0x001d2, 0x001d2), (0x001d4, 0x001d4), (0x001d6, 0x001d6), (0x001d8, 0x001d8),
+    (0x001da, 0x001da), (0x001dc, 0x001dd), (0x001df, 0x001df), (0x001e1, 0x001e1),
+    (0x001e3, 0x001e3), (0x001e5, 0x001e5), (0x001e7, 0x001e7), (0x001e9, 0x001e9),
+    (0x001eb, 0x001eb), (0x001ed, 0x001ed), (0x001ef, 0x001f0), (0x001f3, 0x001f3),
+    (0x001f5, 0x001f5), (0x001f9, 0x001f9), (0x001fb, 0x001fb), (0x001fd, 0x001fd),
+    (0x001ff, 0x001ff), (0x00201, 0x00201), (0x00203, 0x00203), (0x00205, 0x00205),
+    (0x00207, 0x00207), (0x00209, 0x00209), (0x0020b, 0x0020b), (0x0020d, 0x0020d),
+    (0x0020f, 0x0020f), (0x00211, 0x00211), (0x00213, 0x00213), (0x00215, 0x00215),
+    (0x00217, 0x00217), (0x00219, 0x00219), (0x0021b, 0x0021b), (0x0021d, 0x0021d),
+    (0x0021f, 0x0021f), (0x00221, 0x00221), (0x00223, 0x00223), (0x00225, 0x00225),
+    (0x00227, 0x00227), (0x00229, 0x00229), (0x0022b, 0x0022b), (0x0022d, 0x0022d),
+    (0x0022f, 0x0022f), (0x00231, 0x00231), (0x00233, 0x00239), (0x0023c, 0x0023c),
+    (0x0023f, 0x00240), (0x00242, 0x00242), (0x00247, 0x00247), (0x00249, 0x00249),
+    (0x0024b, 0x0024b), (0x0024d, 0x0024d), (0x0024f, 0x00293), (0x00296, 0x002b8),
+    (0x002c0, 0x002c1), (0x002e0, 0x002e4), (0x00345, 0x00345), (0x00371, 0x00371),
+    (0x00373, 0x00373), (0x00377, 0x00377), (0x0037a, 0x0037d), (0x00390, 0x00390),
+    (0x003ac, 0x003ce), (0x003d0, 0x003d1), (0x003d5, 0x003d7), (0x003d9, 0x003d9),
+    (0x003db, 0x003db), (0x003dd, 0x003dd), (0x003df, 0x003df), (0x003e1, 0x003e1),
+    (0x003e3, 0x003e3), (0x003e5, 0x003e5), (0x003e7, 0x003e7), (0x003e9, 0x003e9),
+    (0x003eb, 0x003eb), (0x003ed, 0x003ed), (0x003ef, 0x003f3), (0x003f5, 0x003f5),
+    (0x003f8, 0x003f8), (0x003fb, 0x003fc), (0x00430, 0x0045f), (0x00461, 0x00461),
+    (0x00463, 0x00463), (0x00465, 0x00465), (0x00467, 0x00467), (0x00469, 0x00469),
+    (0x0046b, 0x0046b), (0x0046d, 0x0046d), (0x0046f, 0x0046f), (0x00471, 0x00471),
+    (0x00473, 0x00473), (0x00475, 0x00475), (0x00477, 0x00477), (0x00479, 0x00479),
+    (0x0047b, 0x0047b), (0x0047d, 0x0047d), (0x0047f, 0x0047f), (0x00481, 0x00481),
+    (0x0048b, 0x0048b), (0x0048d, 0x0048d), (0x0048f, 0x0048f), (0x00491, 0x00491),
+    (0x00493, 0x00493), (0x00495, 0x00495), (0x00497, 0x00497), (0x00499, 0x00499),
+    (0x0049b, 0x0049b), (0x0049d, 0x0049d), (0x0049f, 0x0049f), (0x004a1, 0x004a1),
+    (0x004a3, 0x004a3), (0x004a5, 0x004a5), (0x004a7, 0x004a7), (0x004a9, 0x004a9),
+    (0x004ab, 0x004ab), (0x004ad, 0x004ad), (0x004af, 0x004af), (0x004b1, 0x004b1),
+    (0x004b3, 0x004b3), (0x004b5, 0x004b5), (0x004b7, 0x004b7), (0x004b9, 0x004b9),
+    (0x004bb, 0x004bb), (0x004bd, 0x004bd), (0x004bf, 0x004bf), (0x004c2, 0x004c2),
+    (0x004c4, 0x004c4), (0x004c6, 0x004c6), (0x004c8, 0x004c8), (0x004ca, 0x004ca),
+    (0x004cc, 0x004cc), (0x004ce, 0x004cf), (0x004d1, 0x004d1), (0x004d3, 0x004d3),
+    (0x004d5, 0x004d5), (0x004d7, 0x004d7), (0x004d9, 0x004d9), (0x004db, 0x004db),
+    (0x004dd, 0x004dd), (0x004df, 0x004df), (0x004e1, 0x004e1), (0x004e3, 0x004e3),
+    (0x004e5, 0x004e5), (0x004e7, 0x004e7), (0x004e9, 0x004e9), (0x004eb, 0x004eb),
+    (0x004ed, 0x004ed), (0x004ef, 0x004ef), (0x004f1, 0x004f1), (0x004f3, 0x004f3),
+    (0x004f5, 0x004f5), (0x004f7, 0x004f7), (0x004f9, 0x004f9), (0x004fb, 0x004fb),
+    (0x004fd, 0x004fd), (0x004ff, 0x004ff), (0x00501, 0x00501), (0x00503, 0x00503),
+    (0x00505, 0x00505), (0x00507, 0x00507), (0x00509, 0x00509), (0x0050b, 0x0050b),
+    (0x0050d, 0x0050d), (0x0050f, 0x0050f), (0x00511, 0x00511), (0x00513, 0x00513),
+    (0x00515, 0x00515), (0x00517, 0x00517), (0x00519, 0x00519), (0x0051b, 0x0051b),
+    (0x0051d, 0x0051d), (0x0051f, 0x0051f), (0x00521, 0x00521), (0x00523, 0x00523),
+    (0x00525, 0x00525), (0x00527, 0x00527), (0x00529, 0x00529), (0x0052b, 0x0052b),
+    (0x0052d, 0x0052d), (0x0052f, 0x0052f), (0x00560, 0x00588), (0x010d0, 0x010fa),
+    (0x010fc, 0x010ff), (0x013f8, 0x013fd), (0x01c80, 0x01c88), (0x01c8a, 0x01c8a),
+    (0x01d00, 0x01dbf), (0x01e01, 0x01e01), (0x01e03, 0x01e03), (0x01e05, 0x01e05),
+    (0x01e07, 0x01e07), (0x01e09, 0x01e09), (0x01e0b, 0x01e0b), (0x01e0d, 0x01e0d),
+    (0x01e0f, 0x01e0f), (0x01e11, 0x01e11), (0x01e13, 0x01e13), (0x01e15, 0x01e15),
+    (0x01e17, 0x01e17), (0x01e19, 0x01e19), (0x01e1b, 0x01e1b), (0x01e1d, 0x01e1d),
+    (0x01e1f, 0x01e1f), (0x01e21, 0x01e21), (0x01e23, 0x01e23), (0x01e25, 0x01e25),
+    (0x01e27, 0x01e27), (0x01e29, 0x01e29), (0x01e2b, 0x01e2b), (0x01e2d, 0x01e2d),
+    (0x01e2f, 0x01e2f), (0x01e31, 0x01e31), (0x01e33, 0x01e33), (0x01e35, 0x01e35),
+    (0x01e37, 0x01e37), (0x01e39, 0x01e39), (0x01e3b, 0x01e3b), (0x01e3d, 0x01e3d),
+    (0x01e3f, 0x01e3f), (0x01e41, 0x01e41), (0x01e43, 0x01e43), (0x01e45, 0x01e45),
+    (0x01e47, 0x01e47), (0x01e49, 0x01e49), (0x01e4b, 0x01e4b), (0x01e4d, 0x01e4d),
+    (0x01e4f, 0x01e4f), (0x01e51, 0x01e51), (0x01e53, 0x01e53), (0x01e55, 0x01e55),
+    (0x01e57, 0x01e57), (0x01e59, 0x01e59), (0x01e5b, 0x01e5b), (0x01e5d, 0x01e5d),
+    (0x01e5f, 0x01e5f), (0x01e61, 0x01e61), (0x01e63, 0x01e63), (0x01e65, 0x01e65),
+    (0x01e67, 0x01e67), (0x01e69, 0x01e69), (0x01e6b, 0x01e6b), (0x01e6d, 0x01e6d),
+    (0x01e6f, 0x01e6f), (0x01e71, 0x01e71), (0x01e73, 0x01e73), (0x01e75, 0x01e75),
+    (0x01e77, 0x01e77), (0x01e79, 0x01e79), (0x01e7b, 0x01e7b), (0x01e7d, 0x01e7d),
+    (0x01e7f, 0x01e7f), (0x01e81, 0x01e81), (0x01e83, 0x01e83), (0x01e85, 0x01e85),
+    (0x01e87, 0x01e87), (0x01e89, 0x01e89), (0x01e8b, 0x01e8b), (0x01e8d, 0x01e8d),
+    (0x01e8f, 0x01e8f), (0x01e91, 0x01e91), (0x01e93, 0x01e93), (0x01e95, 0x01e9d),
+    (0x01e9f, 0x01e9f), (0x01ea1, 0x01ea1), (0x01ea3, 0x01ea3), (0x01ea5, 0x01ea5),
+    (0x01ea7, 0x01ea7), (0x01ea9, 0x01ea9), (0x01eab, 0x01eab), (0x01ead, 0x01ead),
+    (0x01eaf, 0x01eaf), (0x01eb1, 0x01eb1), (0x01eb3, 0x01eb3), (0x01eb5, 0x01eb5),
+    (0x01eb7, 0x01eb7), (0x01eb9, 0x01eb9), (0x01ebb, 0x01ebb), (0x01ebd, 0x01ebd),
+    (0x01ebf, 0x01ebf), (0x01ec1, 0x01ec1), (0x01ec3, 0x01ec3), (0x01ec5, 0x01ec5),
+    (0x01ec7, 0x01ec7), (0x01ec9, 0x01ec9), (0x01ecb, 0x01ecb), (0x01ecd, 0x01ecd),
+    (0x01ecf, 0x01ecf), (0x01ed1, 0x01ed1), (0x01ed3, 0x01ed3), (0x01ed5, 0x01ed5),
+    (0x01ed7, 0x01ed7), (0x01ed9, 0x01ed9), (0x01edb, 0x01edb), (0x01edd, 0x01edd),
+    (0x01edf, 0x01edf), (0x01ee1, 0x01ee1), (0x01ee3, 0x01ee3), (0x01ee5, 0x01ee5),
+    (0x01ee7, 0x01ee7), (0x01ee9, 0x01ee9), (0x01eeb, 0x01eeb), (0x01eed, 0x01eed),
+    (0x01eef, 0x01eef), (0x01ef1, 0x01ef1), (0x01ef3, 0x01ef3), (0x01ef5, 0x01ef5),
+    (0x01ef7, 0x01ef7), (0x01ef9, 0x01ef9), (0x01efb, 0x01efb), (0x01efd, 0x01efd),
+    (0x01eff, 0x01f07), (0x01f10, 0x01f15), (0x01f20, 0x01f27), (0x01f30, 0x01f37),
+    (0x01f40, 0x01f45), (0x01f50, 0x01f57), (0x01f60, 0x01f67), (0x01f70, 0x01f7d),
+    (0x01f80, 0x01f87), (0x01f90, 0x01f97), (0x01fa0, 0x01fa7), (0x01fb0, 0x01fb4),
+    (0x01fb6, 0x01fb7), (0x01fbe, 0x01fbe), (0x01fc2, 0x01fc4), (0x01fc6, 0x01fc7),
+    (0x01fd0, 0x01fd3), (0x01fd6, 0x01fd7), (0x01fe0, 0x01fe7), (0x01ff2, 0x01ff4),
+    (0x01ff6, 0x01ff7), (0x02071, 0x02071), (0x0207f, 0x0207f), (0x02090, 0x0209c),
+    (0x0210a, 0x0210a), (0x0210e, 0x0210f), (0x02113, 0x02113), (0x0212f, 0x0212f),
+    (0x02134, 0x02134), (0x02139, 0x02139), (0x0213c, 0x0213d), (0x02146, 0x02149),
+    (0x0214e, 0x0214e), (0x02170, 0x0217f), (0x02184, 0x02184), (0x024d0, 0x024e9),
+    (0x02c30, 0x02c5f), (0x02c61, 0x02c61), (0x02c65, 0x02c66), (0x02c68, 0x02c68),
+    (0x02c6a, 0x02c6a), (0x02c6c, 0x02c6c), (0x02c71, 0x02c71), (0x02c73, 0x02c74),
+    (0x02c76, 0x02c7d), (0x02c81, 0x02c81), (0x02c83, 0x02c83), (0x02c85, 0x02c85),
+    (0x02c87, 0x02c87), (0x02c89, 0x02c89), (0x02c8b, 0x02c8b), (0x02c8d, 0x02c8d),
+    (0x02c8f, 0x02c8f), (0x02c91, 0x02c91), (0x02c93, 0x02c93), (0x02c95, 0x02c95),
+    (0x02c97, 0x02c97), (0x02c99, 0x02c99), (0x02c9b, 0x02c9b), (0x02c9d, 0x02c9d),
+    (0x02c9f, 0x02c9f), (0x02ca1, 0x02ca1), (0x02ca3, 0x02ca3), (0x02ca5, 0x02ca5),
+    (0x02ca7, 0x02ca7), (0x02ca9, 0x02ca9), (0x02cab, 0x02cab), (0x02cad, 0x02cad),
+    (0x02caf, 0x02caf), (0x02cb1, 0x02cb1), (0x02cb3, 0x02cb3), (0x02cb5, 0x02cb5),
+    (0x02cb7, 0x02cb7), (0x02cb9, 0x02cb9), (0x02cbb, 0x02cbb), (0x02cbd, 0x02cbd),
+    (0x02cbf, 0x02cbf), (0x02cc1, 0x02cc1), (0x02cc3, 0x02cc3), (0x02cc5, 0x02cc5),
+    (0x02cc7, 0x02cc7), (0x02cc9, 0x02cc9), (0x02ccb, 0x02ccb), (0x02ccd, 0x02ccd),
+    (0x02ccf, 0x02ccf), (0x02cd1, 0x02cd1), (0x02cd3, 0x02cd3), (0x02cd5, 0x02cd5),
+    (0x02cd7, 0x02cd7), (0x02cd9, 0x02cd9), (0x02cdb, 0x02cdb), (0x02cdd, 0x02cdd),
+    (0x02cdf, 0x02cdf), (0x02ce1, 0x02ce1), (0x02ce3, 0x02ce4), (0x02cec, 0x02cec),
+    (0x02cee, 0x02cee), (0x02cf3, 0x02cf3), (0x02d00, 0x02d25), (0x02d27, 0x02d27),
+    (0x02d2d, 0x02d2d), (0x0a641, 0x0a641), (0x0a643, 0x0a643), (0x0a645, 0x0a645),
+    (0x0a647, 0x0a647), (0x0a649, 0x0a649), (0x0a64b, 0x0a64b), (0x0a64d, 0x0a64d),
+    (0x0a64f, 0x0a64f), (0x0a651, 0x0a651), (0x0a653, 0x0a653), (0x0a655, 0x0a655),
+    (0x0a657, 0x0a657), (0x0a659, 0x0a659), (0x0a65b, 0x0a65b), (0x0a65d, 0x0a65d),
+    (0x0a65f, 0x0a65f), (0x0a661, 0x0a661), (0x0a663, 0x0a663), (0x0a665, 0x0a665),
+    (0x0a667, 0x0a667), (0x0a669, 0x0a669), (0x0a66b, 0x0a66b), (0x0a66d, 0x0a66d),
+    (0x0a681, 0x0a681), (0x0a683, 0x0a683), (0x0a685, 0x0a685), (0x0a687, 0x0a687),
+    (0x0a689, 0x0a689), (0x0a68b, 0x0a68b), (0x0a68d, 0x0a68d), (0x0a68f, 0x0a68f),
+    (0x0a691, 0x0a691), (0x0a693, 0x0a693), (0x0a695, 0x0a695), (0x0a697, 0x0a697),
+    (0x0a699, 0x0a699), (0x0a69b, 0x0a69d), (0x0a723, 0x0a723), (0x0a725, 0x0a725),
+    (0x0a727, 0x0a727), (0x0a729, 0x0a729), (0x0a72b, 0x0a72b), (0x0a72d, 0x0a72d),
+    (0x0a72f, 0x0a731), (0x0a733, 0x0a733), (0x0a735, 0x0a735), (0x0a737, 0x0a737),
+    (0x0a739, 0x0a739), (0x0a73b, 0x0a73b), (0x0a73d, 0x0a73d), (0x0a73f, 0x0a73f),
+    (0x0a741, 0x0a741), (0x0a743, 0x0a743), (0x0a745, 0x0a745), (0x0a747, 0x0a747),
+    (0x0a749, 0x0a749), (0x0a74b, 0x0a74b), (0x0a74d, 0x0a74d), (0x0a74f, 0x0a74f),
+    (0x0a751, 0x0a751), (0x0a753, 0x0a753), (0x0a755, 0x0a755), (0x0a757, 0x0a757),
+    (0x0a759, 0x0a759), (0x0a75b, 0x0a75b), (0x0a75d, 0x0a75d), (0x0a75f, 0x0a75f),
+    (0x0a761, 0x0a761), (0x0a763, 0x0a763), (0x0a765, 0x0a765), (0x0a767, 0x0a767),
+    (0x0a769, 0x0a769), (0x0a76b, 0x0a76b), (0x0a76d, 0x0a76d), (0x0a76f, 0x0a778),
+    (0x0a77a, 0x0a77a), (0x0a77c, 0x0a77c), (0x0a77f, 0x0a77f), (0x0a781, 0x0a781),
+    (0x0a783, 0x0a783), (0x0a785, 0x0a785), (0x0a787, 0x0a787), (0x0a78c, 0x0a78c),
+    (0x0a78e, 0x0a78e), (0x0a791, 0x0a791), (0x0a793, 0x0a795), (0x0a797, 0x0a797),
+    (0x0a799, 0x0a799), (0x0a79b, 0x0a79b), (0x0a79d, 0x0a79d), (0x0a79f, 0x0a79f),
+    (0x0a7a1, 0x0a7a1), (0x0a7a3, 0x0a7a3), (0x0a7a5, 0x0a7a5), (0x0a7a7, 0x0a7a7),
+    (0x0a7a9, 0x0a7a9), (0x0a7af, 0x0a7af), (0x0a7b5, 0x0a7b5), (0x0a7b7, 0x0a7b7),
+    (0x0a7b9, 0x0a7b9), (0x0a7bb, 0x0a7bb), (0x0a7bd, 0x0a7bd), (0x0a7bf, 0x0a7bf),
+    (0x0a7c1, 0x0a7c1), (0x0a7c3, 0x0a7c3), (0x0a7c8, 0x0a7c8), (0x0a7ca, 0x0a7ca),
+    (0x0a7cd, 0x0a7cd), (0x0a7cf, 0x0a7cf), (0x0a7d1, 0x0a7d1), (0x0a7d3, 0x0a7d3),
+    (0x0a7d5, 0x0a7d5), (0x0a7d7, 0x0a7d7), (0x0a7d9, 0x0a7d9), (0x0a7db, 0x0a7db),
+    (0x0a7f1, 0x0a7f4), (0x0a7f6, 0x0a7f6), (0x0a7f8, 0x0a7fa), (0x0ab30, 0x0ab5a),
+    (0x0ab5c, 0x0ab69), (0x0ab70, 0x0abbf), (0x0fb00, 0x0fb06), (0x0fb13, 0x0fb17),
+    (0x0ff41, 0x0ff5a), (0x10428, 0x1044f), (0x104d8, 0x104fb), (0x10597, 0x105a1),
+    (0x105a3, 0x105b1), (0x105b3, 0x105b9), (0x105bb, 0x105bc), (0x10780, 0x10780),
+    (0x10783, 0x10785), (0x10787, 0x107b0), (0x107b2, 0x107ba), (0x10cc0, 0x10cf2),
+    (0x10d70, 0x10d85), (0x118c0, 0x118df), (0x16e60, 0x16e7f), (0x16ebb, 0x16ed3),
+    (0x1d41a, 0x1d433), (0x1d44e, 0x1d454), (0x1d456, 0x1d467), (0x1d482, 0x1d49b),
+    (0x1d4b6, 0x1d4b9), (0x1d4bb, 0x1d4bb), (0x1d4bd, 0x1d4c3), (0x1d4c5, 0x1d4cf),
+    (0x1d4ea, 0x1d503), (0x1d51e, 0x1d537), (0x1d552, 0x1d56b), (0x1d586, 0x1d59f),
+    (0x1d5ba, 0x1d5d3), (0x1d5ee, 0x1d607), (0x1d622, 0x1d63b), (0x1d656, 0x1d66f),
+    (0x1d68a, 0x1d6a5), (0x1d6c2, 0x1d6da), (0x1d6dc, 0x1d6e1), (0x1d6fc, 0x1d714),
+    (0x1d716, 0x1d71b), (0x1d736, 0x1d74e), (0x1d750, 0x1d755), (0x1d770, 0x1d788),
+    (0x1d78a, 0x1d78f), (0x1d7aa, 0x1d7c2), (0x1d7c4, 0x1d7c9), (0x1d7cb, 0x1d7cb),
+    (0x1df00, 0x1df09), (0x1df0b, 0x1df1e), (0x1df25, 0x1df2a), (0x1e030, 0x1e06d),
+    (0x1e922, 0x1e943),
+];
+
+#[rustfmt::skip]
+pub const UPPERCASE: &[(u32, u32)] = &[
+    (0x00041, 0x0005a), (0x000c0, 0x000d6), (0x000d8, 0x000de), (0x00100, 0x00100),
+    (0x00102, 0x00102), (0x00104, 0x00104), (0x00106, 0x00106), (0x00108, 0x00108),
+    (0x0010a, 0x0010a), (0x0010c, 0x0010c), (0x0010e, 0x0010e), (0x00110, 0x00110),
+    (0x00112, 0x00112), (0x00114, 0x00114), (0x00116, 0x00116), (0x00118, 0x00118),
+    (0x0011a, 0x0011a), (0x0011c, 0x0011c), (0x0011e, 0x0011e), (0x00120, 0x00120),
+    (0x00122, 0x00122), (0x00124, 0x00124), (0x00126, 0x00126), (0x00128, 0x00128),
+    (0x0012a, 0x0012a), (0x0012c, 0x0012c), (0x0012e, 0x0012e), (0x00130, 0x00130),
+    (0x00132, 0x00132), (0x00134, 0x00134), (0x00136, 0x00136), (0x00139, 0x00139),
+    (0x0013b, 0x0013b), (0x0013d, 0x0013d), (0x0013f, 0x0013f), (0x00141, 0x00141),
+    (0x00143, 0x00143), (0x00145, 0x00145), (0x00147, 0x00147), (0x0014a, 0x0014a),
+    (0x0014c, 0x0014c), (0x0014e, 0x0014e), (0x00150, 0x00150), (0x00152, 0x00152),
+    (0x00154, 0x00154), (0x00156, 0x00156), (0x00158, 0x00158), (0x0015a, 0x0015a),
+    (0x0015c, 0x0015c), (0x0015e, 0x0015e), (0x00160, 0x00160), (0x00162, 0x00162),
+    (0x00164, 0x00164), (0x00166, 0x00166), (0x00168, 0x00168), (0x0016a, 0x0016a),
+    (0x0016c, 0x0016c), (0x0016e, 0x0016e), (0x00170, 0x00170), (0x00172, 0x00172),
+    (0x00174, 0x00174), (0x00176, 0x00176), (0x00178, 0x00179), (0x0017b, 0x0017b),
+    (0x0017d, 0x0017d), (0x00181, 0x00182), (0x00184, 0x00184), (0x00186, 0x00187),
+    (0x00189, 0x0018b), (0x0018e, 0x00191), (0x00193, 0x00194), (0x00196, 0x00198),
+    (0x0019c, 0x0019d), (0x0019f, 0x001a0), (0x001a2, 0x001a2), (0x001a4, 0x001a4),
+    (0x001a6, 0x001a7), (0x001a9, 0x001a9), (0x001ac, 0x001ac), (0x001ae, 0x001af),
+    (0x001b1, 0x001b3), (0x001b5, 0x001b5), (0x001b7, 0x001b8), (0x001bc, 0x001bc),
+    (0x001c4, 0x001c4), (0x001c7, 0x001c7), (0x001ca, 0x001ca), (0x001cd, 0x001cd),
+    (0x001cf, 0x001cf), (0x001d1, 0x001d1), (0x001d3, 0x001d3), (0x001d5, 0x001d5),
+    (0x001d7, 0x001d7), (0x001d9, 0x001d9), (0x001db, 0x001db), (0x001de, 0x001de),
+    (0x001e0, 0x001e0), (0x001e2, 0x001e2), (0x001e4, 0x001e4), (0x001e6, 0x001e6),
+    (0x001e8, 0x001e8), (0x001ea, 0x001ea), (0x001ec, 0x001ec), (0x001ee, 0x001ee),
+    (0x001f1, 0x001f1), (0x001f4, 0x001f4), (0x001f6, 0x001f8), (0x001fa, 0x001fa),
+    (0x001fc, 0x001fc), (0x001fe, 0x001fe), (0x00200, 0x00200), (0x00202, 0x00202),
+    (0x00204, 0x00204), (0x00206, 0x00206), (0x00208, 0x00208), (0x0020a, 0x0020a),
+    (0x0020c, 0x0020c), (0x0020e, 0x0020e), (0x00210, 0x00210), (0x00212, 0x00212),
+    (0x00214, 0x00214), (0x00216, 0x00216), (0x00218, 0x00218), (0x0021a, 0x0021a),
+    (0x0021c, 0x0021c), (0x0021e, 0x0021e), (0x00220, 0x00220), (0x00222, 0x00222),
+    (0x00224, 0x00224), (0x00226, 0x00226), (0x00228, 0x00228), (0x0022a, 0x0022a),
+    (0x0022c, 0x0022c), (0x0022e, 0x0022e), (0x00230, 0x00230), (0x00232, 0x00232),
+    (0x0023a, 0x0023b), (0x0023d, 0x0023e), (0x00241, 0x00241), (0x00243, 0x00246),
+    (0x00248, 0x00248), (0x0024a, 0x0024a), (0x0024c, 0x0024c), (0x0024e, 0x0024e),
+    (0x00370, 0x00370), (0x00372, 0x00372), (0x00376, 0x00376), (0x0037f, 0x0037f),
+    (0x00386, 0x00386), (0x00388, 0x0038a), (0x0038c, 0x0038c), (0x0038e, 0x0038f),
+    (0x00391, 0x003a1), (0x003a3, 0x003ab), (0x003cf, 0x003cf), (0x003d2, 0x003d4),
+    (0x003d8, 0x003d8), (0x003da, 0x003da), (0x003dc, 0x003dc), (0x003de, 0x003de),
+    (0x003e0, 0x003e0), (0x003e2, 0x003e2), (0x003e4, 0x003e4), (0x003e6, 0x003e6),
+    (0x003e8, 0x003e8), (0x003ea, 0x003ea), (0x003ec, 0x003ec), (0x003ee, 0x003ee),
+    (0x003f4, 0x003f4), (0x003f7, 0x003f7), (0x003f9, 0x003fa), (0x003fd, 0x0042f),
+    (0x00460, 0x00460), (0x00462, 0x00462), (0x00464, 0x00464), (0x00466, 0x00466),
+    (0x00468, 0x00468), (0x0046a, 0x0046a), (0x0046c, 0x0046c), (0x0046e, 0x0046e),
+    (0x00470, 0x00470), (0x00472, 0x00472), (0x00474, 0x00474), (0x00476, 0x00476),
+    (0x00478, 0x00478), (0x0047a, 0x0047a), (0x0047c, 0x0047c), (0x0047e, 0x0047e),
+    (0x00480, 0x00480), (0x0048a, 0x0048a), (0x0048c, 0x0048c), (0x0048e, 0x0048e),
+    (0x00490, 0x00490), (0x00492, 0x00492), (0x00494, 0x00494), (0x00496, 0x00496),
+    (0x00498, 0x00498), (0x0049a, 0x0049a), (0x0049c, 0x0049c), (0x0049e, 0x0049e),
+    (0x004a0, 0x004a0), (0x004a2, 0x004a2), (0x004a4, 0x004a4), (0x004a6, 0x004a6),
+    (0x004a8, 0x004a8), (0x004aa, 0x004aa), (0x004ac, 0x004ac), (0x004ae, 0x004ae),
+    (0x004b0, 0x004b0), (0x004b2, 0x004b2), (0x004b4, 0x004b4), (0x004b6, 0x004b6),
+    (0x004b8, 0x004b8), (0x004ba, 0x004ba), (0x004bc, 0x004bc), (0x004be, 0x004be),
+    (0x004c0, 0x004c1), (0x004c3, 0x004c3), (0x004c5, 0x004c5), (0x004c7, 0x004c7),
+    (0x004c9, 0x004c9), (0x004cb, 0x004cb), (0x004cd, 0x004cd), (0x004d0, 0x004d0),
+    (0x004d2, 0x004d2), (0x004d4, 0x004d4), (0x004d6, 0x004d6), (0x004d8, 0x004d8),
+    (0x004da, 0x004da), (0x004dc, 0x004dc), (0x004de, 0x004de), (0x004e0, 0x004e0),
+    (0x004e2, 0x004e2), (0x004e4, 0x004e4), (0x004e6, 0x004e6), (0x004e8, 0x004e8),
+    (0x004ea, 0x004ea), (0x004ec, 0x004ec), (0x004ee, 0x004ee), (0x004f0, 0x004f0),
+    (0x004f2, 0x004f2), (0x004f4, 0x004f4), (0x004f6, 0x004f6), (0x004f8, 0x004f8),
+    (0x004fa, 0x004fa), (0x004fc, 0x004fc), (0x004fe, 0x004fe), (0x00500, 0x00500),
+    (0x00502, 0x00502), (0x00504, 0x00504), (0x00506, 0x00506), (0x00508, 0x00508),
+    (0x0050a, 0x0050a), (0x0050c, 0x0050c), (0x0050e, 0x0050e), (0x00510, 0x00510),
+    (0x00512, 0x00512), (0x00514, 0x00514), (0x00516, 0x00516), (0x00518, 0x00518),
+    (0x0051a, 0x0051a), (0x0051c, 0x0051c), (0x0051e, 0x0051e), (0x00520, 0x00520),
+    (0x00522, 0x00522), (0x00524, 0x00524), (0x00526, 0x00526), (0x00528, 0x00528),
+    (0x0052a, 0x0052a), (0x0052c, 0x0052c), (0x0052e, 0x0052e), (0x00531, 0x00556),
+    (0x010a0, 0x010c5), (0x010c7, 0x010c7), (0x010cd, 0x010cd), (0x013a0, 0x013f5),
+    (0x01c89, 0x01c89), (0x01c90, 0x01cba), (0x01cbd, 0x01cbf), (0x01e00, 0x01e00),
+    (0x01e02, 0x01e02), (0x01e04, 0x01e04), (0x01e06, 0x01e06), (0x01e08, 0x01e08),
+    (0x01e0a, 0x01e0a), (0x01e0c, 0x01e0c), (0x01e0e, 0x01e0e), (0x01e10, 0x01e10),
+    (0x01e12, 0x01e12), (0x01e14, 0x01e14), (0x01e16, 0x01e16), (0x01e18, 0x01e18),
+    (0x01e1a, 0x01e1a), (0x01e1c, 0x01e1c), (0x01e1e, 0x01e1e), (0x01e20, 0x01e20),
+    (0x01e22, 0x01e22), (0x01e24, 0x01e24), (0x01e26, 0x01e26), (0x01e28, 0x01e28),
+    (0x01e2a, 0x01e2a), (0x01e2c, 0x01e2c), (0x01e2e, 0x01e2e), (0x01e30, 0x01e30),
+    (0x01e32, 0x01e32), (0x01e34, 0x01e34), (0x01e36, 0x01e36), (0x01e38, 0x01e38),
+    (0x01e3a, 0x01e3a), (0x01e3c, 0x01e3c), (0x01e3e, 0x01e3e), (0x01e40, 0x01e40),
+    (0x01e42, 0x01e42), (0x01e44, 0x01e44), (0x01e46, 0x01e46), (0x01e48, 0x01e48),
+    (0x01e4a, 0x01e4a), (0x01e4c, 0x01e4c), (0x01e4e, 0x01e4e), (0x01e50, 0x01e50),
+    (0x01e52, 0x01e52), (0x01e54, 0x01e54), (0x01e56, 0x01e56), (0x01e58, 0x01e58),
+    (0x01e5a, 0x01e5a), (0x01e5c, 0x01e5c), (0x01e5e, 0x01e5e), (0x01e60, 0x01e60),
+    (0x01e62, 0x01e62), (0x01e64, 0x01e64), (0x01e66, 0x01e66), (0x01e68, 0x01e68),
+    (0x01e6a, 0x01e6a), (0x01e6c, 0x01e6c), (0x01e6e, 0x01e6e), (0x01e70, 0x01e70),
+    (0x01e72, 0x01e72), (0x01e74, 0x01e74), (0x01e76, 0x01e76), (0x01e78, 0x01e78),
+    (0x01e7a, 0x01e7a), (0x01e7c, 0x01e7c), (0x01e7e, 0x01e7e), (0x01e80, 0x01e80),
+    (0x01e82, 0x01e82), (0x01e84, 0x01e84), (0x01e86, 0x01e86), (0x01e88, 0x01e88),
+    (0x01e8a, 0x01e8a), (0x01e8c, 0x01e8c), (0x01e8e, 0x01e8e), (0x01e90, 0x01e90),
+    (0x01e92, 0x01e92), (0x01e94, 0x01e94), (0x01e9e, 0x01e9e), (0x01ea0, 0x01ea0),
+    (0x01ea2, 0x01ea2), (0x01ea4, 0x01ea4), (0x01ea6, 0x01ea6), (0x01ea8, 0x01ea8),
+    (0x01eaa, 0x01eaa), (0x01eac, 0x01eac), (0x01eae, 0x01eae), (0x01eb0, 0x01eb0),
+    (0x01eb2, 0x01eb2), (0x01eb4, 0x01eb4), (0x01eb6, 0x01eb6), (0x01eb8, 0x01eb8),
+    (0x01eba, 0x01eba), (0x01ebc, 0x01ebc), (0x01ebe, 0x01ebe), (0x01ec0, 0x01ec0),
+    (0x01ec2, 0x01ec2), (0x01ec4, 0x01ec4), (0x01ec6, 0x01ec6), (0x01ec8, 0x01ec8),
+    (0x01eca, 0x01eca), (0x01ecc, 0x01ecc), (0x01ece, 0x01ece), (0x01ed0, 0x01ed0),
+    (0x01ed2, 0x01ed2), (0x01ed4, 0x01ed4), (0x01ed6, 0x01ed6), (0x01ed8, 0x01ed8),
+    (0x01eda, 0x01eda), (0x01edc, 0x01edc), (0x01ede, 0x01ede), (0x01ee0, 0x01ee0),
+    (0x01ee2, 0x01ee2), (0x01ee4, 0x01ee4), (0x01ee6, 0x01ee6), (0x01ee8, 0x01ee8),
+    (0x01eea, 0x01eea), (0x01eec, 0x01eec), (0x01eee, 0x01eee), (0x01ef0, 0x01ef0),
+    (0x01ef2, 0x01ef2), (0x01ef4, 0x01ef4), (0x01ef6, 0x01ef6), (0x01ef8, 0x01ef8),
+    (0x01efa, 0x01efa), (0x01efc, 0x01efc), (0x01efe, 0x01efe), (0x01f08, 0x01f0f),
+    (0x01f18, 0x01f1d), (0x01f28, 0x01f2f), (0x01f38, 0x01f3f), (0x01f48, 0x01f4d),
+    (0x01f59, 0x01f59), (0x01f5b, 0x01f5b), (0x01f5d, 0x01f5d), (0x01f5f, 0x01f5f),
+    (0x01f68, 0x01f6f), (0x01fb8, 0x01fbb), (0x01fc8, 0x01fcb), (0x01fd8, 0x01fdb),
+    (0x01fe8, 0x01fec), (0x01ff8, 0x01ffb), (0x02102, 0x02102), (0x02107, 0x02107),
+    (0x0210b, 0x0210d), (0x02110, 0x02112), (0x02115, 0x02115), (0x02119, 0x0211d),
+    (0x02124, 0x02124), (0x02126, 0x02126), (0x02128, 0x02128), (0x0212a, 0x0212d),
+    (0x02130, 0x02133), (0x0213e, 0x0213f), (0x02145, 0x02145), (0x02160, 0x0216f),
+    (0x02183, 0x02183), (0x024b6, 0x024cf), (0x02c00, 0x02c2f), (0x02c60, 0x02c60),
+    (0x02c62, 0x02c64), (0x02c67, 0x02c67), (0x02c69, 0x02c69), (0x02c6b, 0x02c6b),
+    (0x02c6d, 0x02c70), (0x02c72, 0x02c72), (0x02c75, 0x02c75), (0x02c7e, 0x02c80),
+    (0x02c82, 0x02c82), (0x02c84, 0x02c84), (0x02c86, 0x02c86), (0x02c88, 0x02c88),
+    (0x02c8a, 0x02c8a), (0x02c8c, 0x02c8c), (0x02c8e, 0x02c8e), (0x02c90, 0x02c90),
+    (0x02c92, 0x02c92), (0x02c94, 0x02c94), (0x02c96, 0x02c96), (0x02c98, 0x02c98),
+    (0x02c9a, 0x02c9a), (0x02c9c, 0x02c9c), (0x02c9e, 0x02c9e), (0x02ca0, 0x02ca0),
+    (0x02ca2, 0x02ca2), (0x02ca4, 0x02ca4), (0x02ca6, 0x02ca6), (0x02ca8, 0x02ca8),
+    (0x02caa, 0x02caa), (0x02cac, 0x02cac), (0x02cae, 0x02cae), (0x02cb0, 0x02cb0),
+    (0x02cb2, 0x02cb2), (0x02cb4, 0x02cb4), (0x02cb6, 0x02cb6), (0x02cb8, 0x02cb8),
+    (0x02cba, 0x02cba), (0x02cbc, 0x02cbc), (0x02cbe, 0x02cbe), (0x02cc0, 0x02cc0),
+    (0x02cc2, 0x02cc2), (0x02cc4, 0x02cc4), (0x02cc6, 0x02cc6), (0x02cc8, 0x02cc8),
+    (0x02cca, 0x02cca), (0x02ccc, 0x02ccc), (0x02cce, 0x02cce), (0x02cd0, 0x02cd0),
+    (0x02cd2, 0x02cd2), (0x02cd4, 0x02cd4), (0x02cd6, 0x02cd6), (0x02cd8, 0x02cd8),
+    (0x02cda, 0x02cda), (0x02cdc, 0x02cdc), (0x02cde, 0x02cde), (0x02ce0, 0x02ce0),
+    (0x02ce2, 0x02ce2), (0x02ceb, 0x02ceb), (0x02ced, 0x02ced), (0x02cf2, 0x02cf2),
+    (0x0a640, 0x0a640), (0x0a642, 0x0a642), (0x0a644, 0x0a644), (0x0a646, 0x0a646),
+    (0x0a648, 0x0a648), (0x0a64a, 0x0a64a), (0x0a64c, 0x0a64c), (0x0a64e, 0x0a64e),
+    (0x0a650, 0x0a650), (0x0a652, 0x0a652), (0x0a654, 0x0a654), (0x0a656, 0x0a656),
+    (0x0a658, 0x0a658), (0x0a65a, 0x0a65a), (0x0a65c, 0x0a65c), (0x0a65e, 0x0a65e),
+    (0x0a660, 0x0a660), (0x0a662, 0x0a662), (0x0a664, 0x0a664), (0x0a666, 0x0a666),
+    (0x0a668, 0x0a668), (0x0a66a, 0x0a66a), (0x0a66c, 0x0a66c), (0x0a680, 0x0a680),
+    (0x0a682, 0x0a682), (0x0a684, 0x0a684), (0x0a686, 0x0a686), (0x0a688, 0x0a688),
+    (0x0a68a, 0x0a68a), (0x0a68c, 0x0a68c), (0x0a68e, 0x0a68e), (0x0a690, 0x0a690),
+    (0x0a692, 0x0a692), (0x0a694, 0x0a694), (0x0a696, 0x0a696), (0x0a698, 0x0a698),
+    (0x0a69a, 0x0a69a), (0x0a722, 0x0a722), (0x0a724, 0x0a724), (0x0a726, 0x0a726),
+    (0x0a728, 0x0a728), (0x0a72a, 0x0a72a), (0x0a72c, 0x0a72c), (0x0a72e, 0x0a72e),
+    (0x0a732, 0x0a732), (0x0a734, 0x0a734), (0x0a736, 0x0a736), (0x0a738, 0x0a738),
+    (0x0a73a, 0x0a73a), (0x0a73c, 0x0a73c), (0x0a73e, 0x0a73e), (0x0a740, 0x0a740),
+    (0x0a742, 0x0a742), (0x0a744, 0x0a744), (0x0a746, 0x0a746), (0x0a748, 0x0a748),
+    (0x0a74a, 0x0a74a), (0x0a74c, 0x0a74c), (0x0a74e, 0x0a74e), (0x0a750, 0x0a750),
+    (0x0a752, 0x0a752), (0x0a754, 0x0a754), (0x0a756, 0x0a756), (0x0a758, 0x0a758),
+    (0x0a75a, 0x0a75a), (0x0a75c, 0x0a75c), (0x0a75e, 0x0a75e), (0x0a760, 0x0a760),
+    (0x0a762, 0x0a762), (0x0a764, 0x0a764), (0x0a766, 0x0a766), (0x0a768, 0x0a768),
+    (0x0a76a, 0x0a76a), (0x0a76c, 0x0a76c), (0x0a76e, 0x0a76e), (0x0a779, 0x0a779),
+    (0x0a77b, 0x0a77b), (0x0a77d, 0x0a77e), (0x0a780, 0x0a780), (0x0a782, 0x0a782),
+    (0x0a784, 0x0a784), (0x0a786, 0x0a786), (0x0a78b, 0x0a78b), (0x0a78d, 0x0a78d),
+    (0x0a790, 0x0a790), (0x0a792, 0x0a792), (0x0a796, 0x0a796), (0x0a798, 0x0a798),
+    (0x0a79a, 0x0a79a), (0x0a79c, 0x0a79c), (0x0a79e, 0x0a79e), (0x0a7a0, 0x0a7a0),
+    (0x0a7a2, 0x0a7a2), (0x0a7a4, 0x0a7a4), (0x0a7a6, 0x0a7a6), (0x0a7a8, 0x0a7a8),
+    (0x0a7aa, 0x0a7ae), (0x0a7b0, 0x0a7b4), (0x0a7b6, 0x0a7b6), (0x0a7b8, 0x0a7b8),
+    (0x0a7ba, 0x0a7ba), (0x0a7bc, 0x0a7bc), (0x0a7be, 0x0a7be), (0x0a7c0, 0x0a7c0),
+    (0x0a7c2, 0x0a7c2), (0x0a7c4, 0x0a7c7), (0x0a7c9, 0x0a7c9), (0x0a7cb, 0x0a7cc),
+    (0x0a7ce, 0x0a7ce), (0x0a7d0, 0x0a7d0), (0x0a7d2, 0x0a7d2), (0x0a7d4, 0x0a7d4),
+    (0x0a7d6, 0x0a7d6), (0x0a7d8, 0x0a7d8), (0x0a7da, 0x0a7da), (0x0a7dc, 0x0a7dc),
+    (0x0a7f5, 0x0a7f5), (0x0ff21, 0x0ff3a), (0x10400, 0x10427), (0x104b0, 0x104d3),
+    (0x10570, 0x1057a), (0x1057c, 0x1058a), (0x1058c, 0x10592), (0x10594, 0x10595),
+    (0x10c80, 0x10cb2), (0x10d50, 0x10d65), (0x118a0, 0x118bf), (0x16e40, 0x16e5f),
+    (0x16ea0, 0x16eb8), (0x1d400, 0x1d419), (0x1d434, 0x1d44d), (0x1d468, 0x1d481),
+    (0x1d49c, 0x1d49c), (0x1d49e, 0x1d49f), (0x1d4a2, 0x1d4a2), (0x1d4a5, 0x1d4a6),
+    (0x1d4a9, 0x1d4ac), (0x1d4ae, 0x1d4b5), (0x1d4d0, 0x1d4e9), (0x1d504, 0x1d505),
+    (0x1d507, 0x1d50a), (0x1d50d, 0x1d514), (0x1d516, 0x1d51c), (0x1d538, 0x1d539),
+    (0x1d53b, 0x1d53e), (0x1d540, 0x1d544), (0x1d546, 0x1d546), (0x1d54a, 0x1d550),
+    (0x1d56c, 0x1d585), (0x1d5a0, 0x1d5b9), (0x1d5d4, 0x1d5ed), (0x1d608, 0x1d621),
+    (0x1d63c, 0x1d655), (0x1d670, 0x1d689), (0x1d6a8, 0x1d6c0), (0x1d6e2, 0x1d6fa),
+    (0x1d71c, 0x1d734), (0x1d756, 0x1d76e), (0x1d790, 0x1d7a8), (0x1d7ca, 0x1d7ca),
+    (0x1e900, 0x1e921), (0x1f130, 0x1f149), (0x1f150, 0x1f169), (0x1f170, 0x1f189),
+];
+
+#[rustfmt::skip]
+pub const TITLECASE: &[(u32, u32)] = &[
+    (0x001c5, 0x001c5), (0x001c8, 0x001c8), (0x001cb, 0x001cb), (0x001f2, 0x001f2),
+    (0x01f88, 0x01f8f), (0x01f98, 0x01f9f), (0x01fa8, 0x01faf), (0x01fbc, 0x01fbc),
+    (0x01fcc, 0x01fcc), (0x01ffc, 0x01ffc),
+];
+
+#[rustfmt::skip]
+pub const CASE_IGNORABLE: &[(u32, u32)] = &[
+    (0x00027, 0x00027), (0x0002e, 0x0002e), (0x0003a, 0x0003a), (0x0005e, 0x0005e),
+    (0x00060, 0x00060), (0x000a8, 0x000a8), (0x000ad, 0x000ad), (0x000af, 0x000af),
+    (0x000b4, 0x000b4), (0x000b7, 0x000b8), (0x002b0, 0x0036f), (0x00374, 0x00375),
+    (0x0037a, 0x0037a), (0x00384, 0x00385), (0x00387, 0x00387), (0x00483, 0x00489),
+    (0x00559, 0x00559), (0x0055f, 0x0055f), (0x00591, 0x005bd), (0x005bf, 0x005bf),
+    (0x005c1, 0x005c2), (0x005c4, 0x005c5), (0x005c7, 0x005c7), (0x005f4, 0x005f4),
+    (0x00600, 0x00605), (0x00610, 0x0061a), (0x0061c, 0x0061c), (0x00640, 0x00640),
+    (0x0064b, 0x0065f), (0x00670, 0x00670), (0x006d6, 0x006dd), (0x006df, 0x006e8),
+    (0x006ea, 0x006ed), (0x0070f, 0x0070f), (0x00711, 0x00711), (0x00730, 0x0074a),
+    (0x007a6, 0x007b0), (0x007eb, 0x007f5), (0x007fa, 0x007fa), (0x007fd, 0x007fd),
+    (0x00816, 0x0082d), (0x00859, 0x0085b), (0x00888, 0x00888), (0x00890, 0x00891),
+    (0x00897, 0x0089f), (0x008c9, 0x00902), (0x0093a, 0x0093a), (0x0093c, 0x0093c),
+    (0x00941, 0x00948), (0x0094d, 0x0094d), (0x00951, 0x00957), (0x00962, 0x00963),
+    (0x00971, 0x00971), (0x00981, 0x00981), (0x009bc, 0x009bc), (0x009c1, 0x009c4),
+    (0x009cd, 0x009cd), (0x009e2, 0x009e3), (0x009fe, 0x009fe), (0x00a01, 0x00a02),
+    (0x00a3c, 0x00a3c), (0x00a41, 0x00a42), (0x00a47, 0x00a48), (0x00a4b, 0x00a4d),
+    (0x00a51, 0x00a51), (0x00a70, 0x00a71), (0x00a75, 0x00a75), (0x00a81, 0x00a82),
+    (0x00abc, 0x00abc), (0x00ac1, 0x00ac5), (0x00ac7, 0x00ac8), (0x00acd, 0x00acd),
+    (0x00ae2, 0x00ae3), (0x00afa, 0x00aff), (0x00b01, 0x00b01), (0x00b3c, 0x00b3c),
+    (0x00b3f, 0x00b3f), (0x00b41, 0x00b44), (0x00b4d, 0x00b4d), (0x00b55, 0x00b56),
+    (0x00b62, 0x00b63), (0x00b82, 0x00b82), (0x00bc0, 0x00bc0), (0x00bcd, 0x00bcd),
+    (0x00c00, 0x00c00), (0x00c04, 0x00c04), (0x00c3c, 0x00c3c), (0x00c3e, 0x00c40),
+    (0x00c46, 0x00c48), (0x00c4a, 0x00c4d), (0x00c55, 0x00c56), (0x00c62, 0x00c63),
+    (0x00c81, 0x00c81), (0x00cbc, 0x00cbc), (0x00cbf, 0x00cbf), (0x00cc6, 0x00cc6),
+    (0x00ccc, 0x00ccd), (0x00ce2, 0x00ce3), (0x00d00, 0x00d01), (0x00d3b, 0x00d3c),
+    (0x00d41, 0x00d44), (0x00d4d, 0x00d4d), (0x00d62, 0x00d63), (0x00d81, 0x00d81),
+    (0x00dca, 0x00dca), (0x00dd2, 0x00dd4), (0x00dd6, 0x00dd6), (0x00e31, 0x00e31),
+    (0x00e34, 0x00e3a), (0x00e46, 0x00e4e), (0x00eb1, 0x00eb1), (0x00eb4, 0x00ebc),
+    (0x00ec6, 0x00ec6), (0x00ec8, 0x00ece), (0x00f18, 0x00f19), (0x00f35, 0x00f35),
+    (0x00f37, 0x00f37), (0x00f39, 0x00f39), (0x00f71, 0x00f7e), (0x00f80, 0x00f84),
+    (0x00f86, 0x00f87), (0x00f8d, 0x00f97), (0x00f99, 0x00fbc), (0x00fc6, 0x00fc6),
+    (0x0102d, 0x01030), (0x01032, 0x01037), (0x01039, 0x0103a), (0x0103d, 0x0103e),
+    (0x01058, 0x01059), (0x0105e, 0x01060), (0x01071, 0x01074), (0x01082, 0x01082),
+    (0x01085, 0x01086), (0x0108d, 0x0108d), (0x0109d, 0x0109d), (0x010fc, 0x010fc),
+    (0x0135d, 0x0135f), (0x01712, 0x01714), (0x01732, 0x01733), (0x01752, 0x01753),
+    (0x01772, 0x01773), (0x017b4, 0x017b5), (0x017b7, 0x017bd), (0x017c6, 0x017c6),
+    (0x017c9, 0x017d3), (0x017d7, 0x017d7), (0x017dd, 0x017dd), (0x0180b, 0x0180f),
+    (0x01843, 0x01843), (0x01885, 0x01886), (0x018a9, 0x018a9), (0x01920, 0x01922),
+    (0x01927, 0x01928), (0x01932, 0x01932), (0x01939, 0x0193b), (0x01a17, 0x01a18),
+    (0x01a1b, 0x01a1b), (0x01a56, 0x01a56), (0x01a58, 0x01a5e), (0x01a60, 0x01a60),
+    (0x01a62, 0x01a62), (0x01a65, 0x01a6c), (0x01a73, 0x01a7c), (0x01a7f, 0x01a7f),
+    (0x01aa7, 0x01aa7), (0x01ab0, 0x01add), (0x01ae0, 0x01aeb), (0x01b00, 0x01b03),
+    (0x01b34, 0x01b34), (0x01b36, 0x01b3a), (0x01b3c, 0x01b3c), (0x01b42, 0x01b42),
+    (0x01b6b, 0x01b73), (0x01b80, 0x01b81), (0x01ba2, 0x01ba5), (0x01ba8, 0x01ba9),
+    (0x01bab, 0x01bad), (0x01be6, 0x01be6), (0x01be8, 0x01be9), (0x01bed, 0x01bed),
+    (0x01bef, 0x01bf1), (0x01c2c, 0x01c33), (0x01c36, 0x01c37), (0x01c78, 0x01c7d),
+    (0x01cd0, 0x01cd2), (0x01cd4, 0x01ce0), (0x01ce2, 0x01ce8), (0x01ced, 0x01ced),
+    (0x01cf4, 0x01cf4), (0x01cf8, 0x01cf9), (0x01d2c, 0x01d6a), (0x01d78, 0x01d78),
+    (0x01d9b, 0x01dff), (0x01fbd, 0x01fbd), (0x01fbf, 0x01fc1), (0x01fcd, 0x01fcf),
+    (0x01fdd, 0x01fdf), (0x01fed, 0x01fef), (0x01ffd, 0x01ffe), (0x0200b, 0x0200f),
+    (0x02018, 0x02019), (0x02024, 0x02024), (0x02027, 0x02027), (0x0202a, 0x0202e),
+    (0x02060, 0x02064), (0x02066, 0x0206f), (0x02071, 0x02071), (0x0207f, 0x0207f),
+    (0x02090, 0x0209c), (0x020d0, 0x020f0), (0x02c7c, 0x02c7d), (0x02cef, 0x02cf1),
+    (0x02d6f, 0x02d6f), (0x02d7f, 0x02d7f), (0x02de0, 0x02dff), (0x02e2f, 0x02e2f),
+    (0x03005, 0x03005), (0x0302a, 0x0302d), (0x03031, 0x03035), (0x0303b, 0x0303b),
+    (0x03099, 0x0309e), (0x030fc, 0x030fe), (0x0a015, 0x0a015), (0x0a4f8, 0x0a4fd),
+    (0x0a60c, 0x0a60c), (0x0a66f, 0x0a672), (0x0a674, 0x0a67d), (0x0a67f, 0x0a67f),
+    (0x0a69c, 0x0a69f), (0x0a6f0, 0x0a6f1), (0x0a700, 0x0a721), (0x0a770, 0x0a770),
+    (0x0a788, 0x0a78a), (0x0a7f1, 0x0a7f4), (0x0a7f8, 0x0a7f9), (0x0a802, 0x0a802),
+    (0x0a806, 0x0a806), (0x0a80b, 0x0a80b), (0x0a825, 0x0a826), (0x0a82c, 0x0a82c),
+    (0x0a8c4, 0x0a8c5), (0x0a8e0, 0x0a8f1), (0x0a8ff, 0x0a8ff), (0x0a926, 0x0a92d),
+    (0x0a947, 0x0a951), (0x0a980, 0x0a982), (0x0a9b3, 0x0a9b3), (0x0a9b6, 0x0a9b9),
+    (0x0a9bc, 0x0a9bd), (0x0a9cf, 0x0a9cf), (0x0a9e5, 0x0a9e6), (0x0aa29, 0x0aa2e),
+    (0x0aa31, 0x0aa32), (0x0aa35, 0x0aa36), (0x0aa43, 0x0aa43), (0x0aa4c, 0x0aa4c),
+    (0x0aa70, 0x0aa70), (0x0aa7c, 0x0aa7c), (0x0aab0, 0x0aab0), (0x0aab2, 0x0aab4),
+    (0x0aab7, 0x0aab8), (0x0aabe, 0x0aabf), (0x0aac1, 0x0aac1), (0x0aadd, 0x0aadd),
+    (0x0aaec, 0x0aaed), (0x0aaf3, 0x0aaf4), (0x0aaf6, 0x0aaf6), (0x0ab5b, 0x0ab5f),
+    (0x0ab69, 0x0ab6b), (0x0abe5, 0x0abe5), (0x0abe8, 0x0abe8), (0x0abed, 0x0abed),
+    (0x0fb1e, 0x0fb1e), (0x0fbb2, 0x0fbc2), (0x0fe00, 0x0fe0f), (0x0fe13, 0x0fe13),
+    (0x0fe20, 0x0fe2f), (0x0fe52, 0x0fe52), (0x0fe55, 0x0fe55), (0x0feff, 0x0feff),
+    (0x0ff07, 0x0ff07), (0x0ff0e, 0x0ff0e), (0x0ff1a, 0x0ff1a), (0x0ff3e, 0x0ff3e),
+    (0x0ff40, 0x0ff40), (0x0ff70, 0x0ff70), (0x0ff9e, 0x0ff9f), (0x0ffe3, 0x0ffe3),
+    (0x0fff9, 0x0fffb), (0x101fd, 0x101fd), (0x102e0, 0x102e0), (0x10376, 0x1037a),
+    (0x10780, 0x10785), (0x10787, 0x107b0), (0x107b2, 0x107ba), (0x10a01, 0x10a03),
+    (0x10a05, 0x10a06), (0x10a0c, 0x10a0f), (0x10a38, 0x10a3a), (0x10a3f, 0x10a3f),
+    (0x10ae5, 0x10ae6), (0x10d24, 0x10d27), (0x10d4e, 0x10d4e), (0x10d69, 0x10d6d),
+    (0x10d6f, 0x10d6f), (0x10eab, 0x10eac), (0x10ec5, 0x10ec5), (0x10efa, 0x10eff),
+    (0x10f46, 0x10f50), (0x10f82, 0x10f85), (0x11001, 0x11001), (0x11038, 0x11046),
+    (0x11070, 0x11070), (0x11073, 0x11074), (0x1107f, 0x11081), (0x110b3, 0x110b6),
+    (0x110b9, 0x110ba), (0x110bd, 0x110bd), (0x110c2, 0x110c2), (0x110cd, 0x110cd),
+    (0x11100, 0x11102), (0x11127, 0x1112b), (0x1112d, 0x11134), (0x11173, 0x11173),
+    (0x11180, 0x11181), (0x111b6, 0x111be), (0x111c9, 0x111cc), (0x111cf, 0x111cf),
+    (0x1122f, 0x11231), (0x11234, 0x11234), (0x11236, 0x11237), (0x1123e, 0x1123e),
+    (0x11241, 0x11241), (0x112df, 0x112df), (0x112e3, 0x112ea), (0x11300, 0x11301),
+    (0x1133b, 0x1133c), (0x11340, 0x11340), (0x11366, 0x1136c), (0x11370, 0x11374),
+    (0x113bb, 0x113c0), (0x113ce, 0x113ce), (0x113d0, 0x113d0), (0x113d2, 0x113d2),
+    (0x113e1, 0x113e2), (0x11438, 0x1143f), (0x11442, 0x11444), (0x11446, 0x11446),
+    (0x1145e, 0x1145e), (0x114b3, 0x114b8), (0x114ba, 0x114ba), (0x114bf, 0x114c0),
+    (0x114c2, 0x114c3), (0x115b2, 0x115b5), (0x115bc, 0x115bd), (0x115bf, 0x115c0),
+    (0x115dc, 0x115dd), (0x11633, 0x1163a), (0x1163d, 0x1163d), (0x1163f, 0x11640),
+    (0x116ab, 0x116ab), (0x116ad, 0x116ad), (0x116b0, 0x116b5), (0x116b7, 0x116b7),
+    (0x1171d, 0x1171d), (0x1171f, 0x1171f), (0x11722, 0x11725), (0x11727, 0x1172b),
+    (0x1182f, 0x11837), (0x11839, 0x1183a), (0x1193b, 0x1193c), (0x1193e, 0x1193e),
+    (0x11943, 0x11943), (0x119d4, 0x119d7), (0x119da, 0x119db), (0x119e0, 0x119e0),
+    (0x11a01, 0x11a0a), (0x11a33, 0x11a38), (0x11a3b, 0x11a3e), (0x11a47, 0x11a47),
+    (0x11a51, 0x11a56), (0x11a59, 0x11a5b), (0x11a8a, 0x11a96), (0x11a98, 0x11a99),
+    (0x11b60, 0x11b60), (0x11b62, 0x11b64), (0x11b66, 0x11b66), (0x11c30, 0x11c36),
+    (0x11c38, 0x11c3d), (0x11c3f, 0x11c3f), (0x11c92, 0x11ca7), (0x11caa, 0x11cb0),
+    (0x11cb2, 0x11cb3), (0x11cb5, 0x11cb6), (0x11d31, 0x11d36), (0x11d3a, 0x11d3a),
+    (0x11d3c, 0x11d3d), (0x11d3f, 0x11d45), (0x11d47, 0x11d47), (0x11d90, 0x11d91),
+    (0x11d95, 0x11d95), (0x11d97, 0x11d97), (0x11dd9, 0x11dd9), (0x11ef3, 0x11ef4),
+    (0x11f00, 0x11f01), (0x11f36, 0x11f3a), (0x11f40, 0x11f40), (0x11f42, 0x11f42),
+    (0x11f5a, 0x11f5a), (0x13430, 0x13440), (0x13447, 0x13455), (0x1611e, 0x16129),
+    (0x1612d, 0x1612f), (0x16af0, 0x16af4), (0x16b30, 0x16b36), (0x16b40, 0x16b43),
+    (0x16d40, 0x16d42), (0x16d6b, 0x16d6c), (0x16f4f, 0x16f4f), (0x16f8f, 0x16f9f),
+    (0x16fe0, 0x16fe1), (0x16fe3, 0x16fe4), (0x16ff2, 0x16ff3), (0x1aff0, 0x1aff3),
+    (0x1aff5, 0x1affb), (0x1affd, 0x1affe), (0x1bc9d, 0x1bc9e), (0x1bca0, 0x1bca3),
+    (0x1cf00, 0x1cf2d), (0x1cf30, 0x1cf46), (0x1d167, 0x1d169), (0x1d173, 0x1d182),
+    (0x1d185, 0x1d18b), (0x1d1aa, 0x1d1ad), (0x1d242, 0x1d244), (0x1da00, 0x1da36),
+    (0x1da3b, 0x1da6c), (0x1da75, 0x1da75), (0x1da84, 0x1da84), (0x1da9b, 0x1da9f),
+    (0x1daa1, 0x1daaf), (0x1e000, 0x1e006), (0x1e008, 0x1e018), (0x1e01b, 0x1e021),
+    (0x1e023, 0x1e024), (0x1e026, 0x1e02a), (0x1e030, 0x1e06d), (0x1e08f, 0x1e08f),
+    (0x1e130, 0x1e13d), (0x1e2ae, 0x1e2ae), (0x1e2ec, 0x1e2ef), (0x1e4eb, 0x1e4ef),
+    (0x1e5ee, 0x1e5ef), (0x1e6e3, 0x1e6e3), (0x1e6e6, 0x1e6e6), (0x1e6ee, 0x1e6ef),
+    (0x1e6f5, 0x1e6f5), (0x1e6ff, 0x1e6ff), (0x1e8d0, 0x1e8d6), (0x1e944, 0x1e94b),
+    (0x1f3fb, 0x1f3ff), (0xe0001, 0xe0001), (0xe0020, 0xe007f), (0xe0100, 0xe01ef),
+];
+
+#[rustfmt::skip]
+pub const WORD: &[(u32, u32)] = &[
+    (0x00030, 0x00039), (0x00041, 0x0005a), (0x00061, 0x0007a), (0x000aa, 0x000aa),
+    (0x000b2, 0x000b3), (0x000b5, 0x000b5), (0x000b9, 0x000ba), (0x000bc, 0x000be),
+    (0x000c0, 0x000d6), (0x000d8, 0x000f6), (0x000f8, 0x002c1), (0x002c6, 0x002d1),
+    (0x002e0, 0x002e4), (0x002ec, 0x002ec), (0x002ee, 0x002ee), (0x00300, 0x00374),
+    (0x00376, 0x00377), (0x0037a, 0x0037d), (0x0037f, 0x0037f), (0x00386, 0x00386),
+    (0x00388, 0x0038a), (0x0038c, 0x0038c), (0x0038e, 0x003a1), (0x003a3, 0x003f5),
+    (0x003f7, 0x00481), (0x00483, 0x0052f), (0x00531, 0x00556), (0x00559, 0x00559),
+    (0x00560, 0x00588), (0x00591, 0x005bd), (0x005bf, 0x005bf), (0x005c1, 0x005c2),
+    (0x005c4, 0x005c5), (0x005c7, 0x005c7), (0x005d0, 0x005ea), (0x005ef, 0x005f2),
+    (0x00610, 0x0061a), (0x00620, 0x00669), (0x0066e, 0x006d3), (0x006d5, 0x006dc),
+    (0x006df, 0x006e8), (0x006ea, 0x006fc), (0x006ff, 0x006ff), (0x00710, 0x0074a),
+    (0x0074d, 0x007b1), (0x007c0, 0x007f5), (0x007fa, 0x007fa), (0x007fd, 0x007fd),
+    (0x00800, 0x0082d), (0x00840, 0x0085b), (0x00860, 0x0086a), (0x00870, 0x00887),
+    (0x00889, 0x0088f), (0x00897, 0x008e1), (0x008e3, 0x00963), (0x00966, 0x0096f),
+    (0x00971, 0x00983), (0x00985, 0x0098c), (0x0098f, 0x00990), (0x00993, 0x009a8),
+    (0x009aa, 0x009b0), (0x009b2, 0x009b2), (0x009b6, 0x009b9), (0x009bc, 0x009c4),
+    (0x009c7, 0x009c8), (0x009cb, 0x009ce), (0x009d7, 0x009d7), (0x009dc, 0x009dd),
+    (0x009df, 0x009e3), (0x009e6, 0x009f1), (0x009f4, 0x009f9), (0x009fc, 0x009fc),
+    (0x009fe, 0x009fe), (0x00a01, 0x00a03), (0x00a05, 0x00a0a), (0x00a0f, 0x00a10),
+    (0x00a13, 0x00a28), (0x00a2a, 0x00a30), (0x00a32, 0x00a33), (0x00a35, 0x00a36),
+    (0x00a38, 0x00a39), (0x00a3c, 0x00a3c), (0x00a3e, 0x00a42), (0x00a47, 0x00a48),
+    (0x00a4b, 0x00a4d), (0x00a51, 0x00a51), (0x00a59, 0x00a5c), (0x00a5e, 0x00a5e),
+    (0x00a66, 0x00a75), (0x00a81, 0x00a83), (0x00a85, 0x00a8d), (0x00a8f, 0x00a91),
+    (0x00a93, 0x00aa8), (0x00aaa, 0x00ab0), (0x00ab2, 0x00ab3), (0x00ab5, 0x00ab9),
+    (0x00abc, 0x00ac5), (0x00ac7, 0x00ac9), (0x00acb, 0x00acd), (0x00ad0, 0x00ad0),
+    (0x00ae0, 0x00ae3), (0x00ae6, 0x00aef), (0x00af9, 0x00aff), (0x00b01, 0x00b03),
+    (0x00b05, 0x00b0c), (0x00b0f, 0x00b10), (0x00b13, 0x00b28), (0x00b2a, 0x00b30),
+    (0x00b32, 0x00b33), (0x00b35, 0x00b39), (0x00b3c, 0x00b44), (0x00b47, 0x00b48),
+    (0x00b4b, 0x00b4d), (0x00b55, 0x00b57), (0x00b5c, 0x00b5d), (0x00b5f, 0x00b63),
+    (0x00b66, 0x00b6f), (0x00b71, 0x00b77), (0x00b82, 0x00b83), (0x00b85, 0x00b8a),
+    (0x00b8e, 0x00b90), (0x00b92, 0x00b95), (0x00b99, 0x00b9a), (0x00b9c, 0x00b9c),
+    (0x00b9e, 0x00b9f), (0x00ba3, 0x00ba4), (0x00ba8, 0x00baa), (0x00bae, 0x00bb9),
+    (0x00bbe, 0x00bc2), (0x00bc6, 0x00bc8), (0x00bca, 0x00bcd), (0x00bd0, 0x00bd0),
+    (0x00bd7, 0x00bd7), (0x00be6, 0x00bf2), (0x00c00, 0x00c0c), (0x00c0e, 0x00c10),
+    (0x00c12, 0x00c28), (0x00c2a, 0x00c39), (0x00c3c, 0x00c44), (0x00c46, 0x00c48),
+    (0x00c4a, 0x00c4d), (0x00c55, 0x00c56), (0x00c58, 0x00c5a), (0x00c5c, 0x00c5d),
+    (0x00c60, 0x00c63), (0x00c66, 0x00c6f), (0x00c78, 0x00c7e), (0x00c80, 0x00c83),
+    (0x00c85, 0x00c8c), (0x00c8e, 0x00c90), (0x00c92, 0x00ca8), (0x00caa, 0x00cb3),
+    (0x00cb5, 0x00cb9), (0x00cbc, 0x00cc4), (0x00cc6, 0x00cc8), (0x00cca, 0x00ccd),
+    (0x00cd5, 0x00cd6), (0x00cdc, 0x00cde), (0x00ce0, 0x00ce3), (0x00ce6, 0x00cef),
+    (0x00cf1, 0x00cf3), (0x00d00, 0x00d0c), (0x00d0e, 0x00d10), (0x00d12, 0x00d44),
+    (0x00d46, 0x00d48), (0x00d4a, 0x00d4e), (0x00d54, 0x00d63), (0x00d66, 0x00d78),
+    (0x00d7a, 0x00d7f), (0x00d81, 0x00d83), (0x00d85, 0x00d96), (0x00d9a, 0x00db1),
+    (0x00db3, 0x00dbb), (0x00dbd, 0x00dbd), (0x00dc0, 0x00dc6), (0x00dca, 0x00dca),
+    (0x00dcf, 0x00dd4), (0x00dd6, 0x00dd6), (0x00dd8, 0x00ddf), (0x00de6, 0x00def),
+    (0x00df2, 0x00df3), (0x00e01, 0x00e3a), (0x00e40, 0x00e4e), (0x00e50, 0x00e59),
+    (0x00e81, 0x00e82), (0x00e84, 0x00e84), (0x00e86, 0x00e8a), (0x00e8c, 0x00ea3),
+    (0x00ea5, 0x00ea5), (0x00ea7, 0x00ebd), (0x00ec0, 0x00ec4), (0x00ec6, 0x00ec6),
+    (0x00ec8, 0x00ece), (0x00ed0, 0x00ed9), (0x00edc, 0x00edf), (0x00f00, 0x00f00),
+    (0x00f18, 0x00f19), (0x00f20, 0x00f33), (0x00f35, 0x00f35), (0x00f37, 0x00f37),
+    (0x00f39, 0x00f39), (0x00f3e, 0x00f47), (0x00f49, 0x00f6c), (0x00f71, 0x00f84),
+    (0x00f86, 0x00f97), (0x00f99, 0x00fbc), (0x00fc6, 0x00fc6), (0x01000, 0x01049),
+    (0x01050, 0x0109d), (0x010a0, 0x010c5), (0x010c7, 0x010c7), (0x010cd, 0x010cd),
+    (0x010d0, 0x010fa), (0x010fc, 0x01248), (0x0124a, 0x0124d), (0x01250, 0x01256),
+    (0x01258, 0x01258), (0x0125a, 0x0125d), (0x01260, 0x01288), (0x0128a, 0x0128d),
+    (0x01290, 0x012b0), (0x012b2, 0x012b5), (0x012b8, 0x012be), (0x012c0, 0x012c0),
+    (0x012c2, 0x012c5), (0x012c8, 0x012d6), (0x012d8, 0x01310), (0x01312, 0x01315),
+    (0x01318, 0x0135a), (0x0135d, 0x0135f), (0x01369, 0x0137c), (0x01380, 0x0138f),
+    (0x013a0, 0x013f5), (0x013f8, 0x013fd), (0x01401, 0x0166c), (0x0166f, 0x0167f),
+    (0x01681, 0x0169a), (0x016a0, 0x016ea), (0x016ee, 0x016f8), (0x01700, 0x01715),
+    (0x0171f, 0x01734), (0x01740, 0x01753), (0x01760, 0x0176c), (0x0176e, 0x01770),
+    (0x01772, 0x01773), (0x01780, 0x017d3), (0x017d7, 0x017d7), (0x017dc, 0x017dd),
+    (0x017e0, 0x017e9), (0x017f0, 0x017f9), (0x0180b, 0x0180d), (0x0180f, 0x01819),
+    (0x01820, 0x01878), (0x01880, 0x018aa), (0x018b0, 0x018f5), (0x01900, 0x0191e),
+    (0x01920, 0x0192b), (0x01930, 0x0193b), (0x01946, 0x0196d), (0x01970, 0x01974),
+    (0x01980, 0x019ab), (0x019b0, 0x019c9), (0x019d0, 0x019da), (0x01a00, 0x01a1b),
+    (0x01a20, 0x01a5e), (0x01a60, 0x01a7c), (0x01a7f, 0x01a89), (0x01a90, 0x01a99),
+    (0x01aa7, 0x01aa7), (0x01ab0, 0x01add), (0x01ae0, 0x01aeb), (0x01b00, 0x01b4c),
+    (0x01b50, 0x01b59), (0x01b6b, 0x01b73), (0x01b80, 0x01bf3), (0x01c00, 0x01c37),
+    (0x01c40, 0x01c49), (0x01c4d, 0x01c7d), (0x01c80, 0x01c8a), (0x01c90, 0x01cba),
+    (0x01cbd, 0x01cbf), (0x01cd0, 0x01cd2), (0x01cd4, 0x01cfa), (0x01d00, 0x01f15),
+    (0x01f18, 0x01f1d), (0x01f20, 0x01f45), (0x01f48, 0x01f4d), (0x01f50, 0x01f57),
+    (0x01f59, 0x01f59), (0x01f5b, 0x01f5b), (0x01f5d, 0x01f5d), (0x01f5f, 0x01f7d),
+    (0x01f80, 0x01fb4), (0x01fb6, 0x01fbc), (0x01fbe, 0x01fbe), (0x01fc2, 0x01fc4),
+    (0x01fc6, 0x01fcc), (0x01fd0, 0x01fd3), (0x01fd6, 0x01fdb), (0x01fe0, 0x01fec),
+    (0x01ff2, 0x01ff4), (0x01ff6, 0x01ffc), (0x02070, 0x02071), (0x02074, 0x02079),
+    (0x0207f, 0x02089), (0x02090, 0x0209c), (0x020d0, 0x020f0), (0x02102, 0x02102),
+    (0x02107, 0x02107), (0x0210a, 0x02113), (0x02115, 0x02115), (0x02119, 0x0211d),
+    (0x02124, 0x02124), (0x02126, 0x02126), (0x02128, 0x02128), (0x0212a, 0x0212d),
+    (0x0212f, 0x02139), (0x0213c, 0x0213f), (0x02145, 0x02149), (0x0214e, 0x0214e),
+    (0x02150, 0x02189), (0x02460, 0x0249b), (0x024b6, 0x024ff), (0x02776, 0x02793),
+    (0x02c00, 0x02ce4), (0x02ceb, 0x02cf3), (0x02cfd, 0x02cfd), (0x02d00, 0x02d25),
+    (0x02d27, 0x02d27), (0x02d2d, 0x02d2d), (0x02d30, 0x02d67), (0x02d6f, 0x02d6f),
+    (0x02d7f, 0x02d96), (0x02da0, 0x02da6), (0x02da8, 0x02dae), (0x02db0, 0x02db6),
+    (0x02db8, 0x02dbe), (0x02dc0, 0x02dc6), (0x02dc8, 0x02dce), (0x02dd0, 0x02dd6),
+    (0x02dd8, 0x02dde), (0x02de0, 0x02dff), (0x02e2f, 0x02e2f), (0x03005, 0x03007),
+    (0x03021, 0x0302f), (0x03031, 0x03035), (0x03038, 0x0303c), (0x03041, 0x03096),
+    (0x03099, 0x0309a), (0x0309d, 0x0309f), (0x030a1, 0x030fa), (0x030fc, 0x030ff),
+    (0x03105, 0x0312f), (0x03131, 0x0318e), (0x03192, 0x03195), (0x031a0, 0x031bf),
+    (0x031f0, 0x031ff), (0x03220, 0x03229), (0x03248, 0x0324f), (0x03251, 0x0325f),
+    (0x03280, 0x03289), (0x032b1, 0x032bf), (0x03400, 0x04dbf), (0x04e00, 0x0a48c),
+    (0x0a4d0, 0x0a4fd), (0x0a500, 0x0a60c), (0x0a610, 0x0a62b), (0x0a640, 0x0a672),
+    (0x0a674, 0x0a67d), (0x0a67f, 0x0a6f1), (0x0a717, 0x0a71f), (0x0a722, 0x0a788),
+    (0x0a78b, 0x0a7dc), (0x0a7f1, 0x0a827), (0x0a82c, 0x0a82c), (0x0a830, 0x0a835),
+    (0x0a840, 0x0a873), (0x0a880, 0x0a8c5), (0x0a8d0, 0x0a8d9), (0x0a8e0, 0x0a8f7),
+    (0x0a8fb, 0x0a8fb), (0x0a8fd, 0x0a92d), (0x0a930, 0x0a953), (0x0a960, 0x0a97c),
+    (0x0a980, 0x0a9c0), (0x0a9cf, 0x0a9d9), (0x0a9e0, 0x0a9fe), (0x0aa00, 0x0aa36),
+    (0x0aa40, 0x0aa4d), (0x0aa50, 0x0aa59), (0x0aa60, 0x0aa76), (0x0aa7a, 0x0aac2),
+    (0x0aadb, 0x0aadd), (0x0aae0, 0x0aaef), (0x0aaf2, 0x0aaf6), (0x0ab01, 0x0ab06),
+    (0x0ab09, 0x0ab0e), (0x0ab11, 0x0ab16), (0x0ab20, 0x0ab26), (0x0ab28, 0x0ab2e),
+    (0x0ab30, 0x0ab5a), (0x0ab5c, 0x0ab69), (0x0ab70, 0x0abea), (0x0abec, 0x0abed),
+    (0x0abf0, 0x0abf9), (0x0ac00, 0x0d7a3), (0x0d7b0, 0x0d7c6), (0x0d7cb, 0x0d7fb),
+    (0x0f900, 0x0fa6d), (0x0fa70, 0x0fad9), (0x0fb00, 0x0fb06), (0x0fb13, 0x0fb17),
+    (0x0fb1d, 0x0fb28), (0x0fb2a, 0x0fb36), (0x0fb38, 0x0fb3c), (0x0fb3e, 0x0fb3e),
+    (0x0fb40, 0x0fb41), (0x0fb43, 0x0fb44), (0x0fb46, 0x0fbb1), (0x0fbd3, 0x0fd3d),
+    (0x0fd50, 0x0fd8f), (0x0fd92, 0x0fdc7), (0x0fdf0, 0x0fdfb), (0x0fe00, 0x0fe0f),
+    (0x0fe20, 0x0fe2f), (0x0fe70, 0x0fe74), (0x0fe76, 0x0fefc), (0x0ff10, 0x0ff19),
+    (0x0ff21, 0x0ff3a), (0x0ff41, 0x0ff5a), (0x0ff66, 0x0ffbe), (0x0ffc2, 0x0ffc7),
+    (0x0ffca, 0x0ffcf), (0x0ffd2, 0x0ffd7), (0x0ffda, 0x0ffdc), (0x10000, 0x1000b),
+    (0x1000d, 0x10026), (0x10028, 0x1003a), (0x1003c, 0x1003d), (0x1003f, 0x1004d),
+    (0x10050, 0x1005d), (0x10080, 0x100fa), (0x10107, 0x10133), (0x10140, 0x10178),
+    (0x1018a, 0x1018b), (0x101fd, 0x101fd), (0x10280, 0x1029c), (0x102a0, 0x102d0),
+    (0x102e0, 0x102fb), (0x10300, 0x10323), (0x1032d, 0x1034a), (0x10350, 0x1037a),
+    (0x10380, 0x1039d), (0x103a0, 0x103c3), (0x103c8, 0x103cf), (0x103d1, 0x103d5),
+    (0x10400, 0x1049d), (0x104a0, 0x104a9), (0x104b0, 0x104d3), (0x104d8, 0x104fb),
+    (0x10500, 0x10527), (0x10530, 0x10563), (0x10570, 0x1057a), (0x1057c, 0x1058a),
+    (0x1058c, 0x10592), (0x10594, 0x10595), (0x10597, 0x105a1), (0x105a3, 0x105b1),
+    (0x105b3, 0x105b9), (0x105bb, 0x105bc), (0x105c0, 0x105f3), (0x10600, 0x10736),
+    (0x10740, 0x10755), (0x10760, 0x10767), (0x10780, 0x10785), (0x10787, 0x107b0),
+    (0x107b2, 0x107ba), (0x10800, 0x10805), (0x10808, 0x10808), (0x1080a, 0x10835),
+    (0x10837, 0x10838), (0x1083c, 0x1083c), (0x1083f, 0x10855), (0x10858, 0x10876),
+    (0x10879, 0x1089e), (0x108a7, 0x108af), (0x108e0, 0x108f2), (0x108f4, 0x108f5),
+    (0x108fb, 0x1091b), (0x10920, 0x10939), (0x10940, 0x10959), (0x10980, 0x109b7),
+    (0x109bc, 0x109cf), (0x109d2, 0x10a03), (0x10a05, 0x10a06), (0x10a0c, 0x10a13),
+    (0x10a15, 0x10a17), (0x10a19, 0x10a35), (0x10a38, 0x10a3a), (0x10a3f, 0x10a48),
+    (0x10a60, 0x10a7e), (0x10a80, 0x10a9f), (0x10ac0, 0x10ac7), (0x10ac9, 0x10ae6),
+    (0x10aeb, 0x10aef), (0x10b00, 0x10b35), (0x10b40, 0x10b55), (0x10b58, 0x10b72),
+    (0x10b78, 0x10b91), (0x10ba9, 0x10baf), (0x10c00, 0x10c48), (0x10c80, 0x10cb2),
+    (0x10cc0, 0x10cf2), (0x10cfa, 0x10d27), (0x10d30, 0x10d39), (0x10d40, 0x10d65),
+    (0x10d69, 0x10d6d), (0x10d6f, 0x10d85), (0x10e60, 0x10e7e), (0x10e80, 0x10ea9),
+    (0x10eab, 0x10eac), (0x10eb0, 0x10eb1), (0x10ec2, 0x10ec7), (0x10efa, 0x10f27),
+    (0x10f30, 0x10f54), (0x10f70, 0x10f85), (0x10fb0, 0x10fcb), (0x10fe0, 0x10ff6),
+    (0x11000, 0x11046), (0x11052, 0x11075), (0x1107f, 0x110ba), (0x110c2, 0x110c2),
+    (0x110d0, 0x110e8), (0x110f0, 0x110f9), (0x11100, 0x11134), (0x11136, 0x1113f),
+    (0x11144, 0x11147), (0x11150, 0x11173), (0x11176, 0x11176), (0x11180, 0x111c4),
+    (0x111c9, 0x111cc), (0x111ce, 0x111da), (0x111dc, 0x111dc), (0x111e1, 0x111f4),
+    (0x11200, 0x11211), (0x11213, 0x11237), (0x1123e, 0x11241), (0x11280, 0x11286),
+    (0x11288, 0x11288), (0x1128a, 0x1128d), (0x1128f, 0x1129d), (0x1129f, 0x112a8),
+    (0x112b0, 0x112ea), (0x112f0, 0x112f9), (0x11300, 0x11303), (0x11305, 0x1130c),
+    (0x1130f, 0x11310), (0x11313, 0x11328), (0x1132a, 0x11330), (0x11332, 0x11333),
+    (0x11335, 0x11339), (0x1133b, 0x11344), (0x11347, 0x11348), (0x1134b, 0x1134d),
+    (0x11350, 0x11350), (0x11357, 0x11357), (0x1135d, 0x11363), (0x11366, 0x1136c),
+    (0x11370, 0x11374), (0x11380, 0x11389), (0x1138b, 0x1138b), (0x1138e, 0x1138e),
+    (0x11390, 0x113b5), (0x113b7, 0x113c0), (0x113c2, 0x113c2), (0x113c5, 0x113c5),
+    (0x113c7, 0x113ca), (0x113cc, 0x113d3), (0x113e1, 0x113e2), (0x11400, 0x1144a),
+    (0x11450, 0x11459), (0x1145e, 0x11461), (0x11480, 0x114c5), (0x114c7, 0x114c7),
+    (0x114d0, 0x114d9), (0x11580, 0x115b5), (0x115b8, 0x115c0), (0x115d8, 0x115dd),
+    (0x11600, 0x11640), (0x11644, 0x11644), (0x11650, 0x11659), (0x11680, 0x116b8),
+    (0x116c0, 0x116c9), (0x116d0, 0x116e3), (0x11700, 0x1171a), (0x1171d, 0x1172b),
+    (0x11730, 0x1173b), (0x11740, 0x11746), (0x11800, 0x1183a), (0x118a0, 0x118f2),
+    (0x118ff, 0x11906), (0x11909, 0x11909), (0x1190c, 0x11913), (0x11915, 0x11916),
+    (0x11918, 0x11935), (0x11937, 0x11938), (0x1193b, 0x11943), (0x11950, 0x11959),
+    (0x119a0, 0x119a7), (0x119aa, 0x119d7), (0x119da, 0x119e1), (0x119e3, 0x119e4),
+    (0x11a00, 0x11a3e), (0x11a47, 0x11a47), (0x11a50, 0x11a99), (0x11a9d, 0x11a9d),
+    (0x11ab0, 0x11af8), (0x11b60, 0x11b67), (0x11bc0, 0x11be0), (0x11bf0, 0x11bf9),
+    (0x11c00, 0x11c08), (0x11c0a, 0x11c36), (0x11c38, 0x11c40), (0x11c50, 0x11c6c),
+    (0x11c72, 0x11c8f), (0x11c92, 0x11ca7), (0x11ca9, 0x11cb6), (0x11d00, 0x11d06),
+    (0x11d08, 0x11d09), (0x11d0b, 0x11d36), (0x11d3a, 0x11d3a), (0x11d3c, 0x11d3d),
+    (0x11d3f, 0x11d47), (0x11d50, 0x11d59), (0x11d60, 0x11d65), (0x11d67, 0x11d68),
+    (0x11d6a, 0x11d8e), (0x11d90, 0x11d91), (0x11d93, 0x11d98), (0x11da0, 0x11da9),
+    (0x11db0, 0x11ddb), (0x11de0, 0x11de9), (0x11ee0, 0x11ef6), (0x11f00, 0x11f10),
+    (0x11f12, 0x11f3a), (0x11f3e, 0x11f42), (0x11f50, 0x11f5a), (0x11fb0, 0x11fb0),
+    (0x11fc0, 0x11fd4), (0x12000, 0x12399), (0x12400, 0x1246e), (0x12480, 0x12543),
+    (0x12f90, 0x12ff0), (0x13000, 0x1342f), (0x13440, 0x13455), (0x13460, 0x143fa),
+    (0x14400, 0x14646), (0x16100, 0x16139), (0x16800, 0x16a38), (0x16a40, 0x16a5e),
+    (0x16a60, 0x16a69), (0x16a70, 0x16abe), (0x16ac0, 0x16ac9), (0x16ad0, 0x16aed),
+    (0x16af0, 0x16af4), (0x16b00, 0x16b36), (0x16b40, 0x16b43), (0x16b50, 0x16b59),
+    (0x16b5b, 0x16b61), (0x16b63, 0x16b77), (0x16b7d, 0x16b8f), (0x16d40, 0x16d6c),
+    (0x16d70, 0x16d79), (0x16e40, 0x16e96), (0x16ea0, 0x16eb8), (0x16ebb, 0x16ed3),
+    (0x16f00, 0x16f4a), (0x16f4f, 0x16f87), (0x16f8f, 0x16f9f), (0x16fe0, 0x16fe1),
+    (0x16fe3, 0x16fe4), (0x16ff0, 0x16ff6), (0x17000, 0x18cd5), (0x18cff, 0x18d1e),
+    (0x18d80, 0x18df2), (0x1aff0, 0x1aff3), (0x1aff5, 0x1affb), (0x1affd, 0x1affe),
+    (0x1b000, 0x1b122), (0x1b132, 0x1b132), (0x1b150, 0x1b152), (0x1b155, 0x1b155),
+    (0x1b164, 0x1b167), (0x1b170, 0x1b2fb), (0x1bc00, 0x1bc6a), (0x1bc70, 0x1bc7c),
+    (0x1bc80, 0x1bc88), (0x1bc90, 0x1bc99), (0x1bc9d, 0x1bc9e), (0x1ccf0, 0x1ccf9),
+    (0x1cf00, 0x1cf2d), (0x1cf30, 0x1cf46), (0x1d165, 0x1d169), (0x1d16d, 0x1d172),
+    (0x1d17b, 0x1d182), (0x1d185, 0x1d18b), (0x1d1aa, 0x1d1ad), (0x1d242, 0x1d244),
+    (0x1d2c0, 0x1d2d3), (0x1d2e0, 0x1d2f3), (0x1d360, 0x1d378), (0x1d400, 0x1d454),
+    (0x1d456, 0x1d49c), (0x1d49e, 0x1d49f), (0x1d4a2, 0x1d4a2), (0x1d4a5, 0x1d4a6),
+    (0x1d4a9, 0x1d4ac), (0x1d4ae, 0x1d4b9), (0x1d4bb, 0x1d4bb), (0x1d4bd, 0x1d4c3),
+    (0x1d4c5, 0x1d505), (0x1d507, 0x1d50a), (0x1d50d, 0x1d514), (0x1d516, 0x1d51c),
+    (0x1d51e, 0x1d539), (0x1d53b, 0x1d53e), (0x1d540, 0x1d544), (0x1d546, 0x1d546),
+    (0x1d54a, 0x1d550), (0x1d552, 0x1d6a5), (0x1d6a8, 0x1d6c0), (0x1d6c2, 0x1d6da),
+    (0x1d6dc, 0x1d6fa), (0x1d6fc, 0x1d714), (0x1d716, 0x1d734), (0x1d736, 0x1d74e),
+    (0x1d750, 0x1d76e), (0x1d770, 0x1d788), (0x1d78a, 0x1d7a8), (0x1d7aa, 0x1d7c2),
+    (0x1d7c4, 0x1d7cb), (0x1d7ce, 0x1d7ff), (0x1da00, 0x1da36), (0x1da3b, 0x1da6c),
+    (0x1da75, 0x1da75), (0x1da84, 0x1da84), (0x1da9b, 0x1da9f), (0x1daa1, 0x1daaf),
+    (0x1df00, 0x1df1e), (0x1df25, 0x1df2a), (0x1e000, 0x1e006), (0x1e008, 0x1e018),
+    (0x1e01b, 0x1e021), (0x1e023, 0x1e024), (0x1e026, 0x1e02a), (0x1e030, 0x1e06d),
+    (0x1e08f, 0x1e08f), (0x1e100, 0x1e12c), (0x1e130, 0x1e13d), (0x1e140, 0x1e149),
+    (0x1e14e, 0x1e14e), (0x1e290, 0x1e2ae), (0x1e2c0, 0x1e2f9), (0x1e4d0, 0x1e4f9),
+    (0x1e5d0, 0x1e5fa), (0x1e6c0, 0x1e6de), (0x1e6e0, 0x1e6f5), (0x1e6fe, 0x1e6ff),
+    (0x1e7e0, 0x1e7e6), (0x1e7e8, 0x1e7eb), (0x1e7ed, 0x1e7ee), (0x1e7f0, 0x1e7fe),
+    (0x1e800, 0x1e8c4), (0x1e8c7, 0x1e8d6), (0x1e900, 0x1e94b), (0x1e950, 0x1e959),
+    (0x1ec71, 0x1ecab), (0x1ecad, 0x1ecaf), (0x1ecb1, 0x1ecb4), (0x1ed01, 0x1ed2d),
+    (0x1ed2f, 0x1ed3d), (0x1ee00, 0x1ee03), (0x1ee05, 0x1ee1f), (0x1ee21, 0x1ee22),
+    (0x1ee24, 0x1ee24), (0x1ee27, 0x1ee27), (0x1ee29, 0x1ee32), (0x1ee34, 0x1ee37),
+    (0x1ee39, 0x1ee39), (0x1ee3b, 0x1ee3b), (0x1ee42, 0x1ee42), (0x1ee47, 0x1ee47),
+    (0x1ee49, 0x1ee49), (0x1ee4b, 0x1ee4b), (0x1ee4d, 0x1ee4f), (0x1ee51, 0x1ee52),
+    (0x1ee54, 0x1ee54), (0x1ee57, 0x1ee57), (0x1ee59, 0x1ee59), (0x1ee5b, 0x1ee5b),
+    (0x1ee5d, 0x1ee5d), (0x1ee5f, 0x1ee5f), (0x1ee61, 0x1ee62), (0x1ee64, 0x1ee64),
+    (0x1ee67, 0x1ee6a), (0x1ee6c, 0x1ee72), (0x1ee74, 0x1ee77), (0x1ee79, 0x1ee7c),
+    (0x1ee7e, 0x1ee7e), (0x1ee80, 0x1ee89), (0x1ee8b, 0x1ee9b), (0x1eea1, 0x1eea3),
+    (0x1eea5, 0x1eea9), (0x1eeab, 0x1eebb), (0x1f100, 0x1f10c), (0x1f130, 0x1f149),
+    (0x1f150, 0x1f169), (0x1f170, 0x1f189), (0x1fbf0, 0x1fbf9), (0x20000, 0x2a6df),
+    (0x2a700, 0x2b81d), (0x2b820, 0x2cead), (0x2ceb0, 0x2ebe0), (0x2ebf0, 0x2ee5d),
+    (0x2f800, 0x2fa1d), (0x30000, 0x3134a), (0x31350, 0x33479), (0xe0100, 0xe01ef),
+];
@@ -1,11 +1,12 @@
 #![allow(unsafe_code)]
 
-use core::ops::Range;
-
 use crate::__ctfe::StrBuf;
-use crate::slice::subslice;
+use crate::bytes::starts_with;
+use crate::slice::{advance, subslice};
+use crate::unicode_case;
+use crate::utf8::{next_char, CharEncodeUtf8};
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 #[repr(u8)]
 enum TokenKind {
     NonAscii = 1,
@@ -35,234 +36,744 @@ impl TokenKind {
         }
         TokenKind::Other
     }
+}
 
-    const fn is_boundary_word(s: &[u8]) -> bool {
-        let mut i = 0;
-        while i < s.len() {
-            let kind = Self::new(s[i]);
-            match kind {
-                TokenKind::Other | TokenKind::Dot => {}
-                _ => return false,
+/// Options of word boundaries.
+///
+/// # Examples
+///
+/// ```
+/// use const_str::ascii_case::CaseOptions;
+/// use const_str::convert_ascii_case;
+///
+/// const OPTIONS: CaseOptions = CaseOptions {
+///     split_digits: true,
+///     acronyms: &["OAuth"],
+///     ..CaseOptions::DEFAULT
+/// };
+///
+/// const S1: &str = convert_ascii_case!(snake, "HTTP2Server", OPTIONS);
+/// const S2: &str = convert_ascii_case!(snake, "getOAuthToken", OPTIONS);
+///
+/// assert_eq!(S1, "http_2_server");
+/// assert_eq!(S2, "get_oauth_token");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CaseOptions<'a> {
+    /// Splits digits from letters, so `"HTTP2Server"` becomes `"HTTP"`, `"2"` and `"Server"`.
+    pub split_digits: bool,
+
+    /// Treats `.` as a separator like `_` and `-`, which is what `heck` does.
+    pub dot_as_separator: bool,
+
+    /// Words which are never split, such as `"OAuth"` or `"macOS"`.
+    ///
+    /// The match is case-sensitive. An occurrence ending with an uppercase letter
+    /// is ignored when it is followed by a lowercase letter,
+    /// since that letter starts a new word.
+    pub acronyms: &'a [&'a str],
+}
+
+impl CaseOptions<'static> {
+    /// The default rules.
+    pub const DEFAULT: Self = Self {
+        split_digits: false,
+        dot_as_separator: false,
+        acronyms: &[],
+    };
+}
+
+impl Default for CaseOptions<'static> {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl<'a> CaseOptions<'a> {
+    const fn kind(&self, b: u8) -> TokenKind {
+        if self.dot_as_separator && b == b'.' {
+            return TokenKind::Other;
+        }
+        TokenKind::new(b)
+    }
+
+    /// Returns true if the boundary `b` is inside an acronym.
+    const fn is_inside_acronym(&self, s: &[u8], b: usize) -> bool {
+        let mut k = 0;
+        while k < self.acronyms.len() {
+            let acronym = self.acronyms[k].as_bytes();
+            k += 1;
+
+            let n = acronym.len();
+            if n < 2 {
+                continue;
+            }
+
+            let mut i = (b + 1).saturating_sub(n);
+            while i < b {
+                let end = i + n;
+                if end <= s.len() && starts_with(advance(s, i), acronym) {
+                    let is_cut = end < s.len()
+                        && s[end - 1].is_ascii_uppercase()
+                        && s[end].is_ascii_lowercase();
+                    if !is_cut {
+                        return true;
+                    }
+                }
+                i += 1;
             }
-            i += 1;
         }
-        true
+        false
     }
 }
 
-#[derive(Debug)]
-struct Boundaries<const N: usize> {
-    buf: [usize; N],
-    len: usize,
+/// Word boundaries of a string, as used by [`convert_ascii_case!`](crate::convert_ascii_case).
+///
+/// The string is split into runs of lowercase letters, uppercase letters, digits,
+/// non-ASCII characters and other ASCII characters. The rules are:
+///
+/// + A boundary is placed between runs of different kinds, except that
+///   letters, digits and non-ASCII characters are never split from each other.
+/// + An uppercase letter followed by a lowercase letter starts a new word,
+///   so `"XMLHttp"` is split into `"XML"` and `"Http"`.
+/// + `.` is attached to the previous run, and starts a new run when it is followed by something else.
+///
+/// These rules can be adjusted with [`CaseOptions`].
+///
+/// Words which only consist of ASCII punctuation, whitespace or control characters
+/// are separators and are skipped by [`next_word`](Self::next_word).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::ascii_case::Boundaries;
+///
+/// const fn count_words(s: &str) -> usize {
+///     let mut iter = Boundaries::new(s);
+///     let mut count = 0;
+///     while let (next, Some(_)) = iter.next_word() {
+///         iter = next;
+///         count += 1;
+///     }
+///     count
+/// }
+///
+/// const N: usize = count_words("XMLHttpRequest_v2");
+/// assert_eq!(N, 4);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Boundaries<'a> {
+    s: &'a [u8],
+    i: usize,
+    k1: Option<TokenKind>,
+    k2: Option<TokenKind>,
+    last: usize,
+    pending: Option<usize>,
+    prev: Option<usize>,
+    finished: bool,
+    opts: CaseOptions<'a>,
+    unicode: bool,
+    dot_start: usize,
 }
 
-impl<const N: usize> Boundaries<N> {
-    const fn new(src: &str) -> Self {
-        let s = src.as_bytes();
-        assert!(s.len() + 1 == N);
+impl<'a> Boundaries<'a> {
+    /// Creates a word boundary iterator over a string slice.
+    pub const fn new(s: &'a str) -> Self {
+        Self::with_options(s, CaseOptions::DEFAULT)
+    }
 
-        let mut buf = [0; N];
-        let mut pos = 0;
+    /// Creates a word boundary iterator over a string slice, with custom rules.
+    pub const fn with_options(s: &'a str, opts: CaseOptions<'a>) -> Self {
+        Self {
+            s: s.as_bytes(),
+            i: 0,
+            k1: None,
+            k2: None,
+            last: 0,
+            pending: None,
+            prev: None,
+            finished: false,
+            opts,
+            unicode: false,
+            dot_start: 0,
+        }
+    }
 
-        macro_rules! push {
-            ($x: expr) => {{
-                buf[pos] = $x;
-                pos += 1;
-            }};
+    /// Classifies non-ASCII characters by their Unicode properties,
+    /// as used by [`convert_case!`](crate::convert_case).
+    ///
+    /// Runs of `.` and `.` at the end are also separators,
+    /// which keeps the output of `heck`, used by [`convert_case!`](crate::convert_case) before.
+    pub(crate) const fn unicode(s: &'a str, opts: CaseOptions<'a>) -> Self {
+        let mut this = Self::with_options(s, opts);
+        this.unicode = true;
+        this
+    }
+
+    /// Returns the kind and the byte length of the token at `i`.
+    const fn kind_at(&self, i: usize) -> (TokenKind, usize) {
+        let b = self.s[i];
+        if b.is_ascii() || !self.unicode {
+            return (self.opts.kind(b), 1);
+        }
+        let (ch, len) = match next_char(advance(self.s, i)) {
+            Some(x) => x,
+            None => unreachable!(),
+        };
+        let kind = if unicode_case::is_uppercase(ch) {
+            TokenKind::Upper
+        } else if unicode_case::is_lowercase(ch) {
+            TokenKind::Lower
+        } else if unicode_case::is_word(ch) {
+            TokenKind::NonAscii
+        } else {
+            TokenKind::Other
+        };
+        (kind, len)
+    }
+
+    /// Returns true if `s[start..end]` only consists of separators.
+    const fn is_separator(&self, start: usize, end: usize) -> bool {
+        let mut i = start;
+        while i < end {
+            let (kind, len) = self.kind_at(i);
+            match kind {
+                TokenKind::Other | TokenKind::Dot => {}
+                _ => return false,
+            }
+            i += len;
         }
+        true
+    }
 
-        let mut k2: Option<TokenKind> = None;
-        let mut k1: Option<TokenKind> = None;
+    /// Returns the byte offset of the next boundary.
+    ///
+    /// The first boundary is `0` and the last one is `s.len()`.
+    /// Offsets are non-decreasing but may repeat.
+    pub const fn next_boundary(mut self) -> (Self, Option<usize>) {
+        loop {
+            let (next, b) = self.next_raw_boundary();
+            self = next;
+            match b {
+                Some(b) if self.opts.is_inside_acronym(self.s, b) => continue,
+                _ => return (self, b),
+            }
+        }
+    }
 
-        let mut i = 0;
-        while i < s.len() {
-            let b = s[i];
-            let k0 = TokenKind::new(b);
+    const fn next_raw_boundary(mut self) -> (Self, Option<usize>) {
+        if let Some(b) = self.pending {
+            self.pending = None;
+            return (self, Some(b));
+        }
+
+        let s = self.s;
+        while self.i < s.len() {
+            let i = self.i;
+            let (k0, len) = self.kind_at(i);
+
+            let mut output = None;
 
             use TokenKind::*;
 
-            match (k1, k0) {
-                (None, _) => push!(i),
-                (Some(k1), k0) => {
+            if matches!(k0, Dot) && !matches!(self.k1, Some(Dot)) {
+                self.dot_start = i;
+            }
+
+            match self.k1 {
+                None => output = Some(i),
+                Some(k1) => {
                     if k1 as u8 != k0 as u8 {
                         match (k1, k0) {
-                            (Upper, Lower) => push!(i - 1),
+                            (Upper, Lower) => output = Some(self.last),
+                            (Lower | Upper | NonAscii, Digit)
+                            | (Digit, Lower | Upper | NonAscii)
+                                if self.opts.split_digits =>
+                            {
+                                output = Some(i)
+                            }
                             (NonAscii, Digit) => {} // Don't create boundary between NonAscii and Digit
                             (NonAscii, Lower | Upper) => {} // Don't create boundary between NonAscii and alphabetic
                             (Lower | Upper, Digit) => {}    // or-pattens stable since 1.53
                             (Digit, Lower | Upper | NonAscii) => {}
                             (Lower | Upper, NonAscii) => {} // Don't create boundary between alphabetic and NonAscii
                             (_, Dot) => {}
-                            (Dot, _) => match (k2, k0) {
-                                (None, _) => push!(i),
-                                (Some(_), _) => {
-                                    push!(i - 1);
-                                    push!(i);
+                            (Dot, _) => match self.k2 {
+                                None => output = Some(i),
+                                Some(_) if self.unicode => {
+                                    output = Some(self.dot_start);
+                                    self.pending = Some(i);
+                                }
+                                Some(_) => {
+                                    output = Some(self.last);
+                                    self.pending = Some(i);
                                 }
                             },
-                            _ => push!(i),
+                            _ => output = Some(i),
                         }
                     }
                 }
             }
 
-            k2 = k1;
-            k1 = Some(k0);
-            i += 1;
+            self.k2 = self.k1;
+            self.k1 = Some(k0);
+            self.last = i;
+            self.i += len;
+
+            if output.is_some() {
+                return (self, output);
+            }
         }
-        push!(i);
 
-        Self { buf, len: pos }
+        if self.finished {
+            return (self, None);
+        }
+        self.finished = true;
+        if self.unicode && matches!(self.k1, Some(TokenKind::Dot)) && self.dot_start > 0 {
+            self.pending = Some(s.len());
+            return (self, Some(self.dot_start));
+        }
+        (self, Some(s.len()))
     }
 
-    const fn words_count(&self) -> usize {
-        self.len - 1
-    }
+    /// Returns the next word, skipping separators.
+    pub const fn next_word(mut self) -> (Self, Option<&'a str>) {
+        loop {
+            let start = match self.prev {
+                Some(b) => b,
+                None => match self.next_boundary() {
+                    (next, Some(b)) => {
+                        self = next;
+                        b
+                    }
+                    (next, None) => return (next, None),
+                },
+            };
+            let end = match self.next_boundary() {
+                (next, Some(b)) => {
+                    self = next;
+                    b
+                }
+                (next, None) => return (next, None),
+            };
+            self.prev = Some(end);
 
-    const fn word_range(&self, idx: usize) -> Range<usize> {
-        self.buf[idx]..self.buf[idx + 1]
+            if !self.is_separator(start, end) {
+                let word = subslice(self.s, start..end);
+                // The boundaries are always on char boundaries.
+                return (self, Some(unsafe { core::str::from_utf8_unchecked(word) }));
+            }
+        }
     }
 }
 
+/// Target cases of [`convert_ascii_case!`](crate::convert_ascii_case).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AsciiCase {
+    /// `lower case`
     Lower,
+    /// `UPPER CASE`
     Upper,
+    /// `lowerCamelCase`
     LowerCamel,
+    /// `UpperCamelCase`
     UpperCamel,
+    /// `Title Case`
     Title,
+    /// `Train-Case`
     Train,
+    /// `snake_case`
     Snake,
+    /// `kebab-case`
     Kebab,
+    /// `SHOUTY_SNAKE_CASE`
     ShoutySnake,
+    /// `SHOUTY-KEBAB-CASE`, also known as `COBOL-CASE`
     ShoutyKebab,
+    /// `dot.case`
+    Dot,
+    /// `path/case`
+    Path,
+    /// `Sentence case`
+    Sentence,
+    /// `Ada_Case`
+    Ada,
+    /// `flatcase`
+    Flat,
+    /// `UPPERFLATCASE`
+    UpperFlat,
 }
 
+/// Cases which can be detected, the more specific ones first.
+///
+/// `Lower` and `Upper` are not included because they do not describe words.
+const DETECTION_ORDER: [AsciiCase; 14] = {
+    use AsciiCase::*;
+    [
+        Snake,
+        Kebab,
+        Dot,
+        Path,
+        ShoutySnake,
+        ShoutyKebab,
+        UpperCamel,
+        LowerCamel,
+        Title,
+        Train,
+        Sentence,
+        Ada,
+        Flat,
+        UpperFlat,
+    ]
+};
+
 impl AsciiCase {
     const fn get_seperator(&self) -> Option<u8> {
         match self {
-            Self::Title => Some(b' '),
-            Self::Snake | Self::ShoutySnake => Some(b'_'),
+            Self::Title | Self::Sentence => Some(b' '),
+            Self::Snake | Self::ShoutySnake | Self::Ada => Some(b'_'),
             Self::Train | Self::Kebab | Self::ShoutyKebab => Some(b'-'),
+            Self::Dot => Some(b'.'),
+            Self::Path => Some(b'/'),
             _ => None,
         }
     }
+
+    /// Returns the length of the converted string.
+    pub const fn output_len(self, s: &str) -> usize {
+        self.output_len_with_options(s, CaseOptions::DEFAULT)
+    }
+
+    /// Returns the length of the converted string, with custom word boundary rules.
+    pub const fn output_len_with_options(self, s: &str, opts: CaseOptions<'_>) -> usize {
+        Output::new(s, self, opts, false).count()
+    }
+
+    /// Converts a string slice to this case, into a buffer with capacity `N`.
+    ///
+    /// Non-ascii characters are not affected.
+    /// It panics if the capacity is not enough.
+    ///
+    /// Unlike [`convert_ascii_case!`](crate::convert_ascii_case),
+    /// this function can be called in const fns with any arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_str::ascii_case::{AsciiCase, CaseBuf};
+    ///
+    /// trait Named {
+    ///     const NAME: &'static str;
+    /// }
+    ///
+    /// struct HttpServer;
+    ///
+    /// impl Named for HttpServer {
+    ///     const NAME: &'static str = "HttpServer";
+    /// }
+    ///
+    /// const fn snake_name<T: Named>() -> CaseBuf<32> {
+    ///     AsciiCase::Snake.convert(T::NAME)
+    /// }
+    ///
+    /// const NAME: CaseBuf<32> = snake_name::<HttpServer>();
+    /// assert_eq!(NAME.as_str(), "http_server");
+    /// ```
+    pub const fn convert<const N: usize>(self, s: &str) -> CaseBuf<N> {
+        self.convert_with_options(s, CaseOptions::DEFAULT)
+    }
+
+    /// Converts a string slice to this case with custom word boundary rules,
+    /// into a buffer with capacity `N`.
+    ///
+    /// See [`convert`](Self::convert) and [`CaseOptions`].
+    pub const fn convert_with_options<const N: usize>(
+        self,
+        s: &str,
+        opts: CaseOptions<'_>,
+    ) -> CaseBuf<N> {
+        Output::new(s, self, opts, false).collect()
+    }
+
+    /// Returns true if converting `s` to this case does not change it.
+    pub(crate) const fn matches(self, s: &str) -> bool {
+        let bytes = s.as_bytes();
+        let mut iter = Output::new(s, self, CaseOptions::DEFAULT, false);
+        let mut i = 0;
+        while let (next, Some(b)) = iter.next() {
+            iter = next;
+            if i >= bytes.len() || bytes[i] != b {
+                return false;
+            }
+            i += 1;
+        }
+        i == bytes.len()
+    }
+
+    /// Returns the first case in [`DETECTION_ORDER`] which `s` matches.
+    pub(crate) const fn detect(s: &str) -> Option<Self> {
+        if Boundaries::new(s).next_word().1.is_none() {
+            return None;
+        }
+        let mut i = 0;
+        while i < DETECTION_ORDER.len() {
+            let case = DETECTION_ORDER[i];
+            if case.matches(s) {
+                return Some(case);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns the length of the converted string, with full Unicode case mapping.
+    pub(crate) const fn unicode_output_len(self, s: &str, opts: CaseOptions<'_>) -> usize {
+        Output::new(s, self, opts, true).count()
+    }
+
+    /// Converts a string slice to this case, with full Unicode case mapping.
+    pub(crate) const fn unicode_convert<const N: usize>(
+        self,
+        s: &str,
+        opts: CaseOptions<'_>,
+    ) -> CaseBuf<N> {
+        Output::new(s, self, opts, true).collect()
+    }
 }
 
-pub struct ConvAsciiCase<T>(pub T, pub AsciiCase);
+/// A string buffer with a fixed capacity `N`.
+///
+/// See [`AsciiCase::convert`].
+#[derive(Clone, Copy)]
+pub struct CaseBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
 
-impl ConvAsciiCase<&str> {
-    pub const fn output_len<const M: usize>(&self) -> usize {
-        assert!(self.0.len() + 1 == M);
+impl<const N: usize> CaseBuf<N> {
+    /// # Safety
+    /// `buf[..len]` must contain valid utf-8 bytes.
+    const unsafe fn new_unchecked(buf: [u8; N], len: usize) -> Self {
+        Self { buf, len }
+    }
 
-        use AsciiCase::*;
-        match self.1 {
-            Lower | Upper => self.0.len(),
-            LowerCamel | UpperCamel | Title | Train | Snake | Kebab | ShoutySnake | ShoutyKebab => {
-                let mut ans = 0;
+    /// Returns the content as a string slice.
+    pub const fn as_str(&self) -> &str {
+        unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
+    }
 
-                let has_sep = self.1.get_seperator().is_some();
+    /// Returns the content as a byte slice.
+    pub const fn as_bytes(&self) -> &[u8] {
+        subslice(&self.buf, 0..self.len)
+    }
 
-                let boundaries = Boundaries::<M>::new(self.0);
-                let words_count = boundaries.words_count();
+    /// Returns the length of the content in bytes.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
 
-                let mut i = 0;
-                let mut is_starting_boundary: bool = true;
+    /// Returns true if the content is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
 
-                while i < words_count {
-                    let rng = boundaries.word_range(i);
-                    let word = subslice(self.0.as_bytes(), rng);
+    pub(crate) const fn into_str_buf(self) -> StrBuf<N> {
+        assert!(self.len == N);
+        unsafe { StrBuf::new_unchecked(self.buf) }
+    }
+}
 
-                    if !TokenKind::is_boundary_word(word) {
-                        if has_sep && !is_starting_boundary {
-                            ans += 1;
-                        }
-                        ans += word.len();
-                        is_starting_boundary = false;
-                    }
+/// An iterator over the output bytes of a case conversion.
+struct Output<'a> {
+    case: AsciiCase,
+    unicode: bool,
+    words: Boundaries<'a>,
+    word: &'a [u8],
+    j: usize,
+    is_starting_boundary: bool,
+    cased_before: bool,
+    buf: [u8; 12],
+    buf_pos: usize,
+    buf_len: usize,
+}
 
-                    i += 1;
-                }
-                ans
-            }
+impl<'a> Output<'a> {
+    const fn new(s: &'a str, case: AsciiCase, opts: CaseOptions<'a>, unicode: bool) -> Self {
+        let words = if unicode {
+            Boundaries::unicode(s, opts)
+        } else {
+            Boundaries::with_options(s, opts)
+        };
+        let word = match case {
+            AsciiCase::Lower | AsciiCase::Upper => s.as_bytes(),
+            _ => &[],
+        };
+        Self {
+            case,
+            unicode,
+            words,
+            word,
+            j: 0,
+            is_starting_boundary: true,
+            cased_before: false,
+            buf: [0; 12],
+            buf_pos: 0,
+            buf_len: 0,
         }
     }
 
-    pub const fn const_eval<const M: usize, const N: usize>(&self) -> StrBuf<N> {
-        assert!(self.0.len() + 1 == M);
+    const fn is_title_at(&self, j: usize) -> bool {
+        match self.case {
+            AsciiCase::LowerCamel => !self.is_starting_boundary && j == 0,
+            AsciiCase::Sentence => self.is_starting_boundary && j == 0,
+            AsciiCase::UpperCamel | AsciiCase::Title | AsciiCase::Train | AsciiCase::Ada => j == 0,
+            _ => false,
+        }
+    }
 
-        let mut buf = [0; N];
-        let mut pos = 0;
-        let s = self.0.as_bytes();
+    const fn next(mut self) -> (Self, Option<u8>) {
+        use AsciiCase::*;
 
-        macro_rules! push {
-            ($x: expr) => {{
-                buf[pos] = $x;
-                pos += 1;
-            }};
+        if self.buf_pos < self.buf_len {
+            let b = self.buf[self.buf_pos];
+            self.buf_pos += 1;
+            return (self, Some(b));
         }
 
-        use AsciiCase::*;
-        match self.1 {
-            Lower => {
-                while pos < s.len() {
-                    push!(s[pos].to_ascii_lowercase());
-                }
+        if self.j >= self.word.len() {
+            if let Lower | Upper = self.case {
+                return (self, None);
             }
-            Upper => {
-                while pos < s.len() {
-                    push!(s[pos].to_ascii_uppercase());
+            let (words, word) = self.words.next_word();
+            self.words = words;
+            let word = match word {
+                Some(word) => word.as_bytes(),
+                None => return (self, None),
+            };
+            let is_first = self.word.is_empty(); // words are never empty
+            self.word = word;
+            self.j = 0;
+            self.cased_before = false;
+            if !is_first {
+                self.is_starting_boundary = false;
+                if let Some(sep) = self.case.get_seperator() {
+                    return (self, Some(sep));
                 }
             }
-            LowerCamel | UpperCamel | Title | Train | Snake | Kebab | ShoutySnake | ShoutyKebab => {
-                let sep = self.1.get_seperator();
+        }
 
-                let boundaries = Boundaries::<M>::new(self.0);
-                let words_count = boundaries.words_count();
+        let j = self.j;
 
-                let mut i = 0;
-                let mut is_starting_boundary = true;
+        if !self.unicode {
+            let b = self.word[j];
+            self.j += 1;
 
-                while i < words_count {
-                    let rng = boundaries.word_range(i);
-                    let word = subslice(self.0.as_bytes(), rng);
+            let b = match self.case {
+                Upper | ShoutySnake | ShoutyKebab | UpperFlat => b.to_ascii_uppercase(),
+                _ if self.is_title_at(j) => b.to_ascii_uppercase(),
+                _ => b.to_ascii_lowercase(),
+            };
+            return (self, Some(b));
+        }
 
-                    if !TokenKind::is_boundary_word(word) {
-                        if let (Some(sep), false) = (sep, is_starting_boundary) {
-                            push!(sep)
-                        }
-                        let mut j = 0;
-                        while j < word.len() {
-                            let b = match self.1 {
-                                Snake | Kebab => word[j].to_ascii_lowercase(),
-                                ShoutySnake | ShoutyKebab => word[j].to_ascii_uppercase(),
-                                LowerCamel | UpperCamel | Title | Train => {
-                                    let is_upper = match self.1 {
-                                        LowerCamel => !is_starting_boundary && j == 0,
-                                        UpperCamel | Title | Train => j == 0,
-                                        _ => unreachable!(),
-                                    };
-                                    if is_upper {
-                                        word[j].to_ascii_uppercase()
-                                    } else {
-                                        word[j].to_ascii_lowercase()
-                                    }
-                                }
-                                _ => unreachable!(),
-                            };
-                            push!(b);
-                            j += 1;
-                        }
-                        is_starting_boundary = false;
-                    }
+        let (ch, len) = match next_char(advance(self.word, j)) {
+            Some(x) => x,
+            None => unreachable!(),
+        };
+        self.j += len;
 
-                    i += 1;
-                }
+        let chars = match self.case {
+            Upper | ShoutySnake | ShoutyKebab | UpperFlat => unicode_case::to_upper(ch),
+            _ if self.is_title_at(j) => unicode_case::to_title(ch),
+            _ if ch == 'Σ' && self.is_final_sigma() => ['ς', '\0', '\0'],
+            _ => unicode_case::to_lower(ch),
+        };
+        if !unicode_case::is_case_ignorable(ch) {
+            self.cased_before = unicode_case::is_cased(ch);
+        }
+
+        self.buf_len = 0;
+        let mut k = 0;
+        while k < chars.len() {
+            if k > 0 && chars[k] == '\0' {
+                break;
+            }
+            let e = CharEncodeUtf8::new(chars[k]);
+            let bytes = e.as_bytes();
+            let mut l = 0;
+            while l < bytes.len() {
+                self.buf[self.buf_len] = bytes[l];
+                self.buf_len += 1;
+                l += 1;
             }
+            k += 1;
+        }
+        let b = self.buf[0];
+        self.buf_pos = 1;
+        (self, Some(b))
+    }
+
+    const fn count(mut self) -> usize {
+        let mut ans = 0;
+        while let (next, Some(_)) = self.next() {
+            self = next;
+            ans += 1;
         }
+        ans
+    }
 
-        assert!(pos == N);
+    const fn collect<const N: usize>(mut self) -> CaseBuf<N> {
+        let mut buf = [0; N];
+        let mut len = 0;
 
-        unsafe { StrBuf::new_unchecked(buf) }
+        while let (next, Some(b)) = self.next() {
+            self = next;
+            assert!(len < N, "buffer capacity is not enough");
+            buf[len] = b;
+            len += 1;
+        }
+
+        // Only whole chars are changed, and only ASCII bytes are inserted between words.
+        unsafe { CaseBuf::new_unchecked(buf, len) }
+    }
+
+    /// Checks the `Final_Sigma` condition of the current `'Σ'` in the current word.
+    const fn is_final_sigma(&self) -> bool {
+        if !self.cased_before {
+            return false;
+        }
+        let mut rest = advance(self.word, self.j);
+        while let Some((ch, len)) = next_char(rest) {
+            if !unicode_case::is_case_ignorable(ch) {
+                return !unicode_case::is_cased(ch);
+            }
+            rest = advance(rest, len);
+        }
+        true
+    }
+}
+
+pub struct ConvAsciiCase<T>(pub T, pub AsciiCase);
+
+impl ConvAsciiCase<&str> {
+    pub const fn output_len(&self) -> usize {
+        self.1.output_len(self.0)
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        self.1.convert::<N>(self.0).into_str_buf()
+    }
+}
+
+impl ConvAsciiCase<(&str, CaseOptions<'_>)> {
+    pub const fn output_len(&self) -> usize {
+        let (s, opts) = self.0;
+        self.1.output_len_with_options(s, opts)
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let (s, opts) = self.0;
+        self.1.convert_with_options::<N>(s, opts).into_str_buf()
     }
 }
 
@@ -271,10 +782,16 @@ impl ConvAsciiCase<&str> {
 macro_rules! __conv_ascii_case {
     ($s: expr, $case: expr) => {{
         const INPUT: &str = $s;
-        const M: usize = INPUT.len() + 1;
-        const N: usize = $crate::__ctfe::ConvAsciiCase(INPUT, $case).output_len::<M>();
+        const N: usize = $crate::__ctfe::ConvAsciiCase(INPUT, $case).output_len();
         const OUTPUT_BUF: $crate::__ctfe::StrBuf<N> =
-            $crate::__ctfe::ConvAsciiCase(INPUT, $case).const_eval::<M, N>();
+            $crate::__ctfe::ConvAsciiCase(INPUT, $case).const_eval::<N>();
+        OUTPUT_BUF.as_str()
+    }};
+    ($s: expr, $case: expr, $opts: expr) => {{
+        const INPUT: &str = $s;
+        const N: usize = $crate::__ctfe::ConvAsciiCase((INPUT, $opts), $case).output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<N> =
+            $crate::__ctfe::ConvAsciiCase((INPUT, $opts), $case).const_eval::<N>();
         OUTPUT_BUF.as_str()
     }};
 }
@@ -282,6 +799,10 @@ macro_rules! __conv_ascii_case {
 /// Converts a string slice to a specified case. Non-ascii characters are not affected.
 ///
 /// This macro is [const-context only](./index.html#const-context-only).
+/// For const fns, see [`AsciiCase::convert`](crate::ascii_case::AsciiCase::convert).
+///
+/// An optional third argument of type [`CaseOptions`](crate::ascii_case::CaseOptions)
+/// adjusts the word boundary rules.
 ///
 /// # Examples
 ///
@@ -298,6 +819,13 @@ macro_rules! __conv_ascii_case {
 /// const S8: &str = convert_ascii_case!(kebab, "kebab case");
 /// const S9: &str = convert_ascii_case!(shouty_snake, "shouty snake case");
 /// const S10: &str = convert_ascii_case!(shouty_kebab, "shouty kebab case");
+/// const S11: &str = convert_ascii_case!(cobol, "cobol case");
+/// const S12: &str = convert_ascii_case!(dot, "dot case");
+/// const S13: &str = convert_ascii_case!(path, "path case");
+/// const S14: &str = convert_ascii_case!(sentence, "sentence case");
+/// const S15: &str = convert_ascii_case!(ada, "ada case");
+/// const S16: &str = convert_ascii_case!(flat, "flat case");
+/// const S17: &str = convert_ascii_case!(upper_flat, "upper flat case");
 ///
 /// assert_eq!(S1, "lower case");
 /// assert_eq!(S2, "UPPER CASE");
@@ -309,38 +837,66 @@ macro_rules! __conv_ascii_case {
 /// assert_eq!(S8, "kebab-case");
 /// assert_eq!(S9, "SHOUTY_SNAKE_CASE");
 /// assert_eq!(S10, "SHOUTY-KEBAB-CASE");
+/// assert_eq!(S11, "COBOL-CASE");
+/// assert_eq!(S12, "dot.case");
+/// assert_eq!(S13, "path/case");
+/// assert_eq!(S14, "Sentence case");
+/// assert_eq!(S15, "Ada_Case");
+/// assert_eq!(S16, "flatcase");
+/// assert_eq!(S17, "UPPERFLATCASE");
 /// ```
 #[macro_export]
 macro_rules! convert_ascii_case {
-    (lower, $s: expr) => {
-        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Lower)
+    (lower, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Lower $(, $opts)?)
+    };
+    (upper, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Upper $(, $opts)?)
+    };
+    (lower_camel, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::LowerCamel $(, $opts)?)
+    };
+    (upper_camel, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::UpperCamel $(, $opts)?)
+    };
+    (title, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Title $(, $opts)?)
     };
-    (upper, $s: expr) => {
-        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Upper)
+    (train, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Train $(, $opts)?)
     };
-    (lower_camel, $s: expr) => {
-        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::LowerCamel)
+    (snake, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Snake $(, $opts)?)
     };
-    (upper_camel, $s: expr) => {
-        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::UpperCamel)
+    (kebab, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Kebab $(, $opts)?)
     };
-    (title, $s: expr) => {
-        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Title)
+    (shouty_snake, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::ShoutySnake $(, $opts)?)
     };
-    (train, $s: expr) => {
-        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Train)
+    (shouty_kebab, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::ShoutyKebab $(, $opts)?)
     };
-    (snake, $s: expr) => {
-        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Snake)
+    (cobol, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::ShoutyKebab $(, $opts)?)
     };
-    (kebab, $s: expr) => {
-        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Kebab)
+    (dot, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Dot $(, $opts)?)
     };
-    (shouty_snake, $s: expr) => {
-        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::ShoutySnake)
+    (path, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Path $(, $opts)?)
     };
-    (shouty_kebab, $s: expr) => {
-        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::ShoutyKebab)
+    (sentence, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Sentence $(, $opts)?)
+    };
+    (ada, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Ada $(, $opts)?)
+    };
+    (flat, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::Flat $(, $opts)?)
+    };
+    (upper_flat, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_ascii_case!($s, $crate::__ctfe::AsciiCase::UpperFlat $(, $opts)?)
     };
 }
 
@@ -510,76 +1066,254 @@ mod tests {
 
         // Test Lower case
         let conv_lower = ConvAsciiCase("HELLO", AsciiCase::Lower);
-        let len_lower = conv_lower.output_len::<6>();
+        let len_lower = conv_lower.output_len();
         assert_eq!(len_lower, 5);
-        let result_lower: StrBuf<5> = conv_lower.const_eval::<6, 5>();
+        let result_lower: StrBuf<5> = conv_lower.const_eval::<5>();
         assert_eq!(result_lower.as_str(), "hello");
 
         // Test Upper case
         let conv_upper = ConvAsciiCase("hello", AsciiCase::Upper);
-        let len_upper = conv_upper.output_len::<6>();
+        let len_upper = conv_upper.output_len();
         assert_eq!(len_upper, 5);
-        let result_upper: StrBuf<5> = conv_upper.const_eval::<6, 5>();
+        let result_upper: StrBuf<5> = conv_upper.const_eval::<5>();
         assert_eq!(result_upper.as_str(), "HELLO");
 
         // Test LowerCamel case
         let conv_camel = ConvAsciiCase("hello_world", AsciiCase::LowerCamel);
-        let _len_camel = conv_camel.output_len::<12>();
-        let result_camel: StrBuf<10> = conv_camel.const_eval::<12, 10>();
+        let _len_camel = conv_camel.output_len();
+        let result_camel: StrBuf<10> = conv_camel.const_eval::<10>();
         assert_eq!(result_camel.as_str(), "helloWorld");
 
         // Test UpperCamel case
         let conv_upper_camel = ConvAsciiCase("hello_world", AsciiCase::UpperCamel);
-        let _len_upper_camel = conv_upper_camel.output_len::<12>();
-        let result_upper_camel: StrBuf<10> = conv_upper_camel.const_eval::<12, 10>();
+        let _len_upper_camel = conv_upper_camel.output_len();
+        let result_upper_camel: StrBuf<10> = conv_upper_camel.const_eval::<10>();
         assert_eq!(result_upper_camel.as_str(), "HelloWorld");
 
         // Test Title case
         let conv_title = ConvAsciiCase("hello_world", AsciiCase::Title);
-        let _len_title = conv_title.output_len::<12>();
-        let result_title: StrBuf<11> = conv_title.const_eval::<12, 11>();
+        let _len_title = conv_title.output_len();
+        let result_title: StrBuf<11> = conv_title.const_eval::<11>();
         assert_eq!(result_title.as_str(), "Hello World");
 
         // Test Train case
         let conv_train = ConvAsciiCase("hello_world", AsciiCase::Train);
-        let _len_train = conv_train.output_len::<12>();
-        let result_train: StrBuf<11> = conv_train.const_eval::<12, 11>();
+        let _len_train = conv_train.output_len();
+        let result_train: StrBuf<11> = conv_train.const_eval::<11>();
         assert_eq!(result_train.as_str(), "Hello-World");
 
         // Test Snake case
         let conv_snake = ConvAsciiCase("HelloWorld", AsciiCase::Snake);
-        let _len_snake = conv_snake.output_len::<11>();
-        let result_snake: StrBuf<11> = conv_snake.const_eval::<11, 11>();
+        let _len_snake = conv_snake.output_len();
+        let result_snake: StrBuf<11> = conv_snake.const_eval::<11>();
         assert_eq!(result_snake.as_str(), "hello_world");
 
         // Test Kebab case
         let conv_kebab = ConvAsciiCase("HelloWorld", AsciiCase::Kebab);
-        let _len_kebab = conv_kebab.output_len::<11>();
-        let result_kebab: StrBuf<11> = conv_kebab.const_eval::<11, 11>();
+        let _len_kebab = conv_kebab.output_len();
+        let result_kebab: StrBuf<11> = conv_kebab.const_eval::<11>();
         assert_eq!(result_kebab.as_str(), "hello-world");
 
         // Test ShoutySnake case
         let conv_shouty_snake = ConvAsciiCase("helloWorld", AsciiCase::ShoutySnake);
-        let _len_shouty_snake = conv_shouty_snake.output_len::<11>();
-        let result_shouty_snake: StrBuf<11> = conv_shouty_snake.const_eval::<11, 11>();
+        let _len_shouty_snake = conv_shouty_snake.output_len();
+        let result_shouty_snake: StrBuf<11> = conv_shouty_snake.const_eval::<11>();
         assert_eq!(result_shouty_snake.as_str(), "HELLO_WORLD");
 
         // Test ShoutyKebab case
         let conv_shouty_kebab = ConvAsciiCase("helloWorld", AsciiCase::ShoutyKebab);
-        let _len_shouty_kebab = conv_shouty_kebab.output_len::<11>();
-        let result_shouty_kebab: StrBuf<11> = conv_shouty_kebab.const_eval::<11, 11>();
+        let _len_shouty_kebab = conv_shouty_kebab.output_len();
+        let result_shouty_kebab: StrBuf<11> = conv_shouty_kebab.const_eval::<11>();
         assert_eq!(result_shouty_kebab.as_str(), "HELLO-WORLD");
 
         // Test edge cases with numbers and dots
         let conv_edge = ConvAsciiCase("1.2E3", AsciiCase::LowerCamel);
-        let _len_edge = conv_edge.output_len::<6>();
-        let result_edge: StrBuf<4> = conv_edge.const_eval::<6, 4>();
+        let _len_edge = conv_edge.output_len();
+        let result_edge: StrBuf<4> = conv_edge.const_eval::<4>();
         assert_eq!(result_edge.as_str(), "12e3");
 
         // Test empty-ish strings
         let conv_empty = ConvAsciiCase("___", AsciiCase::LowerCamel);
-        let _len_empty = conv_empty.output_len::<4>();
-        let result_empty: StrBuf<0> = conv_empty.const_eval::<4, 0>();
+        let _len_empty = conv_empty.output_len();
+        let result_empty: StrBuf<0> = conv_empty.const_eval::<0>();
         assert_eq!(result_empty.as_str(), "");
     }
+
+    #[test]
+    fn test_boundaries() {
+        use super::*;
+
+        fn words(s: &str) -> Vec<&str> {
+            let mut ans = Vec::new();
+            let mut iter = Boundaries::new(s);
+            while let (next, Some(word)) = iter.next_word() {
+                iter = next;
+                ans.push(word);
+            }
+            ans
+        }
+
+        fn boundaries(s: &str) -> Vec<usize> {
+            let mut ans = Vec::new();
+            let mut iter = Boundaries::new(s);
+            while let (next, Some(b)) = iter.next_boundary() {
+                iter = next;
+                ans.push(b);
+            }
+            ans
+        }
+
+        assert_eq!(words(""), Vec::<&str>::new());
+        assert_eq!(words("__"), Vec::<&str>::new());
+        assert_eq!(words("XMLHttpRequest"), ["XML", "Http", "Request"]);
+        assert_eq!(words("hello world123"), ["hello", "world123"]);
+        assert_eq!(words("b.8"), ["b", "8"]);
+        assert_eq!(words("Http我4t5.c6"), ["Http我4t5", "c6"]);
+
+        assert_eq!(boundaries(""), [0]);
+        assert_eq!(boundaries("ab"), [0, 2]);
+        assert_eq!(boundaries("a_b"), [0, 1, 2, 3]);
+        assert_eq!(boundaries("b.8"), [0, 1, 2, 3]);
+
+        const fn count_words(s: &str) -> usize {
+            let mut iter = Boundaries::new(s);
+            let mut count = 0;
+            while let (next, Some(_)) = iter.next_word() {
+                iter = next;
+                count += 1;
+            }
+            count
+        }
+        const N: usize = count_words("XMLHttpRequest_v2");
+        assert_eq!(N, 4);
+    }
+
+    #[test]
+    fn test_case_options() {
+        use super::*;
+
+        fn words<'a>(s: &'a str, opts: CaseOptions<'a>) -> Vec<&'a str> {
+            let mut ans = Vec::new();
+            let mut iter = Boundaries::with_options(s, opts);
+            while let (next, Some(word)) = iter.next_word() {
+                iter = next;
+                ans.push(word);
+            }
+            ans
+        }
+
+        let default = CaseOptions::default();
+        let digits = CaseOptions {
+            split_digits: true,
+            ..CaseOptions::DEFAULT
+        };
+        let dot = CaseOptions {
+            dot_as_separator: true,
+            ..CaseOptions::DEFAULT
+        };
+        let acronyms = CaseOptions {
+            acronyms: &["OAuth", "macOS", "HTTPS", "IO"],
+            ..CaseOptions::DEFAULT
+        };
+
+        assert_eq!(words("HTTP2Server", default), ["HTTP2", "Server"]);
+        assert_eq!(words("HTTP2Server", digits), ["HTTP", "2", "Server"]);
+        assert_eq!(words("v1_2", digits), ["v", "1", "2"]);
+        assert_eq!(words("我4t5", digits), ["我", "4", "t", "5"]);
+
+        assert_eq!(words("a..b", default), ["a.", "b"]);
+        assert_eq!(words("a..b", dot), ["a", "b"]);
+        assert_eq!(words("1.2E3", dot), ["1", "2E3"]);
+
+        assert_eq!(
+            words("getOAuthToken", default),
+            ["get", "O", "Auth", "Token"]
+        );
+        assert_eq!(words("getOAuthToken", acronyms), ["get", "OAuth", "Token"]);
+        assert_eq!(words("OAuth2Token", acronyms), ["OAuth2", "Token"]);
+        assert_eq!(words("macOSVersion", acronyms), ["macOS", "Version"]);
+        assert_eq!(words("HTTPServer", acronyms), ["HTTP", "Server"]);
+        assert_eq!(words("HTTPSConnection", acronyms), ["HTTPS", "Connection"]);
+        assert_eq!(words("IOError", acronyms), ["IO", "Error"]);
+
+        {
+            const OPTIONS: CaseOptions = CaseOptions {
+                split_digits: true,
+                dot_as_separator: true,
+                acronyms: &["OAuth"],
+            };
+            const S1: &str = convert_ascii_case!(snake, "HTTP2Server", OPTIONS);
+            const S2: &str = convert_ascii_case!(lower_camel, "OAuth2Token", OPTIONS);
+            const S3: &str = convert_ascii_case!(kebab, "a..b", OPTIONS);
+            const S4: &str = convert_ascii_case!(upper, "a..b", OPTIONS);
+            assert_eq!(S1, "http_2_server");
+            assert_eq!(S2, "oauth2Token");
+            assert_eq!(S3, "a-b");
+            assert_eq!(S4, "A..B");
+        }
+
+        {
+            const S: &str = convert_ascii_case!(snake, "a..b", CaseOptions::DEFAULT);
+            assert_eq!(S, convert_ascii_case!(snake, "a..b"));
+        }
+
+        let buf = AsciiCase::ShoutySnake.convert_with_options::<32>("getOAuthToken", acronyms);
+        assert_eq!(buf.as_str(), "GET_OAUTH_TOKEN");
+        assert_eq!(
+            AsciiCase::ShoutySnake.output_len_with_options("getOAuthToken", acronyms),
+            buf.len()
+        );
+    }
+
+    #[test]
+    fn test_ascii_case_convert() {
+        use super::*;
+
+        const fn snake<const N: usize>(s: &str) -> CaseBuf<N> {
+            AsciiCase::Snake.convert(s)
+        }
+
+        const B1: CaseBuf<16> = snake("HelloWorld");
+        assert_eq!(B1.as_str(), "hello_world");
+        assert_eq!(B1.len(), 11);
+        assert!(!B1.is_empty());
+
+        const B2: CaseBuf<0> = snake("__");
+        assert_eq!(B2.as_str(), "");
+        assert!(B2.is_empty());
+
+        let cases = [
+            (AsciiCase::Lower, "xml http request1"),
+            (AsciiCase::Upper, "XML HTTP REQUEST1"),
+            (AsciiCase::LowerCamel, "xmlHttpRequest1"),
+            (AsciiCase::UpperCamel, "XmlHttpRequest1"),
+            (AsciiCase::Title, "Xml Http Request1"),
+            (AsciiCase::Train, "Xml-Http-Request1"),
+            (AsciiCase::Snake, "xml_http_request1"),
+            (AsciiCase::Kebab, "xml-http-request1"),
+            (AsciiCase::ShoutySnake, "XML_HTTP_REQUEST1"),
+            (AsciiCase::ShoutyKebab, "XML-HTTP-REQUEST1"),
+            (AsciiCase::Dot, "xml.http.request1"),
+            (AsciiCase::Path, "xml/http/request1"),
+            (AsciiCase::Sentence, "Xml http request1"),
+            (AsciiCase::Ada, "Xml_Http_Request1"),
+            (AsciiCase::Flat, "xmlhttprequest1"),
+            (AsciiCase::UpperFlat, "XMLHTTPREQUEST1"),
+        ];
+        for (case, ans) in cases {
+            let src = if matches!(case, AsciiCase::Lower | AsciiCase::Upper) {
+                "XML Http Request1"
+            } else {
+                "XMLHttp request1"
+            };
+            assert_eq!(case.convert::<32>(src).as_str(), ans);
+            assert_eq!(case.output_len(src), ans.len());
+        }
+    }
+
+    #[test]
+    #[should_panic = "buffer capacity is not enough"]
+    fn test_ascii_case_convert_overflow() {
+        let _ = super::AsciiCase::Snake.convert::<4>("HelloWorld");
+    }
 }
@@ -0,0 +1,356 @@
+#![allow(unsafe_code)]
+
+use super::StrBuf;
+
+const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+#[derive(Clone, Copy)]
+pub struct Base32Options {
+    pad: bool,
+}
+
+impl Base32Options {
+    pub const DEFAULT: Self = Self { pad: true };
+
+    pub const fn no_pad(self) -> Self {
+        Self { pad: false }
+    }
+}
+
+/// The number of characters that encode the last `r` bytes of a group.
+const fn partial_chars(r: usize) -> usize {
+    (r * 8).div_ceil(5)
+}
+
+const fn encoded_len(len: usize, pad: bool) -> usize {
+    let full = len / 5 * 8;
+    match len % 5 {
+        0 => full,
+        _ if pad => full + 8,
+        r => full + partial_chars(r),
+    }
+}
+
+const fn encode<const N: usize>(src: &[u8], opts: Base32Options) -> [u8; N] {
+    assert!(N == encoded_len(src.len(), opts.pad));
+
+    let mut buf = [b'='; N];
+    let mut pos = 0;
+
+    let mut i = 0;
+    while i < src.len() {
+        let rem = src.len() - i;
+        let mut x: u64 = 0;
+        let mut k = 0;
+        while k < 5 {
+            let b = if k < rem { src[i + k] } else { 0 };
+            x = (x << 8) | b as u64;
+            k += 1;
+        }
+
+        let chars = if rem >= 5 { 8 } else { partial_chars(rem) };
+        let mut k = 0;
+        while k < chars {
+            buf[pos + k] = ALPHABET[((x >> (35 - 5 * k)) & 31) as usize];
+            k += 1;
+        }
+        pos += chars;
+        i += 5;
+    }
+    buf
+}
+
+const fn decode_char(b: u8) -> Option<u8> {
+    match b {
+        b'A'..=b'Z' => Some(b - b'A'),
+        b'a'..=b'z' => Some(b - b'a'),
+        b'2'..=b'7' => Some(b - b'2' + 26),
+        _ => None,
+    }
+}
+
+/// Decodes the concatenation of `parts` into `buf`.
+///
+/// Returns the number of decoded bytes.
+/// The bytes out of the capacity of `buf` are only counted.
+const fn decode<const N: usize>(parts: &[&str], opts: Base32Options) -> ([u8; N], usize) {
+    let mut buf = [0; N];
+    let mut pos = 0;
+
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    let mut chars = 0;
+    let mut pads = 0;
+
+    let mut p = 0;
+    while p < parts.len() {
+        let s = parts[p].as_bytes();
+        let mut i = 0;
+        while i < s.len() {
+            let b = s[i];
+            i += 1;
+
+            match b {
+                b' ' | b'\r' | b'\n' | b'\t' => continue,
+                b'=' => {
+                    pads += 1;
+                    continue;
+                }
+                _ => {}
+            }
+            if pads > 0 {
+                panic!("unexpected character after padding");
+            }
+            let val = match decode_char(b) {
+                Some(val) => val,
+                None => panic!("invalid base32 character"),
+            };
+
+            acc = (acc << 5) | val as u32;
+            bits += 5;
+            chars += 1;
+            if bits >= 8 {
+                bits -= 8;
+                if pos < N {
+                    buf[pos] = (acc >> bits) as u8;
+                }
+                pos += 1;
+                acc &= (1 << bits) - 1;
+            }
+        }
+        p += 1;
+    }
+
+    let rem = chars % 8;
+    assert!(matches!(rem, 0 | 2 | 4 | 5 | 7), "invalid base32 length");
+    assert!(acc == 0, "non-zero trailing bits");
+
+    if opts.pad {
+        let expected = if rem == 0 { 0 } else { 8 - rem };
+        assert!(pads == expected, "invalid base32 padding");
+    } else {
+        assert!(pads == 0, "unexpected base32 padding");
+    }
+
+    (buf, pos)
+}
+
+pub struct Base32Encode<T>(pub T, pub Base32Options);
+
+impl Base32Encode<&[u8]> {
+    pub const fn output_len(&self) -> usize {
+        encoded_len(self.0.len(), self.1.pad)
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let buf = encode(self.0, self.1);
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+impl<const L: usize> Base32Encode<&[u8; L]> {
+    pub const fn output_len(&self) -> usize {
+        Base32Encode(self.0.as_slice(), self.1).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        Base32Encode(self.0.as_slice(), self.1).const_eval()
+    }
+}
+
+impl Base32Encode<&str> {
+    pub const fn output_len(&self) -> usize {
+        Base32Encode(self.0.as_bytes(), self.1).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        Base32Encode(self.0.as_bytes(), self.1).const_eval()
+    }
+}
+
+pub struct Base32Decode<T>(pub T, pub Base32Options);
+
+impl Base32Decode<&[&str]> {
+    pub const fn output_len(&self) -> usize {
+        decode::<0>(self.0, self.1).1
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> [u8; N] {
+        let (buf, len) = decode::<N>(self.0, self.1);
+        assert!(len == N);
+        buf
+    }
+}
+
+impl Base32Decode<&str> {
+    pub const fn output_len(&self) -> usize {
+        let ss: &[&str] = &[self.0];
+        Base32Decode(ss, self.1).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> [u8; N] {
+        let ss: &[&str] = &[self.0];
+        Base32Decode(ss, self.1).const_eval()
+    }
+}
+
+impl<const L: usize> Base32Decode<[&str; L]> {
+    pub const fn output_len(&self) -> usize {
+        let ss: &[&str] = &self.0;
+        Base32Decode(ss, self.1).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> [u8; N] {
+        let ss: &[&str] = &self.0;
+        Base32Decode(ss, self.1).const_eval()
+    }
+}
+
+/// Encodes bytes to a base32 string slice.
+///
+/// It uses the standard alphabet of [RFC 4648](https://www.rfc-editor.org/rfc/rfc4648#section-6).
+///
+/// The input type must be one of:
+/// + [`&str`](str)
+/// + [`&[u8]`](slice)
+/// + [`&[u8; N]`](array)
+///
+/// The output type is [`&str`](str).
+///
+/// The `no_pad` option after the input omits the trailing `=` characters.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`base32_decode!`](crate::base32_decode).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::base32;
+///
+/// const S: &str = base32!("Hello!");
+/// assert_eq!(S, "JBSWY3DPEE======");
+///
+/// const SEED: &[u8] = b"12345678901234567890";
+/// assert_eq!(base32!(SEED, no_pad), "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ");
+/// ```
+///
+#[macro_export]
+macro_rules! base32 {
+    ($s: expr $(, $opt: ident)* $(,)?) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::Base32Encode(
+            $s,
+            $crate::__ctfe::Base32Options::DEFAULT $(.$opt())*,
+        )
+        .output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<OUTPUT_LEN> = $crate::__ctfe::Base32Encode(
+            $s,
+            $crate::__ctfe::Base32Options::DEFAULT $(.$opt())*,
+        )
+        .const_eval();
+        OUTPUT_BUF.as_str()
+    }};
+}
+
+/// Decodes base32 string slices to a byte array.
+///
+/// The input type must be one of:
+/// + [`&str`](str)
+/// + [`&[&str]`](slice)
+/// + [`[&str; N]`](array)
+///
+/// Multiple string slices are decoded as their concatenation.
+/// The formatting characters `' '`, `'\r'`, `'\n'` and `'\t'` are ignored,
+/// and lowercase letters are accepted.
+///
+/// The padding must be complete and the unused bits of the last character must be zero.
+/// The `no_pad` option rejects any `=` characters.
+///
+/// The compilation fails if the input is invalid.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::base32_decode;
+///
+/// const DATA: [u8; 6] = base32_decode!("JBSWY3DPEE======");
+/// assert_eq!(&DATA, b"Hello!");
+///
+/// const SEED: &[u8] = &base32_decode!("gezd gnbv gy3t qojq gezd gnbv gy3t qojq", no_pad);
+/// assert_eq!(SEED, b"12345678901234567890");
+/// ```
+#[macro_export]
+macro_rules! base32_decode {
+    ($s: expr $(, $opt: ident)* $(,)?) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::Base32Decode(
+            $s,
+            $crate::__ctfe::Base32Options::DEFAULT $(.$opt())*,
+        )
+        .output_len();
+        const OUTPUT_BUF: [u8; OUTPUT_LEN] = $crate::__ctfe::Base32Decode(
+            $s,
+            $crate::__ctfe::Base32Options::DEFAULT $(.$opt())*,
+        )
+        .const_eval();
+        OUTPUT_BUF
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base32() {
+        macro_rules! testcase {
+            ($s: expr, $padded: expr) => {{
+                const PADDED: &str = base32!($s);
+                const UNPADDED: &str = base32!($s, no_pad);
+                assert_eq!(PADDED, $padded);
+                assert_eq!(UNPADDED, $padded.trim_end_matches('='));
+                assert_eq!(base32_decode!(PADDED), *$s.as_bytes());
+                assert_eq!(base32_decode!(UNPADDED, no_pad), *$s.as_bytes());
+            }};
+        }
+
+        testcase!("", "");
+        testcase!("f", "MY======");
+        testcase!("fo", "MZXQ====");
+        testcase!("foo", "MZXW6===");
+        testcase!("foob", "MZXW6YQ=");
+        testcase!("fooba", "MZXW6YTB");
+        testcase!("foobar", "MZXW6YTBOI======");
+
+        const BYTES: &[u8; 5] = &[0xff, 0x00, 0xff, 0x00, 0xff];
+        assert_eq!(base32!(BYTES), "74AP6AH7");
+        assert_eq!(base32_decode!("74ap6ah7"), *BYTES);
+
+        const PARTS: [u8; 6] = base32_decode!(["MZXW6", "YTBOI", "======"]);
+        assert_eq!(&PARTS, b"foobar");
+
+        let decode = Base32Decode("MZXW6YQ", Base32Options::DEFAULT.no_pad());
+        assert_eq!(decode.output_len(), 4);
+        let buf: [u8; 4] = decode.const_eval();
+        assert_eq!(&buf, b"foob");
+    }
+
+    #[test]
+    #[should_panic = "invalid base32 length"]
+    fn test_base32_invalid_length() {
+        let _ = Base32Decode("MZX", Base32Options::DEFAULT.no_pad()).output_len();
+    }
+
+    #[test]
+    #[should_panic = "non-zero trailing bits"]
+    fn test_base32_trailing_bits() {
+        let _ = Base32Decode("MZ======", Base32Options::DEFAULT).output_len();
+    }
+
+    #[test]
+    #[should_panic = "invalid base32 padding"]
+    fn test_base32_invalid_padding() {
+        let _ = Base32Decode("MY==", Base32Options::DEFAULT).output_len();
+    }
+}
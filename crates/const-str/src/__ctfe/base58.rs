@@ -0,0 +1,307 @@
+#![allow(unsafe_code)]
+
+use super::StrBuf;
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+const fn decode_char(b: u8) -> Option<u8> {
+    let mut i = 0;
+    while i < ALPHABET.len() {
+        if ALPHABET[i] == b {
+            return Some(i as u8);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Converts the big-endian `digits` from radix `from` to radix `to`.
+///
+/// The result is written to the end of `buf` without leading zeros.
+/// Returns the number of result digits.
+/// The digits out of the capacity of `buf` are only counted.
+const fn convert<const L: usize, const N: usize>(
+    mut digits: [u8; L],
+    from: u32,
+    to: u32,
+) -> ([u8; N], usize) {
+    let mut buf = [0; N];
+    let mut count = 0;
+
+    let mut start = 0;
+    while start < L && digits[start] == 0 {
+        start += 1;
+    }
+
+    while start < L {
+        let mut rem = 0;
+        let mut i = start;
+        while i < L {
+            let cur = rem * from + digits[i] as u32;
+            digits[i] = (cur / to) as u8;
+            rem = cur % to;
+            i += 1;
+        }
+
+        if count < N {
+            buf[N - 1 - count] = rem as u8;
+        }
+        count += 1;
+
+        while start < L && digits[start] == 0 {
+            start += 1;
+        }
+    }
+
+    (buf, count)
+}
+
+const fn encode<const L: usize, const N: usize>(src: &[u8]) -> ([u8; N], usize) {
+    assert!(src.len() == L);
+
+    let mut zeros = 0;
+    while zeros < L && src[zeros] == 0 {
+        zeros += 1;
+    }
+
+    let digits: [u8; L] = crate::slice::to_array(src);
+    let (converted, count) = convert::<L, N>(digits, 256, 58);
+
+    let len = zeros + count;
+    let mut buf = [0; N];
+    if len == N {
+        let mut i = 0;
+        while i < N {
+            buf[i] = if i < zeros {
+                ALPHABET[0]
+            } else {
+                ALPHABET[converted[i] as usize]
+            };
+            i += 1;
+        }
+    }
+    (buf, len)
+}
+
+const fn decode<const L: usize, const N: usize>(src: &[u8]) -> ([u8; N], usize) {
+    assert!(src.len() == L);
+
+    let mut digits = [0; L];
+    let mut i = 0;
+    while i < L {
+        digits[i] = match decode_char(src[i]) {
+            Some(val) => val,
+            None => panic!("invalid base58 character"),
+        };
+        i += 1;
+    }
+
+    let mut zeros = 0;
+    while zeros < L && digits[zeros] == 0 {
+        zeros += 1;
+    }
+
+    let (converted, count) = convert::<L, N>(digits, 58, 256);
+
+    let len = zeros + count;
+    let mut buf = [0; N];
+    if len == N {
+        let mut i = zeros;
+        while i < N {
+            buf[i] = converted[i];
+            i += 1;
+        }
+    }
+    (buf, len)
+}
+
+pub struct Base58Encode<T>(pub T);
+
+impl Base58Encode<&[u8]> {
+    pub const fn input_len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub const fn output_len<const L: usize>(&self) -> usize {
+        encode::<L, 0>(self.0).1
+    }
+
+    pub const fn const_eval<const L: usize, const N: usize>(&self) -> StrBuf<N> {
+        let (buf, len) = encode::<L, N>(self.0);
+        assert!(len == N);
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+impl<const M: usize> Base58Encode<&[u8; M]> {
+    pub const fn input_len(&self) -> usize {
+        M
+    }
+
+    pub const fn output_len<const L: usize>(&self) -> usize {
+        Base58Encode(self.0.as_slice()).output_len::<L>()
+    }
+
+    pub const fn const_eval<const L: usize, const N: usize>(&self) -> StrBuf<N> {
+        Base58Encode(self.0.as_slice()).const_eval::<L, N>()
+    }
+}
+
+impl Base58Encode<&str> {
+    pub const fn input_len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub const fn output_len<const L: usize>(&self) -> usize {
+        Base58Encode(self.0.as_bytes()).output_len::<L>()
+    }
+
+    pub const fn const_eval<const L: usize, const N: usize>(&self) -> StrBuf<N> {
+        Base58Encode(self.0.as_bytes()).const_eval::<L, N>()
+    }
+}
+
+pub struct Base58Decode<'a>(pub &'a str);
+
+impl Base58Decode<'_> {
+    pub const fn input_len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub const fn output_len<const L: usize>(&self) -> usize {
+        decode::<L, 0>(self.0.as_bytes()).1
+    }
+
+    pub const fn const_eval<const L: usize, const N: usize>(&self) -> [u8; N] {
+        let (buf, len) = decode::<L, N>(self.0.as_bytes());
+        assert!(len == N);
+        buf
+    }
+}
+
+/// Encodes bytes to a base58 string slice.
+///
+/// It uses the Bitcoin alphabet. Each leading zero byte is encoded as a `'1'`.
+/// No checksum is added.
+///
+/// The input type must be one of:
+/// + [`&str`](str)
+/// + [`&[u8]`](slice)
+/// + [`&[u8; N]`](array)
+///
+/// The output type is [`&str`](str).
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`base58_decode!`](crate::base58_decode).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::base58;
+///
+/// const S: &str = base58!("Hello World!");
+/// assert_eq!(S, "2NEpo7TZRRrLZSi2U");
+///
+/// const BYTES: &[u8] = &[0, 0, 0x28, 0x7f, 0xb4, 0xcd];
+/// assert_eq!(base58!(BYTES), "11233QC4");
+/// ```
+///
+#[macro_export]
+macro_rules! base58 {
+    ($s: expr) => {{
+        const INPUT_LEN: usize = $crate::__ctfe::Base58Encode($s).input_len();
+        const OUTPUT_LEN: usize = $crate::__ctfe::Base58Encode($s).output_len::<INPUT_LEN>();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<OUTPUT_LEN> =
+            $crate::__ctfe::Base58Encode($s).const_eval::<INPUT_LEN, OUTPUT_LEN>();
+        OUTPUT_BUF.as_str()
+    }};
+}
+
+/// Decodes a base58 string slice to a byte array.
+///
+/// It uses the Bitcoin alphabet. Each leading `'1'` is decoded as a zero byte.
+/// No checksum is verified.
+///
+/// The input type must be [`&str`](str).
+///
+/// The compilation fails if the input contains a character out of the alphabet.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::base58_decode;
+///
+/// const DATA: [u8; 12] = base58_decode!("2NEpo7TZRRrLZSi2U");
+/// assert_eq!(&DATA, b"Hello World!");
+///
+/// assert_eq!(base58_decode!("11233QC4"), [0, 0, 0x28, 0x7f, 0xb4, 0xcd]);
+/// ```
+///
+/// ```compile_fail
+/// // '0' is not in the alphabet
+/// let _ = const_str::base58_decode!("0OIl");
+/// ```
+#[macro_export]
+macro_rules! base58_decode {
+    ($s: expr) => {{
+        const INPUT_LEN: usize = $crate::__ctfe::Base58Decode($s).input_len();
+        const OUTPUT_LEN: usize = $crate::__ctfe::Base58Decode($s).output_len::<INPUT_LEN>();
+        const OUTPUT_BUF: [u8; OUTPUT_LEN] =
+            $crate::__ctfe::Base58Decode($s).const_eval::<INPUT_LEN, OUTPUT_LEN>();
+        OUTPUT_BUF
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base58() {
+        macro_rules! testcase {
+            ($s: expr, $encoded: expr) => {{
+                const ENCODED: &str = base58!($s);
+                assert_eq!(ENCODED, $encoded);
+                assert_eq!(base58_decode!(ENCODED), *$s);
+            }};
+        }
+
+        testcase!(b"", "");
+        testcase!(b"\0", "1");
+        testcase!(b"\0\0\0", "111");
+        testcase!(b"\x61", "2g");
+        testcase!(b"\x62\x62\x62", "a3gV");
+        testcase!(b"\x63\x63\x63", "aPEr");
+        testcase!(b"simply a long string", "2cFupjhnEsSn59qHXstmK2ffpLv2");
+        testcase!(
+            b"\x00\xeb\x15\x23\x1d\xfc\xeb\x60\x92\x58\x86\xb6\x7d\x06\x52\x99\x92\x59\x15\xae\xb1\x72\xc0\x66\x47",
+            "1NS17iag9jJgTHD1VXjvLCEnZuQ3rJDE9L"
+        );
+        testcase!(b"\x51\x6b\x6f\xcd\x0f", "ABnLTmg");
+        testcase!(b"\xbf\x4f\x89\x00\x1e\x67\x02\x74\xdd", "3SEo3LWLoPntC");
+        testcase!(
+            b"\xec\xac\x89\xca\xd9\x39\x23\xc0\x23\x21",
+            "EJDM8drfXA6uyA"
+        );
+        testcase!(b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00", "1111111111");
+        testcase!(
+            b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+            "FPBt6CHo3fovdL"
+        );
+
+        let encode = Base58Encode("Hello World!");
+        assert_eq!(encode.input_len(), 12);
+        assert_eq!(encode.output_len::<12>(), 17);
+        let buf: StrBuf<17> = encode.const_eval::<12, 17>();
+        assert_eq!(buf.as_str(), "2NEpo7TZRRrLZSi2U");
+    }
+
+    #[test]
+    #[should_panic = "invalid base58 character"]
+    fn test_base58_invalid_character() {
+        let _ = Base58Decode("abc0").output_len::<4>();
+    }
+}
@@ -0,0 +1,457 @@
+#![allow(unsafe_code)]
+
+use super::StrBuf;
+
+#[derive(Clone, Copy)]
+pub struct Base64Options {
+    url_safe: bool,
+    pad: bool,
+}
+
+impl Base64Options {
+    pub const DEFAULT: Self = Self {
+        url_safe: false,
+        pad: true,
+    };
+
+    pub const fn url_safe(self) -> Self {
+        Self {
+            url_safe: true,
+            ..self
+        }
+    }
+
+    pub const fn no_pad(self) -> Self {
+        Self { pad: false, ..self }
+    }
+
+    const fn alphabet(&self) -> &'static [u8; 64] {
+        if self.url_safe {
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"
+        } else {
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"
+        }
+    }
+
+    const fn decode_char(&self, b: u8) -> Option<u8> {
+        match b {
+            b'A'..=b'Z' => Some(b - b'A'),
+            b'a'..=b'z' => Some(b - b'a' + 26),
+            b'0'..=b'9' => Some(b - b'0' + 52),
+            b'+' if !self.url_safe => Some(62),
+            b'/' if !self.url_safe => Some(63),
+            b'-' if self.url_safe => Some(62),
+            b'_' if self.url_safe => Some(63),
+            _ => None,
+        }
+    }
+}
+
+const fn encoded_len(len: usize, pad: bool) -> usize {
+    let full = len / 3 * 4;
+    match len % 3 {
+        0 => full,
+        _ if pad => full + 4,
+        r => full + r + 1,
+    }
+}
+
+const fn encode<const N: usize>(src: &[u8], opts: Base64Options) -> [u8; N] {
+    assert!(N == encoded_len(src.len(), opts.pad));
+
+    let alphabet = opts.alphabet();
+    let mut buf = [b'='; N];
+    let mut pos = 0;
+
+    let mut i = 0;
+    while i < src.len() {
+        let rem = src.len() - i;
+        let b0 = src[i] as u32;
+        let b1 = if rem > 1 { src[i + 1] as u32 } else { 0 };
+        let b2 = if rem > 2 { src[i + 2] as u32 } else { 0 };
+        let x = (b0 << 16) | (b1 << 8) | b2;
+
+        let chars = if rem >= 3 { 4 } else { rem + 1 };
+        let mut k = 0;
+        while k < chars {
+            buf[pos + k] = alphabet[((x >> (18 - 6 * k)) & 63) as usize];
+            k += 1;
+        }
+        pos += chars;
+        i += 3;
+    }
+    buf
+}
+
+/// Decodes the concatenation of `parts` into `buf`.
+///
+/// Returns the number of decoded bytes.
+/// The bytes out of the capacity of `buf` are only counted.
+const fn decode<const N: usize>(parts: &[&str], opts: Base64Options) -> ([u8; N], usize) {
+    let mut buf = [0; N];
+    let mut pos = 0;
+
+    macro_rules! push {
+        ($x: expr) => {{
+            if pos < N {
+                buf[pos] = $x as u8;
+            }
+            pos += 1;
+        }};
+    }
+
+    let mut acc: u32 = 0;
+    let mut chars = 0;
+    let mut pads = 0;
+
+    let mut p = 0;
+    while p < parts.len() {
+        let s = parts[p].as_bytes();
+        let mut i = 0;
+        while i < s.len() {
+            let b = s[i];
+            i += 1;
+
+            match b {
+                b' ' | b'\r' | b'\n' | b'\t' => continue,
+                b'=' => {
+                    pads += 1;
+                    continue;
+                }
+                _ => {}
+            }
+            if pads > 0 {
+                panic!("unexpected character after padding");
+            }
+            let val = match opts.decode_char(b) {
+                Some(val) => val,
+                None => panic!("invalid base64 character"),
+            };
+
+            acc = (acc << 6) | val as u32;
+            chars += 1;
+            if chars % 4 == 0 {
+                push!(acc >> 16);
+                push!(acc >> 8);
+                push!(acc);
+                acc = 0;
+            }
+        }
+        p += 1;
+    }
+
+    let rem = chars % 4;
+    match rem {
+        0 => {}
+        1 => panic!("invalid base64 length"),
+        2 => {
+            assert!(acc & 0xf == 0, "non-zero trailing bits");
+            push!(acc >> 4);
+        }
+        _ => {
+            assert!(acc & 0x3 == 0, "non-zero trailing bits");
+            push!(acc >> 10);
+            push!(acc >> 2);
+        }
+    }
+
+    if opts.pad {
+        let expected = if rem == 0 { 0 } else { 4 - rem };
+        assert!(pads == expected, "invalid base64 padding");
+    } else {
+        assert!(pads == 0, "unexpected base64 padding");
+    }
+
+    (buf, pos)
+}
+
+pub struct Base64Encode<T>(pub T, pub Base64Options);
+
+impl Base64Encode<&[u8]> {
+    pub const fn output_len(&self) -> usize {
+        encoded_len(self.0.len(), self.1.pad)
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let buf = encode(self.0, self.1);
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+impl<const L: usize> Base64Encode<&[u8; L]> {
+    pub const fn output_len(&self) -> usize {
+        Base64Encode(self.0.as_slice(), self.1).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        Base64Encode(self.0.as_slice(), self.1).const_eval()
+    }
+}
+
+impl Base64Encode<&str> {
+    pub const fn output_len(&self) -> usize {
+        Base64Encode(self.0.as_bytes(), self.1).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        Base64Encode(self.0.as_bytes(), self.1).const_eval()
+    }
+}
+
+pub struct Base64Decode<T>(pub T, pub Base64Options);
+
+impl Base64Decode<&[&str]> {
+    pub const fn output_len(&self) -> usize {
+        decode::<0>(self.0, self.1).1
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> [u8; N] {
+        let (buf, len) = decode::<N>(self.0, self.1);
+        assert!(len == N);
+        buf
+    }
+}
+
+impl Base64Decode<&str> {
+    pub const fn output_len(&self) -> usize {
+        let ss: &[&str] = &[self.0];
+        Base64Decode(ss, self.1).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> [u8; N] {
+        let ss: &[&str] = &[self.0];
+        Base64Decode(ss, self.1).const_eval()
+    }
+}
+
+impl<const L: usize> Base64Decode<[&str; L]> {
+    pub const fn output_len(&self) -> usize {
+        let ss: &[&str] = &self.0;
+        Base64Decode(ss, self.1).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> [u8; N] {
+        let ss: &[&str] = &self.0;
+        Base64Decode(ss, self.1).const_eval()
+    }
+}
+
+/// Encodes bytes to a base64 string slice.
+///
+/// The input type must be one of:
+/// + [`&str`](str)
+/// + [`&[u8]`](slice)
+/// + [`&[u8; N]`](array)
+///
+/// The output type is [`&str`](str).
+///
+/// The encoding can be changed by the options after the input:
+/// + `url_safe`: uses the URL and filename safe alphabet, which replaces `+` and `/` with `-` and `_`.
+/// + `no_pad`: omits the trailing `=` characters.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`base64_decode!`](crate::base64_decode).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::base64;
+///
+/// const S: &str = base64!("Hello, world!");
+/// assert_eq!(S, "SGVsbG8sIHdvcmxkIQ==");
+///
+/// const BYTES: &[u8] = &[0xfb, 0xff];
+/// assert_eq!(base64!(BYTES), "+/8=");
+/// assert_eq!(base64!(BYTES, url_safe, no_pad), "-_8");
+///
+/// const AUTHORIZATION: &str = const_str::concat!("Basic ", base64!("user:pass"));
+/// assert_eq!(AUTHORIZATION, "Basic dXNlcjpwYXNz");
+/// ```
+///
+#[macro_export]
+macro_rules! base64 {
+    ($s: expr $(, $opt: ident)* $(,)?) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::Base64Encode(
+            $s,
+            $crate::__ctfe::Base64Options::DEFAULT $(.$opt())*,
+        )
+        .output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<OUTPUT_LEN> = $crate::__ctfe::Base64Encode(
+            $s,
+            $crate::__ctfe::Base64Options::DEFAULT $(.$opt())*,
+        )
+        .const_eval();
+        OUTPUT_BUF.as_str()
+    }};
+}
+
+/// Decodes base64 string slices to a byte array.
+///
+/// The input type must be one of:
+/// + [`&str`](str)
+/// + [`&[&str]`](slice)
+/// + [`[&str; N]`](array)
+///
+/// Multiple string slices are decoded as their concatenation.
+/// The formatting characters `' '`, `'\r'`, `'\n'` and `'\t'` are ignored.
+///
+/// The input must be in the canonical form:
+/// the padding must be complete and the unused bits of the last character must be zero.
+/// The options are the same as [`base64!`](crate::base64):
+/// + `url_safe`: accepts the URL and filename safe alphabet instead of the standard one.
+/// + `no_pad`: rejects any `=` characters.
+///
+/// The compilation fails if the input is invalid.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::base64_decode;
+///
+/// const DATA: [u8; 13] = base64_decode!("SGVsbG8sIHdvcmxkIQ==");
+/// assert_eq!(&DATA, b"Hello, world!");
+///
+/// assert_eq!(base64_decode!("-_8", url_safe, no_pad), [0xfb, 0xff]);
+///
+/// const KEY: &[u8] = &base64_decode!([
+///     "AAECAwQFBgcI",
+///     "CQoLDA0ODw==",
+/// ]);
+/// assert_eq!(KEY, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+/// ```
+///
+/// ```compile_fail
+/// let _ = const_str::base64_decode!("SGVsbG8");
+/// ```
+#[macro_export]
+macro_rules! base64_decode {
+    ($s: expr $(, $opt: ident)* $(,)?) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::Base64Decode(
+            $s,
+            $crate::__ctfe::Base64Options::DEFAULT $(.$opt())*,
+        )
+        .output_len();
+        const OUTPUT_BUF: [u8; OUTPUT_LEN] = $crate::__ctfe::Base64Decode(
+            $s,
+            $crate::__ctfe::Base64Options::DEFAULT $(.$opt())*,
+        )
+        .const_eval();
+        OUTPUT_BUF
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64() {
+        macro_rules! testcase {
+            ($s: expr, $std: expr, $url_no_pad: expr) => {{
+                assert_eq!(base64!($s), $std);
+                assert_eq!(base64!($s, url_safe, no_pad), $url_no_pad);
+                assert_eq!(base64_decode!($std), *$s.as_bytes());
+                assert_eq!(
+                    base64_decode!($url_no_pad, url_safe, no_pad),
+                    *$s.as_bytes()
+                );
+            }};
+        }
+
+        testcase!("", "", "");
+        testcase!("f", "Zg==", "Zg");
+        testcase!("fo", "Zm8=", "Zm8");
+        testcase!("foo", "Zm9v", "Zm9v");
+        testcase!("foob", "Zm9vYg==", "Zm9vYg");
+        testcase!("fooba", "Zm9vYmE=", "Zm9vYmE");
+        testcase!("foobar", "Zm9vYmFy", "Zm9vYmFy");
+        testcase!("你好", "5L2g5aW9", "5L2g5aW9");
+
+        const BYTES: &[u8; 3] = &[0xfb, 0xef, 0xff];
+        assert_eq!(base64!(BYTES), "++//");
+        assert_eq!(base64!(BYTES, url_safe), "--__");
+        assert_eq!(base64!(BYTES.as_slice(), no_pad), "++//");
+        assert_eq!(base64_decode!("--__", url_safe), *BYTES);
+
+        const WS: [u8; 6] = base64_decode!(" Zm9v\r\nYmFy\t");
+        assert_eq!(&WS, b"foobar");
+
+        const PARTS: [u8; 4] = base64_decode!(["Zm", "9vYg", "=", "="]);
+        assert_eq!(&PARTS, b"foob");
+    }
+
+    #[test]
+    fn test_base64_roundtrip() {
+        const fn bytes<const N: usize>() -> [u8; N] {
+            let mut buf = [0; N];
+            let mut i = 0;
+            while i < N {
+                buf[i] = (i * 7 + 3) as u8;
+                i += 1;
+            }
+            buf
+        }
+
+        macro_rules! roundtrip {
+            ($($n: literal),*) => {$({
+                const DATA: [u8; $n] = bytes::<$n>();
+                const ENCODED: &str = base64!(&DATA);
+                const DECODED: [u8; $n] = base64_decode!(ENCODED);
+                assert_eq!(DECODED, DATA);
+
+                const URL: &str = base64!(&DATA, url_safe, no_pad);
+                const URL_DECODED: [u8; $n] = base64_decode!(URL, url_safe, no_pad);
+                assert_eq!(URL_DECODED, DATA);
+                assert_eq!(URL, ENCODED.trim_end_matches('=').replace('+', "-").replace('/', "_"));
+            })*};
+        }
+
+        roundtrip!(0, 1, 2, 3, 4, 5, 63, 64, 65, 255, 256, 257);
+    }
+
+    #[test]
+    fn test_base64_runtime() {
+        let encode = Base64Encode("foob", Base64Options::DEFAULT);
+        assert_eq!(encode.output_len(), 8);
+        let buf: StrBuf<8> = encode.const_eval();
+        assert_eq!(buf.as_str(), "Zm9vYg==");
+
+        let decode = Base64Decode("Zm9vYg", Base64Options::DEFAULT.no_pad());
+        assert_eq!(decode.output_len(), 4);
+        let buf: [u8; 4] = decode.const_eval();
+        assert_eq!(&buf, b"foob");
+    }
+
+    #[test]
+    #[should_panic = "invalid base64 character"]
+    fn test_base64_invalid_character() {
+        let _ = Base64Decode("Zm9v-A==", Base64Options::DEFAULT).output_len();
+    }
+
+    #[test]
+    #[should_panic = "invalid base64 padding"]
+    fn test_base64_missing_padding() {
+        let _ = Base64Decode("Zm9vYg", Base64Options::DEFAULT).output_len();
+    }
+
+    #[test]
+    #[should_panic = "unexpected base64 padding"]
+    fn test_base64_unexpected_padding() {
+        let _ = Base64Decode("Zg==", Base64Options::DEFAULT.no_pad()).output_len();
+    }
+
+    #[test]
+    #[should_panic = "non-zero trailing bits"]
+    fn test_base64_trailing_bits() {
+        let _ = Base64Decode("Zh==", Base64Options::DEFAULT).output_len();
+    }
+
+    #[test]
+    #[should_panic = "unexpected character after padding"]
+    fn test_base64_after_padding() {
+        let _ = Base64Decode("Zg==Zg==", Base64Options::DEFAULT).output_len();
+    }
+}
@@ -0,0 +1,696 @@
+#![allow(unsafe_code)]
+
+use super::StrBuf;
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+const GENERATOR: [u32; 5] = [
+    0x3b6a_57b2,
+    0x2650_8e6d,
+    0x1ea1_19fa,
+    0x3d42_33dd,
+    0x2a14_62b3,
+];
+
+const CHECKSUM_LEN: usize = 6;
+
+const MAX_LEN: usize = 90;
+
+#[derive(Clone, Copy)]
+pub struct Bech32Options {
+    bech32m: bool,
+}
+
+impl Bech32Options {
+    pub const DEFAULT: Self = Self { bech32m: false };
+
+    pub const fn bech32m(self) -> Self {
+        Self { bech32m: true }
+    }
+
+    const fn checksum_const(&self) -> u32 {
+        if self.bech32m {
+            0x2bc8_30a3
+        } else {
+            1
+        }
+    }
+}
+
+const fn polymod_step(chk: u32, val: u8) -> u32 {
+    let b = chk >> 25;
+    let mut chk = ((chk & 0x1ff_ffff) << 5) ^ val as u32;
+    let mut i = 0;
+    while i < 5 {
+        if (b >> i) & 1 == 1 {
+            chk ^= GENERATOR[i];
+        }
+        i += 1;
+    }
+    chk
+}
+
+/// Feeds the expanded human-readable part to the checksum.
+const fn polymod_hrp(mut chk: u32, hrp: &[u8]) -> u32 {
+    let mut i = 0;
+    while i < hrp.len() {
+        chk = polymod_step(chk, hrp[i].to_ascii_lowercase() >> 5);
+        i += 1;
+    }
+    chk = polymod_step(chk, 0);
+    let mut i = 0;
+    while i < hrp.len() {
+        chk = polymod_step(chk, hrp[i].to_ascii_lowercase() & 31);
+        i += 1;
+    }
+    chk
+}
+
+const fn check_hrp(hrp: &[u8]) {
+    assert!(
+        !hrp.is_empty() && hrp.len() <= 83,
+        "invalid bech32 hrp length"
+    );
+    let mut i = 0;
+    while i < hrp.len() {
+        assert!(matches!(hrp[i], 33..=126), "invalid bech32 hrp character");
+        i += 1;
+    }
+}
+
+const fn decode_char(b: u8) -> Option<u8> {
+    let b = b.to_ascii_lowercase();
+    let mut i = 0;
+    while i < CHARSET.len() {
+        if CHARSET[i] == b {
+            return Some(i as u8);
+        }
+        i += 1;
+    }
+    None
+}
+
+const fn encoded_len(hrp: &[u8], data: &[u8]) -> usize {
+    let len = hrp.len() + 1 + (data.len() * 8).div_ceil(5) + CHECKSUM_LEN;
+    assert!(len <= MAX_LEN, "bech32 string too long");
+    len
+}
+
+const fn encode<const N: usize>(hrp: &[u8], data: &[u8], opts: Bech32Options) -> [u8; N] {
+    check_hrp(hrp);
+    assert!(N == encoded_len(hrp, data));
+
+    let mut buf = [0; N];
+    let mut pos = 0;
+
+    let mut i = 0;
+    while i < hrp.len() {
+        buf[pos] = hrp[i].to_ascii_lowercase();
+        pos += 1;
+        i += 1;
+    }
+    buf[pos] = b'1';
+    pos += 1;
+
+    let mut chk = polymod_hrp(1, hrp);
+
+    macro_rules! push {
+        ($val: expr) => {{
+            let val = $val;
+            buf[pos] = CHARSET[val as usize];
+            pos += 1;
+            chk = polymod_step(chk, val);
+        }};
+    }
+
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    let mut i = 0;
+    while i < data.len() {
+        acc = (acc << 8) | data[i] as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            push!(((acc >> bits) & 31) as u8);
+        }
+        acc &= (1 << bits) - 1;
+        i += 1;
+    }
+    if bits > 0 {
+        push!(((acc << (5 - bits)) & 31) as u8);
+    }
+
+    let mut i = 0;
+    while i < CHECKSUM_LEN {
+        chk = polymod_step(chk, 0);
+        i += 1;
+    }
+    chk ^= opts.checksum_const();
+
+    let mut i = 0;
+    while i < CHECKSUM_LEN {
+        buf[pos] = CHARSET[((chk >> (5 * (CHECKSUM_LEN - 1 - i))) & 31) as usize];
+        pos += 1;
+        i += 1;
+    }
+
+    buf
+}
+
+/// Splits `s` into the human-readable part and the data part.
+///
+/// Returns the checksum residue, which is checked by the caller.
+const fn parse(s: &str) -> (&[u8], &[u8], u32) {
+    let s = s.as_bytes();
+    assert!(s.len() <= MAX_LEN, "bech32 string too long");
+
+    let mut has_lower = false;
+    let mut has_upper = false;
+    let mut sep = None;
+    let mut i = 0;
+    while i < s.len() {
+        let b = s[i];
+        assert!(matches!(b, 33..=126), "invalid bech32 character");
+        has_lower |= b.is_ascii_lowercase();
+        has_upper |= b.is_ascii_uppercase();
+        if b == b'1' {
+            sep = Some(i);
+        }
+        i += 1;
+    }
+    assert!(!(has_lower && has_upper), "mixed-case bech32 string");
+
+    let sep = match sep {
+        Some(sep) => sep,
+        None => panic!("missing bech32 separator"),
+    };
+    let (hrp, rest) = s.split_at(sep);
+    let data = crate::slice::advance(rest, 1);
+    check_hrp(hrp);
+    assert!(data.len() >= CHECKSUM_LEN, "bech32 data part too short");
+
+    let mut chk = polymod_hrp(1, hrp);
+    let mut i = 0;
+    while i < data.len() {
+        chk = polymod_step(chk, decode_value(data[i]));
+        i += 1;
+    }
+
+    let payload = crate::slice::subslice(data, 0..data.len() - CHECKSUM_LEN);
+    (hrp, payload, chk)
+}
+
+const fn decode_value(b: u8) -> u8 {
+    match decode_char(b) {
+        Some(val) => val,
+        None => panic!("invalid bech32 character"),
+    }
+}
+
+/// Regroups the 5-bit values of `data` into bytes in `buf`.
+///
+/// Returns the number of bytes.
+/// The bytes out of the capacity of `buf` are only counted.
+const fn regroup<const N: usize>(data: &[u8]) -> ([u8; N], usize) {
+    let mut buf = [0; N];
+    let mut pos = 0;
+
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    let mut i = 0;
+    while i < data.len() {
+        acc = (acc << 5) | decode_value(data[i]) as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            if pos < N {
+                buf[pos] = (acc >> bits) as u8;
+            }
+            pos += 1;
+            acc &= (1 << bits) - 1;
+        }
+        i += 1;
+    }
+
+    assert!(bits < 5, "invalid bech32 padding");
+    assert!(acc == 0, "non-zero trailing bits");
+
+    (buf, pos)
+}
+
+const fn decode<const N: usize>(s: &str, opts: Bech32Options) -> ([u8; N], usize) {
+    let (_, payload, chk) = parse(s);
+    assert!(chk == opts.checksum_const(), "invalid bech32 checksum");
+    regroup(payload)
+}
+
+/// Decodes a segwit address into the witness version and the witness program.
+///
+/// Version 0 uses the checksum of BIP 173, and the others use the checksum of BIP 350.
+const fn segwit_decode<const N: usize>(s: &str) -> (u8, [u8; N], usize) {
+    let (_, payload, chk) = parse(s);
+    assert!(!payload.is_empty(), "missing segwit version");
+
+    let version = decode_value(payload[0]);
+    assert!(version <= 16, "invalid segwit version");
+
+    let opts = if version == 0 {
+        Bech32Options::DEFAULT
+    } else {
+        Bech32Options::DEFAULT.bech32m()
+    };
+    assert!(chk == opts.checksum_const(), "invalid bech32 checksum");
+
+    let (program, len) = regroup(crate::slice::advance(payload, 1));
+    assert!(2 <= len && len <= 40, "invalid segwit program length");
+    assert!(
+        version != 0 || len == 20 || len == 32,
+        "invalid segwit v0 program length"
+    );
+    (version, program, len)
+}
+
+pub struct Bech32Encode<'a, T>(pub &'a str, pub T, pub Bech32Options);
+
+impl Bech32Encode<'_, &[u8]> {
+    pub const fn output_len(&self) -> usize {
+        encoded_len(self.0.as_bytes(), self.1)
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let buf = encode(self.0.as_bytes(), self.1, self.2);
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+impl<const L: usize> Bech32Encode<'_, &[u8; L]> {
+    pub const fn output_len(&self) -> usize {
+        Bech32Encode(self.0, self.1.as_slice(), self.2).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        Bech32Encode(self.0, self.1.as_slice(), self.2).const_eval()
+    }
+}
+
+impl Bech32Encode<'_, &str> {
+    pub const fn output_len(&self) -> usize {
+        Bech32Encode(self.0, self.1.as_bytes(), self.2).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        Bech32Encode(self.0, self.1.as_bytes(), self.2).const_eval()
+    }
+}
+
+pub struct Bech32Decode<'a>(pub &'a str, pub Bech32Options);
+
+impl Bech32Decode<'_> {
+    pub const fn output_len(&self) -> usize {
+        decode::<0>(self.0, self.1).1
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> [u8; N] {
+        let (buf, len) = decode::<N>(self.0, self.1);
+        assert!(len == N);
+        buf
+    }
+}
+
+pub struct SegwitDecode<'a>(pub &'a str);
+
+impl SegwitDecode<'_> {
+    pub const fn output_len(&self) -> usize {
+        segwit_decode::<0>(self.0).2
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> (u8, [u8; N]) {
+        let (version, program, len) = segwit_decode::<N>(self.0);
+        assert!(len == N);
+        (version, program)
+    }
+}
+
+/// Encodes a human-readable part and bytes to a bech32 string slice.
+///
+/// The bytes are regrouped into 5-bit values with zero padding,
+/// as in [BIP 173](https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki).
+/// The human-readable part is converted to lowercase.
+///
+/// The data type must be one of:
+/// + [`&str`](str)
+/// + [`&[u8]`](slice)
+/// + [`&[u8; N]`](array)
+///
+/// The output type is [`&str`](str).
+///
+/// The `bech32m` option after the data uses the checksum of
+/// [BIP 350](https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki).
+///
+/// The output must be at most 90 characters long.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`bech32_decode!`](crate::bech32_decode).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::bech32;
+///
+/// const ADDR: &str = bech32!("abc", b"\x00\xff");
+/// assert_eq!(ADDR, "abc1qrls2lpcdk");
+///
+/// const ADDR_M: &str = bech32!("abc", b"\x00\xff", bech32m);
+/// assert_eq!(ADDR_M, "abc1qrlslr35g5");
+/// ```
+///
+#[macro_export]
+macro_rules! bech32 {
+    ($hrp: expr, $data: expr $(, $opt: ident)* $(,)?) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::Bech32Encode(
+            $hrp,
+            $data,
+            $crate::__ctfe::Bech32Options::DEFAULT $(.$opt())*,
+        )
+        .output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<OUTPUT_LEN> = $crate::__ctfe::Bech32Encode(
+            $hrp,
+            $data,
+            $crate::__ctfe::Bech32Options::DEFAULT $(.$opt())*,
+        )
+        .const_eval();
+        OUTPUT_BUF.as_str()
+    }};
+}
+
+/// Decodes the data part of a bech32 string slice to a byte array.
+///
+/// The checksum is validated, and the 5-bit values are regrouped into bytes.
+/// The padding must be at most 4 zero bits.
+/// The input can be either all lowercase or all uppercase.
+///
+/// The input type must be [`&str`](str).
+///
+/// The `bech32m` option validates the checksum of
+/// [BIP 350](https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki) instead.
+///
+/// The input must be at most 90 characters long.
+/// The compilation fails if the input is invalid.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`segwit_decode!`](crate::segwit_decode).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::bech32_decode;
+///
+/// const DATA: [u8; 2] = bech32_decode!("abc1qrls2lpcdk");
+/// assert_eq!(DATA, [0x00, 0xff]);
+///
+/// const DATA_M: [u8; 2] = bech32_decode!("ABC1QRLSLR35G5", bech32m);
+/// assert_eq!(DATA_M, [0x00, 0xff]);
+/// ```
+///
+/// ```compile_fail
+/// // invalid bech32 checksum
+/// let _ = const_str::bech32_decode!("abc1qrls2lpcdj");
+/// ```
+#[macro_export]
+macro_rules! bech32_decode {
+    ($s: expr $(, $opt: ident)* $(,)?) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::Bech32Decode(
+            $s,
+            $crate::__ctfe::Bech32Options::DEFAULT $(.$opt())*,
+        )
+        .output_len();
+        const OUTPUT_BUF: [u8; OUTPUT_LEN] = $crate::__ctfe::Bech32Decode(
+            $s,
+            $crate::__ctfe::Bech32Options::DEFAULT $(.$opt())*,
+        )
+        .const_eval();
+        OUTPUT_BUF
+    }};
+}
+
+/// Decodes a segwit address to a witness version and a witness program.
+///
+/// The address is decoded as in
+/// [BIP 173](https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki)
+/// and [BIP 350](https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki):
+/// + The first 5-bit value is the witness version, which must be at most 16.
+/// + The checksum of version 0 is bech32, and the checksum of the other versions is bech32m.
+/// + The witness program must be 2 to 40 bytes, and 20 or 32 bytes for version 0.
+///
+/// The human-readable part is not checked.
+///
+/// The input type must be [`&str`](str).
+///
+/// The output type is `(u8, [u8; N])`.
+///
+/// The compilation fails if the input is invalid.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::{hex, segwit_decode};
+///
+/// const ADDR: (u8, [u8; 20]) = segwit_decode!("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4");
+/// assert_eq!(ADDR, (0, hex!("751e76e8199196d454941c45d1b3a323f1433bd6")));
+/// ```
+///
+/// ```compile_fail
+/// // version 1 with the bech32 checksum
+/// let _ = const_str::segwit_decode!(
+///     "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd"
+/// );
+/// ```
+#[macro_export]
+macro_rules! segwit_decode {
+    ($s: expr) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::SegwitDecode($s).output_len();
+        const OUTPUT: (u8, [u8; OUTPUT_LEN]) = $crate::__ctfe::SegwitDecode($s).const_eval();
+        OUTPUT
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bech32() {
+        // test vectors from BIP 173 and BIP 350
+        assert_eq!(bech32!("a", b""), "a12uel5l");
+        assert_eq!(bech32!("A", b""), "a12uel5l");
+        assert_eq!(bech32!("a", b"", bech32m), "a1lqfn3a");
+
+        const DATA: [u8; 0] = bech32_decode!("A12UEL5L");
+        assert_eq!(DATA, []);
+        const DATA_M: [u8; 0] = bech32_decode!("A1LQFN3A", bech32m);
+        assert_eq!(DATA_M, []);
+
+        const ASCENDING: &[u8; 20] = &crate::hex!("00443214c74254b635cf84653a56d7c675be77df");
+        const DESCENDING: &[u8; 20] = &crate::hex!("ffbbcdeb38bdab49ca307b9ac5a928398a418820");
+
+        const S1: &str = bech32!("abcdef", ASCENDING);
+        assert_eq!(S1, "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw");
+        assert_eq!(bech32_decode!(S1), *ASCENDING);
+
+        const S2: &str = bech32!("abcdef", DESCENDING, bech32m);
+        assert_eq!(S2, "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx");
+        assert_eq!(bech32_decode!(S2, bech32m), *DESCENDING);
+
+        const S3: &str = "split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w";
+        assert_eq!(Bech32Decode(S3, Bech32Options::DEFAULT).output_len(), 30);
+
+        const S4: &str = bech32!("npub", "hello");
+        assert_eq!(bech32_decode!(S4), *b"hello");
+
+        let decode = Bech32Decode("?1ezyfcl", Bech32Options::DEFAULT);
+        assert_eq!(decode.output_len(), 0);
+    }
+
+    #[test]
+    #[should_panic = "invalid bech32 checksum"]
+    fn test_bech32_invalid_checksum() {
+        let _ = Bech32Decode("a1lqfn3a", Bech32Options::DEFAULT).output_len();
+    }
+
+    #[test]
+    #[should_panic = "mixed-case bech32 string"]
+    fn test_bech32_mixed_case() {
+        let _ = Bech32Decode("A12uel5l", Bech32Options::DEFAULT).output_len();
+    }
+
+    #[test]
+    #[should_panic = "missing bech32 separator"]
+    fn test_bech32_missing_separator() {
+        let _ = Bech32Decode("pzry9x0s0muk", Bech32Options::DEFAULT).output_len();
+    }
+
+    #[test]
+    #[should_panic = "invalid bech32 hrp length"]
+    fn test_bech32_empty_hrp() {
+        let _ = Bech32Decode("1pzry9x0s0muk", Bech32Options::DEFAULT).output_len();
+    }
+
+    #[test]
+    fn test_bech32_max_len() {
+        const HRP: &str =
+            "an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio";
+        const S: &str = bech32!(HRP, b"");
+        assert_eq!(
+            S,
+            concat!(
+            "an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio",
+            "1tt5tgs"
+        )
+        );
+        assert_eq!(S.len(), 90);
+        assert_eq!(Bech32Decode(S, Bech32Options::DEFAULT).output_len(), 0);
+    }
+
+    #[test]
+    #[should_panic = "bech32 string too long"]
+    fn test_bech32_decode_too_long() {
+        let s = "an84characterslonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1569pvx";
+        let _ = Bech32Decode(s, Bech32Options::DEFAULT).output_len();
+    }
+
+    #[test]
+    #[should_panic = "bech32 string too long"]
+    fn test_bech32_encode_too_long() {
+        let _ = Bech32Encode("bc", &[0u8; 65], Bech32Options::DEFAULT).output_len();
+    }
+
+    #[test]
+    fn test_segwit() {
+        // valid addresses from BIP 173 and BIP 350
+        const A1: (u8, [u8; 20]) = segwit_decode!("BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4");
+        assert_eq!(
+            A1,
+            (0, crate::hex!("751e76e8199196d454941c45d1b3a323f1433bd6"))
+        );
+
+        const A2: (u8, [u8; 32]) =
+            segwit_decode!("tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7");
+        assert_eq!(
+            A2,
+            (
+                0,
+                crate::hex!("1863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262")
+            )
+        );
+
+        const A3: (u8, [u8; 40]) = segwit_decode!(
+            "bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7kt5nd6y"
+        );
+        assert_eq!(
+            A3,
+            (
+                1,
+                crate::hex!([
+                    "751e76e8199196d454941c45d1b3a323f1433bd6",
+                    "751e76e8199196d454941c45d1b3a323f1433bd6",
+                ])
+            )
+        );
+
+        const A4: (u8, [u8; 2]) = segwit_decode!("BC1SW50QGDZ25J");
+        assert_eq!(A4, (16, [0x75, 0x1e]));
+
+        const A5: (u8, [u8; 16]) = segwit_decode!("bc1zw508d6qejxtdg4y5r3zarvaryvaxxpcs");
+        assert_eq!(A5, (2, crate::hex!("751e76e8199196d454941c45d1b3a323")));
+
+        const A6: (u8, [u8; 32]) =
+            segwit_decode!("tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c");
+        assert_eq!(
+            A6,
+            (
+                1,
+                crate::hex!("000000c4a5cad46221b2a187905e5266362b99d5e91c6ce24d165dab93e86433")
+            )
+        );
+
+        const A7: (u8, [u8; 32]) =
+            segwit_decode!("bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0");
+        assert_eq!(
+            A7,
+            (
+                1,
+                crate::hex!("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+            )
+        );
+    }
+
+    #[test]
+    fn test_segwit_invalid() {
+        // invalid addresses from BIP 173 and BIP 350
+        macro_rules! testcase {
+            ($s: expr, $msg: expr) => {{
+                let result = std::panic::catch_unwind(|| SegwitDecode($s).output_len());
+                let err = result.unwrap_err();
+                let err = err
+                    .downcast_ref::<&str>()
+                    .copied()
+                    .unwrap_or_else(|| err.downcast_ref::<String>().unwrap().as_str());
+                assert_eq!(err, $msg, "{}", $s);
+            }};
+        }
+
+        let checksum = "invalid bech32 checksum";
+        testcase!(
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd",
+            checksum
+        );
+        testcase!(
+            "tb1z0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqglt7rf",
+            checksum
+        );
+        testcase!(
+            "BC1S0XLXVLHEMJA6C4DQV22UAPCTQUPFHLXM9H8Z3K2E72Q4K9HCZ7VQ54WELL",
+            checksum
+        );
+        testcase!("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kemeawh", checksum);
+        testcase!(
+            "tb1q0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq24jc47",
+            checksum
+        );
+
+        testcase!("bc1gmk9yu", "missing segwit version");
+        testcase!(
+            "BC130XLXVLHEMJA6C4DQV22UAPCTQUPFHLXM9H8Z3K2E72Q4K9HCZ7VQ7ZWS8R",
+            "invalid segwit version"
+        );
+        testcase!("bc1pw5dgrnzv", "invalid segwit program length");
+        testcase!(
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7v8n0nx0muaewav253zgeav",
+            "invalid segwit program length"
+        );
+        testcase!(
+            "BC1QR508D6QEJXTDG4Y5R3ZARVARYV98GJ9P",
+            "invalid segwit v0 program length"
+        );
+        testcase!(
+            "bc1p38j9r5y49hruaue7wxjce0updqjuyyx0kh56v8s25huc6995vvpql3jow4",
+            "invalid bech32 character"
+        );
+        testcase!(
+            "tb1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq47Zagq",
+            "mixed-case bech32 string"
+        );
+        testcase!(
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7v07qwwzcrf",
+            "invalid bech32 padding"
+        );
+        testcase!(
+            "tb1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vpggkg4j",
+            "non-zero trailing bits"
+        );
+    }
+}
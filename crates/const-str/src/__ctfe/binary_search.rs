@@ -0,0 +1,129 @@
+use super::{SortKey, SortOptions};
+
+use core::cmp::Ordering;
+
+pub struct BinarySearch<T, K>(pub T, pub K, pub SortOptions);
+
+macro_rules! impl_binary_search {
+    ($ty: ty $(, $lt: lifetime)?) => {
+        impl<$($lt)?> BinarySearch<&[$ty], $ty> {
+            pub const fn const_eval(&self) -> Result<usize, usize> {
+                let s = self.0;
+                let mut lo = 0;
+                let mut hi = s.len();
+                while lo < hi {
+                    let mid = lo + (hi - lo) / 2;
+                    match SortKey(s[mid]).compare(self.1, self.2) {
+                        Ordering::Less => lo = mid + 1,
+                        Ordering::Greater => hi = mid,
+                        Ordering::Equal => return Ok(mid),
+                    }
+                }
+                Err(lo)
+            }
+        }
+
+        impl<$($lt,)? const L: usize> BinarySearch<[$ty; L], $ty> {
+            pub const fn const_eval(&self) -> Result<usize, usize> {
+                BinarySearch(self.0.as_slice(), self.1, self.2).const_eval()
+            }
+        }
+
+        impl<$($lt,)? const L: usize> BinarySearch<&[$ty; L], $ty> {
+            pub const fn const_eval(&self) -> Result<usize, usize> {
+                BinarySearch(self.0.as_slice(), self.1, self.2).const_eval()
+            }
+        }
+    };
+}
+
+impl_binary_search!(&'a str, 'a);
+impl_binary_search!(&'a [u8], 'a);
+impl_binary_search!(u8);
+impl_binary_search!(u16);
+impl_binary_search!(u32);
+impl_binary_search!(u64);
+impl_binary_search!(u128);
+impl_binary_search!(usize);
+impl_binary_search!(i8);
+impl_binary_search!(i16);
+impl_binary_search!(i32);
+impl_binary_search!(i64);
+impl_binary_search!(i128);
+impl_binary_search!(isize);
+
+/// Binary searches a sorted array or slice for a given key.
+///
+/// Returns [`Ok`] with the index of a matching item,
+/// or [`Err`] with the index where the key could be inserted to keep the order.
+/// If there are multiple matches, any one of them may be returned.
+///
+/// The input types and options are the same as [`sorted!`](crate::sorted).
+/// The table must be sorted with the same options.
+///
+/// This macro is [const-fn compatible](./index.html#const-fn-compatible).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::{binary_search, sorted};
+///
+/// const KEYWORDS: &[&str] = &sorted!(["while", "fn", "let", "if", "else"]);
+///
+/// const fn is_keyword(s: &str) -> bool {
+///     binary_search!(KEYWORDS, s).is_ok()
+/// }
+///
+/// assert!(is_keyword("let"));
+/// assert!(!is_keyword("loop"));
+///
+/// const _: () = assert!(matches!(binary_search!(KEYWORDS, "for"), Err(2)));
+///
+/// const VERSIONS: &[&str] = &sorted!(["v10", "v9", "v1"], natural);
+/// const _: () = assert!(matches!(binary_search!(VERSIONS, "v9", natural), Ok(1)));
+/// ```
+#[macro_export]
+macro_rules! binary_search {
+    ($s: expr, $key: expr $(, $opt: ident)* $(,)?) => {
+        $crate::__ctfe::BinarySearch($s, $key, $crate::__ctfe::SortOptions::DEFAULT $(.$opt())*)
+            .const_eval()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_binary_search() {
+        const XS: &[&str] = &["a", "c", "e", "g"];
+        const R: Result<usize, usize> = binary_search!(XS, "e");
+        assert_eq!(R, Ok(2));
+
+        for key in ["", "a", "b", "c", "d", "e", "f", "g", "h", "aa"] {
+            assert_eq!(binary_search!(XS, key), XS.binary_search(&key), "{key:?}");
+        }
+
+        let empty: &[&str] = &[];
+        assert_eq!(binary_search!(empty, "a"), Err(0));
+
+        let ci = ["a", "B", "c"];
+        assert_eq!(binary_search!(ci, "b", ignore_ascii_case), Ok(1));
+        assert_eq!(binary_search!(&ci, "C", ignore_ascii_case), Ok(2));
+
+        let natural = ["1", "2", "10", "20"];
+        assert_eq!(binary_search!(&natural, "10", natural), Ok(2));
+        assert_eq!(binary_search!(&natural, "3", natural), Err(2));
+
+        let rev = [9_u32, 5, 3, 1];
+        assert_eq!(binary_search!(rev, 5, reverse), Ok(1));
+        assert_eq!(binary_search!(rev, 4, reverse), Err(2));
+
+        let ints: &[i64] = &[-5, 0, 5];
+        for key in -6..=6 {
+            assert_eq!(binary_search!(ints, key), ints.binary_search(&key));
+        }
+
+        let bytes: &[&[u8]] = &[b"GET", b"POST", b"PUT"];
+        assert_eq!(binary_search!(bytes, b"PUT".as_slice()), Ok(2));
+        assert_eq!(binary_search!(bytes, b"HEAD".as_slice()), Err(1));
+    }
+}
@@ -0,0 +1,354 @@
+use super::{AsciiCase, CaseOptions, StrBuf};
+
+pub struct ConvCase<T>(pub T, pub AsciiCase);
+
+impl ConvCase<&str> {
+    pub const fn output_len(&self) -> usize {
+        self.1.unicode_output_len(self.0, CaseOptions::DEFAULT)
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        self.1
+            .unicode_convert::<N>(self.0, CaseOptions::DEFAULT)
+            .into_str_buf()
+    }
+}
+
+impl ConvCase<(&str, CaseOptions<'_>)> {
+    pub const fn output_len(&self) -> usize {
+        let (s, opts) = self.0;
+        self.1.unicode_output_len(s, opts)
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let (s, opts) = self.0;
+        self.1.unicode_convert::<N>(s, opts).into_str_buf()
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __conv_case {
+    ($s: expr, $case: expr) => {{
+        const INPUT: &str = $s;
+        const N: usize = $crate::__ctfe::ConvCase(INPUT, $case).output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<N> =
+            $crate::__ctfe::ConvCase(INPUT, $case).const_eval::<N>();
+        OUTPUT_BUF.as_str()
+    }};
+    ($s: expr, $case: expr, $opts: expr) => {{
+        const INPUT: &str = $s;
+        const N: usize = $crate::__ctfe::ConvCase((INPUT, $opts), $case).output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<N> =
+            $crate::__ctfe::ConvCase((INPUT, $opts), $case).const_eval::<N>();
+        OUTPUT_BUF.as_str()
+    }};
+}
+
+/// Converts a string slice to a specified case, with full Unicode case mapping.
+///
+/// Words are split by the same rules as [`convert_ascii_case!`](crate::convert_ascii_case),
+/// except that non-ASCII characters are classified by their Unicode properties:
+/// uppercase and lowercase letters split words like ASCII letters,
+/// and characters which are neither alphanumeric nor combining marks are separators.
+/// Runs of `.` and `.` at the end are also separators, as in the `heck` crate,
+/// so `"a..b"` becomes `"a_b"` in snake case.
+/// Otherwise the result is the same as [`convert_ascii_case!`](crate::convert_ascii_case) for ASCII input.
+///
+/// The first character of a capitalized word is converted to titlecase.
+/// `'Σ'` is converted to `'ς'` at the end of a word.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// The `case` feature is no longer needed and is deprecated. It does nothing.
+///
+/// An optional third argument of type [`CaseOptions`](crate::ascii_case::CaseOptions)
+/// adjusts the word boundary rules.
+///
+/// # Examples
+///
+/// ```
+/// use const_str::convert_case;
+///
+/// const S1: &str = convert_case!(lower, "Lower Case");
+/// const S2: &str = convert_case!(upper, "Upper Case");
+/// const S3: &str = convert_case!(lower_camel, "lower camel case");
+/// const S4: &str = convert_case!(upper_camel, "upper camel case");
+/// const S5: &str = convert_case!(title, "title case");
+/// const S6: &str = convert_case!(train, "train case");
+/// const S7: &str = convert_case!(snake, "snake case");
+/// const S8: &str = convert_case!(kebab, "kebab case");
+/// const S9: &str = convert_case!(shouty_snake, "shouty snake case");
+/// const S10: &str = convert_case!(shouty_kebab, "shouty kebab case");
+/// const S11: &str = convert_case!(cobol, "cobol case");
+/// const S12: &str = convert_case!(dot, "dot case");
+/// const S13: &str = convert_case!(path, "path case");
+/// const S14: &str = convert_case!(sentence, "sentence case");
+/// const S15: &str = convert_case!(ada, "ada case");
+/// const S16: &str = convert_case!(flat, "flat case");
+/// const S17: &str = convert_case!(upper_flat, "upper flat case");
+///
+/// assert_eq!(S1, "lower case");
+/// assert_eq!(S2, "UPPER CASE");
+/// assert_eq!(S3, "lowerCamelCase");
+/// assert_eq!(S4, "UpperCamelCase");
+/// assert_eq!(S5, "Title Case");
+/// assert_eq!(S6, "Train-Case");
+/// assert_eq!(S7, "snake_case");
+/// assert_eq!(S8, "kebab-case");
+/// assert_eq!(S9, "SHOUTY_SNAKE_CASE");
+/// assert_eq!(S10, "SHOUTY-KEBAB-CASE");
+/// assert_eq!(S11, "COBOL-CASE");
+/// assert_eq!(S12, "dot.case");
+/// assert_eq!(S13, "path/case");
+/// assert_eq!(S14, "Sentence case");
+/// assert_eq!(S15, "Ada_Case");
+/// assert_eq!(S16, "flatcase");
+/// assert_eq!(S17, "UPPERFLATCASE");
+/// ```
+///
+/// Non-ASCII input and non-literal arguments:
+///
+/// ```
+/// use const_str::{concat, convert_case};
+///
+/// const A: &str = "straße";
+/// const B: &str = "ΌΡΟΣ";
+///
+/// const S1: &str = convert_case!(shouty_snake, concat!(A, " ", B));
+/// const S2: &str = convert_case!(upper_camel, concat!(A, "_", B));
+/// const S3: &str = convert_case!(lower, B);
+///
+/// assert_eq!(S1, "STRASSE_ΌΡΟΣ");
+/// assert_eq!(S2, "StraßeΌρος");
+/// assert_eq!(S3, "όρος");
+/// ```
+#[macro_export]
+macro_rules! convert_case {
+    (lower, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::Lower $(, $opts)?)
+    };
+    (upper, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::Upper $(, $opts)?)
+    };
+    (lower_camel, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::LowerCamel $(, $opts)?)
+    };
+    (upper_camel, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::UpperCamel $(, $opts)?)
+    };
+    (title, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::Title $(, $opts)?)
+    };
+    (train, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::Train $(, $opts)?)
+    };
+    (snake, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::Snake $(, $opts)?)
+    };
+    (kebab, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::Kebab $(, $opts)?)
+    };
+    (shouty_snake, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::ShoutySnake $(, $opts)?)
+    };
+    (shouty_kebab, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::ShoutyKebab $(, $opts)?)
+    };
+    (cobol, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::ShoutyKebab $(, $opts)?)
+    };
+    (dot, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::Dot $(, $opts)?)
+    };
+    (path, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::Path $(, $opts)?)
+    };
+    (sentence, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::Sentence $(, $opts)?)
+    };
+    (ada, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::Ada $(, $opts)?)
+    };
+    (flat, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::Flat $(, $opts)?)
+    };
+    (upper_flat, $s: expr $(, $opts: expr)?) => {
+        $crate::__conv_case!($s, $crate::__ctfe::AsciiCase::UpperFlat $(, $opts)?)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ascii_case::CaseOptions;
+
+    #[test]
+    fn test_convert_case() {
+        macro_rules! testcase {
+            ($v: tt, $a: expr, $b: expr) => {{
+                const A: &str = $a;
+                const B: &str = convert_case!($v, A);
+                assert_eq!(B, $b);
+            }};
+        }
+
+        macro_rules! testcase_std {
+            ($a: expr) => {{
+                const A: &str = $a;
+                assert_eq!(convert_case!(lower, A), A.to_lowercase());
+                assert_eq!(convert_case!(upper, A), A.to_uppercase());
+            }};
+        }
+
+        testcase_std!("ΌΣΟΣ Σ ΑΣ'Σ ΣΑ.Σ");
+        testcase_std!("straße ﬁx ŉ İstanbul");
+        testcase_std!("ǅemal ᾼ ΐ Ԁ Ꙁ");
+
+        testcase!(lower, "ΌΣΟΣ Σ ΑΣ'Σ", "όσος σ ασ'ς");
+        testcase!(upper, "straße ﬁx ŉ", "STRASSE FIX ʼN");
+        testcase!(snake, "ΑβΓδ", "αβ_γδ");
+        testcase!(snake, "ÉcoleNormale—Supérieure", "école_normale_supérieure");
+        testcase!(snake, "我的Name", "我的_name");
+        testcase!(snake, "e\u{301}Clair", "e\u{301}_clair");
+        testcase!(kebab, "ΌΡΟΣ_ΚΑΙ_ΛΟΓΟΣ", "όρος-και-λογος");
+        testcase!(upper_camel, "ǆemal ßar", "ǅemalSsar");
+        testcase!(lower_camel, "ǆemal ßar", "ǆemalSsar");
+        testcase!(title, "İstanbul straße", "İstanbul Straße");
+        testcase!(train, "журнал проверка", "Журнал-Проверка");
+        testcase!(shouty_kebab, "Größe  Maß", "GRÖSSE-MASS");
+        testcase!(sentence, "ǅEMAL ŒUVRE", "ǅemal œuvre");
+        testcase!(ada, "ǆemal œuvre", "ǅemal_Œuvre");
+        testcase!(upper_flat, "straße-öl", "STRASSEÖL");
+        testcase!(path, "ΌροςΚαι", "όρος/και");
+        testcase!(shouty_snake, "", "");
+        testcase!(snake, "…", "");
+        testcase!(snake, "a..b", "a_b");
+        testcase!(upper_camel, "a..b", "AB");
+        testcase!(kebab, "v1.2.", "v1-2");
+    }
+
+    /// Converts cases like `heck`, which was used by `convert_case!` before.
+    fn heck_convert(case: &str, s: &str) -> String {
+        use heck::*;
+
+        match case {
+            "lower" => s.to_lowercase(),
+            "upper" => s.to_uppercase(),
+            "lower_camel" => s.to_lower_camel_case(),
+            "upper_camel" => s.to_upper_camel_case(),
+            "title" => s.to_title_case(),
+            "train" => s.to_train_case(),
+            "snake" => s.to_snake_case(),
+            "kebab" => s.to_kebab_case(),
+            "shouty_snake" => s.to_shouty_snake_case(),
+            "shouty_kebab" | "cobol" => s.to_shouty_kebab_case(),
+            "dot" => s.to_snake_case().replace('_', "."),
+            "path" => s.to_snake_case().replace('_', "/"),
+            "sentence" => {
+                let s = s.to_snake_case().replace('_', " ");
+                let mut chars = s.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => s,
+                }
+            }
+            "ada" => s.to_title_case().replace(' ', "_"),
+            "flat" => s.to_snake_case().replace('_', ""),
+            "upper_flat" => s.to_shouty_snake_case().replace('_', ""),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_case_corpus() {
+        macro_rules! testcase {
+            ($s: literal) => {
+                testcase!(
+                    $s,
+                    [
+                        lower, upper, lower_camel, upper_camel, title, train,
+                        snake, kebab, shouty_snake, shouty_kebab, cobol,
+                        dot, path, sentence, ada, flat, upper_flat
+                    ]
+                )
+            };
+            ($s: literal, [$($v: tt),+]) => {{
+                const S: &str = $s;
+                // runs of `.` and `.` at the end are only separators in `convert_case!`
+                let same_as_ascii = !S.contains("..") && !S.ends_with('.');
+                $(
+                    let ans = convert_case!($v, S);
+                    if same_as_ascii {
+                        assert_eq!(ans, crate::convert_ascii_case!($v, S));
+                    }
+                    assert_eq!(ans, heck_convert(stringify!($v), S), "{}", stringify!($v));
+                )+
+            }};
+        }
+
+        macro_rules! corpus {
+            ($($s: literal),+) => {
+                $(testcase!($s);)+
+            };
+        }
+
+        corpus!(
+            "",
+            "_",
+            "a",
+            "hello world",
+            "HelloWorld",
+            "helloWorld",
+            "XMLHttpRequest",
+            "  hello world  ",
+            "__a__b-c__d__",
+            "snake_case_input",
+            "kebab-case-input",
+            "SHOUTY_SNAKE_INPUT",
+            "Title Case Input",
+            "futures-core123",
+            "version2 alpha",
+            "Hello World123!XMLHttp4t5.c6.7b.8",
+            "1.2E3",
+            "a..b",
+            "b.8",
+            "a...b",
+            ".a.",
+            "a.b.",
+            "1..2",
+            "ab..CD"
+        );
+    }
+
+    #[test]
+    fn test_convert_case_runtime() {
+        use crate::__ctfe::{AsciiCase, ConvCase, StrBuf};
+
+        let conv = ConvCase("ΌΡΟΣ ΚΑΙ", AsciiCase::UpperCamel);
+        assert_eq!(conv.output_len(), 14);
+        let buf: StrBuf<14> = conv.const_eval();
+        assert_eq!(buf.as_str(), "ΌροςΚαι");
+
+        let opts = CaseOptions {
+            split_digits: true,
+            ..CaseOptions::DEFAULT
+        };
+        let conv = ConvCase(("Ωmega2Σ", opts), AsciiCase::Snake);
+        assert_eq!(conv.output_len(), 11);
+        let buf: StrBuf<11> = conv.const_eval();
+        assert_eq!(buf.as_str(), "ωmega_2_σ");
+    }
+
+    #[test]
+    fn test_convert_case_options() {
+        const OPTIONS: CaseOptions = CaseOptions {
+            dot_as_separator: true,
+            acronyms: &["ΌΡΟΣ"],
+            ..CaseOptions::DEFAULT
+        };
+        const S1: &str = convert_case!(snake, "serde.json.Value", OPTIONS);
+        const S2: &str = convert_case!(upper_camel, "ΌΡΟΣΚαι", OPTIONS);
+        assert_eq!(S1, "serde_json_value");
+        assert_eq!(S2, "ΌροςΚαι");
+    }
+}
@@ -1,5 +1,9 @@
 #![allow(unsafe_code)]
 
+use core::fmt;
+
+use super::{Encode, Utf16Encoder};
+
 pub struct ToCStr<T>(pub T);
 
 impl ToCStr<&str> {
@@ -34,6 +38,119 @@ impl ToCStr<&str> {
     }
 }
 
+pub struct ToWCStr<T>(pub T);
+
+impl ToWCStr<&str> {
+    const fn check_nul(&self) {
+        let bytes = self.0.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == 0 {
+                const_panic!("interior nul character at offset ", usize: i);
+            }
+            i += 1;
+        }
+    }
+
+    pub const fn output_len(&self) -> usize {
+        self.check_nul();
+        crate::utf16::str_len_utf16(self.0) + 1
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> [u16; N] {
+        self.check_nul();
+        Encode(
+            self.0,
+            Utf16Encoder {
+                nul_terminated: true,
+            },
+        )
+        .const_eval()
+    }
+}
+
+/// A borrowed nul-terminated UTF-16 string, the wide counterpart of [`CStr`](core::ffi::CStr).
+///
+/// It is guaranteed to end with a nul code unit and to contain no other nul code units.
+/// The code units are not required to be valid UTF-16.
+///
+/// See also [`wcstr!`](crate::wcstr).
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct WStr([u16]);
+
+impl WStr {
+    /// Wraps code units that end with the only nul code unit.
+    ///
+    /// Returns `None` if the last code unit is not nul or if there is an interior nul.
+    pub const fn from_units_with_nul(units: &[u16]) -> Option<&Self> {
+        if units.is_empty() || units[units.len() - 1] != 0 {
+            return None;
+        }
+        let mut i = 0;
+        while i < units.len() - 1 {
+            if units[i] == 0 {
+                return None;
+            }
+            i += 1;
+        }
+        Some(unsafe { Self::from_units_with_nul_unchecked(units) })
+    }
+
+    /// Wraps code units without checking the nul code units.
+    ///
+    /// # Safety
+    /// The last code unit must be nul and the others must be non-nul.
+    pub const unsafe fn from_units_with_nul_unchecked(units: &[u16]) -> &Self {
+        unsafe { &*(units as *const [u16] as *const Self) }
+    }
+
+    /// Returns a pointer to the first code unit, which is suitable for `LPCWSTR` parameters.
+    pub const fn as_ptr(&self) -> *const u16 {
+        self.0.as_ptr()
+    }
+
+    /// Returns the number of code units, excluding the trailing nul.
+    pub const fn len(&self) -> usize {
+        self.0.len() - 1
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the code units, excluding the trailing nul.
+    pub const fn as_units(&self) -> &[u16] {
+        crate::slice::subslice(&self.0, 0..self.len())
+    }
+
+    /// Returns the code units, including the trailing nul.
+    pub const fn as_units_with_nul(&self) -> &[u16] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for WStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        f.write_char('"')?;
+        for ch in char::decode_utf16(self.as_units().iter().copied()) {
+            let ch = ch.unwrap_or(char::REPLACEMENT_CHARACTER);
+            for e in ch.escape_debug() {
+                f.write_char(e)?;
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+impl AsRef<[u16]> for WStr {
+    fn as_ref(&self) -> &[u16] {
+        self.as_units()
+    }
+}
+
 /// Converts a string slice to [`*const c_char`](core::ffi::c_char).
 ///
 /// The C-style string is guaranteed to be terminated by a nul byte.
@@ -89,6 +206,70 @@ macro_rules! cstr {
     }};
 }
 
+/// Converts a string slice to a nul-terminated UTF-16 pointer.
+///
+/// The wide string is guaranteed to be terminated by a nul code unit.
+/// This trailing nul code unit will be appended by this macro.
+/// The compilation fails if the provided data contains a nul character.
+///
+/// The pointer can be passed as `LPCWSTR` to Windows APIs.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`wcstr!`](crate::wcstr)
+///
+/// # Examples
+///
+/// ```
+/// const TITLE: *const u16 = const_str::raw_wcstr!("Hello");
+/// ```
+#[macro_export]
+macro_rules! raw_wcstr {
+    ($s: expr) => {
+        $crate::wcstr!($s).as_ptr()
+    };
+}
+
+/// Converts a string slice to [`&WStr`](crate::ffi::WStr), a nul-terminated UTF-16 string.
+///
+/// The wide string is guaranteed to be terminated by a nul code unit.
+/// This trailing nul code unit will be appended by this macro.
+/// The compilation fails if the provided data contains a nul character.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`raw_wcstr!`](crate::raw_wcstr), [`encode_z!`](crate::encode_z).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::{ffi::WStr, wcstr};
+///
+/// const CLASS_NAME: &WStr = wcstr!("MyWindow😀");
+/// assert_eq!(CLASS_NAME.len(), 10);
+/// assert_eq!(CLASS_NAME.as_units_with_nul().last(), Some(&0));
+///
+/// let ptr: *const u16 = CLASS_NAME.as_ptr();
+/// ```
+///
+/// ```compile_fail
+/// // interior nul character
+/// const S: &const_str::ffi::WStr = const_str::wcstr!("a\0b");
+/// ```
+#[macro_export]
+macro_rules! wcstr {
+    ($s: expr) => {{
+        const OUTPUT_LEN: ::core::primitive::usize = $crate::__ctfe::ToWCStr($s).output_len();
+        const OUTPUT_BUF: [u16; OUTPUT_LEN] = $crate::__ctfe::ToWCStr($s).const_eval();
+        const OUTPUT: &$crate::ffi::WStr = match $crate::ffi::WStr::from_units_with_nul(&OUTPUT_BUF)
+        {
+            ::core::option::Option::Some(s) => s,
+            ::core::option::Option::None => ::core::unreachable!(),
+        };
+        OUTPUT
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,4 +304,42 @@ mod tests {
         let buf3: [u8; 12] = to_cstr_long.const_eval();
         assert_eq!(&buf3, b"test string\0");
     }
+
+    #[test]
+    fn test_wcstr() {
+        const S: &str = "abc你好𤭢";
+        const W: &WStr = wcstr!(S);
+        let mut units = S.encode_utf16().collect::<Vec<_>>();
+        assert_eq!(W.as_units(), units.as_slice());
+        assert_eq!(W.len(), 7);
+        assert!(!W.is_empty());
+        units.push(0);
+        assert_eq!(W.as_units_with_nul(), units.as_slice());
+        assert_eq!(format!("{W:?}"), "\"abc你好𤭢\"");
+
+        const EMPTY: &WStr = wcstr!("");
+        assert!(EMPTY.is_empty());
+        assert_eq!(EMPTY.as_units_with_nul(), [0]);
+
+        let ptr = raw_wcstr!("hi");
+        let units: &[u16] = unsafe { core::slice::from_raw_parts(ptr, 3) };
+        assert_eq!(units, [b'h' as u16, b'i' as u16, 0]);
+    }
+
+    #[test]
+    fn test_wstr_from_units() {
+        assert!(WStr::from_units_with_nul(&[]).is_none());
+        assert!(WStr::from_units_with_nul(&[0x61]).is_none());
+        assert!(WStr::from_units_with_nul(&[0x61, 0, 0x62, 0]).is_none());
+
+        let w = WStr::from_units_with_nul(&[0x61, 0xd800, 0]).unwrap();
+        assert_eq!(w.len(), 2);
+        assert_eq!(format!("{w:?}"), "\"a\u{fffd}\"");
+    }
+
+    #[test]
+    #[should_panic = "interior nul character at offset 3"]
+    fn test_wcstr_interior_nul() {
+        let _ = ToWCStr("abc\0").output_len();
+    }
 }
@@ -0,0 +1,291 @@
+#![allow(unsafe_code)]
+
+use super::StrBuf;
+
+use crate::utf8::CharEncodeUtf8;
+
+pub struct Utf16Decoder;
+
+pub struct Utf32Decoder;
+
+pub struct Decode<T, D>(pub T, pub D);
+
+macro_rules! push_char {
+    ($buf: ident, $pos: ident, $ch: expr) => {{
+        let e = CharEncodeUtf8::new($ch);
+        let bytes = e.as_bytes();
+        let mut k = 0;
+        while k < bytes.len() {
+            if $pos < N {
+                $buf[$pos] = bytes[k];
+            }
+            $pos += 1;
+            k += 1;
+        }
+    }};
+}
+
+/// Decodes UTF-16 code units into UTF-8 bytes.
+///
+/// Returns the number of decoded bytes.
+/// The bytes out of the capacity of `buf` are only counted.
+const fn decode_utf16<const N: usize>(units: &[u16]) -> ([u8; N], usize) {
+    let mut buf = [0; N];
+    let mut pos = 0;
+
+    let mut i = 0;
+    while i < units.len() {
+        let unit = units[i] as u32;
+        let code = match unit {
+            0xD800..=0xDBFF => {
+                let low = if i + 1 < units.len() {
+                    units[i + 1] as u32
+                } else {
+                    0
+                };
+                if !matches!(low, 0xDC00..=0xDFFF) {
+                    const_panic!("unpaired surrogate at index ", usize: i);
+                }
+                i += 1;
+                0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00)
+            }
+            0xDC00..=0xDFFF => const_panic!("unpaired surrogate at index ", usize: i),
+            _ => unit,
+        };
+        i += 1;
+
+        let ch = match char::from_u32(code) {
+            Some(ch) => ch,
+            None => unreachable!(),
+        };
+        push_char!(buf, pos, ch);
+    }
+
+    (buf, pos)
+}
+
+/// Decodes UTF-32 code points into UTF-8 bytes.
+///
+/// Returns the number of decoded bytes.
+/// The bytes out of the capacity of `buf` are only counted.
+const fn decode_utf32<const N: usize>(codes: &[u32]) -> ([u8; N], usize) {
+    let mut buf = [0; N];
+    let mut pos = 0;
+
+    let mut i = 0;
+    while i < codes.len() {
+        let ch = match char::from_u32(codes[i]) {
+            Some(ch) => ch,
+            None => const_panic!("invalid code point at index ", usize: i),
+        };
+        push_char!(buf, pos, ch);
+        i += 1;
+    }
+
+    (buf, pos)
+}
+
+const fn decode_chars<const N: usize>(chars: &[char]) -> ([u8; N], usize) {
+    let mut buf = [0; N];
+    let mut pos = 0;
+
+    let mut i = 0;
+    while i < chars.len() {
+        push_char!(buf, pos, chars[i]);
+        i += 1;
+    }
+
+    (buf, pos)
+}
+
+impl Decode<&[u16], Utf16Decoder> {
+    pub const fn output_len(&self) -> usize {
+        decode_utf16::<0>(self.0).1
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let (buf, len) = decode_utf16::<N>(self.0);
+        assert!(len == N);
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+impl<const L: usize> Decode<&[u16; L], Utf16Decoder> {
+    pub const fn output_len(&self) -> usize {
+        Decode(self.0.as_slice(), Utf16Decoder).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        Decode(self.0.as_slice(), Utf16Decoder).const_eval()
+    }
+}
+
+impl Decode<&[u32], Utf32Decoder> {
+    pub const fn output_len(&self) -> usize {
+        decode_utf32::<0>(self.0).1
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let (buf, len) = decode_utf32::<N>(self.0);
+        assert!(len == N);
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+impl<const L: usize> Decode<&[u32; L], Utf32Decoder> {
+    pub const fn output_len(&self) -> usize {
+        Decode(self.0.as_slice(), Utf32Decoder).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        Decode(self.0.as_slice(), Utf32Decoder).const_eval()
+    }
+}
+
+impl Decode<&[char], Utf32Decoder> {
+    pub const fn output_len(&self) -> usize {
+        decode_chars::<0>(self.0).1
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let (buf, len) = decode_chars::<N>(self.0);
+        assert!(len == N);
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+impl<const L: usize> Decode<&[char; L], Utf32Decoder> {
+    pub const fn output_len(&self) -> usize {
+        Decode(self.0.as_slice(), Utf32Decoder).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        Decode(self.0.as_slice(), Utf32Decoder).const_eval()
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __decode {
+    ($d: ident, $s: expr) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::Decode($s, $crate::__ctfe::$d).output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<OUTPUT_LEN> =
+            $crate::__ctfe::Decode($s, $crate::__ctfe::$d).const_eval();
+        OUTPUT_BUF.as_str()
+    }};
+}
+
+/// Decode a string slice from code units of a specified encoding.
+///
+/// Supported encodings:
+///
+/// | encoding | input type                                                   |
+/// | -------- | ------------------------------------------------------------ |
+/// | `utf16`  | `&[u16]`, `&[u16; N]`                                        |
+/// | `utf32`  | `&[u32]`, `&[u32; N]`, `&[char]`, `&[char; N]`               |
+///
+/// The output type is [`&str`](str).
+///
+/// The compilation fails if the input contains an unpaired surrogate in `utf16`,
+/// or a value that is not a Unicode scalar value in `utf32`.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`const_str::encode!`][crate::encode]
+///
+/// # Examples
+/// ``` rust
+/// use const_str::{decode, encode};
+///
+/// const UNITS: &[u16] = &[104, 105, 0xd83d, 0xde00];
+/// const S: &str = decode!(utf16, UNITS);
+/// assert_eq!(S, "hi😀");
+///
+/// const ROUND_TRIP: &str = decode!(utf16, encode!(utf16, "你好"));
+/// assert_eq!(ROUND_TRIP, "你好");
+///
+/// const CODES: &[u32] = &[0x41, 0x1f600];
+/// assert_eq!(decode!(utf32, CODES), "A😀");
+///
+/// const CHARS: &[char; 3] = &['a', 'b', 'c'];
+/// assert_eq!(decode!(utf32, CHARS), "abc");
+/// ```
+///
+/// ```compile_fail
+/// // unpaired high surrogate
+/// const S: &str = const_str::decode!(utf16, &[0xd83d_u16, 0x41]);
+/// ```
+///
+#[macro_export]
+macro_rules! decode {
+    (utf16, $s: expr) => {
+        $crate::__decode!(Utf16Decoder, $s)
+    };
+    (utf32, $s: expr) => {
+        $crate::__decode!(Utf32Decoder, $s)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_utf16() {
+        const S: &str = "abc你好𤭢";
+        const UNITS: &[u16] = crate::encode!(utf16, S);
+        assert_eq!(decode!(utf16, UNITS), S);
+
+        const EMPTY: &[u16] = &[];
+        assert_eq!(decode!(utf16, EMPTY), "");
+
+        const ARRAY: &[u16; 2] = &[0xd852, 0xdf62];
+        assert_eq!(decode!(utf16, ARRAY), "𤭢");
+
+        let decode = Decode(UNITS, Utf16Decoder);
+        assert_eq!(decode.output_len(), S.len());
+        let buf: StrBuf<13> = decode.const_eval();
+        assert_eq!(buf.as_str(), S);
+    }
+
+    #[test]
+    fn test_decode_utf32() {
+        const S: &str = "abc你好𤭢";
+        const CODES: &[u32] = crate::encode!(utf32, S);
+        assert_eq!(decode!(utf32, CODES), S);
+
+        const CHARS: &[char] = &['a', '你', '𤭢'];
+        assert_eq!(decode!(utf32, CHARS), "a你𤭢");
+
+        const ARRAY: &[char; 2] = &['\0', 'z'];
+        assert_eq!(decode!(utf32, ARRAY), "\0z");
+    }
+
+    #[test]
+    #[should_panic = "unpaired surrogate at index 1"]
+    fn test_decode_utf16_unpaired_high() {
+        let units: &[u16] = &[0x61, 0xd800, 0x62];
+        let _ = Decode(units, Utf16Decoder).output_len();
+    }
+
+    #[test]
+    #[should_panic = "unpaired surrogate at index 2"]
+    fn test_decode_utf16_unpaired_low() {
+        let units: &[u16] = &[0x61, 0x62, 0xdc00];
+        let _ = Decode(units, Utf16Decoder).output_len();
+    }
+
+    #[test]
+    #[should_panic = "unpaired surrogate at index 0"]
+    fn test_decode_utf16_truncated() {
+        let units: &[u16] = &[0xd800];
+        let _ = Decode(units, Utf16Decoder).output_len();
+    }
+
+    #[test]
+    #[should_panic = "invalid code point at index 1"]
+    fn test_decode_utf32_invalid() {
+        let codes: &[u32] = &[0x61, 0x110000];
+        let _ = Decode(codes, Utf32Decoder).output_len();
+    }
+}
@@ -0,0 +1,544 @@
+use super::{SortKey, SortOptions};
+
+pub struct Dedup<T>(pub T);
+
+pub struct Unique<T>(pub T);
+
+pub struct FindDuplicate<T>(pub T);
+
+pub struct IsSorted<T>(pub T, pub SortOptions);
+
+macro_rules! impl_dedup {
+    ($ty: ty $(, $lt: lifetime)?) => {
+        impl<$($lt)?> SortKey<$ty> {
+            /// Returns the indices of `s` in the stable sorted order.
+            const fn argsort<const L: usize>(s: &[$ty]) -> [usize; L] {
+                assert!(s.len() == L);
+                let mut idx = [0; L];
+                let mut i = 0;
+                while i < L {
+                    idx[i] = i;
+                    i += 1;
+                }
+                crate::__merge_sort!(idx, |a, b| SortKey(s[a]).greater(s[b], SortOptions::DEFAULT))
+            }
+
+            /// Marks the items which are equal to an earlier item.
+            const fn duplicates<const L: usize>(s: &[$ty]) -> [bool; L] {
+                let idx = Self::argsort::<L>(s);
+                let mut dup = [false; L];
+                let mut k = 1;
+                while k < L {
+                    if SortKey(s[idx[k - 1]]).equal(s[idx[k]]) {
+                        dup[idx[k]] = true;
+                    }
+                    k += 1;
+                }
+                dup
+            }
+        }
+
+        impl<$($lt)?> Dedup<&[$ty]> {
+            const fn is_kept(s: &[$ty], i: usize) -> bool {
+                i == 0 || !SortKey(s[i - 1]).equal(s[i])
+            }
+
+            pub const fn output_len(&self) -> usize {
+                let s = self.0;
+                let mut n = 0;
+                let mut i = 0;
+                while i < s.len() {
+                    if Self::is_kept(s, i) {
+                        n += 1;
+                    }
+                    i += 1;
+                }
+                n
+            }
+
+            pub const fn const_eval<const N: usize>(&self) -> [$ty; N] {
+                self.select(self.indices())
+            }
+
+            /// Returns the indices of the kept items.
+            pub const fn indices<const N: usize>(&self) -> [usize; N] {
+                let s = self.0;
+                let mut idx = [0; N];
+                let mut n = 0;
+                let mut i = 0;
+                while i < s.len() {
+                    if Self::is_kept(s, i) {
+                        idx[n] = i;
+                        n += 1;
+                    }
+                    i += 1;
+                }
+                assert!(n == N);
+                idx
+            }
+
+            pub const fn select<const N: usize>(&self, idx: [usize; N]) -> [$ty; N] {
+                crate::slice::select(self.0, &idx)
+            }
+        }
+
+        impl<$($lt,)? const M: usize> Dedup<[$ty; M]> {
+            pub const fn output_len(&self) -> usize {
+                Dedup(self.0.as_slice()).output_len()
+            }
+
+            pub const fn const_eval<const N: usize>(&self) -> [$ty; N] {
+                Dedup(self.0.as_slice()).const_eval()
+            }
+
+            pub const fn indices<const N: usize>(&self) -> [usize; N] {
+                Dedup(self.0.as_slice()).indices()
+            }
+
+            pub const fn select<const N: usize>(&self, idx: [usize; N]) -> [$ty; N] {
+                Dedup(self.0.as_slice()).select(idx)
+            }
+        }
+
+        impl<$($lt,)? const M: usize> Dedup<&[$ty; M]> {
+            pub const fn output_len(&self) -> usize {
+                Dedup(self.0.as_slice()).output_len()
+            }
+
+            pub const fn const_eval<const N: usize>(&self) -> [$ty; N] {
+                Dedup(self.0.as_slice()).const_eval()
+            }
+
+            pub const fn indices<const N: usize>(&self) -> [usize; N] {
+                Dedup(self.0.as_slice()).indices()
+            }
+
+            pub const fn select<const N: usize>(&self, idx: [usize; N]) -> [$ty; N] {
+                Dedup(self.0.as_slice()).select(idx)
+            }
+        }
+
+        impl<$($lt)?> Unique<&[$ty]> {
+            pub const fn input_len(&self) -> usize {
+                self.0.len()
+            }
+
+            pub const fn output_len<const L: usize>(&self) -> usize {
+                let dup = SortKey::<$ty>::duplicates::<L>(self.0);
+                let mut n = 0;
+                let mut i = 0;
+                while i < L {
+                    if !dup[i] {
+                        n += 1;
+                    }
+                    i += 1;
+                }
+                n
+            }
+
+            pub const fn const_eval<const L: usize, const N: usize>(&self) -> [$ty; N] {
+                self.select(self.indices::<L, N>())
+            }
+
+            /// Returns the indices of the first occurrences.
+            pub const fn indices<const L: usize, const N: usize>(&self) -> [usize; N] {
+                let dup = SortKey::<$ty>::duplicates::<L>(self.0);
+                let mut idx = [0; N];
+                let mut n = 0;
+                let mut i = 0;
+                while i < L {
+                    if !dup[i] {
+                        idx[n] = i;
+                        n += 1;
+                    }
+                    i += 1;
+                }
+                assert!(n == N);
+                idx
+            }
+
+            pub const fn select<const N: usize>(&self, idx: [usize; N]) -> [$ty; N] {
+                crate::slice::select(self.0, &idx)
+            }
+        }
+
+        impl<$($lt,)? const M: usize> Unique<[$ty; M]> {
+            pub const fn input_len(&self) -> usize {
+                M
+            }
+
+            pub const fn output_len<const L: usize>(&self) -> usize {
+                Unique(self.0.as_slice()).output_len::<L>()
+            }
+
+            pub const fn const_eval<const L: usize, const N: usize>(&self) -> [$ty; N] {
+                Unique(self.0.as_slice()).const_eval::<L, N>()
+            }
+
+            pub const fn indices<const L: usize, const N: usize>(&self) -> [usize; N] {
+                Unique(self.0.as_slice()).indices::<L, N>()
+            }
+
+            pub const fn select<const N: usize>(&self, idx: [usize; N]) -> [$ty; N] {
+                Unique(self.0.as_slice()).select(idx)
+            }
+        }
+
+        impl<$($lt,)? const M: usize> Unique<&[$ty; M]> {
+            pub const fn input_len(&self) -> usize {
+                M
+            }
+
+            pub const fn output_len<const L: usize>(&self) -> usize {
+                Unique(self.0.as_slice()).output_len::<L>()
+            }
+
+            pub const fn const_eval<const L: usize, const N: usize>(&self) -> [$ty; N] {
+                Unique(self.0.as_slice()).const_eval::<L, N>()
+            }
+
+            pub const fn indices<const L: usize, const N: usize>(&self) -> [usize; N] {
+                Unique(self.0.as_slice()).indices::<L, N>()
+            }
+
+            pub const fn select<const N: usize>(&self, idx: [usize; N]) -> [$ty; N] {
+                Unique(self.0.as_slice()).select(idx)
+            }
+        }
+
+        impl<$($lt)?> IsSorted<&[$ty]> {
+            pub const fn const_eval(&self) -> bool {
+                let s = self.0;
+                let mut i = 1;
+                while i < s.len() {
+                    if SortKey(s[i - 1]).greater(s[i], self.1) {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            }
+        }
+
+        impl<$($lt,)? const M: usize> IsSorted<[$ty; M]> {
+            pub const fn const_eval(&self) -> bool {
+                IsSorted(self.0.as_slice(), self.1).const_eval()
+            }
+        }
+
+        impl<$($lt,)? const M: usize> IsSorted<&[$ty; M]> {
+            pub const fn const_eval(&self) -> bool {
+                IsSorted(self.0.as_slice(), self.1).const_eval()
+            }
+        }
+    };
+}
+
+// Byte strings are not supported, since the message of `assert_unique!` cannot show them.
+macro_rules! impl_find_duplicate {
+    ($ty: ty, $default: expr $(, $lt: lifetime)?) => {
+        impl<$($lt)?> FindDuplicate<&[$ty]> {
+            pub const fn input_len(&self) -> usize {
+                self.0.len()
+            }
+
+            /// Returns the indices of two equal items.
+            pub const fn const_eval<const L: usize>(&self) -> Option<(usize, usize)> {
+                let s = self.0;
+                let idx = SortKey::<$ty>::argsort::<L>(s);
+                let mut k = 1;
+                while k < L {
+                    if SortKey(s[idx[k - 1]]).equal(s[idx[k]]) {
+                        return Some((idx[k - 1], idx[k]));
+                    }
+                    k += 1;
+                }
+                None
+            }
+
+            pub const fn item(&self, i: usize) -> $ty {
+                if i < self.0.len() {
+                    self.0[i]
+                } else {
+                    $default
+                }
+            }
+        }
+
+        impl<$($lt,)? const M: usize> FindDuplicate<[$ty; M]> {
+            pub const fn input_len(&self) -> usize {
+                M
+            }
+
+            pub const fn const_eval<const L: usize>(&self) -> Option<(usize, usize)> {
+                FindDuplicate(self.0.as_slice()).const_eval::<L>()
+            }
+
+            pub const fn item(&self, i: usize) -> $ty {
+                FindDuplicate(self.0.as_slice()).item(i)
+            }
+        }
+
+        impl<$($lt,)? const M: usize> FindDuplicate<&[$ty; M]> {
+            pub const fn input_len(&self) -> usize {
+                M
+            }
+
+            pub const fn const_eval<const L: usize>(&self) -> Option<(usize, usize)> {
+                FindDuplicate(self.0.as_slice()).const_eval::<L>()
+            }
+
+            pub const fn item(&self, i: usize) -> $ty {
+                FindDuplicate(self.0.as_slice()).item(i)
+            }
+        }
+    };
+}
+
+impl_dedup!(&'a str, 'a);
+impl_dedup!(&'a [u8], 'a);
+impl_dedup!(u8);
+impl_dedup!(u16);
+impl_dedup!(u32);
+impl_dedup!(u64);
+impl_dedup!(u128);
+impl_dedup!(usize);
+impl_dedup!(i8);
+impl_dedup!(i16);
+impl_dedup!(i32);
+impl_dedup!(i64);
+impl_dedup!(i128);
+impl_dedup!(isize);
+
+impl_find_duplicate!(&'a str, "", 'a);
+impl_find_duplicate!(u8, 0);
+impl_find_duplicate!(u16, 0);
+impl_find_duplicate!(u32, 0);
+impl_find_duplicate!(u64, 0);
+impl_find_duplicate!(u128, 0);
+impl_find_duplicate!(usize, 0);
+impl_find_duplicate!(i8, 0);
+impl_find_duplicate!(i16, 0);
+impl_find_duplicate!(i32, 0);
+impl_find_duplicate!(i64, 0);
+impl_find_duplicate!(i128, 0);
+impl_find_duplicate!(isize, 0);
+
+/// Removes consecutive repeated items and returns a new array.
+///
+/// If the input is sorted, this removes all duplicates.
+///
+/// The input type must be one of:
+/// + [`&[T]`](slice)
+/// + [`[T; N]`](array)
+/// + [`&[T; N]`](array)
+///
+/// where `T` is [`&str`](str), [`&[u8]`](slice) or an integer type.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::{dedup, sorted};
+///
+/// const KEYWORDS: &[&str] = &dedup!(sorted!(["if", "else", "fn", "if", "let", "fn"]));
+/// assert_eq!(KEYWORDS, ["else", "fn", "if", "let"]);
+///
+/// const RUNS: [u8; 4] = dedup!([1_u8, 1, 2, 1, 3, 3]);
+/// assert_eq!(RUNS, [1, 2, 1, 3]);
+/// ```
+#[macro_export]
+macro_rules! dedup {
+    ($s: expr) => {{
+        const N: usize = $crate::__ctfe::Dedup($s).output_len();
+        const INDICES: [usize; N] = $crate::__ctfe::Dedup($s).indices();
+        $crate::__ctfe::Dedup($s).select(INDICES)
+    }};
+}
+
+/// Removes all repeated items and returns a new array.
+///
+/// The first occurrence of each item is kept, in the original order.
+///
+/// The input types are the same as [`dedup!`](crate::dedup).
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// # Examples
+///
+/// ```
+/// const METHODS: [&str; 3] = const_str::unique!(["GET", "POST", "GET", "PUT", "POST"]);
+/// assert_eq!(METHODS, ["GET", "POST", "PUT"]);
+/// ```
+#[macro_export]
+macro_rules! unique {
+    ($s: expr) => {{
+        const L: usize = $crate::__ctfe::Unique($s).input_len();
+        const N: usize = $crate::__ctfe::Unique($s).output_len::<L>();
+        const INDICES: [usize; N] = $crate::__ctfe::Unique($s).indices::<L, N>();
+        $crate::__ctfe::Unique($s).select(INDICES)
+    }};
+}
+
+/// Asserts that an array or slice has no repeated items.
+///
+/// The compilation fails if two items are equal,
+/// and the message shows the item and its indices.
+///
+/// The input types are the same as [`dedup!`](crate::dedup),
+/// except that byte strings are not supported, since they cannot be shown in the message.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// # Examples
+///
+/// ```
+/// const KEYWORDS: &[&str] = &["fn", "let", "if"];
+/// const_str::assert_unique!(KEYWORDS);
+/// ```
+///
+/// ```compile_fail
+/// const KEYWORDS: &[&str] = &["fn", "let", "if", "let"];
+/// // duplicate item "let" at indices 1 and 3
+/// const_str::assert_unique!(KEYWORDS);
+/// ```
+///
+/// ```compile_fail
+/// // byte strings are not supported
+/// const_str::assert_unique!([b"fn" as &[u8], b"let"]);
+/// ```
+#[macro_export]
+macro_rules! assert_unique {
+    ($s: expr) => {
+        const _: () = {
+            const L: usize = $crate::__ctfe::FindDuplicate($s).input_len();
+            const DUP: Option<(usize, usize)> = $crate::__ctfe::FindDuplicate($s).const_eval::<L>();
+            if DUP.is_some() {
+                const I: usize = match DUP {
+                    Some((i, _)) => i,
+                    None => 0,
+                };
+                const J: usize = match DUP {
+                    Some((_, j)) => j,
+                    None => 0,
+                };
+                const MSG: &str = $crate::concat!(
+                    "duplicate item \"",
+                    $crate::__ctfe::FindDuplicate($s).item(I),
+                    "\" at indices ",
+                    I,
+                    " and ",
+                    J,
+                );
+                panic!("{}", MSG);
+            }
+        };
+    };
+}
+
+/// Checks whether an array or slice is sorted.
+///
+/// The input types and options are the same as [`sorted!`](crate::sorted).
+///
+/// This macro is [const-fn compatible](./index.html#const-fn-compatible).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::is_sorted;
+///
+/// const _: () = {
+///     assert!(is_sorted!(["a", "b", "b", "c"]));
+///     assert!(!is_sorted!(["1", "2", "10"]));
+///     assert!(is_sorted!(["1", "2", "10"], natural));
+///     assert!(is_sorted!([3_u32, 2, 1], reverse));
+/// };
+/// ```
+#[macro_export]
+macro_rules! is_sorted {
+    ($s: expr $(, $opt: ident)* $(,)?) => {
+        $crate::__ctfe::IsSorted($s, $crate::__ctfe::SortOptions::DEFAULT $(.$opt())*).const_eval()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_dedup() {
+        const XS: &[&str] = &["a", "a", "b", "a", "c", "c", "c"];
+        const DEDUP: [&str; 4] = dedup!(XS);
+        let mut ans = XS.to_vec();
+        ans.dedup();
+        assert_eq!(DEDUP, &*ans);
+
+        const EMPTY: [&str; 0] = dedup!([] as [&str; 0]);
+        assert_eq!(EMPTY, [""; 0]);
+
+        const SORTED: &[&str] = &dedup!(crate::sorted!(["b", "a", "b", "c", "a"]));
+        assert_eq!(SORTED, ["a", "b", "c"]);
+
+        const BYTES: [&[u8]; 2] = dedup!(&[b"x" as &[u8], b"x", b"y"]);
+        assert_eq!(BYTES, [b"x" as &[u8], b"y"]);
+
+        const INTS: [i64; 3] = dedup!([-1_i64, -1, 0, 0, 1]);
+        assert_eq!(INTS, [-1, 0, 1]);
+    }
+
+    #[test]
+    fn test_unique() {
+        const XS: &[&str] = &["b", "a", "b", "c", "a", "d"];
+        const UNIQUE: [&str; 4] = unique!(XS);
+        assert_eq!(UNIQUE, ["b", "a", "c", "d"]);
+
+        const ALL: [u16; 3] = unique!([3_u16, 2, 1]);
+        assert_eq!(ALL, [3, 2, 1]);
+
+        const ONE: [u8; 1] = unique!(&[7_u8, 7, 7]);
+        assert_eq!(ONE, [7]);
+
+        const EMPTY: &[&[u8]] = &[];
+        const UNIQUE_EMPTY: [&[u8]; 0] = unique!(EMPTY);
+        assert_eq!(UNIQUE_EMPTY.len(), 0);
+    }
+
+    #[test]
+    fn test_assert_unique() {
+        const KEYWORDS: &[&str] = &["fn", "let", "if", "else"];
+        assert_unique!(KEYWORDS);
+        assert_unique!([1_u32, 2, 3]);
+        assert_unique!([] as [&str; 0]);
+        assert_unique!(&["a", "A"]);
+
+        use crate::__ctfe::FindDuplicate;
+        let xs: &[&str] = &["c", "a", "b", "a", "c"];
+        assert_eq!(FindDuplicate(xs).const_eval::<5>(), Some((1, 3)));
+        assert_eq!(
+            FindDuplicate(&[1_i8, 2, 3, 2]).const_eval::<4>(),
+            Some((1, 3))
+        );
+        assert_eq!(FindDuplicate(&[1_i8, 2, 3]).const_eval::<3>(), None);
+    }
+
+    #[test]
+    fn test_is_sorted() {
+        const XS: &[&str] = &["a", "b", "c"];
+        const _: () = assert!(is_sorted!(XS));
+
+        assert!(is_sorted!([] as [&str; 0]));
+        assert!(is_sorted!(["a"]));
+        assert!(is_sorted!(["a", "a"]));
+        assert!(!is_sorted!(["b", "a"]));
+        assert!(is_sorted!(["A", "b", "C"], ignore_ascii_case));
+        assert!(!is_sorted!(["A", "b", "C"]));
+        assert!(is_sorted!(["v1", "v2", "v10"], natural));
+        assert!(is_sorted!(["c", "b", "a"], reverse));
+        assert!(is_sorted!(&[b"a" as &[u8], b"b"]));
+        assert!(is_sorted!([-3_i32, 0, 3]));
+        assert!(!is_sorted!([-3_i32, 0, 3], reverse));
+
+        let xs = ["x", "y", "z"];
+        assert!(is_sorted!(&xs));
+        assert!(is_sorted!(xs.as_slice()));
+    }
+}
@@ -9,6 +9,24 @@ pub struct Utf16Encoder {
     pub nul_terminated: bool,
 }
 
+pub struct Utf16BytesEncoder {
+    pub nul_terminated: bool,
+    pub big_endian: bool,
+    pub bom: bool,
+}
+
+pub struct Utf32Encoder {
+    pub nul_terminated: bool,
+}
+
+pub struct Latin1Encoder {
+    pub nul_terminated: bool,
+}
+
+pub struct Windows1252Encoder {
+    pub nul_terminated: bool,
+}
+
 pub struct Encode<'a, T>(pub &'a str, pub T);
 
 impl Encode<'_, Utf8Encoder> {
@@ -80,6 +98,182 @@ impl Encode<'_, Utf16Encoder> {
     }
 }
 
+impl Encode<'_, Utf16BytesEncoder> {
+    pub const fn output_len(&self) -> usize {
+        let units = crate::utf16::str_len_utf16(self.0)
+            + (self.1.bom as usize)
+            + (self.1.nul_terminated as usize);
+        units * 2
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> [u8; N] {
+        let mut s = self.0.as_bytes();
+
+        let mut buf = [0; N];
+        let mut pos = 0;
+
+        macro_rules! push_unit {
+            ($unit: expr) => {{
+                let unit: u16 = $unit;
+                let bytes = if self.1.big_endian {
+                    unit.to_be_bytes()
+                } else {
+                    unit.to_le_bytes()
+                };
+                buf[pos] = bytes[0];
+                buf[pos + 1] = bytes[1];
+                pos += 2;
+            }};
+        }
+
+        if self.1.bom {
+            push_unit!(0xFEFF);
+        }
+
+        while let Some((code, count)) = crate::utf8::next_char(s) {
+            s = advance(s, count);
+            let e = CharEncodeUtf16::new(code);
+
+            if self.1.nul_terminated {
+                assert!(e.first() != 0);
+            }
+
+            push_unit!(e.first());
+            if e.has_second() {
+                push_unit!(e.second());
+            }
+        }
+
+        if self.1.nul_terminated {
+            pos += 2;
+        }
+
+        assert!(pos == N);
+
+        buf
+    }
+}
+
+impl Encode<'_, Utf32Encoder> {
+    pub const fn output_len(&self) -> usize {
+        crate::utf8::str_count_chars(self.0) + (self.1.nul_terminated as usize)
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> [u32; N] {
+        let mut s = self.0.as_bytes();
+
+        let mut buf = [0; N];
+        let mut pos = 0;
+
+        while let Some((code, count)) = crate::utf8::next_char(s) {
+            s = advance(s, count);
+            if self.1.nul_terminated {
+                assert!(code != '\0');
+            }
+            buf[pos] = code as u32;
+            pos += 1;
+        }
+
+        if self.1.nul_terminated {
+            pos += 1;
+        }
+
+        assert!(pos == N);
+
+        buf
+    }
+}
+
+/// The characters of bytes `0x80..=0x9F` in Windows-1252.
+///
+/// The five unassigned bytes are mapped to the C1 controls of the same values,
+/// as the [WHATWG Encoding Standard](https://encoding.spec.whatwg.org/#windows-1252) does.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+const fn single_byte(ch: char, windows1252: bool) -> Option<u8> {
+    let code = ch as u32;
+    if !windows1252 {
+        return if code <= 0xFF { Some(code as u8) } else { None };
+    }
+    if code < 0x80 || (code >= 0xA0 && code <= 0xFF) {
+        return Some(code as u8);
+    }
+    let mut i = 0;
+    while i < WINDOWS_1252_HIGH.len() {
+        if WINDOWS_1252_HIGH[i] == ch {
+            return Some(0x80 + i as u8);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Encodes `s` with a single-byte encoding.
+///
+/// Returns the number of encoded bytes.
+/// The bytes out of the capacity of `buf` are only counted.
+const fn encode_single_byte<const N: usize>(
+    s: &str,
+    windows1252: bool,
+    nul_terminated: bool,
+) -> ([u8; N], usize) {
+    let bytes = s.as_bytes();
+
+    let mut buf = [0; N];
+    let mut pos = 0;
+
+    let mut i = 0;
+    while let Some((ch, count)) = crate::utf8::next_char(advance(bytes, i)) {
+        let b = match single_byte(ch, windows1252) {
+            Some(b) => b,
+            None => const_panic!("unrepresentable character at offset ", usize: i),
+        };
+        if nul_terminated {
+            assert!(b != 0);
+        }
+        if pos < N {
+            buf[pos] = b;
+        }
+        pos += 1;
+        i += count;
+    }
+
+    if nul_terminated {
+        pos += 1;
+    }
+
+    (buf, pos)
+}
+
+impl Encode<'_, Latin1Encoder> {
+    pub const fn output_len(&self) -> usize {
+        encode_single_byte::<0>(self.0, false, self.1.nul_terminated).1
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> [u8; N] {
+        let (buf, len) = encode_single_byte::<N>(self.0, false, self.1.nul_terminated);
+        assert!(len == N);
+        buf
+    }
+}
+
+impl Encode<'_, Windows1252Encoder> {
+    pub const fn output_len(&self) -> usize {
+        encode_single_byte::<0>(self.0, true, self.1.nul_terminated).1
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> [u8; N] {
+        let (buf, len) = encode_single_byte::<N>(self.0, true, self.1.nul_terminated);
+        assert!(len == N);
+        buf
+    }
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __encoder {
@@ -103,6 +297,92 @@ macro_rules! __encoder {
             nul_terminated: true,
         }
     }};
+    (utf16le) => {{
+        $crate::__ctfe::Utf16BytesEncoder {
+            nul_terminated: false,
+            big_endian: false,
+            bom: false,
+        }
+    }};
+    (utf16le_z) => {{
+        $crate::__ctfe::Utf16BytesEncoder {
+            nul_terminated: true,
+            big_endian: false,
+            bom: false,
+        }
+    }};
+    (utf16be) => {{
+        $crate::__ctfe::Utf16BytesEncoder {
+            nul_terminated: false,
+            big_endian: true,
+            bom: false,
+        }
+    }};
+    (utf16be_z) => {{
+        $crate::__ctfe::Utf16BytesEncoder {
+            nul_terminated: true,
+            big_endian: true,
+            bom: false,
+        }
+    }};
+    (utf16le_bom) => {{
+        $crate::__ctfe::Utf16BytesEncoder {
+            nul_terminated: false,
+            big_endian: false,
+            bom: true,
+        }
+    }};
+    (utf16le_bom_z) => {{
+        $crate::__ctfe::Utf16BytesEncoder {
+            nul_terminated: true,
+            big_endian: false,
+            bom: true,
+        }
+    }};
+    (utf16be_bom) => {{
+        $crate::__ctfe::Utf16BytesEncoder {
+            nul_terminated: false,
+            big_endian: true,
+            bom: true,
+        }
+    }};
+    (utf16be_bom_z) => {{
+        $crate::__ctfe::Utf16BytesEncoder {
+            nul_terminated: true,
+            big_endian: true,
+            bom: true,
+        }
+    }};
+    (utf32) => {{
+        $crate::__ctfe::Utf32Encoder {
+            nul_terminated: false,
+        }
+    }};
+    (utf32_z) => {{
+        $crate::__ctfe::Utf32Encoder {
+            nul_terminated: true,
+        }
+    }};
+    (latin1) => {{
+        $crate::__ctfe::Latin1Encoder {
+            nul_terminated: false,
+        }
+    }};
+    (latin1_z) => {{
+        $crate::__ctfe::Latin1Encoder {
+            nul_terminated: true,
+        }
+    }};
+    (windows1252) => {{
+        $crate::__ctfe::Windows1252Encoder {
+            nul_terminated: false,
+        }
+    }};
+    (windows1252_z) => {{
+        $crate::__ctfe::Windows1252Encoder {
+            nul_terminated: true,
+        }
+    }};
 }
 
 #[doc(hidden)]
@@ -118,11 +398,24 @@ macro_rules! __encode {
 ///
 /// Supported encodings:
 ///
-/// + utf8
-/// + utf16
+/// | encoding      | output type   | description                                     |
+/// | ------------- | ------------- | ----------------------------------------------- |
+/// | `utf8`        | `&[u8; N]`    | UTF-8                                           |
+/// | `utf16`       | `&[u16; N]`   | UTF-16 code units                               |
+/// | `utf16le`     | `&[u8; N]`    | UTF-16 little-endian bytes                      |
+/// | `utf16be`     | `&[u8; N]`    | UTF-16 big-endian bytes                         |
+/// | `utf16le_bom` | `&[u8; N]`    | UTF-16 little-endian bytes with a leading BOM   |
+/// | `utf16be_bom` | `&[u8; N]`    | UTF-16 big-endian bytes with a leading BOM      |
+/// | `utf32`       | `&[u32; N]`   | UTF-32 code points                              |
+/// | `latin1`      | `&[u8; N]`    | ISO-8859-1                                      |
+/// | `windows1252` | `&[u8; N]`    | Windows-1252                                    |
+///
+/// The compilation fails if a character is not representable in `latin1` or `windows1252`.
 ///
 /// This macro is [const-context only](./index.html#const-context-only).
 ///
+/// See also [`const_str::decode!`][crate::decode]
+///
 /// # Examples
 /// ``` rust
 /// use const_str::encode;
@@ -134,6 +427,20 @@ macro_rules! __encode {
 ///
 /// const S_UTF16: &[u16] = encode!(utf16, S);
 /// assert_eq!(S_UTF16, [104, 101, 108, 108, 111, 20320, 22909]);
+///
+/// const S_UTF32: &[u32] = encode!(utf32, S);
+/// assert_eq!(S_UTF32, [104, 101, 108, 108, 111, 0x4f60, 0x597d]);
+///
+/// const HEADER: &[u8] = encode!(utf16le_bom, "hi");
+/// assert_eq!(HEADER, [0xff, 0xfe, b'h', 0, b'i', 0]);
+///
+/// const PRICE: &[u8] = encode!(windows1252, "5 €");
+/// assert_eq!(PRICE, [b'5', b' ', 0x80]);
+/// ```
+///
+/// ```compile_fail
+/// // '€' is not in Latin-1
+/// const PRICE: &[u8] = const_str::encode!(latin1, "5 €");
 /// ```
 ///
 #[macro_export]
@@ -144,12 +451,36 @@ macro_rules! encode {
     (utf16, $s: expr) => {
         $crate::__encode!(utf16, $s)
     };
+    (utf16le, $s: expr) => {
+        $crate::__encode!(utf16le, $s)
+    };
+    (utf16be, $s: expr) => {
+        $crate::__encode!(utf16be, $s)
+    };
+    (utf16le_bom, $s: expr) => {
+        $crate::__encode!(utf16le_bom, $s)
+    };
+    (utf16be_bom, $s: expr) => {
+        $crate::__encode!(utf16be_bom, $s)
+    };
+    (utf32, $s: expr) => {
+        $crate::__encode!(utf32, $s)
+    };
+    (latin1, $s: expr) => {
+        $crate::__encode!(latin1, $s)
+    };
+    (windows1252, $s: expr) => {
+        $crate::__encode!(windows1252, $s)
+    };
 }
 
 /// Encode a string slice with a specified encoding and append a nul character.
 ///
 /// The provided data should not contain any nul bytes in it.
 ///
+/// The nul character is encoded with the same encoding,
+/// so it takes two bytes in `utf16le` and `utf16be`.
+///
 /// This macro is [const-context only](./index.html#const-context-only).
 ///
 /// See also [`const_str::encode!`][crate::encode]
@@ -165,6 +496,9 @@ macro_rules! encode {
 ///
 /// const S_UTF16_Z: &[u16] = encode_z!(utf16, S);
 /// assert_eq!(S_UTF16_Z, [104, 101, 108, 108, 111, 20320, 22909, 0]);
+///
+/// const S_UTF16BE_Z: &[u8] = encode_z!(utf16be, "hi");
+/// assert_eq!(S_UTF16BE_Z, [0, b'h', 0, b'i', 0, 0]);
 /// ```
 ///
 #[macro_export]
@@ -175,6 +509,27 @@ macro_rules! encode_z {
     (utf16, $s: expr) => {
         $crate::__encode!(utf16_z, $s)
     };
+    (utf16le, $s: expr) => {
+        $crate::__encode!(utf16le_z, $s)
+    };
+    (utf16be, $s: expr) => {
+        $crate::__encode!(utf16be_z, $s)
+    };
+    (utf16le_bom, $s: expr) => {
+        $crate::__encode!(utf16le_bom_z, $s)
+    };
+    (utf16be_bom, $s: expr) => {
+        $crate::__encode!(utf16be_bom_z, $s)
+    };
+    (utf32, $s: expr) => {
+        $crate::__encode!(utf32_z, $s)
+    };
+    (latin1, $s: expr) => {
+        $crate::__encode!(latin1_z, $s)
+    };
+    (windows1252, $s: expr) => {
+        $crate::__encode!(windows1252_z, $s)
+    };
 }
 
 #[cfg(test)]
@@ -268,4 +623,95 @@ mod tests {
         );
         assert_eq!(encoder_empty.output_len(), 0);
     }
+
+    #[test]
+    fn test_encode_utf16_bytes() {
+        const S: &str = "a你𤭢";
+        let units = S.encode_utf16().collect::<Vec<_>>();
+        let le = units
+            .iter()
+            .flat_map(|u| u.to_le_bytes())
+            .collect::<Vec<_>>();
+        let be = units
+            .iter()
+            .flat_map(|u| u.to_be_bytes())
+            .collect::<Vec<_>>();
+
+        const LE: &[u8; 8] = encode!(utf16le, S);
+        const BE: &[u8; 8] = encode!(utf16be, S);
+        assert_eq!(LE, le.as_slice());
+        assert_eq!(BE, be.as_slice());
+
+        const LE_BOM: &[u8] = encode!(utf16le_bom, S);
+        const BE_BOM: &[u8] = encode!(utf16be_bom, S);
+        assert_eq!(LE_BOM[..2], [0xff, 0xfe]);
+        assert_eq!(BE_BOM[..2], [0xfe, 0xff]);
+        assert_eq!(&LE_BOM[2..], le.as_slice());
+        assert_eq!(&BE_BOM[2..], be.as_slice());
+
+        const LE_Z: &[u8] = encode_z!(utf16le, "ab");
+        const BE_BOM_Z: &[u8] = encode_z!(utf16be_bom, "ab");
+        assert_eq!(LE_Z, [b'a', 0, b'b', 0, 0, 0]);
+        assert_eq!(BE_BOM_Z, [0xfe, 0xff, 0, b'a', 0, b'b', 0, 0]);
+
+        const EMPTY: &[u8] = encode!(utf16le_bom, "");
+        assert_eq!(EMPTY, [0xff, 0xfe]);
+    }
+
+    #[test]
+    fn test_encode_utf32() {
+        const S: &str = "abc你好𤭢";
+        const B1: &[u32; 6] = encode!(utf32, S);
+        const B2: &[u32; 7] = encode_z!(utf32, S);
+        let mut ans = S.chars().map(u32::from).collect::<Vec<_>>();
+        assert_eq!(B1, ans.as_slice());
+        ans.push(0);
+        assert_eq!(B2, ans.as_slice());
+    }
+
+    #[test]
+    fn test_encode_single_byte() {
+        const S: &str = "Grüße, ÿ\u{80}";
+        const L1: &[u8] = encode!(latin1, S);
+        assert_eq!(L1, b"Gr\xfc\xdfe, \xff\x80");
+
+        const L1_Z: &[u8] = encode_z!(latin1, "é");
+        assert_eq!(L1_Z, [0xe9, 0]);
+
+        const W1: &[u8] = encode!(windows1252, "€‚ƒ„…†‡ˆ‰Š‹ŒŽ‘’“”•–—˜™š›œžŸ");
+        let expected = (0x80..=0x9f)
+            .filter(|b| ![0x81, 0x8d, 0x8f, 0x90, 0x9d].contains(b))
+            .collect::<Vec<u8>>();
+        assert_eq!(W1, expected.as_slice());
+
+        const W2: &[u8] = encode!(windows1252, "café \u{81}\u{a0}ÿ");
+        assert_eq!(W2, b"caf\xe9 \x81\xa0\xff");
+
+        const W3: &[u8] = encode_z!(windows1252, "€");
+        assert_eq!(W3, [0x80, 0]);
+    }
+
+    #[test]
+    #[should_panic = "unrepresentable character at offset 4"]
+    fn test_encode_latin1_unrepresentable() {
+        let _ = Encode(
+            "5 \u{e9}\u{20ac}",
+            Latin1Encoder {
+                nul_terminated: false,
+            },
+        )
+        .output_len();
+    }
+
+    #[test]
+    #[should_panic = "unrepresentable character at offset 1"]
+    fn test_encode_windows1252_unrepresentable() {
+        let _ = Encode(
+            "a\u{80}",
+            Windows1252Encoder {
+                nul_terminated: false,
+            },
+        )
+        .output_len();
+    }
 }
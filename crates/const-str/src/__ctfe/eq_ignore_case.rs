@@ -0,0 +1,277 @@
+use crate::slice::advance;
+use crate::unicode_case::fold;
+use crate::utf8::next_char;
+
+/// Returns the next folded code point and the rest of `s`.
+///
+/// ASCII bytes are folded without decoding.
+/// If `unicode` is false, non-ASCII bytes are returned as is.
+const fn next_folded(s: &[u8], unicode: bool) -> Option<(u32, &[u8])> {
+    if s.is_empty() {
+        return None;
+    }
+    let b = s[0];
+    if b < 0x80 || !unicode {
+        return Some((b.to_ascii_lowercase() as u32, advance(s, 1)));
+    }
+    match next_char(s) {
+        Some((ch, count)) => Some((fold(ch) as u32, advance(s, count))),
+        None => None,
+    }
+}
+
+const fn strip_prefix_ignore_case<'s>(
+    mut s: &'s [u8],
+    mut prefix: &[u8],
+    unicode: bool,
+) -> Option<&'s [u8]> {
+    loop {
+        let (p, prefix_rest) = match next_folded(prefix, unicode) {
+            Some(x) => x,
+            None => return Some(s),
+        };
+        let (c, s_rest) = match next_folded(s, unicode) {
+            Some(x) => x,
+            None => return None,
+        };
+        if c != p {
+            return None;
+        }
+        prefix = prefix_rest;
+        s = s_rest;
+    }
+}
+
+const fn eq_ignore_case(lhs: &[u8], rhs: &[u8], unicode: bool) -> bool {
+    match strip_prefix_ignore_case(lhs, rhs, unicode) {
+        Some(rest) => rest.is_empty(),
+        None => false,
+    }
+}
+
+const fn starts_with_ignore_case(haystack: &[u8], needle: &[u8], unicode: bool) -> bool {
+    strip_prefix_ignore_case(haystack, needle, unicode).is_some()
+}
+
+const fn contains_ignore_case(mut haystack: &[u8], needle: &[u8], unicode: bool) -> bool {
+    loop {
+        if starts_with_ignore_case(haystack, needle, unicode) {
+            return true;
+        }
+        haystack = match next_folded(haystack, unicode) {
+            Some((_, rest)) => rest,
+            None => return false,
+        };
+    }
+}
+
+pub struct EqIgnoreCase<T1, T2>(pub T1, pub T2);
+
+impl EqIgnoreCase<&str, &str> {
+    pub const fn const_eval(&self) -> bool {
+        eq_ignore_case(self.0.as_bytes(), self.1.as_bytes(), true)
+    }
+}
+
+impl EqIgnoreCase<&[u8], &[u8]> {
+    pub const fn const_eval(&self) -> bool {
+        eq_ignore_case(self.0, self.1, false)
+    }
+}
+
+impl<const N1: usize, const N2: usize> EqIgnoreCase<&[u8; N1], &[u8; N2]> {
+    pub const fn const_eval(&self) -> bool {
+        eq_ignore_case(self.0.as_slice(), self.1.as_slice(), false)
+    }
+}
+
+/// Checks that two (string) slices are a case-insensitive match.
+///
+/// String slices are compared with the Unicode simple case folding,
+/// so a character never matches a sequence of characters, such as `"ß"` and `"ss"`.
+/// The Turkic mappings of `'I'` and `'İ'` are not applied.
+///
+/// Byte slices are compared like [`eq_ignore_ascii_case!`](crate::eq_ignore_ascii_case).
+///
+/// The input type must be one of:
+/// + [`&str`](str)
+/// + [`&[u8]`](slice)
+/// + [`&[u8; N]`](array)
+///
+/// The output type is [`bool`].
+///
+/// This macro is [const-fn compatible](./index.html#const-fn-compatible).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::eq_ignore_case;
+///
+/// const _: () = {
+///     assert!(eq_ignore_case!("Ferrös", "FERRÖS"));
+///     assert!(eq_ignore_case!("ΣΊΣΥΦΟΣ", "σίσυφος"));
+///     assert!(eq_ignore_case!(b"Content-Type", b"content-type"));
+///     assert!(!eq_ignore_case!("straße", "STRASSE"));
+/// };
+/// ```
+#[macro_export]
+macro_rules! eq_ignore_case {
+    ($lhs:expr, $rhs:expr) => {
+        $crate::__ctfe::EqIgnoreCase($lhs, $rhs).const_eval()
+    };
+}
+
+pub struct StartsWithIgnoreCase<T1, T2>(pub T1, pub T2);
+
+impl StartsWithIgnoreCase<&str, &str> {
+    pub const fn const_eval(&self) -> bool {
+        starts_with_ignore_case(self.0.as_bytes(), self.1.as_bytes(), true)
+    }
+}
+
+impl StartsWithIgnoreCase<&[u8], &[u8]> {
+    pub const fn const_eval(&self) -> bool {
+        starts_with_ignore_case(self.0, self.1, false)
+    }
+}
+
+impl<const N1: usize, const N2: usize> StartsWithIgnoreCase<&[u8; N1], &[u8; N2]> {
+    pub const fn const_eval(&self) -> bool {
+        starts_with_ignore_case(self.0.as_slice(), self.1.as_slice(), false)
+    }
+}
+
+/// Returns [`true`] if the given pattern matches a prefix of this (string) slice, ignoring case.
+///
+/// The rules of matching are the same as [`eq_ignore_case!`](crate::eq_ignore_case).
+///
+/// This macro is [const-fn compatible](./index.html#const-fn-compatible).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::starts_with_ignore_case;
+///
+/// const _: () = {
+///     assert!(starts_with_ignore_case!("Bearer abc", "bearer "));
+///     assert!(starts_with_ignore_case!("ÉCOLE", "éc"));
+///     assert!(!starts_with_ignore_case!(b"Basic abc", b"bearer "));
+/// };
+/// ```
+#[macro_export]
+macro_rules! starts_with_ignore_case {
+    ($haystack:expr, $pattern:expr) => {
+        $crate::__ctfe::StartsWithIgnoreCase($haystack, $pattern).const_eval()
+    };
+}
+
+pub struct ContainsIgnoreCase<T1, T2>(pub T1, pub T2);
+
+impl ContainsIgnoreCase<&str, &str> {
+    pub const fn const_eval(&self) -> bool {
+        contains_ignore_case(self.0.as_bytes(), self.1.as_bytes(), true)
+    }
+}
+
+impl ContainsIgnoreCase<&[u8], &[u8]> {
+    pub const fn const_eval(&self) -> bool {
+        contains_ignore_case(self.0, self.1, false)
+    }
+}
+
+impl<const N1: usize, const N2: usize> ContainsIgnoreCase<&[u8; N1], &[u8; N2]> {
+    pub const fn const_eval(&self) -> bool {
+        contains_ignore_case(self.0.as_slice(), self.1.as_slice(), false)
+    }
+}
+
+/// Returns [`true`] if the given pattern matches a sub-slice of this (string) slice, ignoring case.
+///
+/// The rules of matching are the same as [`eq_ignore_case!`](crate::eq_ignore_case).
+///
+/// This macro is [const-fn compatible](./index.html#const-fn-compatible).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::contains_ignore_case;
+///
+/// const fn is_chunked(transfer_encoding: &str) -> bool {
+///     contains_ignore_case!(transfer_encoding, "chunked")
+/// }
+///
+/// assert!(is_chunked("gzip, Chunked"));
+/// assert!(!is_chunked("gzip"));
+///
+/// const _: () = assert!(contains_ignore_case!("Добрый ДЕНЬ", "день"));
+/// ```
+#[macro_export]
+macro_rules! contains_ignore_case {
+    ($haystack:expr, $pattern:expr) => {
+        $crate::__ctfe::ContainsIgnoreCase($haystack, $pattern).const_eval()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_eq_ignore_case() {
+        const S1: &str = "Ferris";
+        const _: () = assert!(eq_ignore_case!(S1, "FERRIS"));
+
+        let cases = [
+            ("Ferris", "ferris", true),
+            ("Ferris", "FERRI", false),
+            ("", "", true),
+            ("ß", "ẞ", true),
+            ("ß", "ss", false),
+            ("Kelvin", "\u{212a}elvin", true),
+            ("ſ", "S", true),
+            ("ΣΑΣ", "σας", true),
+            ("ꭰ", "Ꭰ", true),
+            ("İ", "i", false),
+            ("ı", "I", false),
+            ("我", "我", true),
+        ];
+        for (lhs, rhs, ans) in cases {
+            assert_eq!(eq_ignore_case!(lhs, rhs), ans, "{lhs:?} {rhs:?}");
+            assert_eq!(eq_ignore_case!(rhs, lhs), ans, "{rhs:?} {lhs:?}");
+            if lhs.is_ascii() && rhs.is_ascii() {
+                assert_eq!(lhs.eq_ignore_ascii_case(rhs), ans);
+            }
+        }
+
+        assert!(eq_ignore_case!(b"Ferris", b"FERRIS"));
+        assert!(!eq_ignore_case!("Ferrös".as_bytes(), "FERRÖS".as_bytes()));
+        assert!(eq_ignore_case!("Ferrös".as_bytes(), "FERRöS".as_bytes()));
+    }
+
+    #[test]
+    fn test_starts_with_ignore_case() {
+        const _: () = assert!(starts_with_ignore_case!("Content-Type", "content-"));
+
+        assert!(starts_with_ignore_case!("Hello", ""));
+        assert!(starts_with_ignore_case!("", ""));
+        assert!(!starts_with_ignore_case!("", "a"));
+        assert!(starts_with_ignore_case!("ΣΊΣΥΦΟΣ", "σί"));
+        assert!(starts_with_ignore_case!("\u{212a}elvin", "KEL"));
+        assert!(!starts_with_ignore_case!("He", "Hello"));
+        assert!(starts_with_ignore_case!(b"HTTP/1.1", b"http/"));
+        assert!(!starts_with_ignore_case!(b"\xc3\x89", b"\xc3\xa9"));
+    }
+
+    #[test]
+    fn test_contains_ignore_case() {
+        const _: () = assert!(contains_ignore_case!("keep-alive, Upgrade", "upgrade"));
+
+        assert!(contains_ignore_case!("Hello", ""));
+        assert!(contains_ignore_case!("", ""));
+        assert!(!contains_ignore_case!("", "a"));
+        assert!(contains_ignore_case!("aaB", "ab"));
+        assert!(contains_ignore_case!("Grüße, Jürgen", "JÜRGEN"));
+        assert!(!contains_ignore_case!("Grüße", "GRÜSSE"));
+        assert!(!contains_ignore_case!("ab", "abc"));
+        assert!(contains_ignore_case!(b"X-Forwarded-For", b"FORWARDED"));
+        assert!(contains_ignore_case!(&b"x-a"[..], &b"A"[..]));
+    }
+}
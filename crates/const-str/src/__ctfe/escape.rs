@@ -0,0 +1,345 @@
+#![allow(unsafe_code)]
+
+use super::StrBuf;
+
+use crate::ascii::num_to_hex_digit;
+
+/// The target languages of [`Escape`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscapeKind {
+    /// The content of a JSON string
+    Json,
+    /// Text or attribute values in HTML
+    Html,
+    /// A single-quoted word of POSIX shells
+    Shell,
+    /// The content of a C string literal
+    C,
+}
+
+struct Escaped {
+    buf: [u8; 6],
+    len: usize,
+}
+
+impl Escaped {
+    const fn byte(b: u8) -> Self {
+        Self {
+            buf: [b, 0, 0, 0, 0, 0],
+            len: 1,
+        }
+    }
+
+    const fn ascii(s: &str) -> Self {
+        let bytes = s.as_bytes();
+        let mut buf = [0; 6];
+        let mut i = 0;
+        while i < bytes.len() {
+            buf[i] = bytes[i];
+            i += 1;
+        }
+        Self {
+            buf,
+            len: bytes.len(),
+        }
+    }
+
+    const fn json_unicode(b: u8) -> Self {
+        Self {
+            buf: [
+                b'\\',
+                b'u',
+                b'0',
+                b'0',
+                num_to_hex_digit(b >> 4),
+                num_to_hex_digit(b & 0x0f),
+            ],
+            len: 6,
+        }
+    }
+
+    const fn c_octal(b: u8) -> Self {
+        Self {
+            buf: [
+                b'\\',
+                b'0' + (b >> 6),
+                b'0' + ((b >> 3) & 7),
+                b'0' + (b & 7),
+                0,
+                0,
+            ],
+            len: 4,
+        }
+    }
+
+    const fn new(kind: EscapeKind, b: u8) -> Self {
+        match kind {
+            EscapeKind::Json => match b {
+                b'"' => Self::ascii("\\\""),
+                b'\\' => Self::ascii("\\\\"),
+                b'\x08' => Self::ascii("\\b"),
+                b'\x0c' => Self::ascii("\\f"),
+                b'\n' => Self::ascii("\\n"),
+                b'\r' => Self::ascii("\\r"),
+                b'\t' => Self::ascii("\\t"),
+                0..=0x1f => Self::json_unicode(b),
+                _ => Self::byte(b),
+            },
+            EscapeKind::Html => match b {
+                b'&' => Self::ascii("&amp;"),
+                b'<' => Self::ascii("&lt;"),
+                b'>' => Self::ascii("&gt;"),
+                b'"' => Self::ascii("&quot;"),
+                b'\'' => Self::ascii("&#39;"),
+                _ => Self::byte(b),
+            },
+            EscapeKind::Shell => match b {
+                b'\'' => Self::ascii("'\\''"),
+                _ => Self::byte(b),
+            },
+            EscapeKind::C => match b {
+                b'"' => Self::ascii("\\\""),
+                b'\\' => Self::ascii("\\\\"),
+                b'\x07' => Self::ascii("\\a"),
+                b'\x08' => Self::ascii("\\b"),
+                b'\x0c' => Self::ascii("\\f"),
+                b'\n' => Self::ascii("\\n"),
+                b'\r' => Self::ascii("\\r"),
+                b'\t' => Self::ascii("\\t"),
+                b'\x0b' => Self::ascii("\\v"),
+                b' '..=b'~' => Self::byte(b),
+                _ => Self::c_octal(b),
+            },
+        }
+    }
+
+    const fn as_bytes(&self) -> &[u8] {
+        crate::slice::subslice(&self.buf, 0..self.len)
+    }
+}
+
+const fn quote(kind: EscapeKind) -> &'static str {
+    match kind {
+        EscapeKind::Shell => "'",
+        _ => "",
+    }
+}
+
+pub struct Escape<T>(pub T, pub EscapeKind);
+
+impl Escape<&str> {
+    pub const fn output_len(&self) -> usize {
+        let bytes = self.0.as_bytes();
+        let mut ans = quote(self.1).len() * 2;
+        let mut i = 0;
+        while i < bytes.len() {
+            ans += Escaped::new(self.1, bytes[i]).len;
+            i += 1;
+        }
+        ans
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let mut buf = [0; N];
+        let mut pos = 0;
+
+        macro_rules! push_bytes {
+            ($bytes: expr) => {{
+                let bytes: &[u8] = $bytes;
+                let mut k = 0;
+                while k < bytes.len() {
+                    buf[pos] = bytes[k];
+                    pos += 1;
+                    k += 1;
+                }
+            }};
+        }
+
+        push_bytes!(quote(self.1).as_bytes());
+
+        let bytes = self.0.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let e = Escaped::new(self.1, bytes[i]);
+            push_bytes!(e.as_bytes());
+            i += 1;
+        }
+
+        push_bytes!(quote(self.1).as_bytes());
+
+        assert!(pos == N);
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __escape {
+    ($s: expr, $kind: ident) => {{
+        const OUTPUT_LEN: usize =
+            $crate::__ctfe::Escape($s, $crate::__ctfe::EscapeKind::$kind).output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<OUTPUT_LEN> =
+            $crate::__ctfe::Escape($s, $crate::__ctfe::EscapeKind::$kind).const_eval();
+        OUTPUT_BUF.as_str()
+    }};
+}
+
+/// Escapes a string slice for the content of a JSON string.
+///
+/// `"` and `\` are escaped with a backslash,
+/// and control characters are escaped as `\b`, `\f`, `\n`, `\r`, `\t` or `\u00XX`.
+/// Other characters are kept as they are.
+///
+/// The output does not include the surrounding quotes.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::{concat, escape_json};
+///
+/// const MSG: &str = "say \"hi\"\n\t\\ 😀\x1b";
+/// const JSON: &str = concat!("{\"msg\":\"", escape_json!(MSG), "\"}");
+/// assert_eq!(JSON, r#"{"msg":"say \"hi\"\n\t\\ 😀\u001b"}"#);
+/// ```
+#[macro_export]
+macro_rules! escape_json {
+    ($s: expr) => {
+        $crate::__escape!($s, Json)
+    };
+}
+
+/// Escapes a string slice for HTML text or a quoted attribute value.
+///
+/// `&`, `<`, `>`, `"` and `'` are replaced with
+/// `&amp;`, `&lt;`, `&gt;`, `&quot;` and `&#39;`.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::escape_html;
+///
+/// const TITLE: &str = escape_html!("<Tom & Jerry's \"show\">");
+/// assert_eq!(TITLE, "&lt;Tom &amp; Jerry&#39;s &quot;show&quot;&gt;");
+/// ```
+#[macro_export]
+macro_rules! escape_html {
+    ($s: expr) => {
+        $crate::__escape!($s, Html)
+    };
+}
+
+/// Quotes a string slice as a single word of POSIX shells.
+///
+/// The output is always enclosed in single quotes,
+/// and each `'` is replaced with `'\''`.
+/// No other characters are special in single quotes.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::{concat, shell_quote};
+///
+/// const PATH: &str = "/tmp/it's a file";
+/// const CMD: &str = concat!("rm -- ", shell_quote!(PATH));
+/// assert_eq!(CMD, r#"rm -- '/tmp/it'\''s a file'"#);
+///
+/// assert_eq!(shell_quote!(""), "''");
+/// assert_eq!(shell_quote!("$HOME"), "'$HOME'");
+/// ```
+#[macro_export]
+macro_rules! shell_quote {
+    ($s: expr) => {
+        $crate::__escape!($s, Shell)
+    };
+}
+
+/// Escapes a string slice for the content of a C string literal.
+///
+/// `"` and `\` are escaped with a backslash, and the control characters with
+/// simple escape sequences (`\a`, `\b`, `\f`, `\n`, `\r`, `\t`, `\v`) are escaped as such.
+/// Other bytes out of printable ASCII, including the UTF-8 bytes of non-ASCII characters,
+/// are escaped as three-digit octal escape sequences,
+/// which never absorb the following characters like hex escape sequences.
+///
+/// The output does not include the surrounding quotes.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::{concat, escape_c};
+///
+/// const MSG: &str = "Grüße\n\"C\"\0";
+/// const SRC: &str = concat!("const char *msg = \"", escape_c!(MSG), "\";");
+/// assert_eq!(SRC, r#"const char *msg = "Gr\303\274\303\237e\n\"C\"\000";"#);
+/// ```
+#[macro_export]
+macro_rules! escape_c {
+    ($s: expr) => {
+        $crate::__escape!($s, C)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_json() {
+        assert_eq!(escape_json!(""), "");
+        assert_eq!(escape_json!("abc"), "abc");
+        assert_eq!(escape_json!("\"\\/"), "\\\"\\\\/");
+        assert_eq!(
+            escape_json!("\x00\x08\x0c\n\r\t\x1f\x7f"),
+            "\\u0000\\b\\f\\n\\r\\t\\u001f\x7f"
+        );
+        assert_eq!(escape_json!("你好 \u{2028}"), "你好 \u{2028}");
+        assert_eq!(escape_json!("'<>&"), "'<>&");
+    }
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(escape_html!(""), "");
+        assert_eq!(escape_html!("a&b"), "a&amp;b");
+        assert_eq!(escape_html!("&amp;"), "&amp;amp;");
+        assert_eq!(
+            escape_html!("<a href=\"x\">'</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&lt;/a&gt;"
+        );
+        assert_eq!(escape_html!("中文\n"), "中文\n");
+    }
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(shell_quote!(""), "''");
+        assert_eq!(shell_quote!("abc"), "'abc'");
+        assert_eq!(shell_quote!("'"), "''\\'''");
+        assert_eq!(shell_quote!("a'b'c"), "'a'\\''b'\\''c'");
+        assert_eq!(
+            shell_quote!("$(rm -rf /) `x` \"y\" \\z\n"),
+            "'$(rm -rf /) `x` \"y\" \\z\n'"
+        );
+    }
+
+    #[test]
+    fn test_escape_c() {
+        assert_eq!(escape_c!(""), "");
+        assert_eq!(escape_c!("hello, world"), "hello, world");
+        assert_eq!(escape_c!("\x07\x08\x0c\n\r\t\x0b"), "\\a\\b\\f\\n\\r\\t\\v");
+        assert_eq!(escape_c!("\"\\'?"), "\\\"\\\\'?");
+        assert_eq!(escape_c!("\x001\x1b[0m\x7f"), "\\0001\\033[0m\\177");
+        assert_eq!(escape_c!("é"), "\\303\\251");
+
+        let escape = Escape("a\"", EscapeKind::C);
+        assert_eq!(escape.output_len(), 3);
+        let buf: StrBuf<3> = escape.const_eval();
+        assert_eq!(buf.as_str(), "a\\\"");
+    }
+}
@@ -1,56 +1,148 @@
+#![allow(unsafe_code)]
+
+use super::StrBuf;
+use crate::ascii::num_to_hex_digit;
+
+#[derive(Clone, Copy)]
+pub struct HexOptions<'a> {
+    strict: bool,
+    separators: &'a str,
+    prefix: bool,
+}
+
+impl<'a> HexOptions<'a> {
+    pub const DEFAULT: Self = Self {
+        strict: false,
+        separators: "",
+        prefix: false,
+    };
+
+    pub const fn strict(self) -> Self {
+        Self {
+            strict: true,
+            ..self
+        }
+    }
+
+    pub const fn separators(self, separators: &'a str) -> Self {
+        let bytes = separators.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let b = bytes[i];
+            assert!(
+                b.is_ascii() && !b.is_ascii_hexdigit(),
+                "separators must be ASCII non-hex characters"
+            );
+            i += 1;
+        }
+        Self { separators, ..self }
+    }
+
+    pub const fn prefix(self) -> Self {
+        Self {
+            prefix: true,
+            ..self
+        }
+    }
+
+    const fn is_separator(&self, b: u8) -> bool {
+        let seps = self.separators.as_bytes();
+        let mut i = 0;
+        while i < seps.len() {
+            if seps[i] == b {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+}
+
 pub struct Hex<T>(pub T);
 
+const fn hex_digit(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
 struct Iter<'a> {
-    s: &'a [u8],
+    parts: &'a [&'a str],
+    part: usize,
     i: usize,
+    token_start: bool,
+    opts: HexOptions<'a>,
 }
 
 impl<'a> Iter<'a> {
-    const fn new(s: &'a str) -> Self {
+    const fn new(parts: &'a [&'a str], opts: HexOptions<'a>) -> Self {
         Self {
-            s: s.as_bytes(),
+            parts,
+            part: 0,
             i: 0,
+            token_start: true,
+            opts,
+        }
+    }
+
+    const fn error(&self, msg: &str, offset: usize) -> ! {
+        if self.parts.len() > 1 {
+            const_panic!(msg, " at offset ", usize: offset, " of string ", usize: self.part)
+        } else {
+            const_panic!(msg, " at offset ", usize: offset)
         }
     }
 
     const fn next(mut self) -> (Self, Option<u8>) {
-        let mut i = self.i;
-        let s = self.s;
-
-        macro_rules! next {
-            () => {{
-                if i < s.len() {
-                    let b = s[i];
-                    i += 1;
-                    Some(b)
-                } else {
-                    None
+        while self.part < self.parts.len() {
+            let s = self.parts[self.part].as_bytes();
+            while self.i < s.len() {
+                let i = self.i;
+                let b = s[i];
+
+                if self.token_start
+                    && self.opts.prefix
+                    && b == b'0'
+                    && i + 1 < s.len()
+                    && matches!(s[i + 1], b'x' | b'X')
+                {
+                    self.i += 2;
+                    self.token_start = false;
+                    continue;
                 }
-            }};
-        }
 
-        while let Some(b) = next!() {
-            let high = match b {
-                b'0'..=b'9' => b - b'0',
-                b'a'..=b'f' => b - b'a' + 10,
-                b'A'..=b'F' => b - b'A' + 10,
-                b' ' | b'\r' | b'\n' | b'\t' => continue,
-                _ => panic!("invalid character"),
-            };
-
-            let low = match next!() {
-                None => panic!("expected even number of hex characters"),
-                Some(b) => match b {
-                    b'0'..=b'9' => b - b'0',
-                    b'a'..=b'f' => b - b'a' + 10,
-                    b'A'..=b'F' => b - b'A' + 10,
-                    _ => panic!("expected hex character"),
-                },
-            };
-
-            self.i = i;
-            let val = (high << 4) | low;
-            return (self, Some(val));
+                if let Some(high) = hex_digit(b) {
+                    let low = if i + 1 < s.len() {
+                        hex_digit(s[i + 1])
+                    } else {
+                        None
+                    };
+                    let low = match low {
+                        Some(low) => low,
+                        None if i + 1 < s.len() => self.error("expected hex character", i + 1),
+                        None => self.error("unpaired hex character", i),
+                    };
+                    self.i += 2;
+                    self.token_start = false;
+                    return (self, Some((high << 4) | low));
+                }
+
+                let skipped = match b {
+                    b' ' | b'\r' | b'\n' | b'\t' => !self.opts.strict,
+                    _ => self.opts.is_separator(b),
+                };
+                if !skipped {
+                    self.error("invalid character", i);
+                }
+                self.i += 1;
+                self.token_start = true;
+            }
+            self.part += 1;
+            self.i = 0;
+            self.token_start = true;
         }
         (self, None)
     }
@@ -58,33 +150,32 @@ impl<'a> Iter<'a> {
 
 impl Hex<&[&str]> {
     pub const fn output_len(&self) -> usize {
-        let mut i = 0;
-        let mut ans = 0;
+        self.output_len_with_options(HexOptions::DEFAULT)
+    }
 
-        while i < self.0.len() {
-            let mut iter = Iter::new(self.0[i]);
-            while let (next, Some(_)) = iter.next() {
-                iter = next;
-                ans += 1;
-            }
-            i += 1;
+    pub const fn output_len_with_options(&self, opts: HexOptions<'_>) -> usize {
+        let mut ans = 0;
+        let mut iter = Iter::new(self.0, opts);
+        while let (next, Some(_)) = iter.next() {
+            iter = next;
+            ans += 1;
         }
         ans
     }
 
     pub const fn const_eval<const N: usize>(&self) -> [u8; N] {
+        self.const_eval_with_options(HexOptions::DEFAULT)
+    }
+
+    pub const fn const_eval_with_options<const N: usize>(&self, opts: HexOptions<'_>) -> [u8; N] {
         let mut buf = [0; N];
         let mut pos = 0;
 
-        let mut i = 0;
-        while i < self.0.len() {
-            let mut iter = Iter::new(self.0[i]);
-            while let (next, Some(val)) = iter.next() {
-                iter = next;
-                buf[pos] = val;
-                pos += 1;
-            }
-            i += 1;
+        let mut iter = Iter::new(self.0, opts);
+        while let (next, Some(val)) = iter.next() {
+            iter = next;
+            buf[pos] = val;
+            pos += 1;
         }
         assert!(pos == N);
         buf
@@ -93,23 +184,35 @@ impl Hex<&[&str]> {
 
 impl Hex<&str> {
     pub const fn output_len(&self) -> usize {
+        self.output_len_with_options(HexOptions::DEFAULT)
+    }
+    pub const fn output_len_with_options(&self, opts: HexOptions<'_>) -> usize {
         let ss: &[&str] = &[self.0];
-        Hex(ss).output_len()
+        Hex(ss).output_len_with_options(opts)
     }
     pub const fn const_eval<const N: usize>(&self) -> [u8; N] {
+        self.const_eval_with_options(HexOptions::DEFAULT)
+    }
+    pub const fn const_eval_with_options<const N: usize>(&self, opts: HexOptions<'_>) -> [u8; N] {
         let ss: &[&str] = &[self.0];
-        Hex(ss).const_eval()
+        Hex(ss).const_eval_with_options(opts)
     }
 }
 
 impl<const L: usize> Hex<[&str; L]> {
     pub const fn output_len(&self) -> usize {
+        self.output_len_with_options(HexOptions::DEFAULT)
+    }
+    pub const fn output_len_with_options(&self, opts: HexOptions<'_>) -> usize {
         let ss: &[&str] = &self.0;
-        Hex(ss).output_len()
+        Hex(ss).output_len_with_options(opts)
     }
     pub const fn const_eval<const N: usize>(&self) -> [u8; N] {
+        self.const_eval_with_options(HexOptions::DEFAULT)
+    }
+    pub const fn const_eval_with_options<const N: usize>(&self, opts: HexOptions<'_>) -> [u8; N] {
         let ss: &[&str] = &self.0;
-        Hex(ss).const_eval()
+        Hex(ss).const_eval_with_options(opts)
     }
 }
 
@@ -122,8 +225,21 @@ impl<const L: usize> Hex<[&str; L]> {
 /// - `' '`, `'\r'`, `'\n'`, `'\t'` — formatting characters which will be
 ///   ignored
 ///
+/// The two hex characters of a byte must be adjacent.
+///
+/// The accepted input can be changed by the options after the input:
+/// - `strict` — rejects the formatting characters.
+/// - `separators(SEPS)` — ignores the ASCII characters in the string slice `SEPS`,
+///   such as `":"`, `"-"` or `"_"`.
+/// - `prefix` — ignores a `0x` or `0X` prefix at the beginning of the input
+///   and after each ignored character.
+///
+/// The compilation fails with the byte offset of the first invalid character.
+///
 /// This macro is [const-context only](./index.html#const-context-only).
 ///
+/// See also [`to_hex!`](crate::to_hex).
+///
 /// # Examples
 /// ```
 /// use const_str::hex;
@@ -145,16 +261,192 @@ impl<const L: usize> Hex<[&str; L]> {
 ///
 /// assert_eq!(B1, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
 /// assert_eq!(B2, B1);
+///
+/// const MAC: [u8; 6] = hex!("00:1A:2B-3C:4D:5E", strict, separators(":-"));
+/// assert_eq!(MAC, [0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+///
+/// const BYTES: [u8; 3] = hex!("0x01, 0x02, 0xff", separators(","), prefix);
+/// assert_eq!(BYTES, [1, 2, 255]);
+///
+/// assert_eq!(hex!("0xdead_beef", prefix, separators("_")), [0xDE, 0xAD, 0xBE, 0xEF]);
+/// ```
+///
+/// ```compile_fail
+/// // invalid character at offset 2
+/// const DATA: [u8; 2] = const_str::hex!("01 02", strict);
 /// ```
 #[macro_export]
 macro_rules! hex {
-    ($s: expr) => {{
-        const OUTPUT_LEN: usize = $crate::__ctfe::Hex($s).output_len();
-        const OUTPUT_BUF: [u8; OUTPUT_LEN] = $crate::__ctfe::Hex($s).const_eval();
+    ($s: expr $(, $opt: ident $(($($arg: expr),*))?)* $(,)?) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::Hex($s).output_len_with_options(
+            $crate::__ctfe::HexOptions::DEFAULT $(.$opt($($($arg),*)?))*
+        );
+        const OUTPUT_BUF: [u8; OUTPUT_LEN] = $crate::__ctfe::Hex($s).const_eval_with_options(
+            $crate::__ctfe::HexOptions::DEFAULT $(.$opt($($($arg),*)?))*
+        );
         OUTPUT_BUF
     }};
 }
 
+#[derive(Clone, Copy)]
+pub struct ToHexOptions<'a> {
+    upper: bool,
+    sep: &'a str,
+}
+
+impl<'a> ToHexOptions<'a> {
+    pub const DEFAULT: Self = Self {
+        upper: false,
+        sep: "",
+    };
+
+    pub const fn lower(self) -> Self {
+        Self {
+            upper: false,
+            ..self
+        }
+    }
+
+    pub const fn upper(self) -> Self {
+        Self {
+            upper: true,
+            ..self
+        }
+    }
+
+    pub const fn sep(self, sep: &'a str) -> Self {
+        Self { sep, ..self }
+    }
+}
+
+pub struct ToHex<'a, T>(pub T, pub ToHexOptions<'a>);
+
+impl ToHex<'_, &[u8]> {
+    pub const fn output_len(&self) -> usize {
+        let n = self.0.len();
+        if n == 0 {
+            0
+        } else {
+            n * 2 + (n - 1) * self.1.sep.len()
+        }
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        assert!(N == self.output_len());
+
+        let sep = self.1.sep.as_bytes();
+        let mut buf = [0; N];
+        let mut pos = 0;
+
+        let mut i = 0;
+        while i < self.0.len() {
+            if i > 0 {
+                let mut j = 0;
+                while j < sep.len() {
+                    buf[pos] = sep[j];
+                    pos += 1;
+                    j += 1;
+                }
+            }
+
+            let b = self.0[i];
+            let mut digits = [num_to_hex_digit(b >> 4), num_to_hex_digit(b & 0x0f)];
+            if self.1.upper {
+                digits[0] = digits[0].to_ascii_uppercase();
+                digits[1] = digits[1].to_ascii_uppercase();
+            }
+            buf[pos] = digits[0];
+            buf[pos + 1] = digits[1];
+            pos += 2;
+
+            i += 1;
+        }
+
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+impl<const L: usize> ToHex<'_, [u8; L]> {
+    pub const fn output_len(&self) -> usize {
+        ToHex(self.0.as_slice(), self.1).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        ToHex(self.0.as_slice(), self.1).const_eval()
+    }
+}
+
+impl<const L: usize> ToHex<'_, &[u8; L]> {
+    pub const fn output_len(&self) -> usize {
+        ToHex(self.0.as_slice(), self.1).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        ToHex(self.0.as_slice(), self.1).const_eval()
+    }
+}
+
+impl ToHex<'_, &str> {
+    pub const fn output_len(&self) -> usize {
+        ToHex(self.0.as_bytes(), self.1).output_len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        ToHex(self.0.as_bytes(), self.1).const_eval()
+    }
+}
+
+/// Converts bytes to a hexadecimal string slice.
+///
+/// The input type must be one of:
+/// + [`&str`](str)
+/// + [`&[u8]`](slice)
+/// + [`[u8; N]`](array)
+/// + [`&[u8; N]`](array)
+///
+/// The output type is [`&str`](str).
+///
+/// The letter case can be specified after the input, which is either `lower` (default) or `upper`.
+/// A separator string slice can follow the case, which is inserted between bytes.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`hex!`](crate::hex).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::to_hex;
+///
+/// const ID: &str = to_hex!(b"\x01\xab");
+/// assert_eq!(ID, "01ab");
+///
+/// const MAC: [u8; 6] = [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e];
+/// assert_eq!(to_hex!(MAC, upper, ":"), "00:1A:2B:3C:4D:5E");
+/// assert_eq!(to_hex!(&MAC, lower, " "), "00 1a 2b 3c 4d 5e");
+///
+/// const DIGEST: [u8; 4] = const_str::hex!("deadbeef");
+/// const MESSAGE: &str = const_str::concat!("digest: 0x", to_hex!(DIGEST, upper));
+/// assert_eq!(MESSAGE, "digest: 0xDEADBEEF");
+/// ```
+///
+#[macro_export]
+macro_rules! to_hex {
+    ($s: expr $(, $case: ident $(, $sep: expr)?)? $(,)?) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::ToHex(
+            $s,
+            $crate::__ctfe::ToHexOptions::DEFAULT $(.$case() $(.sep($sep))?)?,
+        )
+        .output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<OUTPUT_LEN> = $crate::__ctfe::ToHex(
+            $s,
+            $crate::__ctfe::ToHexOptions::DEFAULT $(.$case() $(.sep($sep))?)?,
+        )
+        .const_eval();
+        OUTPUT_BUF.as_str()
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -230,4 +522,76 @@ mod tests {
         let buf5: [u8; 3] = hex_mixed.const_eval();
         assert_eq!(buf5, [0xAA, 0xBB, 0xCC]);
     }
+
+    #[test]
+    fn test_hex_options() {
+        const STRICT: [u8; 3] = hex!("0102ff", strict);
+        assert_eq!(STRICT, [1, 2, 255]);
+
+        assert_eq!(hex!("01:02-03_04", separators(":-_")), [1, 2, 3, 4]);
+        assert_eq!(hex!("01: 02", separators(":")), [1, 2]);
+        assert_eq!(hex!("01::02:", strict, separators(":")), [1, 2]);
+
+        assert_eq!(hex!("0x0102", prefix), [1, 2]);
+        assert_eq!(hex!("0X01 0x02", prefix), [1, 2]);
+        assert_eq!(hex!(["0x01", "0x02"], strict, prefix), [1, 2]);
+        assert_eq!(hex!("{ 0x01, 0x02 }", prefix, separators("{,}")), [1, 2]);
+        assert_eq!(hex!("00", prefix), [0]);
+        assert_eq!(hex!("0x", prefix), []);
+    }
+
+    #[test]
+    #[should_panic = "invalid character at offset 2"]
+    fn test_hex_strict_whitespace() {
+        let _ = Hex("01 02").output_len_with_options(HexOptions::DEFAULT.strict());
+    }
+
+    #[test]
+    #[should_panic = "expected hex character at offset 1"]
+    fn test_hex_split_pair() {
+        let _ = Hex("0 1").output_len();
+    }
+
+    #[test]
+    #[should_panic = "unpaired hex character at offset 2 of string 1"]
+    fn test_hex_unpaired() {
+        let _ = Hex(["01", "020"]).output_len();
+    }
+
+    #[test]
+    #[should_panic = "invalid character at offset 3"]
+    fn test_hex_invalid_character() {
+        let _ = Hex("01 g2").output_len();
+    }
+
+    #[test]
+    #[should_panic = "expected hex character at offset 3"]
+    fn test_hex_prefix_inside_token() {
+        let _ = Hex("010x02").output_len_with_options(HexOptions::DEFAULT.prefix());
+    }
+
+    #[test]
+    fn test_to_hex() {
+        const ID: &str = to_hex!(b"\x01\xab");
+        assert_eq!(ID, "01ab");
+
+        assert_eq!(to_hex!(b"\x01\xab", lower), "01ab");
+        assert_eq!(to_hex!(b"\x01\xab", upper), "01AB");
+        assert_eq!(to_hex!(b"", upper, ":"), "");
+        assert_eq!(to_hex!(b"\xff", upper, ":"), "FF");
+        assert_eq!(to_hex!("AZ", lower, ", "), "41, 5a");
+        assert_eq!(to_hex!([0_u8, 15, 16, 255], lower, "→"), "00→0f→10→ff");
+
+        const BYTES: &[u8] = &[0x12, 0x34, 0xcd];
+        assert_eq!(to_hex!(BYTES, upper, " "), "12 34 CD");
+
+        const ROUNDTRIP: [u8; 3] = hex!(to_hex!(BYTES, upper, " "));
+        assert_eq!(ROUNDTRIP, *BYTES);
+
+        let opts = ToHexOptions::DEFAULT.upper().sep("-");
+        let to_hex = ToHex(BYTES, opts);
+        assert_eq!(to_hex.output_len(), 8);
+        let buf: StrBuf<8> = to_hex.const_eval();
+        assert_eq!(buf.as_str(), "12-34-CD");
+    }
 }
@@ -0,0 +1,254 @@
+use super::AsciiCase;
+
+pub struct IsCase<T>(pub T, pub AsciiCase);
+
+impl IsCase<&str> {
+    pub const fn const_eval(&self) -> bool {
+        self.1.matches(self.0)
+    }
+}
+
+pub struct DetectCase<T>(pub T);
+
+impl DetectCase<&str> {
+    pub const fn const_eval(&self) -> Option<AsciiCase> {
+        AsciiCase::detect(self.0)
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ascii_case {
+    (lower) => {
+        $crate::__ctfe::AsciiCase::Lower
+    };
+    (upper) => {
+        $crate::__ctfe::AsciiCase::Upper
+    };
+    (lower_camel) => {
+        $crate::__ctfe::AsciiCase::LowerCamel
+    };
+    (upper_camel) => {
+        $crate::__ctfe::AsciiCase::UpperCamel
+    };
+    (title) => {
+        $crate::__ctfe::AsciiCase::Title
+    };
+    (train) => {
+        $crate::__ctfe::AsciiCase::Train
+    };
+    (snake) => {
+        $crate::__ctfe::AsciiCase::Snake
+    };
+    (kebab) => {
+        $crate::__ctfe::AsciiCase::Kebab
+    };
+    (shouty_snake) => {
+        $crate::__ctfe::AsciiCase::ShoutySnake
+    };
+    (shouty_kebab) => {
+        $crate::__ctfe::AsciiCase::ShoutyKebab
+    };
+    (cobol) => {
+        $crate::__ctfe::AsciiCase::ShoutyKebab
+    };
+    (dot) => {
+        $crate::__ctfe::AsciiCase::Dot
+    };
+    (path) => {
+        $crate::__ctfe::AsciiCase::Path
+    };
+    (sentence) => {
+        $crate::__ctfe::AsciiCase::Sentence
+    };
+    (ada) => {
+        $crate::__ctfe::AsciiCase::Ada
+    };
+    (flat) => {
+        $crate::__ctfe::AsciiCase::Flat
+    };
+    (upper_flat) => {
+        $crate::__ctfe::AsciiCase::UpperFlat
+    };
+}
+
+/// Checks whether a string slice is in a specified case,
+/// that is, [`convert_ascii_case!`](crate::convert_ascii_case) would not change it.
+///
+/// The case names are the same as [`convert_ascii_case!`](crate::convert_ascii_case).
+///
+/// This macro is [const-fn compatible](./index.html#const-fn-compatible).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::is_case;
+///
+/// const _: () = {
+///     assert!(is_case!(kebab, "content-type"));
+///     assert!(is_case!(shouty_snake, "MAX_SIZE"));
+///     assert!(!is_case!(snake, "maxSize"));
+///     assert!(!is_case!(upper_camel, "_Private"));
+/// };
+/// ```
+#[macro_export]
+macro_rules! is_case {
+    ($case: tt, $s: expr) => {
+        $crate::__ctfe::IsCase($s, $crate::__ascii_case!($case)).const_eval()
+    };
+}
+
+/// Detects the case of a string slice.
+///
+/// Returns [`None`] if the string has no words or matches no case.
+/// `lower` and `upper` are never returned.
+///
+/// A string can be in several cases at once, such as a single lowercase word.
+/// In that case, the first match in this order is returned:
+/// `snake`, `kebab`, `dot`, `path`, `shouty_snake`, `shouty_kebab`,
+/// `upper_camel`, `lower_camel`, `title`, `train`, `sentence`, `ada`, `flat`, `upper_flat`.
+///
+/// This macro is [const-fn compatible](./index.html#const-fn-compatible).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::ascii_case::AsciiCase;
+/// use const_str::detect_case;
+///
+/// const C1: Option<AsciiCase> = detect_case!("content-type");
+/// const C2: Option<AsciiCase> = detect_case!("HttpRequest");
+/// const C3: Option<AsciiCase> = detect_case!("hello");
+/// const C4: Option<AsciiCase> = detect_case!("mixed_Case");
+///
+/// assert_eq!(C1, Some(AsciiCase::Kebab));
+/// assert_eq!(C2, Some(AsciiCase::UpperCamel));
+/// assert_eq!(C3, Some(AsciiCase::Snake));
+/// assert_eq!(C4, None);
+/// ```
+#[macro_export]
+macro_rules! detect_case {
+    ($s: expr) => {
+        $crate::__ctfe::DetectCase($s).const_eval()
+    };
+}
+
+/// Asserts that a string slice is in a specified case.
+///
+/// The compilation fails if [`is_case!`](crate::is_case) returns false,
+/// and the message shows the expected form of the string.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// # Examples
+///
+/// ```
+/// const METRIC: &str = "http_requests_total";
+/// const_str::assert_case!(snake, METRIC);
+/// ```
+///
+/// ```compile_fail
+/// const METRIC: &str = "httpRequestsTotal";
+/// // "httpRequestsTotal" is not in snake case, expected "http_requests_total"
+/// const_str::assert_case!(snake, METRIC);
+/// ```
+#[macro_export]
+macro_rules! assert_case {
+    ($case: tt, $s: expr) => {
+        const _: () = {
+            if !$crate::is_case!($case, $s) {
+                const MSG: &str = $crate::concat!(
+                    "\"",
+                    $s,
+                    "\" is not in ",
+                    stringify!($case),
+                    " case, expected \"",
+                    $crate::convert_ascii_case!($case, $s),
+                    "\"",
+                );
+                panic!("{}", MSG);
+            }
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::__ctfe::AsciiCase;
+
+    #[test]
+    fn test_is_case() {
+        const S1: &str = "hello_world";
+        const _: () = assert!(is_case!(snake, S1));
+
+        assert!(is_case!(snake, "hello_world"));
+        assert!(is_case!(kebab, "hello-world"));
+        assert!(is_case!(lower_camel, "helloWorld"));
+        assert!(is_case!(upper_camel, "HelloWorld"));
+        assert!(is_case!(title, "Hello World"));
+        assert!(is_case!(train, "Hello-World"));
+        assert!(is_case!(shouty_snake, "HELLO_WORLD"));
+        assert!(is_case!(shouty_kebab, "HELLO-WORLD"));
+        assert!(is_case!(cobol, "HELLO-WORLD"));
+        assert!(is_case!(dot, "hello.world"));
+        assert!(is_case!(path, "hello/world"));
+        assert!(is_case!(sentence, "Hello world"));
+        assert!(is_case!(ada, "Hello_World"));
+        assert!(is_case!(flat, "helloworld"));
+        assert!(is_case!(upper_flat, "HELLOWORLD"));
+        assert!(is_case!(lower, "hello, world!"));
+        assert!(is_case!(upper, "HELLO, WORLD!"));
+        assert!(is_case!(snake, ""));
+        assert!(is_case!(snake, "v2"));
+
+        assert!(!is_case!(snake, "hello__world"));
+        assert!(!is_case!(snake, "_hello"));
+        assert!(!is_case!(snake, "hello_"));
+        assert!(!is_case!(kebab, "hello_world"));
+        assert!(!is_case!(lower_camel, "HelloWorld"));
+        assert!(!is_case!(upper_camel, "HTTPServer"));
+        assert!(!is_case!(title, "Hello  World"));
+        assert!(!is_case!(lower, "Hello"));
+
+        let s = String::from("hello-world");
+        assert!(is_case!(kebab, s.as_str()));
+    }
+
+    #[test]
+    fn test_detect_case() {
+        const C: Option<AsciiCase> = detect_case!("hello_world");
+        assert_eq!(C, Some(AsciiCase::Snake));
+
+        let cases = [
+            ("hello-world", Some(AsciiCase::Kebab)),
+            ("hello.world", Some(AsciiCase::Dot)),
+            ("hello/world", Some(AsciiCase::Path)),
+            ("HELLO_WORLD", Some(AsciiCase::ShoutySnake)),
+            ("HELLO-WORLD", Some(AsciiCase::ShoutyKebab)),
+            ("HelloWorld", Some(AsciiCase::UpperCamel)),
+            ("helloWorld", Some(AsciiCase::LowerCamel)),
+            ("Hello World", Some(AsciiCase::Title)),
+            ("Hello-World", Some(AsciiCase::Train)),
+            ("Hello world", Some(AsciiCase::Sentence)),
+            ("Hello_World", Some(AsciiCase::Ada)),
+            ("hello", Some(AsciiCase::Snake)),
+            ("HELLO", Some(AsciiCase::ShoutySnake)),
+            ("Hello", Some(AsciiCase::UpperCamel)),
+            ("hello world", None),
+            ("Hello_world", None),
+            ("", None),
+            ("__", None),
+        ];
+        for (s, ans) in cases {
+            assert_eq!(detect_case!(s), ans, "{s:?}");
+        }
+    }
+
+    #[test]
+    fn test_assert_case() {
+        const NAME: &str = "x-request-id";
+        assert_case!(kebab, NAME);
+        assert_case!(shouty_snake, "MAX_LEN");
+        assert_case!(upper_camel, crate::concat!("Http", "Server"));
+    }
+}
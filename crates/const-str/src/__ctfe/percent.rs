@@ -0,0 +1,326 @@
+#![allow(unsafe_code)]
+
+use super::StrBuf;
+
+/// The characters that are not percent-encoded by [`percent_encode!`](crate::percent_encode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PercentEncodeSet {
+    /// `ALPHA / DIGIT / "-" / "." / "_" / "~"`
+    Unreserved,
+    /// unreserved, sub-delims, `":"`, `"@"` and `"/"`
+    Path,
+    /// unreserved, sub-delims, `":"`, `"@"`, `"/"` and `"?"`
+    Query,
+    /// unreserved, sub-delims, `":"`, `"@"`, `"/"` and `"?"`
+    Fragment,
+    /// unreserved, sub-delims and `":"`
+    Userinfo,
+}
+
+impl PercentEncodeSet {
+    const fn contains(self, b: u8) -> bool {
+        let unreserved =
+            matches!(b, b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~');
+        let sub_delims = matches!(
+            b,
+            b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'='
+        );
+        match self {
+            Self::Unreserved => unreserved,
+            Self::Path => unreserved || sub_delims || matches!(b, b':' | b'@' | b'/'),
+            Self::Query | Self::Fragment => {
+                unreserved || sub_delims || matches!(b, b':' | b'@' | b'/' | b'?')
+            }
+            Self::Userinfo => unreserved || sub_delims || b == b':',
+        }
+    }
+}
+
+pub struct PercentEncode<T>(pub T, pub PercentEncodeSet);
+
+impl PercentEncode<&str> {
+    pub const fn output_len(&self) -> usize {
+        let bytes = self.0.as_bytes();
+        let mut ans = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            ans += if self.1.contains(bytes[i]) { 1 } else { 3 };
+            i += 1;
+        }
+        ans
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let bytes = self.0.as_bytes();
+        let mut buf = [0; N];
+        let mut pos = 0;
+
+        let mut i = 0;
+        while i < bytes.len() {
+            let b = bytes[i];
+            if self.1.contains(b) {
+                buf[pos] = b;
+                pos += 1;
+            } else {
+                buf[pos] = b'%';
+                buf[pos + 1] = crate::ascii::num_to_hex_digit(b >> 4).to_ascii_uppercase();
+                buf[pos + 2] = crate::ascii::num_to_hex_digit(b & 0x0f).to_ascii_uppercase();
+                pos += 3;
+            }
+            i += 1;
+        }
+
+        assert!(pos == N);
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+pub struct PercentDecode<T>(pub T);
+
+const fn hex_digit(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decodes the `%XX` sequence at `i`.
+const fn decode_escape(s: &[u8], i: usize) -> u8 {
+    if i + 2 < s.len() {
+        if let (Some(high), Some(low)) = (hex_digit(s[i + 1]), hex_digit(s[i + 2])) {
+            return (high << 4) | low;
+        }
+    }
+    const_panic!("invalid percent-encoding at offset ", usize: i)
+}
+
+impl PercentDecode<&str> {
+    pub const fn output_len(&self) -> usize {
+        let bytes = self.0.as_bytes();
+        let mut ans = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'%' {
+                decode_escape(bytes, i);
+                i += 3;
+            } else {
+                i += 1;
+            }
+            ans += 1;
+        }
+        ans
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let bytes = self.0.as_bytes();
+        let mut buf = [0; N];
+        let mut pos = 0;
+
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'%' {
+                buf[pos] = decode_escape(bytes, i);
+                i += 3;
+            } else {
+                buf[pos] = bytes[i];
+                i += 1;
+            }
+            pos += 1;
+        }
+        assert!(pos == N);
+
+        if let Err(err) = core::str::from_utf8(&buf) {
+            const_panic!(
+                "invalid UTF-8 in percent-decoded bytes at offset ",
+                usize: err.valid_up_to()
+            )
+        }
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+/// Percent-encodes a string slice for a component of URI.
+///
+/// The bytes of the UTF-8 encoding which are not in the set of the component
+/// are encoded as `%XX` with uppercase hex digits.
+///
+/// The sets follow [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-3).
+/// The component is one of:
+/// + `unreserved` (default): `ALPHA / DIGIT / "-" / "." / "_" / "~"`
+/// + `path`: unreserved, sub-delims, `":"`, `"@"` and `"/"`
+/// + `query`: unreserved, sub-delims, `":"`, `"@"`, `"/"` and `"?"`
+/// + `fragment`: the same as `query`
+/// + `userinfo`: unreserved, sub-delims and `":"`
+///
+/// The sub-delims of `query` include `"&"` and `"="`,
+/// so a key or a value in a query string should be encoded with `unreserved`.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`percent_decode!`](crate::percent_decode).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::{concat, percent_encode};
+///
+/// const NAME: &str = "Grüße & Co";
+/// const PATH: &str = concat!("/users/", percent_encode!(NAME, path));
+/// assert_eq!(PATH, "/users/Gr%C3%BC%C3%9Fe%20&%20Co");
+///
+/// const URL: &str = concat!("https://example.com/search?q=", percent_encode!(NAME));
+/// assert_eq!(URL, "https://example.com/search?q=Gr%C3%BC%C3%9Fe%20%26%20Co");
+///
+/// assert_eq!(percent_encode!("a b?c#d", query), "a%20b?c%23d");
+/// assert_eq!(percent_encode!("user:p@ss", userinfo), "user:p%40ss");
+/// ```
+#[macro_export]
+macro_rules! percent_encode {
+    ($s: expr $(,)?) => {
+        $crate::__percent_encode!($s, $crate::__ctfe::PercentEncodeSet::Unreserved)
+    };
+    ($s: expr, unreserved $(,)?) => {
+        $crate::__percent_encode!($s, $crate::__ctfe::PercentEncodeSet::Unreserved)
+    };
+    ($s: expr, path $(,)?) => {
+        $crate::__percent_encode!($s, $crate::__ctfe::PercentEncodeSet::Path)
+    };
+    ($s: expr, query $(,)?) => {
+        $crate::__percent_encode!($s, $crate::__ctfe::PercentEncodeSet::Query)
+    };
+    ($s: expr, fragment $(,)?) => {
+        $crate::__percent_encode!($s, $crate::__ctfe::PercentEncodeSet::Fragment)
+    };
+    ($s: expr, userinfo $(,)?) => {
+        $crate::__percent_encode!($s, $crate::__ctfe::PercentEncodeSet::Userinfo)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __percent_encode {
+    ($s: expr, $set: expr) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::PercentEncode($s, $set).output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<OUTPUT_LEN> =
+            $crate::__ctfe::PercentEncode($s, $set).const_eval();
+        OUTPUT_BUF.as_str()
+    }};
+}
+
+/// Decodes a percent-encoded string slice.
+///
+/// Each `%XX` sequence is decoded to a byte, and the other bytes are kept as they are.
+/// `"+"` is not decoded to a space.
+///
+/// The compilation fails if a `%` is not followed by two hex digits,
+/// or if the decoded bytes are not valid UTF-8.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::percent_decode;
+///
+/// const NAME: &str = percent_decode!("Gr%C3%BC%C3%9Fe%20%26%20Co");
+/// assert_eq!(NAME, "Grüße & Co");
+///
+/// assert_eq!(percent_decode!("a+b%2Bc"), "a+b+c");
+/// ```
+///
+/// ```compile_fail
+/// // invalid percent-encoding at offset 1
+/// const S: &str = const_str::percent_decode!("a%2");
+/// ```
+///
+/// ```compile_fail
+/// // invalid UTF-8 in percent-decoded bytes at offset 0
+/// const S: &str = const_str::percent_decode!("%FF");
+/// ```
+#[macro_export]
+macro_rules! percent_decode {
+    ($s: expr) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::PercentDecode($s).output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<OUTPUT_LEN> =
+            $crate::__ctfe::PercentDecode($s).const_eval();
+        OUTPUT_BUF.as_str()
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_encode() {
+        const ALL: &str = " !\"#$%&'()*+,-./0123456789:;<=>?@AZ[\\]^_`az{|}~";
+
+        assert_eq!(
+            percent_encode!(ALL),
+            "%20%21%22%23%24%25%26%27%28%29%2A%2B%2C-.%2F0123456789%3A%3B%3C%3D%3E%3F%40AZ%5B%5C%5D%5E_%60az%7B%7C%7D~"
+        );
+        assert_eq!(
+            percent_encode!(ALL, path),
+            "%20!%22%23$%25&'()*+,-./0123456789:;%3C=%3E%3F@AZ%5B%5C%5D%5E_%60az%7B%7C%7D~"
+        );
+        assert_eq!(
+            percent_encode!(ALL, query),
+            "%20!%22%23$%25&'()*+,-./0123456789:;%3C=%3E?@AZ%5B%5C%5D%5E_%60az%7B%7C%7D~"
+        );
+        assert_eq!(percent_encode!(ALL, fragment), percent_encode!(ALL, query));
+        assert_eq!(
+            percent_encode!(ALL, userinfo),
+            "%20!%22%23$%25&'()*+,-.%2F0123456789:;%3C=%3E%3F%40AZ%5B%5C%5D%5E_%60az%7B%7C%7D~"
+        );
+
+        assert_eq!(percent_encode!(""), "");
+        assert_eq!(percent_encode!("\0\x7f"), "%00%7F");
+        assert_eq!(percent_encode!("😀", unreserved), "%F0%9F%98%80");
+
+        let encode = PercentEncode("a b", PercentEncodeSet::Path);
+        assert_eq!(encode.output_len(), 5);
+        let buf: StrBuf<5> = encode.const_eval();
+        assert_eq!(buf.as_str(), "a%20b");
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        const ALL: &str = " !\"#$%&'()*+,-./0123456789:;<=>?@AZ[\\]^_`az{|}~";
+
+        macro_rules! roundtrip {
+            ($s: expr $(, $set: ident)?) => {{
+                const ENCODED: &str = percent_encode!($s $(, $set)?);
+                assert_eq!(percent_decode!(ENCODED), $s);
+            }};
+        }
+
+        roundtrip!(ALL);
+        roundtrip!(ALL, path);
+        roundtrip!(ALL, query);
+        roundtrip!(ALL, userinfo);
+        roundtrip!("Grüße, 世界 😀");
+
+        assert_eq!(percent_decode!(""), "");
+        assert_eq!(percent_decode!("%e4%B8%96"), "世");
+        assert_eq!(percent_decode!("100%25"), "100%");
+
+        let decode = PercentDecode("%41b");
+        assert_eq!(decode.output_len(), 2);
+        let buf: StrBuf<2> = decode.const_eval();
+        assert_eq!(buf.as_str(), "Ab");
+    }
+
+    #[test]
+    #[should_panic = "invalid percent-encoding at offset 3"]
+    fn test_percent_decode_invalid() {
+        let _ = PercentDecode("abc%g0").output_len();
+    }
+
+    #[test]
+    #[should_panic = "invalid UTF-8 in percent-decoded bytes at offset 1"]
+    fn test_percent_decode_invalid_utf8() {
+        let _: StrBuf<3> = PercentDecode("a%C3b").const_eval();
+    }
+}
@@ -0,0 +1,398 @@
+use core::fmt;
+
+/// The average number of keys in a bucket.
+const LAMBDA: usize = 5;
+
+const MAX_SEEDS: u64 = 1024;
+
+const fn fnv1a(bytes: &[u8], seed: u64) -> u64 {
+    let mut h = 0xcbf2_9ce4_8422_2325 ^ seed;
+    let mut i = 0;
+    while i < bytes.len() {
+        h ^= bytes[i] as u64;
+        h = h.wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+    h
+}
+
+/// The finalizer of splitmix64.
+const fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+#[derive(Clone, Copy)]
+struct Hashes {
+    g: u32,
+    f1: u32,
+    f2: u32,
+}
+
+const fn hash(key: &str, seed: u64) -> Hashes {
+    let h1 = mix(fnv1a(key.as_bytes(), seed));
+    let h2 = mix(h1 ^ 0x9e37_79b9_7f4a_7c15);
+    Hashes {
+        g: (h1 >> 32) as u32,
+        f1: h1 as u32,
+        f2: h2 as u32,
+    }
+}
+
+const fn displace(h: Hashes, (d1, d2): (u32, u32), len: usize) -> usize {
+    let x = h.f1.wrapping_add(d1.wrapping_mul(h.f2)).wrapping_add(d2);
+    (x % (len as u32)) as usize
+}
+
+pub struct PhfState<const N: usize, const B: usize> {
+    pub seed: u64,
+    pub disps: [(u32, u32); B],
+    pub slots: [usize; N],
+}
+
+pub struct Phf<'a>(pub &'a [&'a str]);
+
+impl Phf<'_> {
+    pub const fn buckets_len(&self) -> usize {
+        self.0.len().div_ceil(LAMBDA)
+    }
+
+    /// The keys must be unique, which is checked by `phf_map!` with `assert_unique!`.
+    pub const fn const_eval<const N: usize, const B: usize>(&self) -> PhfState<N, B> {
+        let keys = self.0;
+        assert!(keys.len() == N && B == N.div_ceil(LAMBDA));
+        assert!(N <= u32::MAX as usize);
+
+        let mut seed = 0;
+        while seed < MAX_SEEDS {
+            if let Some(state) = try_build(keys, seed) {
+                return state;
+            }
+            seed += 1;
+        }
+        panic!("failed to build the perfect hash function");
+    }
+}
+
+/// Builds the displacements with the hash-and-displace algorithm.
+///
+/// The keys are grouped into buckets by `g`.
+/// Starting from the largest bucket, each bucket finds a displacement `(d1, d2)`
+/// which places all its keys into free slots.
+const fn try_build<const N: usize, const B: usize>(
+    keys: &[&str],
+    seed: u64,
+) -> Option<PhfState<N, B>> {
+    let mut hashes = [Hashes { g: 0, f1: 0, f2: 0 }; N];
+    let mut counts = [0; B];
+    let mut i = 0;
+    while i < N {
+        hashes[i] = hash(keys[i], seed);
+        counts[hashes[i].g as usize % B] += 1;
+        i += 1;
+    }
+
+    let mut starts = [0; B];
+    let mut b = 1;
+    while b < B {
+        starts[b] = starts[b - 1] + counts[b - 1];
+        b += 1;
+    }
+
+    let mut members = [0; N];
+    let mut filled = starts;
+    let mut i = 0;
+    while i < N {
+        let b = hashes[i].g as usize % B;
+        members[filled[b]] = i;
+        filled[b] += 1;
+        i += 1;
+    }
+
+    let mut order = [0; B];
+    let mut b = 0;
+    while b < B {
+        order[b] = b;
+        b += 1;
+    }
+    let order = crate::__merge_sort!(order, |x, y| counts[x] < counts[y]);
+
+    const EMPTY: usize = usize::MAX;
+    let mut slots = [EMPTY; N];
+    let mut disps = [(0, 0); B];
+    let mut marks = [0; N];
+    let mut generation = 0;
+
+    let mut k = 0;
+    while k < B {
+        let b = order[k];
+        let (start, len) = (starts[b], counts[b]);
+        if len == 0 {
+            break;
+        }
+
+        let mut found = false;
+        let mut d1 = 0;
+        'search: while d1 < N as u32 {
+            let mut d2 = 0;
+            while d2 < N as u32 {
+                generation += 1;
+                let mut m = 0;
+                while m < len {
+                    let slot = displace(hashes[members[start + m]], (d1, d2), N);
+                    if slots[slot] != EMPTY || marks[slot] == generation {
+                        break;
+                    }
+                    marks[slot] = generation;
+                    m += 1;
+                }
+                if m == len {
+                    let mut m = 0;
+                    while m < len {
+                        let i = members[start + m];
+                        slots[displace(hashes[i], (d1, d2), N)] = i;
+                        m += 1;
+                    }
+                    disps[b] = (d1, d2);
+                    found = true;
+                    break 'search;
+                }
+                d2 += 1;
+            }
+            d1 += 1;
+        }
+        if !found {
+            return None;
+        }
+        k += 1;
+    }
+
+    Some(PhfState { seed, disps, slots })
+}
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct PhfTable<'a> {
+    pub seed: u64,
+    pub disps: &'a [(u32, u32)],
+    pub slots: &'a [usize],
+    pub keys: &'a [&'a str],
+}
+
+/// A compile-time perfect hash map from string slices to values.
+///
+/// It is created by [`phf_map!`](crate::phf_map).
+/// A lookup hashes the key once and compares it with at most one entry.
+pub struct PhfMap<'a, V> {
+    table: PhfTable<'a>,
+    values: &'a [V],
+}
+
+impl<V> Clone for PhfMap<'_, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V> Copy for PhfMap<'_, V> {}
+
+impl<V: fmt::Debug> fmt::Debug for PhfMap<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.table.keys.iter().zip(self.values))
+            .finish()
+    }
+}
+
+impl<'a, V> PhfMap<'a, V> {
+    #[doc(hidden)]
+    pub const fn new(table: PhfTable<'a>, values: &'a [V]) -> Self {
+        assert!(table.keys.len() == values.len());
+        Self { table, values }
+    }
+
+    /// Returns the number of entries.
+    pub const fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if the map has no entries.
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the index of the key in the original order.
+    pub const fn get_index(&self, key: &str) -> Option<usize> {
+        let table = &self.table;
+        if table.keys.is_empty() {
+            return None;
+        }
+        let h = hash(key, table.seed);
+        let d = table.disps[h.g as usize % table.disps.len()];
+        let i = table.slots[displace(h, d, table.slots.len())];
+        if crate::str::equal(table.keys[i], key) {
+            Some(i)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value of the key.
+    pub const fn get(&self, key: &str) -> Option<&'a V> {
+        match self.get_index(key) {
+            Some(i) => Some(&self.values[i]),
+            None => None,
+        }
+    }
+
+    /// Returns true if the map contains the key.
+    pub const fn contains_key(&self, key: &str) -> bool {
+        self.get_index(key).is_some()
+    }
+
+    /// Returns the keys in the original order.
+    pub const fn keys(&self) -> &'a [&'a str] {
+        self.table.keys
+    }
+
+    /// Returns the values in the original order.
+    pub const fn values(&self) -> &'a [V] {
+        self.values
+    }
+}
+
+/// Creates a compile-time perfect hash map from string slices to values.
+///
+/// The hash function is searched at compile time,
+/// and a lookup takes constant time regardless of the number of entries.
+///
+/// The keys must be unique, otherwise the compilation fails.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See [`phf::Map`](crate::phf::Map) for the lookup methods.
+///
+/// # Examples
+///
+/// ```
+/// use const_str::phf::Map;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Keyword {
+///     Fn,
+///     Let,
+///     If,
+/// }
+///
+/// const KEYWORDS: Map<Keyword> = const_str::phf_map! {
+///     "fn" => Keyword::Fn,
+///     "let" => Keyword::Let,
+///     "if" => Keyword::If,
+/// };
+///
+/// assert_eq!(KEYWORDS.get("let"), Some(&Keyword::Let));
+/// assert_eq!(KEYWORDS.get("loop"), None);
+///
+/// const fn is_keyword(s: &str) -> bool {
+///     KEYWORDS.contains_key(s)
+/// }
+/// assert!(is_keyword("fn"));
+/// ```
+///
+/// ```compile_fail
+/// // duplicate item "fn" at indices 0 and 2
+/// const KEYWORDS: const_str::phf::Map<u8> = const_str::phf_map! {
+///     "fn" => 1,
+///     "let" => 2,
+///     "fn" => 3,
+/// };
+/// ```
+#[macro_export]
+macro_rules! phf_map {
+    ($($key: expr => $value: expr),* $(,)?) => {
+        $crate::__ctfe::PhfMap::new(
+            {
+                const KEYS: &[&str] = &[$($key),*];
+                $crate::assert_unique!(KEYS);
+                const N: usize = KEYS.len();
+                const B: usize = $crate::__ctfe::Phf(KEYS).buckets_len();
+                const STATE: $crate::__ctfe::PhfState<N, B> = $crate::__ctfe::Phf(KEYS).const_eval();
+                $crate::__ctfe::PhfTable {
+                    seed: STATE.seed,
+                    disps: &STATE.disps,
+                    slots: &STATE.slots,
+                    keys: KEYS,
+                }
+            },
+            &[$($value),*],
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::phf::Map;
+
+    #[test]
+    fn test_phf_map() {
+        const MAP: Map<u32> = phf_map! {
+            "a" => 1,
+            "b" => 2,
+            "c" => 3,
+        };
+        assert_eq!(MAP.len(), 3);
+        assert_eq!(MAP.get("a"), Some(&1));
+        assert_eq!(MAP.get("b"), Some(&2));
+        assert_eq!(MAP.get("c"), Some(&3));
+        assert_eq!(MAP.get("d"), None);
+        assert_eq!(MAP.get(""), None);
+        assert_eq!(MAP.keys(), ["a", "b", "c"]);
+        assert_eq!(MAP.values(), [1, 2, 3]);
+        assert_eq!(format!("{MAP:?}"), r#"{"a": 1, "b": 2, "c": 3}"#);
+
+        const EMPTY: Map<u8> = phf_map! {};
+        assert!(EMPTY.is_empty());
+        assert_eq!(EMPTY.get("a"), None);
+
+        const ONE: Map<&str> = phf_map! { "" => "empty" };
+        assert_eq!(ONE.get(""), Some(&"empty"));
+        assert_eq!(ONE.get("x"), None);
+
+        const X: Option<&u32> = MAP.get("c");
+        assert_eq!(X, Some(&3));
+    }
+
+    #[test]
+    fn test_phf_map_large() {
+        const KEYWORDS: &[&str] = &[
+            "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+            "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod",
+            "move", "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super",
+            "trait", "true", "type", "union", "unsafe", "use", "where", "while", "abstract",
+            "become", "box", "do", "final", "macro", "override", "priv", "typeof", "unsized",
+            "virtual", "yield", "try", "gen",
+        ];
+
+        macro_rules! map {
+            ($($i: literal),*) => {
+                phf_map! { $(KEYWORDS[$i] => $i),* }
+            };
+        }
+
+        const MAP: Map<usize> = map!(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45,
+            46, 47, 48, 49, 50, 51, 52
+        );
+
+        assert_eq!(MAP.len(), KEYWORDS.len());
+        for (i, &kw) in KEYWORDS.iter().enumerate() {
+            assert_eq!(MAP.get(kw), Some(&i), "{kw}");
+            assert_eq!(MAP.get_index(kw), Some(i), "{kw}");
+        }
+        for s in ["", "x", "Fn", "self_", "unions", "asyn"] {
+            assert_eq!(MAP.get(s), None, "{s}");
+        }
+    }
+}
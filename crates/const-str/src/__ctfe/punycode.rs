@@ -0,0 +1,675 @@
+#![allow(unsafe_code)]
+
+use super::StrBuf;
+
+use crate::slice::{advance, subslice};
+use crate::utf8::{next_char, CharEncodeUtf8};
+
+const BASE: u32 = 36;
+const TMIN: u32 = 1;
+const TMAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 128;
+
+const ACE_PREFIX: &[u8] = b"xn--";
+const MAX_LABEL_LEN: usize = 63;
+const MAX_NAME_LEN: usize = 253;
+
+const fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - TMIN) * TMAX) / 2 {
+        delta /= BASE - TMIN;
+        k += BASE;
+    }
+    k + (BASE - TMIN + 1) * delta / (delta + SKEW)
+}
+
+const fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        TMIN
+    } else if k >= bias + TMAX {
+        TMAX
+    } else {
+        k - bias
+    }
+}
+
+const fn encode_digit(d: u32) -> u8 {
+    if d < 26 {
+        b'a' + d as u8
+    } else {
+        b'0' + (d - 26) as u8
+    }
+}
+
+const fn decode_digit(b: u8) -> Option<u32> {
+    match b {
+        b'a'..=b'z' => Some((b - b'a') as u32),
+        b'A'..=b'Z' => Some((b - b'A') as u32),
+        b'0'..=b'9' => Some((b - b'0') as u32 + 26),
+        _ => None,
+    }
+}
+
+const fn is_ascii(label: &[u8]) -> bool {
+    let mut i = 0;
+    while i < label.len() {
+        if label[i] >= 0x80 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn has_ace_prefix(label: &[u8]) -> bool {
+    if label.len() < ACE_PREFIX.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < ACE_PREFIX.len() {
+        if label[i].to_ascii_lowercase() != ACE_PREFIX[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Splits off the first label of a domain name.
+///
+/// Returns the label and the remaining string after the dot, if any.
+const fn next_label(s: &str) -> (&[u8], Option<&str>) {
+    match crate::str::next_match(s, ".") {
+        Some((m, remain)) => (subslice(s.as_bytes(), 0..m), Some(remain)),
+        None => (s.as_bytes(), None),
+    }
+}
+
+/// Encodes a label with Punycode and appends the result to `buf` from `pos`.
+///
+/// Returns the buffer and the new position.
+/// The bytes out of the capacity of `buf` are only counted.
+const fn encode_label<const N: usize>(
+    label: &[u8],
+    offset: usize,
+    mut buf: [u8; N],
+    mut pos: usize,
+) -> ([u8; N], usize) {
+    macro_rules! push {
+        ($b: expr) => {{
+            if pos < N {
+                buf[pos] = $b;
+            }
+            pos += 1;
+        }};
+    }
+
+    macro_rules! checked {
+        ($e: expr) => {
+            match $e {
+                Some(x) => x,
+                None => const_panic!("punycode overflow at offset ", usize: offset),
+            }
+        };
+    }
+
+    let mut total: u32 = 0;
+    let mut basic: u32 = 0;
+    {
+        let mut rest = label;
+        while let Some((ch, count)) = next_char(rest) {
+            rest = advance(rest, count);
+            total += 1;
+            if (ch as u32) < INITIAL_N {
+                push!(ch as u8);
+                basic += 1;
+            }
+        }
+    }
+    if basic > 0 {
+        push!(b'-');
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut h = basic;
+
+    while h < total {
+        let mut m = u32::MAX;
+        let mut rest = label;
+        while let Some((ch, count)) = next_char(rest) {
+            rest = advance(rest, count);
+            let c = ch as u32;
+            if c >= n && c < m {
+                m = c;
+            }
+        }
+
+        delta = checked!(checked!((m - n).checked_mul(h + 1)).checked_add(delta));
+        n = m;
+
+        let mut rest = label;
+        while let Some((ch, count)) = next_char(rest) {
+            rest = advance(rest, count);
+            let c = ch as u32;
+            if c < n {
+                delta = checked!(delta.checked_add(1));
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    push!(encode_digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                push!(encode_digit(q));
+                bias = adapt(delta, h + 1, h == basic);
+                delta = 0;
+                h += 1;
+            }
+        }
+
+        delta += 1;
+        n += 1;
+    }
+
+    (buf, pos)
+}
+
+/// Decodes a Punycode label without the ACE prefix and appends the result to `buf` from `pos`.
+///
+/// Returns the buffer and the new position.
+/// The bytes out of the capacity of `buf` are only counted.
+const fn decode_label<const N: usize>(
+    label: &[u8],
+    offset: usize,
+    mut buf: [u8; N],
+    mut pos: usize,
+) -> ([u8; N], usize) {
+    macro_rules! invalid {
+        () => {
+            const_panic!("invalid punycode at offset ", usize: offset)
+        };
+    }
+
+    macro_rules! checked {
+        ($e: expr) => {
+            match $e {
+                Some(x) => x,
+                None => invalid!(),
+            }
+        };
+    }
+
+    if label.is_empty() || ACE_PREFIX.len() + label.len() > MAX_LABEL_LEN {
+        invalid!();
+    }
+
+    let mut output = [0u32; MAX_LABEL_LEN];
+    let mut len = 0;
+
+    let mut start = 0;
+    {
+        let mut j = label.len();
+        while j > 0 {
+            j -= 1;
+            if label[j] == b'-' {
+                start = j + 1;
+                break;
+            }
+        }
+        let mut i = 0;
+        while i + 1 < start {
+            if label[i] >= 0x80 {
+                invalid!();
+            }
+            output[len] = label[i] as u32;
+            len += 1;
+            i += 1;
+        }
+    }
+
+    let mut n = INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut idx = start;
+
+    while idx < label.len() {
+        let old_i = i;
+        let mut w: u32 = 1;
+        let mut k = BASE;
+        loop {
+            if idx >= label.len() {
+                invalid!();
+            }
+            let digit = checked!(decode_digit(label[idx]));
+            idx += 1;
+            i = checked!(checked!(digit.checked_mul(w)).checked_add(i));
+            let t = threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            w = checked!(w.checked_mul(BASE - t));
+            k += BASE;
+        }
+
+        let num_points = len as u32 + 1;
+        bias = adapt(i - old_i, num_points, old_i == 0);
+        n = checked!(n.checked_add(i / num_points));
+        i %= num_points;
+
+        if n < INITIAL_N || char::from_u32(n).is_none() {
+            invalid!();
+        }
+
+        let mut j = len;
+        while j > i as usize {
+            output[j] = output[j - 1];
+            j -= 1;
+        }
+        output[i as usize] = n;
+        len += 1;
+        i += 1;
+    }
+
+    let mut j = 0;
+    while j < len {
+        let ch = match char::from_u32(output[j]) {
+            Some(ch) => ch,
+            None => invalid!(),
+        };
+        let e = CharEncodeUtf8::new(ch);
+        let bytes = e.as_bytes();
+        let mut k = 0;
+        while k < bytes.len() {
+            if pos < N {
+                buf[pos] = bytes[k];
+            }
+            pos += 1;
+            k += 1;
+        }
+        j += 1;
+    }
+
+    (buf, pos)
+}
+
+const fn copy_label<const N: usize>(
+    label: &[u8],
+    mut buf: [u8; N],
+    mut pos: usize,
+) -> ([u8; N], usize) {
+    let mut i = 0;
+    while i < label.len() {
+        if pos < N {
+            buf[pos] = label[i];
+        }
+        pos += 1;
+        i += 1;
+    }
+    (buf, pos)
+}
+
+/// Converts each non-ASCII label to its ACE form (`to_ascii`)
+/// or each ACE label to its Unicode form (`!to_ascii`).
+///
+/// Returns the number of converted bytes.
+/// The bytes out of the capacity of `buf` are only counted.
+const fn convert<const N: usize>(s: &str, to_ascii: bool) -> ([u8; N], usize) {
+    let mut buf = [0; N];
+    let mut pos = 0;
+
+    let mut rest = s;
+    loop {
+        let offset = s.len() - rest.len();
+        let (label, next) = next_label(rest);
+
+        (buf, pos) = if to_ascii {
+            if is_ascii(label) {
+                copy_label(label, buf, pos)
+            } else {
+                let (buf, pos) = copy_label(ACE_PREFIX, buf, pos);
+                encode_label(label, offset, buf, pos)
+            }
+        } else if has_ace_prefix(label) {
+            let label = advance(label, ACE_PREFIX.len());
+            decode_label(label, offset, buf, pos)
+        } else {
+            copy_label(label, buf, pos)
+        };
+
+        match next {
+            Some(remain) => {
+                (buf, pos) = copy_label(b".", buf, pos);
+                rest = remain;
+            }
+            None => break,
+        }
+    }
+
+    (buf, pos)
+}
+
+/// Checks a domain name and returns the length of its ACE form.
+const fn check_domain_name(s: &str) -> usize {
+    let mut rest = s;
+    loop {
+        let offset = s.len() - rest.len();
+        let (label, next) = next_label(rest);
+
+        if label.is_empty() {
+            // a trailing dot is allowed in a fully qualified domain name
+            if next.is_some() || offset == 0 {
+                const_panic!("empty label at offset ", usize: offset);
+            }
+            break;
+        }
+
+        if label[0] == b'-' || label[label.len() - 1] == b'-' {
+            const_panic!("label starts or ends with a hyphen at offset ", usize: offset);
+        }
+
+        let mut i = 0;
+        while i < label.len() {
+            let b = label[i];
+            if b < 0x80 && !(b.is_ascii_alphanumeric() || b == b'-') {
+                const_panic!("invalid character at offset ", usize: offset + i);
+            }
+            i += 1;
+        }
+
+        let len = if is_ascii(label) {
+            if has_ace_prefix(label) {
+                let ace = advance(label, ACE_PREFIX.len());
+                decode_label::<0>(ace, offset, [], 0);
+            }
+            label.len()
+        } else {
+            encode_label::<0>(label, offset, [], ACE_PREFIX.len()).1
+        };
+        if len > MAX_LABEL_LEN {
+            const_panic!("label too long at offset ", usize: offset);
+        }
+
+        match next {
+            Some(remain) => rest = remain,
+            None => break,
+        }
+    }
+
+    let len = convert::<0>(s, true).1;
+    let trailing_dot = crate::str::ends_with(s, ".") as usize;
+    assert!(len - trailing_dot <= MAX_NAME_LEN, "domain name too long");
+    len
+}
+
+pub struct Punycode<'a>(pub &'a str);
+
+impl Punycode<'_> {
+    pub const fn output_len(&self) -> usize {
+        convert::<0>(self.0, true).1
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let (buf, len) = convert::<N>(self.0, true);
+        assert!(len == N);
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+pub struct PunycodeDecode<'a>(pub &'a str);
+
+impl PunycodeDecode<'_> {
+    pub const fn output_len(&self) -> usize {
+        convert::<0>(self.0, false).1
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let (buf, len) = convert::<N>(self.0, false);
+        assert!(len == N);
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+pub struct DomainName<'a>(pub &'a str);
+
+impl DomainName<'_> {
+    pub const fn output_len(&self) -> usize {
+        check_domain_name(self.0)
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        Punycode(self.0).const_eval()
+    }
+}
+
+/// Converts a domain name to its ASCII form with [Punycode](https://www.rfc-editor.org/rfc/rfc3492).
+///
+/// The domain name is processed label by label.
+/// Each label containing non-ASCII characters is encoded and prefixed with `xn--`.
+/// Other labels are kept as they are.
+///
+/// No IDNA mapping (such as case folding or normalization) is performed,
+/// so the input should already be in lowercase NFC.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`punycode_decode!`](crate::punycode_decode), [`domain_name!`](crate::domain_name).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::punycode;
+///
+/// const HOST: &str = punycode!("bücher.example");
+/// assert_eq!(HOST, "xn--bcher-kva.example");
+///
+/// assert_eq!(punycode!("münchen.de"), "xn--mnchen-3ya.de");
+/// assert_eq!(punycode!("例え.テスト"), "xn--r8jz45g.xn--zckzah");
+/// ```
+#[macro_export]
+macro_rules! punycode {
+    ($s: expr) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::Punycode($s).output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<OUTPUT_LEN> =
+            $crate::__ctfe::Punycode($s).const_eval();
+        OUTPUT_BUF.as_str()
+    }};
+}
+
+/// Converts a domain name to its Unicode form with [Punycode](https://www.rfc-editor.org/rfc/rfc3492).
+///
+/// The domain name is processed label by label.
+/// Each label starting with `xn--` (case-insensitively) is decoded.
+/// Other labels are kept as they are.
+///
+/// The compilation fails if an `xn--` label is not valid Punycode.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`punycode!`](crate::punycode).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::punycode_decode;
+///
+/// const HOST: &str = punycode_decode!("xn--bcher-kva.example");
+/// assert_eq!(HOST, "bücher.example");
+///
+/// assert_eq!(punycode_decode!("XN--mnchen-3ya.de"), "münchen.de");
+/// ```
+#[macro_export]
+macro_rules! punycode_decode {
+    ($s: expr) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::PunycodeDecode($s).output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<OUTPUT_LEN> =
+            $crate::__ctfe::PunycodeDecode($s).const_eval();
+        OUTPUT_BUF.as_str()
+    }};
+}
+
+/// Validates a domain name and converts it to its ASCII form.
+///
+/// The rules are checked against the ASCII form:
+///
+/// + Each label is 1 to 63 bytes long.
+/// + The whole name is at most 253 bytes long, excluding an optional trailing dot.
+/// + ASCII characters are letters, digits and hyphens (LDH).
+/// + No label starts or ends with a hyphen.
+/// + Each `xn--` label is valid Punycode.
+///
+/// Non-ASCII labels are converted as [`punycode!`](crate::punycode) does.
+///
+/// The compilation fails if the domain name is invalid.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::domain_name;
+///
+/// const HOST: &str = domain_name!("bücher.example");
+/// assert_eq!(HOST, "xn--bcher-kva.example");
+///
+/// const FQDN: &str = domain_name!("www.rust-lang.org.");
+/// assert_eq!(FQDN, "www.rust-lang.org.");
+/// ```
+///
+/// ```compile_fail
+/// // '_' is not allowed
+/// const HOST: &str = const_str::domain_name!("my_host.example");
+/// ```
+#[macro_export]
+macro_rules! domain_name {
+    ($s: expr) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::DomainName($s).output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<OUTPUT_LEN> =
+            $crate::__ctfe::DomainName($s).const_eval();
+        OUTPUT_BUF.as_str()
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_punycode() {
+        macro_rules! testcase {
+            ($unicode: expr, $ascii: expr) => {{
+                const ASCII: &str = punycode!($unicode);
+                const UNICODE: &str = punycode_decode!($ascii);
+                assert_eq!(ASCII, $ascii);
+                assert_eq!(UNICODE, $unicode);
+            }};
+        }
+
+        testcase!("", "");
+        testcase!("example.com", "example.com");
+        testcase!("bücher", "xn--bcher-kva");
+        testcase!("ü", "xn--tda");
+        testcase!("aü", "xn--a-eha");
+        testcase!("ü-a", "xn---a-wka");
+        testcase!("😀.example.", "xn--e28h.example.");
+        testcase!("ドメイン名例.jp", "xn--eckwd4c7cu47r2wf.jp");
+        testcase!("他们为什么不说中文", "xn--ihqwcrb4cv8a8dqg056pqjye");
+        testcase!("a.münchen.bücher", "a.xn--mnchen-3ya.xn--bcher-kva");
+
+        assert_eq!(punycode!("Bücher"), "xn--Bcher-kva");
+        assert_eq!(punycode_decode!("Xn--Bcher-KVA"), "Bücher");
+
+        let p = Punycode("bücher.example");
+        assert_eq!(p.output_len(), 21);
+        let buf: StrBuf<21> = p.const_eval();
+        assert_eq!(buf.as_str(), "xn--bcher-kva.example");
+    }
+
+    #[test]
+    fn test_domain_name() {
+        assert_eq!(domain_name!("localhost"), "localhost");
+        assert_eq!(domain_name!("a-b.c0.example."), "a-b.c0.example.");
+        assert_eq!(domain_name!("bücher.example"), "xn--bcher-kva.example");
+        assert_eq!(
+            domain_name!("xn--bcher-kva.example"),
+            "xn--bcher-kva.example"
+        );
+
+        const LABEL_63: &str = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        const NAME_253: &str = crate::concat!(
+            LABEL_63,
+            ".",
+            LABEL_63,
+            ".",
+            LABEL_63,
+            ".",
+            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+        );
+        assert_eq!(domain_name!(LABEL_63), LABEL_63);
+        assert_eq!(domain_name!(NAME_253).len(), 253);
+        assert_eq!(domain_name!(crate::concat!(NAME_253, ".")).len(), 254);
+    }
+
+    #[test]
+    #[should_panic = "empty label at offset 2"]
+    fn test_domain_name_empty_label() {
+        let _ = DomainName("a..b").output_len();
+    }
+
+    #[test]
+    #[should_panic = "empty label at offset 0"]
+    fn test_domain_name_empty() {
+        let _ = DomainName("").output_len();
+    }
+
+    #[test]
+    #[should_panic = "invalid character at offset 6"]
+    fn test_domain_name_invalid_character() {
+        let _ = DomainName("host.a_b").output_len();
+    }
+
+    #[test]
+    #[should_panic = "label starts or ends with a hyphen at offset 2"]
+    fn test_domain_name_hyphen() {
+        let _ = DomainName("a.b-.c").output_len();
+    }
+
+    #[test]
+    #[should_panic = "label too long at offset 0"]
+    fn test_domain_name_label_too_long() {
+        let label = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaü";
+        let _ = DomainName(label).output_len();
+    }
+
+    #[test]
+    #[should_panic = "domain name too long"]
+    fn test_domain_name_too_long() {
+        let label = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let name = [
+            label,
+            label,
+            label,
+            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        ]
+        .join(".");
+        let _ = DomainName(&name).output_len();
+    }
+
+    #[test]
+    #[should_panic = "invalid punycode at offset 4"]
+    fn test_punycode_decode_invalid() {
+        let _ = PunycodeDecode("www.xn--a!b").output_len();
+    }
+}
@@ -0,0 +1,300 @@
+#![allow(unsafe_code)]
+
+use crate::slice::advance;
+use crate::utf8::CharEncodeUtf8;
+
+use super::str::StrBuf;
+
+pub struct Chars;
+
+pub struct Graphemes;
+
+pub struct Reverse<T, P>(pub T, pub P);
+
+/// Copies each segment `input[a..b]` to `output[len-b..len-a]`.
+macro_rules! mirror {
+    ($buf: expr, $input: expr, $range: expr) => {{
+        let input: &[u8] = $input;
+        let range: core::ops::Range<usize> = $range;
+        let len = input.len();
+        let mut i = range.start;
+        while i < range.end {
+            $buf[len - range.end + (i - range.start)] = input[i];
+            i += 1;
+        }
+    }};
+}
+
+impl Reverse<&str, Chars> {
+    pub const fn output_len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let input = self.0.as_bytes();
+        assert!(input.len() == N);
+
+        let mut buf = [0; N];
+        let mut pos = 0;
+        while let Some((_, count)) = crate::utf8::next_char(advance(input, pos)) {
+            mirror!(buf, input, pos..pos + count);
+            pos += count;
+        }
+
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+impl Reverse<&str, Graphemes> {
+    pub const fn output_len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let input = self.0.as_bytes();
+        assert!(input.len() == N);
+
+        let mut buf = [0; N];
+        let mut pos = 0;
+        while let Some(count) = crate::grapheme::next_grapheme(advance(input, pos)) {
+            mirror!(buf, input, pos..pos + count);
+            pos += count;
+        }
+
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+impl Reverse<&str, &str> {
+    pub const fn output_len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        if self.1.is_empty() {
+            return Reverse(self.0, Chars).const_eval();
+        }
+
+        let input = self.0.as_bytes();
+        assert!(input.len() == N);
+
+        let mut buf = [0; N];
+        let mut pos = 0;
+        let mut remain = self.0;
+        while let Some((m, next)) = crate::str::next_match(remain, self.1) {
+            mirror!(buf, input, pos..pos + m);
+            pos += m;
+            mirror!(buf, input, pos..pos + self.1.len());
+            pos += self.1.len();
+            remain = next;
+        }
+        mirror!(buf, input, pos..input.len());
+
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+impl Reverse<&str, char> {
+    pub const fn output_len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let ch = CharEncodeUtf8::new(self.1);
+        Reverse(self.0, ch.as_str()).const_eval()
+    }
+}
+
+/// Reverses a string slice.
+///
+/// + `reverse!(s)` reverses the characters.
+/// + `reverse!(s, grapheme)` reverses the grapheme clusters,
+///   so that combining marks stay attached to their base characters.
+/// + `reverse!(s, sep)` reverses the parts separated by a pattern.
+///   The pattern type must be one of [`&str`](str) or [`char`].
+///
+/// Grapheme clusters are the extended grapheme clusters of
+/// [UAX #29](https://www.unicode.org/reports/tr29/), with the tables of Unicode 17.0.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`chars_rev!`](crate::chars_rev).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::reverse;
+///
+/// assert_eq!(reverse!("hello, 世界"), "界世 ,olleh");
+/// assert_eq!(reverse!("cafe\u{301}", grapheme), "e\u{301}fac");
+/// assert_eq!(reverse!("www.example.com", '.'), "com.example.www");
+/// assert_eq!(reverse!("a::b::c", "::"), "c::b::a");
+/// ```
+#[macro_export]
+macro_rules! reverse {
+    ($s: expr) => {
+        $crate::__reverse!($s, $crate::__ctfe::Chars)
+    };
+    ($s: expr, grapheme) => {
+        $crate::__reverse!($s, $crate::__ctfe::Graphemes)
+    };
+    ($s: expr, $sep: expr) => {
+        $crate::__reverse!($s, $sep)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __reverse {
+    ($s: expr, $p: expr) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::Reverse($s, $p).output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<OUTPUT_LEN> =
+            $crate::__ctfe::Reverse($s, $p).const_eval();
+        OUTPUT_BUF.as_str()
+    }};
+}
+
+pub struct CharsRev<T>(pub T);
+
+impl CharsRev<&str> {
+    pub const fn output_len(&self) -> usize {
+        crate::utf8::str_count_chars(self.0)
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> [char; N] {
+        let mut buf: [char; N] = crate::utf8::str_chars(self.0);
+        let mut i = 0;
+        while i * 2 < N {
+            let (a, b) = (buf[i], buf[N - 1 - i]);
+            buf[i] = b;
+            buf[N - 1 - i] = a;
+            i += 1;
+        }
+        buf
+    }
+}
+
+/// Converts a string slice into an array of its characters in reverse order.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`to_char_array!`](crate::to_char_array), [`reverse!`](crate::reverse).
+///
+/// # Examples
+/// ```
+/// const CHARS: [char; 5] = const_str::chars_rev!("Hello");
+/// assert_eq!(CHARS, ['o', 'l', 'l', 'e', 'H']);
+/// ```
+///
+#[macro_export]
+macro_rules! chars_rev {
+    ($s: expr) => {{
+        const OUTPUT_LEN: usize = $crate::__ctfe::CharsRev($s).output_len();
+        const OUTPUT_BUF: [char; OUTPUT_LEN] =
+            $crate::__ctfe::CharsRev($s).const_eval::<OUTPUT_LEN>();
+        OUTPUT_BUF
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reverse() {
+        macro_rules! testcase {
+            ($s: expr) => {{
+                const OUTPUT: &str = reverse!($s);
+                let ans: String = $s.chars().rev().collect();
+                assert_eq!(OUTPUT, ans);
+            }};
+        }
+
+        testcase!("");
+        testcase!("a");
+        testcase!("ab");
+        testcase!("abc");
+        testcase!("我");
+        testcase!("hello, 世界");
+        testcase!("a𐍈b€c");
+    }
+
+    #[test]
+    fn test_reverse_grapheme() {
+        const S1: &str = reverse!("cafe\u{301}", grapheme);
+        assert_eq!(S1, "e\u{301}fac");
+
+        const S2: &str = reverse!("a👍🏽b", grapheme);
+        assert_eq!(S2, "b👍🏽a");
+
+        const S3: &str = reverse!("🇺🇸🇨🇳", grapheme);
+        assert_eq!(S3, "🇨🇳🇺🇸");
+
+        const S4: &str = reverse!("a\r\nb", grapheme);
+        assert_eq!(S4, "b\r\na");
+
+        const S5: &str = reverse!("", grapheme);
+        assert_eq!(S5, "");
+
+        const S6: &str = reverse!("\u{1100}\u{1161}\u{11a8}a", grapheme);
+        assert_eq!(S6, "a\u{1100}\u{1161}\u{11a8}");
+
+        const S7: &str = reverse!("a\u{200d}b", grapheme);
+        assert_eq!(S7, "ba\u{200d}");
+    }
+
+    #[test]
+    fn test_reverse_sep() {
+        macro_rules! testcase {
+            ($s: expr, $sep: expr) => {{
+                const OUTPUT: &str = reverse!($s, $sep);
+                let mut parts = $s.split($sep).collect::<Vec<_>>();
+                parts.reverse();
+                let ans = parts.join(&$sep.to_string());
+                assert_eq!(OUTPUT, ans);
+            }};
+        }
+
+        testcase!("", ".");
+        testcase!(".", ".");
+        testcase!("example.com", ".");
+        testcase!("www.example.com", '.');
+        testcase!(".a..b.", '.');
+        testcase!("a::b::c", "::");
+        testcase!("a:::b", "::");
+        testcase!("你和我", '和');
+
+        const EMPTY_SEP: &str = reverse!("abc", "");
+        assert_eq!(EMPTY_SEP, "cba");
+    }
+
+    #[test]
+    fn test_reverse_runtime() {
+        let r1 = Reverse("abc", Chars);
+        assert_eq!(r1.output_len(), 3);
+        let buf1: StrBuf<3> = r1.const_eval();
+        assert_eq!(buf1.as_str(), "cba");
+
+        let r2 = Reverse("a.b", '.');
+        let buf2: StrBuf<3> = r2.const_eval();
+        assert_eq!(buf2.as_str(), "b.a");
+
+        let r3 = Reverse("o\u{308}x", Graphemes);
+        let buf3: StrBuf<4> = r3.const_eval();
+        assert_eq!(buf3.as_str(), "xo\u{308}");
+    }
+
+    #[test]
+    fn test_chars_rev() {
+        const CHARS: [char; 3] = chars_rev!("你好!");
+        assert_eq!(CHARS, ['!', '好', '你']);
+
+        const EMPTY: [char; 0] = chars_rev!("");
+        assert_eq!(EMPTY, []);
+
+        let to_arr = CharsRev("abcd");
+        assert_eq!(to_arr.output_len(), 4);
+        let result: [char; 4] = to_arr.const_eval();
+        assert_eq!(result, ['d', 'c', 'b', 'a']);
+    }
+}
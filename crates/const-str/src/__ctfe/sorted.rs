@@ -1,98 +1,508 @@
-const fn str_clone<'a, const N: usize>(ss: &[&'a str]) -> [&'a str; N] {
-    assert!(ss.len() == N);
-    let mut buf = [""; N];
-    let mut i = 0;
-    while i < ss.len() {
-        buf[i] = ss[i];
+use core::cmp::Ordering;
+
+/// Sorts an array with a stable bottom-up merge sort.
+///
+/// `$greater` decides whether `$a` should be placed after `$b`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __merge_sort {
+    ($buf: ident, |$a: ident, $b: ident| $greater: expr) => {{
+        let n = $buf.len();
+        let mut src = $buf;
+        let mut dst = $buf;
+        let mut width = 1;
+        while width < n {
+            let mut lo = 0;
+            while lo < n {
+                let mid = if n - lo > width { lo + width } else { n };
+                let hi = if n - mid > width { mid + width } else { n };
+                let (mut i, mut j, mut k) = (lo, mid, lo);
+                while k < hi {
+                    let take_left = if i == mid {
+                        false
+                    } else if j == hi {
+                        true
+                    } else {
+                        let $a = src[i];
+                        let $b = src[j];
+                        !$greater
+                    };
+                    if take_left {
+                        dst[k] = src[i];
+                        i += 1;
+                    } else {
+                        dst[k] = src[j];
+                        j += 1;
+                    }
+                    k += 1;
+                }
+                lo = hi;
+            }
+            let tmp = src;
+            src = dst;
+            dst = tmp;
+            width *= 2;
+        }
+        src
+    }};
+}
+
+#[derive(Clone, Copy)]
+pub struct SortOptions {
+    natural: bool,
+    ignore_ascii_case: bool,
+    reverse: bool,
+}
+
+impl SortOptions {
+    pub const DEFAULT: Self = Self {
+        natural: false,
+        ignore_ascii_case: false,
+        reverse: false,
+    };
+
+    pub const fn natural(self) -> Self {
+        Self {
+            natural: true,
+            ..self
+        }
+    }
+
+    pub const fn ignore_ascii_case(self) -> Self {
+        Self {
+            ignore_ascii_case: true,
+            ..self
+        }
+    }
+
+    pub const fn reverse(self) -> Self {
+        Self {
+            reverse: true,
+            ..self
+        }
+    }
+
+    const fn apply(&self, ord: Ordering) -> Ordering {
+        if self.reverse {
+            ord.reverse()
+        } else {
+            ord
+        }
+    }
+}
+
+const fn compare_usize(lhs: usize, rhs: usize) -> Ordering {
+    if lhs < rhs {
+        Ordering::Less
+    } else if lhs > rhs {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+const fn digits_end(s: &[u8], mut i: usize) -> usize {
+    while i < s.len() && s[i].is_ascii_digit() {
         i += 1;
     }
-    buf
+    i
 }
 
-const fn str_sorted<'a, const N: usize>(ss: &[&'a str]) -> [&'a str; N] {
-    let mut buf = str_clone(ss);
+/// Compares the numbers starting at `lhs[i]` and `rhs[j]`,
+/// returning the ordering and the ends of the numbers.
+const fn compare_numbers(lhs: &[u8], i: usize, rhs: &[u8], j: usize) -> (Ordering, usize, usize) {
+    let (lhs_end, rhs_end) = (digits_end(lhs, i), digits_end(rhs, j));
 
-    let mut l = N;
-    while l > 1 {
-        let mut swapped = false;
+    let (mut i, mut j) = (i, j);
+    while i + 1 < lhs_end && lhs[i] == b'0' {
+        i += 1;
+    }
+    while j + 1 < rhs_end && rhs[j] == b'0' {
+        j += 1;
+    }
+
+    let ord = compare_usize(lhs_end - i, rhs_end - j);
+    if !matches!(ord, Ordering::Equal) {
+        return (ord, lhs_end, rhs_end);
+    }
+    while i < lhs_end {
+        let ord = compare_usize(lhs[i] as usize, rhs[j] as usize);
+        if !matches!(ord, Ordering::Equal) {
+            return (ord, lhs_end, rhs_end);
+        }
+        i += 1;
+        j += 1;
+    }
+    (Ordering::Equal, lhs_end, rhs_end)
+}
 
-        let mut i = 0;
-        while i < l - 1 {
-            let (lhs, rhs) = (buf[i], buf[i + 1]);
-            if crate::compare!(>, lhs, rhs) {
-                (buf[i], buf[i + 1]) = (rhs, lhs);
-                swapped = true;
+const fn compare_bytes(lhs: &[u8], rhs: &[u8], opts: &SortOptions) -> Ordering {
+    let (mut i, mut j) = (0, 0);
+    while i < lhs.len() && j < rhs.len() {
+        if opts.natural && lhs[i].is_ascii_digit() && rhs[j].is_ascii_digit() {
+            let (ord, next_i, next_j) = compare_numbers(lhs, i, rhs, j);
+            if !matches!(ord, Ordering::Equal) {
+                return ord;
             }
-            i += 1;
+            i = next_i;
+            j = next_j;
+            continue;
         }
 
-        if !swapped {
-            break;
+        let (mut x, mut y) = (lhs[i], rhs[j]);
+        if opts.ignore_ascii_case {
+            x = x.to_ascii_lowercase();
+            y = y.to_ascii_lowercase();
         }
+        let ord = compare_usize(x as usize, y as usize);
+        if !matches!(ord, Ordering::Equal) {
+            return ord;
+        }
+        i += 1;
+        j += 1;
+    }
+    compare_usize(lhs.len() - i, rhs.len() - j)
+}
+
+pub struct SortKey<T>(pub T);
+
+impl SortKey<&str> {
+    pub const fn equal(&self, rhs: &str) -> bool {
+        crate::str::equal(self.0, rhs)
+    }
+
+    pub const fn compare(&self, rhs: &str, opts: SortOptions) -> Ordering {
+        if opts.natural || opts.ignore_ascii_case {
+            opts.apply(compare_bytes(self.0.as_bytes(), rhs.as_bytes(), &opts))
+        } else {
+            opts.apply(crate::str::compare(self.0, rhs))
+        }
+    }
 
-        l -= 1;
+    pub const fn greater(&self, rhs: &str, opts: SortOptions) -> bool {
+        matches!(self.compare(rhs, opts), Ordering::Greater)
     }
+}
 
+impl SortKey<&[u8]> {
+    pub const fn equal(&self, rhs: &[u8]) -> bool {
+        crate::bytes::equal(self.0, rhs)
+    }
+
+    pub const fn compare(&self, rhs: &[u8], opts: SortOptions) -> Ordering {
+        opts.apply(compare_bytes(self.0, rhs, &opts))
+    }
+
+    pub const fn greater(&self, rhs: &[u8], opts: SortOptions) -> bool {
+        matches!(self.compare(rhs, opts), Ordering::Greater)
+    }
+}
+
+impl SortKey<char> {
+    pub const fn greater(&self, rhs: char, opts: SortOptions) -> bool {
+        let ord = compare_usize(self.0 as usize, rhs as usize);
+        matches!(opts.apply(ord), Ordering::Greater)
+    }
+}
+
+impl SortKey<bool> {
+    pub const fn greater(&self, rhs: bool, opts: SortOptions) -> bool {
+        let ord = compare_usize(self.0 as usize, rhs as usize);
+        matches!(opts.apply(ord), Ordering::Greater)
+    }
+}
+
+macro_rules! impl_integer_sort_key {
+    ($($ty: ty),+) => {
+        $(
+            impl SortKey<$ty> {
+                pub const fn equal(&self, rhs: $ty) -> bool {
+                    self.0 == rhs
+                }
+
+                pub const fn compare(&self, rhs: $ty, opts: SortOptions) -> Ordering {
+                    let ord = if self.0 < rhs {
+                        Ordering::Less
+                    } else if self.0 > rhs {
+                        Ordering::Greater
+                    } else {
+                        Ordering::Equal
+                    };
+                    opts.apply(ord)
+                }
+
+                pub const fn greater(&self, rhs: $ty, opts: SortOptions) -> bool {
+                    matches!(self.compare(rhs, opts), Ordering::Greater)
+                }
+            }
+        )+
+    };
+}
+
+impl_integer_sort_key!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Returns `[0, 1, ..., N - 1]`.
+const fn identity<const N: usize>() -> [usize; N] {
+    let mut buf = [0; N];
+    let mut i = 0;
+    while i < N {
+        buf[i] = i;
+        i += 1;
+    }
     buf
 }
 
+#[derive(Clone, Copy)]
 pub struct Sorted<T>(pub T);
 
-impl<'a> Sorted<&[&'a str]> {
+/// Names the element type of `Sorted` by the kind returned by `Sorted::kind`,
+/// so that the output of `sorted!` can be a const item.
+pub struct SortedKind<const K: usize>;
+
+pub trait SortedElem {
+    type Type;
+}
+
+pub type SortedOutput<const K: usize, const N: usize> = [<SortedKind<K> as SortedElem>::Type; N];
+
+// The methods for `&str` take `self` by value and the others take `&self`.
+// Method resolution tries the by-value receiver first,
+// so the element type of an empty array literal is inferred as `&str`, as in `sorted!([])`.
+macro_rules! impl_sorted {
+    ([$($r: tt)?] $kind: literal => $ty: ty, $static_ty: ty $(, $lt: lifetime)?) => {
+        impl SortedElem for SortedKind<$kind> {
+            type Type = $static_ty;
+        }
+
+        impl<$($lt)?> Sorted<&[$ty]> {
+            pub const fn output_len($($r)? self) -> usize {
+                self.0.len()
+            }
+
+            pub const fn kind($($r)? self) -> usize {
+                $kind
+            }
+
+            pub const fn const_eval<const N: usize>($($r)? self) -> [$ty; N] {
+                self.const_eval_with_options(SortOptions::DEFAULT)
+            }
+
+            pub const fn const_eval_with_options<const N: usize>($($r)? self, opts: SortOptions) -> [$ty; N] {
+                let s = self.0;
+                assert!(s.len() == N);
+                let order: [usize; N] = identity();
+                let order = crate::__merge_sort!(order, |a, b| SortKey(s[a]).greater(s[b], opts));
+                crate::slice::select(s, &order)
+            }
+        }
+
+        impl<$($lt,)? const L: usize> Sorted<[$ty; L]> {
+            pub const fn output_len($($r)? self) -> usize {
+                L
+            }
+
+            pub const fn kind($($r)? self) -> usize {
+                $kind
+            }
+
+            pub const fn const_eval<const N: usize>($($r)? self) -> [$ty; N] {
+                self.const_eval_with_options(SortOptions::DEFAULT)
+            }
+
+            pub const fn const_eval_with_options<const N: usize>($($r)? self, opts: SortOptions) -> [$ty; N] {
+                Sorted(self.0.as_slice()).const_eval_with_options(opts)
+            }
+        }
+
+        impl<$($lt,)? const L: usize> Sorted<&[$ty; L]> {
+            pub const fn output_len($($r)? self) -> usize {
+                L
+            }
+
+            pub const fn kind($($r)? self) -> usize {
+                $kind
+            }
+
+            pub const fn const_eval<const N: usize>($($r)? self) -> [$ty; N] {
+                self.const_eval_with_options(SortOptions::DEFAULT)
+            }
+
+            pub const fn const_eval_with_options<const N: usize>($($r)? self, opts: SortOptions) -> [$ty; N] {
+                Sorted(self.0.as_slice()).const_eval_with_options(opts)
+            }
+        }
+    };
+}
+
+impl_sorted!([] 0 => &'a str, &'static str, 'a);
+impl_sorted!([&] 1 => &'a [u8], &'static [u8], 'a);
+impl_sorted!([&] 2 => u8, u8);
+impl_sorted!([&] 3 => u16, u16);
+impl_sorted!([&] 4 => u32, u32);
+impl_sorted!([&] 5 => u64, u64);
+impl_sorted!([&] 6 => u128, u128);
+impl_sorted!([&] 7 => usize, usize);
+impl_sorted!([&] 8 => i8, i8);
+impl_sorted!([&] 9 => i16, i16);
+impl_sorted!([&] 10 => i32, i32);
+impl_sorted!([&] 11 => i64, i64);
+impl_sorted!([&] 12 => i128, i128);
+impl_sorted!([&] 13 => isize, isize);
+
+/// Sorts string slices, byte strings or integers and returns a new array.
+///
+/// The input type must be one of:
+/// + [`&[T]`](slice)
+/// + [`[T; N]`](array)
+/// + [`&[T; N]`](array)
+///
+/// where `T` is [`&str`](str), [`&[u8]`](slice) or an integer type.
+/// Integer literals need a type suffix.
+///
+/// The sort is stable. The order can be changed by the options after the input:
+/// + `natural`: compares runs of ASCII digits by their numeric values, so `"2"` comes before `"10"`.
+/// + `ignore_ascii_case`: compares ASCII letters case-insensitively.
+/// + `reverse`: sorts in descending order.
+///
+/// `natural` and `ignore_ascii_case` have no effect on integers.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`sorted_by_key!`](crate::sorted_by_key).
+///
+/// # Examples
+///
+/// ```rust
+/// const SORTED1: &[&str] = &const_str::sorted!(["one", "two", "three"]);
+/// assert_eq!(SORTED1, &["one", "three", "two"]);
+///
+/// const SORTED2: [&str; 3] = const_str::sorted!(["1", "2", "10"]);
+/// assert_eq!(SORTED2, ["1", "10", "2"]);
+///
+/// const SORTED3: [&str; 4] = const_str::sorted!(["v10", "v2", "V1", "v1.5"], natural, ignore_ascii_case);
+/// assert_eq!(SORTED3, ["V1", "v1.5", "v2", "v10"]);
+///
+/// const SORTED4: [u32; 4] = const_str::sorted!([3_u32, 10, 1, 2], reverse);
+/// assert_eq!(SORTED4, [10, 3, 2, 1]);
+///
+/// const KEYWORDS: &[&[u8]] = &[b"while", b"fn", b"let"];
+/// const SORTED5: &[&[u8]] = &const_str::sorted!(KEYWORDS);
+/// assert_eq!(SORTED5, [b"fn" as &[u8], b"let", b"while"]);
+/// ```
+///
+#[macro_export]
+macro_rules! sorted {
+    ($s: expr $(, $opt: ident)* $(,)?) => {{
+        const N: usize = $crate::__ctfe::Sorted($s).output_len();
+        const K: usize = $crate::__ctfe::Sorted($s).kind();
+        const OUTPUT: $crate::__ctfe::SortedOutput<K, N> = $crate::__ctfe::Sorted($s)
+            .const_eval_with_options($crate::__ctfe::SortOptions::DEFAULT $(.$opt())*);
+        OUTPUT
+    }};
+}
+
+pub struct SortedByKey<T>(pub T);
+
+impl<T: Copy> SortedByKey<&[T]> {
     pub const fn output_len(&self) -> usize {
         self.0.len()
     }
 
-    pub const fn const_eval<const N: usize>(&self) -> [&'a str; N] {
-        str_sorted(self.0)
+    pub const fn as_slice(&self) -> &[T] {
+        self.0
+    }
+
+    pub const fn identity<const N: usize>(&self) -> [usize; N] {
+        identity()
+    }
+
+    pub const fn permute<const N: usize>(&self, order: [usize; N]) -> [T; N] {
+        crate::slice::select(self.0, &order)
     }
 }
 
-impl<'a, const L: usize> Sorted<[&'a str; L]> {
+impl<T: Copy, const L: usize> SortedByKey<[T; L]> {
     pub const fn output_len(&self) -> usize {
         L
     }
 
-    pub const fn const_eval(&self) -> [&'a str; L] {
-        str_sorted(&self.0)
+    pub const fn as_slice(&self) -> &[T] {
+        self.0.as_slice()
+    }
+
+    pub const fn identity<const N: usize>(&self) -> [usize; N] {
+        identity()
+    }
+
+    pub const fn permute<const N: usize>(&self, order: [usize; N]) -> [T; N] {
+        crate::slice::select(self.0.as_slice(), &order)
     }
 }
 
-impl<'a, const L: usize> Sorted<&[&'a str; L]> {
+impl<T: Copy, const L: usize> SortedByKey<&[T; L]> {
     pub const fn output_len(&self) -> usize {
         L
     }
 
-    pub const fn const_eval(&self) -> [&'a str; L] {
-        str_sorted(self.0)
+    pub const fn as_slice(&self) -> &[T] {
+        self.0.as_slice()
+    }
+
+    pub const fn identity<const N: usize>(&self) -> [usize; N] {
+        identity()
+    }
+
+    pub const fn permute<const N: usize>(&self, order: [usize; N]) -> [T; N] {
+        crate::slice::select(self.0.as_slice(), &order)
     }
 }
 
-/// Sorts string slices and returns a new array.
+/// Sorts an array or slice by a key and returns a new array.
 ///
-/// The input type must be one of:
-/// + [`&[&str]`](slice)
-/// + [`[&str; N]`](array)
-/// + [`&[&str; N]`](array)
+/// The elements must be [`Copy`].
+/// The key is written like a closure, `|x| key`, and is evaluated for each comparison.
+/// It must be one of [`&str`](str), [`&[u8]`](slice), [`char`], [`bool`] or an integer type.
+///
+/// The sort is stable and accepts the same options as [`sorted!`](crate::sorted).
 ///
 /// This macro is [const-context only](./index.html#const-context-only).
 ///
 /// # Examples
 ///
 /// ```rust
-/// const SORTED1: &[&str] = &const_str::sorted!(["one", "two", "three"]);
-/// assert_eq!(SORTED1, &["one", "three", "two"]);
+/// use const_str::sorted_by_key;
 ///
-/// const SORTED2: [&str; 3] = const_str::sorted!(["1", "2", "10"]);
-/// assert_eq!(SORTED2, ["1", "10", "2"]);
+/// const KEYWORDS: &[(&str, u8)] = &[("while", 3), ("Fn", 1), ("let", 2)];
+///
+/// const BY_NAME: &[(&str, u8)] = &sorted_by_key!(KEYWORDS, |(name, _)| name, ignore_ascii_case);
+/// assert_eq!(BY_NAME, [("Fn", 1), ("let", 2), ("while", 3)]);
+///
+/// const BY_ID: [(&str, u8); 3] = sorted_by_key!(KEYWORDS, |kw| kw.1, reverse);
+/// assert_eq!(BY_ID, [("while", 3), ("let", 2), ("Fn", 1)]);
 /// ```
 ///
 #[macro_export]
-macro_rules! sorted {
-    ($s:expr) => {{
-        const N: usize = $crate::__ctfe::Sorted($s).output_len();
-        const SS: [&str; N] = $crate::__ctfe::Sorted($s).const_eval();
-        SS
+macro_rules! sorted_by_key {
+    ($s: expr, |$x: pat_param| $key: expr $(, $opt: ident)* $(,)?) => {{
+        const N: usize = $crate::__ctfe::SortedByKey($s).output_len();
+        const ORDER: [usize; N] = {
+            let opts = $crate::__ctfe::SortOptions::DEFAULT $(.$opt())*;
+            let input = $crate::__ctfe::SortedByKey($s);
+            let s = input.as_slice();
+            let buf: [usize; N] = input.identity();
+            $crate::__merge_sort!(buf, |lhs, rhs| {
+                let lhs = {
+                    let $x = s[lhs];
+                    $key
+                };
+                let rhs = {
+                    let $x = s[rhs];
+                    $key
+                };
+                $crate::__ctfe::SortKey(lhs).greater(rhs, opts)
+            })
+        };
+        $crate::__ctfe::SortedByKey($s).permute(ORDER)
     }};
 }
 
@@ -124,6 +534,19 @@ mod tests {
         testcase!(["1", "2", "10", "20", "3"]);
     }
 
+    #[test]
+    fn test_sorted_static() {
+        fn keywords() -> &'static [&'static str] {
+            &sorted!(["while", "fn", "let"])
+        }
+        assert_eq!(keywords(), ["fn", "let", "while"]);
+
+        fn numbers() -> &'static [u32] {
+            &sorted!([3_u32, 1, 2], reverse)
+        }
+        assert_eq!(numbers(), [3, 2, 1]);
+    }
+
     #[test]
     fn test_sorted_runtime() {
         use super::*;
@@ -163,4 +586,135 @@ mod tests {
         let result_array: [&str; 3] = sorted_array.const_eval();
         assert_eq!(result_array, ["a", "b", "c"]);
     }
+
+    #[test]
+    fn test_sorted_options() {
+        const XS: &[&str] = &["file10", "File2", "file1", "file02", "file2b", "a", ""];
+
+        const NATURAL: [&str; 7] = sorted!(XS, natural);
+        assert_eq!(
+            NATURAL,
+            ["", "File2", "a", "file1", "file02", "file2b", "file10"]
+        );
+
+        const NATURAL_CI: [&str; 7] = sorted!(XS, natural, ignore_ascii_case);
+        assert_eq!(
+            NATURAL_CI,
+            ["", "a", "file1", "File2", "file02", "file2b", "file10"]
+        );
+
+        const CI: [&str; 7] = sorted!(XS, ignore_ascii_case);
+        assert_eq!(
+            CI,
+            ["", "a", "file02", "file1", "file10", "File2", "file2b"]
+        );
+
+        const REV: [&str; 7] = sorted!(XS, reverse);
+        let mut ans = XS.to_vec();
+        ans.sort_by(|a, b| b.cmp(a));
+        assert_eq!(REV, &*ans);
+
+        let numbers = sorted!(["1", "10", "2", "007", "7", "0", "00"], natural);
+        assert_eq!(numbers, ["0", "00", "1", "2", "007", "7", "10"]);
+
+        let big = sorted!(
+            ["99999999999999999999999", "100000000000000000000000"],
+            natural
+        );
+        assert_eq!(big, ["99999999999999999999999", "100000000000000000000000"]);
+    }
+
+    #[test]
+    fn test_sorted_types() {
+        const INTS: [i32; 6] = sorted!([3_i32, -1, 10, 0, -20, 3]);
+        assert_eq!(INTS, [-20, -1, 0, 3, 3, 10]);
+
+        const REV: [u8; 4] = sorted!(&[1_u8, 255, 0, 7], reverse);
+        assert_eq!(REV, [255, 7, 1, 0]);
+
+        const US: &[usize] = &[5, 4, 3, 2, 1];
+        const SORTED_US: [usize; 5] = sorted!(US);
+        assert_eq!(SORTED_US, [1, 2, 3, 4, 5]);
+
+        const WIDE: [i128; 3] = sorted!([i128::MAX, i128::MIN, 0]);
+        assert_eq!(WIDE, [i128::MIN, 0, i128::MAX]);
+
+        const BYTES: &[&[u8]] = &[b"b", b"", b"A", b"a\xff", b"a"];
+        const SORTED_BYTES: [&[u8]; 5] = sorted!(BYTES);
+        let mut ans = BYTES.to_vec();
+        ans.sort();
+        assert_eq!(SORTED_BYTES, &*ans);
+
+        const BYTES_CI: [&[u8]; 5] = sorted!(BYTES, ignore_ascii_case);
+        assert_eq!(BYTES_CI, [b"" as &[u8], b"A", b"a", b"a\xff", b"b"]);
+    }
+
+    #[test]
+    fn test_sorted_large() {
+        const fn gen<const N: usize>() -> [u32; N] {
+            let mut buf = [0; N];
+            let mut x: u32 = 1;
+            let mut i = 0;
+            while i < N {
+                x = x.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                buf[i] = x >> 16;
+                i += 1;
+            }
+            buf
+        }
+
+        const XS: [u32; 2000] = gen();
+        const SORTED: [u32; 2000] = sorted!(XS);
+        let mut ans = XS;
+        ans.sort();
+        assert_eq!(SORTED, ans);
+    }
+
+    #[test]
+    fn test_sorted_by_key() {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct Keyword {
+            name: &'static str,
+            id: u8,
+        }
+
+        const KEYWORDS: &[Keyword] = &[
+            Keyword {
+                name: "while",
+                id: 3,
+            },
+            Keyword { name: "If", id: 1 },
+            Keyword {
+                name: "else",
+                id: 1,
+            },
+            Keyword { name: "fn", id: 0 },
+        ];
+
+        const BY_NAME: [Keyword; 4] = sorted_by_key!(KEYWORDS, |kw| kw.name);
+        let names: Vec<_> = BY_NAME.iter().map(|kw| kw.name).collect();
+        assert_eq!(names, ["If", "else", "fn", "while"]);
+
+        const BY_NAME_CI: [Keyword; 4] = sorted_by_key!(KEYWORDS, |kw| kw.name, ignore_ascii_case);
+        let names: Vec<_> = BY_NAME_CI.iter().map(|kw| kw.name).collect();
+        assert_eq!(names, ["else", "fn", "If", "while"]);
+
+        // stable
+        const BY_ID: [Keyword; 4] = sorted_by_key!(KEYWORDS, |kw| kw.id);
+        let mut ans = KEYWORDS.to_vec();
+        ans.sort_by_key(|kw| kw.id);
+        assert_eq!(BY_ID, &*ans);
+
+        const BY_ID_REV: [Keyword; 4] = sorted_by_key!(KEYWORDS, |Keyword { id, .. }| id, reverse);
+        let ids: Vec<_> = BY_ID_REV.iter().map(|kw| kw.id).collect();
+        assert_eq!(ids, [3, 1, 1, 0]);
+
+        const PAIRS: [(char, bool); 3] =
+            sorted_by_key!([('b', true), ('a', false), ('c', true)], |(_, b)| b);
+        assert_eq!(PAIRS, [('a', false), ('b', true), ('c', true)]);
+
+        const EMPTY: &[(u8,)] = &[];
+        const SORTED_EMPTY: [(u8,); 0] = sorted_by_key!(EMPTY, |x| x.0);
+        assert_eq!(SORTED_EMPTY, []);
+    }
 }
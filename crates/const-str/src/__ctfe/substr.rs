@@ -0,0 +1,263 @@
+#![allow(unsafe_code)]
+
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use crate::slice::{advance, subslice};
+
+const fn char_offset(s: &str, idx: usize) -> usize {
+    match crate::utf8::str_char_offset(s, idx) {
+        Some(offset) => offset,
+        None => const_panic!(
+            "char index ",
+            usize: idx,
+            " is out of bounds of a string with ",
+            usize: crate::utf8::str_count_chars(s),
+            " chars"
+        ),
+    }
+}
+
+const fn substr(s: &str, start: usize, end: usize) -> &str {
+    if start > end {
+        const_panic!("char range starts at ", usize: start, " but ends at ", usize: end);
+    }
+    let start_offset = char_offset(s, start);
+    let rest = unsafe { core::str::from_utf8_unchecked(advance(s.as_bytes(), start_offset)) };
+    let len = match crate::utf8::str_char_offset(rest, end - start) {
+        Some(len) => len,
+        None => char_offset(s, end),
+    };
+    let bytes = subslice(s.as_bytes(), start_offset..start_offset + len);
+    unsafe { core::str::from_utf8_unchecked(bytes) }
+}
+
+pub struct Substr<T, R>(pub T, pub R);
+
+impl<'a> Substr<&'a str, Range<usize>> {
+    pub const fn const_eval(&self) -> &'a str {
+        substr(self.0, self.1.start, self.1.end)
+    }
+}
+
+impl<'a> Substr<&'a str, RangeInclusive<usize>> {
+    pub const fn const_eval(&self) -> &'a str {
+        let end = match self.1.end().checked_add(1) {
+            Some(end) => end,
+            None => panic!("attempted to index str up to maximum usize"),
+        };
+        substr(self.0, *self.1.start(), end)
+    }
+}
+
+impl<'a> Substr<&'a str, RangeFrom<usize>> {
+    pub const fn const_eval(&self) -> &'a str {
+        let s = self.0;
+        let start_offset = char_offset(s, self.1.start);
+        unsafe { core::str::from_utf8_unchecked(advance(s.as_bytes(), start_offset)) }
+    }
+}
+
+impl<'a> Substr<&'a str, RangeTo<usize>> {
+    pub const fn const_eval(&self) -> &'a str {
+        substr(self.0, 0, self.1.end)
+    }
+}
+
+impl<'a> Substr<&'a str, RangeToInclusive<usize>> {
+    pub const fn const_eval(&self) -> &'a str {
+        Substr(self.0, 0..=self.1.end).const_eval()
+    }
+}
+
+impl<'a> Substr<&'a str, RangeFull> {
+    pub const fn const_eval(&self) -> &'a str {
+        self.0
+    }
+}
+
+/// Returns a substring by a range of **character** indices.
+///
+/// The range type must be one of
+/// [`Range`], [`RangeInclusive`], [`RangeFrom`], [`RangeTo`], [`RangeToInclusive`], [`RangeFull`].
+///
+/// The function panics if the range is out of bounds,
+/// and the message names the offending index.
+///
+/// This macro is [const-fn compatible](./index.html#const-fn-compatible).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::substr;
+///
+/// const S: &str = "你好, world";
+///
+/// const _: () = {
+///     assert!(const_str::equal!(substr!(S, 0..2), "你好"));
+///     assert!(const_str::equal!(substr!(S, 4..), "world"));
+///     assert!(const_str::equal!(substr!(S, ..=2), "你好,"));
+/// };
+///
+/// const fn initial(name: &str) -> &str {
+///     substr!(name, ..1)
+/// }
+/// assert_eq!(initial("Ümit"), "Ü");
+/// ```
+///
+/// ```compile_fail
+/// const S: &str = const_str::substr!("abc", 1..4);
+/// // error: char index 4 is out of bounds of a string with 3 chars
+/// ```
+#[macro_export]
+macro_rules! substr {
+    ($s: expr, $range: expr) => {
+        $crate::__ctfe::Substr($s, $range).const_eval()
+    };
+}
+
+pub struct CharAt<T>(pub T, pub usize);
+
+impl CharAt<&str> {
+    pub const fn const_eval(&self) -> char {
+        let offset = char_offset(self.0, self.1);
+        match crate::utf8::next_char(advance(self.0.as_bytes(), offset)) {
+            Some((ch, _)) => ch,
+            None => const_panic!(
+                "char index ",
+                usize: self.1,
+                " is out of bounds of a string with ",
+                usize: crate::utf8::str_count_chars(self.0),
+                " chars"
+            ),
+        }
+    }
+}
+
+/// Returns the character at a **character** index.
+///
+/// The function panics if the index is out of bounds,
+/// and the message names the offending index.
+///
+/// This macro is [const-fn compatible](./index.html#const-fn-compatible).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::char_at;
+///
+/// const C: char = char_at!("a我b", 1);
+/// assert_eq!(C, '我');
+///
+/// const fn last(s: &str) -> char {
+///     char_at!(s, const_str::char_len!(s) - 1)
+/// }
+/// assert_eq!(last("你好!"), '!');
+/// ```
+#[macro_export]
+macro_rules! char_at {
+    ($s: expr, $idx: expr) => {
+        $crate::__ctfe::CharAt($s, $idx).const_eval()
+    };
+}
+
+pub struct CharLen<T>(pub T);
+
+impl CharLen<&str> {
+    pub const fn const_eval(&self) -> usize {
+        crate::utf8::str_count_chars(self.0)
+    }
+}
+
+/// Returns the number of characters in a string slice.
+///
+/// This macro is [const-fn compatible](./index.html#const-fn-compatible).
+///
+/// # Examples
+///
+/// ```
+/// const N: usize = const_str::char_len!("你好, world");
+/// assert_eq!(N, 9);
+/// ```
+#[macro_export]
+macro_rules! char_len {
+    ($s: expr) => {
+        $crate::__ctfe::CharLen($s).const_eval()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_substr() {
+        const S: &str = "a我b𐍈c";
+
+        const S1: &str = substr!(S, 0..0);
+        const S2: &str = substr!(S, 1..4);
+        const S3: &str = substr!(S, 1..=3);
+        const S4: &str = substr!(S, 2..);
+        const S5: &str = substr!(S, ..2);
+        const S6: &str = substr!(S, ..=4);
+        const S7: &str = substr!(S, ..);
+        const S8: &str = substr!(S, 5..);
+        const S9: &str = substr!(S, 5..5);
+
+        assert_eq!(S1, "");
+        assert_eq!(S2, "我b𐍈");
+        assert_eq!(S3, "我b𐍈");
+        assert_eq!(S4, "b𐍈c");
+        assert_eq!(S5, "a我");
+        assert_eq!(S6, S);
+        assert_eq!(S7, S);
+        assert_eq!(S8, "");
+        assert_eq!(S9, "");
+
+        let chars: Vec<char> = S.chars().collect();
+        for i in 0..=chars.len() {
+            for j in i..=chars.len() {
+                let ans: String = chars[i..j].iter().collect();
+                assert_eq!(substr!(S, i..j), ans);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic = "char index 6 is out of bounds of a string with 5 chars"]
+    fn test_substr_end_out_of_bounds() {
+        let _ = substr!("a我b𐍈c", 2..6);
+    }
+
+    #[test]
+    #[should_panic = "char index 7 is out of bounds of a string with 5 chars"]
+    fn test_substr_start_out_of_bounds() {
+        let _ = substr!("a我b𐍈c", 7..);
+    }
+
+    #[test]
+    #[should_panic = "char range starts at 3 but ends at 2"]
+    fn test_substr_inverted() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let _ = substr!("abcd", 3..2);
+    }
+
+    #[test]
+    fn test_char_at() {
+        const C0: char = char_at!("a我b", 0);
+        const C1: char = char_at!("a我b", 1);
+        const C2: char = char_at!("a我b", 2);
+        assert_eq!([C0, C1, C2], ['a', '我', 'b']);
+    }
+
+    #[test]
+    #[should_panic = "char index 3 is out of bounds of a string with 3 chars"]
+    fn test_char_at_out_of_bounds() {
+        let _ = char_at!("a我b", 3);
+    }
+
+    #[test]
+    fn test_char_len() {
+        const N1: usize = char_len!("");
+        const N2: usize = char_len!("a我b𐍈");
+        assert_eq!(N1, 0);
+        assert_eq!(N2, 4);
+    }
+}
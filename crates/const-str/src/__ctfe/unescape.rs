@@ -0,0 +1,468 @@
+#![allow(unsafe_code)]
+
+use super::StrBuf;
+
+use crate::utf8::CharEncodeUtf8;
+
+/// The escape syntaxes of [`Unescape`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnescapeKind {
+    /// Rust string literals
+    Rust,
+    /// JSON strings
+    Json,
+    /// C string literals
+    C,
+}
+
+/// The bytes of an escape sequence and the offset after it.
+struct Unescaped {
+    buf: [u8; 4],
+    len: usize,
+    next: usize,
+}
+
+impl Unescaped {
+    const fn byte(b: u8, next: usize) -> Self {
+        Self {
+            buf: [b, 0, 0, 0],
+            len: 1,
+            next,
+        }
+    }
+
+    const fn char(ch: char, next: usize) -> Self {
+        let e = CharEncodeUtf8::new(ch);
+        let bytes = e.as_bytes();
+        let mut buf = [0; 4];
+        let mut i = 0;
+        while i < bytes.len() {
+            buf[i] = bytes[i];
+            i += 1;
+        }
+        Self {
+            buf,
+            len: bytes.len(),
+            next,
+        }
+    }
+
+    const fn as_bytes(&self) -> &[u8] {
+        crate::slice::subslice(&self.buf, 0..self.len)
+    }
+}
+
+const fn hex_digit(b: u8) -> Option<u32> {
+    match b {
+        b'0'..=b'9' => Some((b - b'0') as u32),
+        b'a'..=b'f' => Some((b - b'a' + 10) as u32),
+        b'A'..=b'F' => Some((b - b'A' + 10) as u32),
+        _ => None,
+    }
+}
+
+/// Parses exactly `count` hex digits at `start`.
+const fn parse_hex(s: &[u8], start: usize, count: usize) -> Option<u32> {
+    if start + count > s.len() {
+        return None;
+    }
+    let mut ans = 0;
+    let mut i = 0;
+    while i < count {
+        ans = match hex_digit(s[start + i]) {
+            Some(d) => (ans << 4) | d,
+            None => return None,
+        };
+        i += 1;
+    }
+    Some(ans)
+}
+
+const fn to_char(code: u32, offset: usize) -> char {
+    match char::from_u32(code) {
+        Some(ch) => ch,
+        None => const_panic!("invalid unicode character escape at offset ", usize: offset),
+    }
+}
+
+const fn invalid_escape(offset: usize) -> ! {
+    const_panic!("invalid escape at offset ", usize: offset)
+}
+
+/// Unescapes the escape sequence starting with the backslash at `i`.
+const fn unescape_rust(s: &[u8], i: usize) -> Unescaped {
+    if i + 1 >= s.len() {
+        invalid_escape(i);
+    }
+    let next = i + 2;
+    match s[i + 1] {
+        b'n' => Unescaped::byte(b'\n', next),
+        b'r' => Unescaped::byte(b'\r', next),
+        b't' => Unescaped::byte(b'\t', next),
+        b'\\' => Unescaped::byte(b'\\', next),
+        b'0' => Unescaped::byte(b'\0', next),
+        b'\'' => Unescaped::byte(b'\'', next),
+        b'"' => Unescaped::byte(b'"', next),
+        b'x' => match parse_hex(s, next, 2) {
+            Some(x) if x <= 0x7f => Unescaped::byte(x as u8, next + 2),
+            _ => invalid_escape(i),
+        },
+        b'u' => {
+            if next >= s.len() || s[next] != b'{' {
+                invalid_escape(i);
+            }
+            let mut j = next + 1;
+            let mut code: u32 = 0;
+            let mut digits = 0;
+            loop {
+                if j >= s.len() {
+                    invalid_escape(i);
+                }
+                let b = s[j];
+                j += 1;
+                if b == b'}' {
+                    break;
+                }
+                if b == b'_' && digits > 0 {
+                    continue;
+                }
+                code = match hex_digit(b) {
+                    Some(d) if digits < 6 => (code << 4) | d,
+                    _ => invalid_escape(i),
+                };
+                digits += 1;
+            }
+            if digits == 0 {
+                invalid_escape(i);
+            }
+            Unescaped::char(to_char(code, i), j)
+        }
+        b'\n' | b'\r' => {
+            let mut j = i + 1;
+            while j < s.len() && matches!(s[j], b' ' | b'\t' | b'\n' | b'\r') {
+                j += 1;
+            }
+            Unescaped {
+                buf: [0; 4],
+                len: 0,
+                next: j,
+            }
+        }
+        _ => invalid_escape(i),
+    }
+}
+
+/// Unescapes the escape sequence starting with the backslash at `i`.
+const fn unescape_json(s: &[u8], i: usize) -> Unescaped {
+    if i + 1 >= s.len() {
+        invalid_escape(i);
+    }
+    let next = i + 2;
+    match s[i + 1] {
+        b'"' => Unescaped::byte(b'"', next),
+        b'\\' => Unescaped::byte(b'\\', next),
+        b'/' => Unescaped::byte(b'/', next),
+        b'b' => Unescaped::byte(b'\x08', next),
+        b'f' => Unescaped::byte(b'\x0c', next),
+        b'n' => Unescaped::byte(b'\n', next),
+        b'r' => Unescaped::byte(b'\r', next),
+        b't' => Unescaped::byte(b'\t', next),
+        b'u' => {
+            let high = match parse_hex(s, next, 4) {
+                Some(x) => x,
+                None => invalid_escape(i),
+            };
+            match high {
+                0xd800..=0xdbff => {
+                    let j = next + 4;
+                    let low = if j + 1 < s.len() && s[j] == b'\\' && s[j + 1] == b'u' {
+                        parse_hex(s, j + 2, 4)
+                    } else {
+                        None
+                    };
+                    match low {
+                        Some(low @ 0xdc00..=0xdfff) => {
+                            let code = 0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00);
+                            Unescaped::char(to_char(code, i), j + 6)
+                        }
+                        _ => const_panic!("unpaired surrogate at offset ", usize: i),
+                    }
+                }
+                0xdc00..=0xdfff => const_panic!("unpaired surrogate at offset ", usize: i),
+                _ => Unescaped::char(to_char(high, i), next + 4),
+            }
+        }
+        _ => invalid_escape(i),
+    }
+}
+
+/// Unescapes the escape sequence starting with the backslash at `i`.
+const fn unescape_c(s: &[u8], i: usize) -> Unescaped {
+    if i + 1 >= s.len() {
+        invalid_escape(i);
+    }
+    let next = i + 2;
+    match s[i + 1] {
+        b'a' => Unescaped::byte(b'\x07', next),
+        b'b' => Unescaped::byte(b'\x08', next),
+        b'f' => Unescaped::byte(b'\x0c', next),
+        b'n' => Unescaped::byte(b'\n', next),
+        b'r' => Unescaped::byte(b'\r', next),
+        b't' => Unescaped::byte(b'\t', next),
+        b'v' => Unescaped::byte(b'\x0b', next),
+        b'\\' => Unescaped::byte(b'\\', next),
+        b'\'' => Unescaped::byte(b'\'', next),
+        b'"' => Unescaped::byte(b'"', next),
+        b'?' => Unescaped::byte(b'?', next),
+        b'0'..=b'7' => {
+            let mut j = i + 1;
+            let mut x: u32 = 0;
+            while j < s.len() && j < i + 4 && matches!(s[j], b'0'..=b'7') {
+                x = (x << 3) | (s[j] - b'0') as u32;
+                j += 1;
+            }
+            if x > 0xff {
+                invalid_escape(i);
+            }
+            Unescaped::byte(x as u8, j)
+        }
+        b'x' => {
+            let mut j = next;
+            let mut x: u32 = 0;
+            while j < s.len() {
+                match hex_digit(s[j]) {
+                    Some(d) => x = (x << 4) | d,
+                    None => break,
+                }
+                if x > 0xff {
+                    invalid_escape(i);
+                }
+                j += 1;
+            }
+            if j == next {
+                invalid_escape(i);
+            }
+            Unescaped::byte(x as u8, j)
+        }
+        b'u' | b'U' => {
+            let count = if s[i + 1] == b'u' { 4 } else { 8 };
+            match parse_hex(s, next, count) {
+                Some(code) => Unescaped::char(to_char(code, i), next + count),
+                None => invalid_escape(i),
+            }
+        }
+        _ => invalid_escape(i),
+    }
+}
+
+const fn unescape_at(s: &[u8], i: usize, kind: UnescapeKind) -> Unescaped {
+    match kind {
+        UnescapeKind::Rust => unescape_rust(s, i),
+        UnescapeKind::Json => unescape_json(s, i),
+        UnescapeKind::C => unescape_c(s, i),
+    }
+}
+
+pub struct Unescape<T>(pub T, pub UnescapeKind);
+
+impl Unescape<&str> {
+    pub const fn output_len(&self) -> usize {
+        let s = self.0.as_bytes();
+        let mut ans = 0;
+        let mut i = 0;
+        while i < s.len() {
+            if s[i] == b'\\' {
+                let e = unescape_at(s, i, self.1);
+                ans += e.len;
+                i = e.next;
+            } else {
+                ans += 1;
+                i += 1;
+            }
+        }
+        ans
+    }
+
+    pub const fn const_eval<const N: usize>(&self) -> StrBuf<N> {
+        let s = self.0.as_bytes();
+        let mut buf = [0; N];
+        let mut pos = 0;
+
+        let mut i = 0;
+        while i < s.len() {
+            if s[i] == b'\\' {
+                let e = unescape_at(s, i, self.1);
+                let bytes = e.as_bytes();
+                let mut k = 0;
+                while k < bytes.len() {
+                    buf[pos] = bytes[k];
+                    pos += 1;
+                    k += 1;
+                }
+                i = e.next;
+            } else {
+                buf[pos] = s[i];
+                pos += 1;
+                i += 1;
+            }
+        }
+        assert!(pos == N);
+
+        if let Err(err) = core::str::from_utf8(&buf) {
+            const_panic!(
+                "invalid UTF-8 in unescaped bytes at offset ",
+                usize: err.valid_up_to()
+            )
+        }
+        unsafe { StrBuf::new_unchecked(buf) }
+    }
+}
+
+/// Decodes the escape sequences in a string slice.
+///
+/// The syntax of escape sequences is one of:
+/// + `rust`: [Rust string literals](https://doc.rust-lang.org/reference/tokens.html#string-literals),
+///   including `\u{...}` and line continuations
+/// + `json`: JSON strings, including surrogate pairs like `\ud83d\ude00`
+/// + `c`: C string literals, including octal, hex and universal character names
+///
+/// The output type is [`&str`](str).
+///
+/// The compilation fails with the byte offset of the backslash if an escape sequence is invalid.
+/// It also fails if the bytes from `c` escapes are not valid UTF-8.
+///
+/// This macro is [const-context only](./index.html#const-context-only).
+///
+/// See also [`escape_json!`](crate::escape_json), [`escape_c!`](crate::escape_c).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::unescape;
+///
+/// const RUST: &str = unescape!(r"line\nnext\u{1F600}", rust);
+/// assert_eq!(RUST, "line\nnext😀");
+///
+/// const JSON: &str = unescape!(r#"\"hi\" \ud83d\ude00 \u00e9"#, json);
+/// assert_eq!(JSON, "\"hi\" 😀 é");
+///
+/// const C: &str = unescape!(r"tab\there\041 \303\251\x41", c);
+/// assert_eq!(C, "tab\there! éA");
+/// ```
+///
+/// ```compile_fail
+/// // invalid escape at offset 3
+/// const S: &str = const_str::unescape!(r"abc\q", rust);
+/// ```
+#[macro_export]
+macro_rules! unescape {
+    ($s: expr, rust $(,)?) => {
+        $crate::__unescape!($s, Rust)
+    };
+    ($s: expr, json $(,)?) => {
+        $crate::__unescape!($s, Json)
+    };
+    ($s: expr, c $(,)?) => {
+        $crate::__unescape!($s, C)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __unescape {
+    ($s: expr, $kind: ident) => {{
+        const OUTPUT_LEN: usize =
+            $crate::__ctfe::Unescape($s, $crate::__ctfe::UnescapeKind::$kind).output_len();
+        const OUTPUT_BUF: $crate::__ctfe::StrBuf<OUTPUT_LEN> =
+            $crate::__ctfe::Unescape($s, $crate::__ctfe::UnescapeKind::$kind).const_eval();
+        OUTPUT_BUF.as_str()
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unescape_rust() {
+        assert_eq!(unescape!("", rust), "");
+        assert_eq!(unescape!("abc 你好", rust), "abc 你好");
+        assert_eq!(
+            unescape!(r#"\n\r\t\\\0\'\"\x41\x7f"#, rust),
+            "\n\r\t\\\0\'\"\x41\x7f"
+        );
+        assert_eq!(
+            unescape!(r"\u{0}\u{e9}\u{1F_600}\u{10FFFF}", rust),
+            "\0é😀\u{10FFFF}"
+        );
+        assert_eq!(unescape!("a\\\n    b\\\r\n\tc", rust), "abc");
+
+        const S: &str = "line\\nnext\\u{1F600}";
+        const _: () = assert!(crate::equal!(unescape!(S, rust), "line\nnext😀"));
+    }
+
+    #[test]
+    fn test_unescape_json() {
+        assert_eq!(unescape!("", json), "");
+        assert_eq!(
+            unescape!(r#"\"\\\/\b\f\n\r\t"#, json),
+            "\"\\/\x08\x0c\n\r\t"
+        );
+        assert_eq!(unescape!(r"\u0041\u00e9\u4E2D", json), "Aé中");
+        assert_eq!(unescape!(r"\ud83d\ude00\uD83D\uDE00", json), "😀😀");
+
+        const ROUNDTRIP: &str = "say \"hi\"\n\t\\ 😀\x1b\x7f";
+        assert_eq!(unescape!(crate::escape_json!(ROUNDTRIP), json), ROUNDTRIP);
+    }
+
+    #[test]
+    fn test_unescape_c() {
+        assert_eq!(unescape!("", c), "");
+        assert_eq!(
+            unescape!(r#"\a\b\f\n\r\t\v\\\'\"\?"#, c),
+            "\x07\x08\x0c\n\r\t\x0b\\'\"?"
+        );
+        assert_eq!(unescape!(r"\0\101\1011\177", c), "\0AA1\x7f");
+        assert_eq!(unescape!(r"\x41\x041\xe4\xb8\xad", c), "AA中");
+        assert_eq!(unescape!(r"\u00e9\U0001F600", c), "é😀");
+
+        const ROUNDTRIP: &str = "Grüße\n\"C\"\0\x1b[0m";
+        assert_eq!(unescape!(crate::escape_c!(ROUNDTRIP), c), ROUNDTRIP);
+    }
+
+    #[test]
+    #[should_panic = "invalid escape at offset 2"]
+    fn test_unescape_invalid() {
+        let _ = Unescape(r"ab\q", UnescapeKind::Rust).output_len();
+    }
+
+    #[test]
+    #[should_panic = "invalid escape at offset 0"]
+    fn test_unescape_rust_hex_out_of_range() {
+        let _ = Unescape(r"\x80", UnescapeKind::Rust).output_len();
+    }
+
+    #[test]
+    #[should_panic = "invalid unicode character escape at offset 1"]
+    fn test_unescape_rust_surrogate() {
+        let _ = Unescape(r"a\u{D800}", UnescapeKind::Rust).output_len();
+    }
+
+    #[test]
+    #[should_panic = "unpaired surrogate at offset 4"]
+    fn test_unescape_json_unpaired() {
+        let _ = Unescape(r"abc \ud83d", UnescapeKind::Json).output_len();
+    }
+
+    #[test]
+    #[should_panic = "invalid escape at offset 5"]
+    fn test_unescape_trailing_backslash() {
+        let _ = Unescape("abcde\\", UnescapeKind::C).output_len();
+    }
+
+    #[test]
+    #[should_panic = "invalid UTF-8 in unescaped bytes at offset 1"]
+    fn test_unescape_c_invalid_utf8() {
+        let _: StrBuf<3> = Unescape(r"a\377b", UnescapeKind::C).const_eval();
+    }
+}
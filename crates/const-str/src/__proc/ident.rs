@@ -0,0 +1,168 @@
+pub use const_str_proc_macro::ident;
+
+/// Returns an identifier built from string literals and identifiers.
+///
+/// The input is a restricted expression, evaluated at compile time:
+/// + a string, char, integer or bool literal
+/// + an identifier, which stands for its own name (`r#` is stripped)
+/// + [`concat!`](crate::concat)`(x, y, ...)`
+/// + [`convert_case!`](crate::convert_case)`(case, x)` or [`convert_ascii_case!`](crate::convert_ascii_case)`(case, x)`
+/// + [`replace!`](crate::replace)`(x, from, to)`
+///
+/// The cases are converted by the same rules and Unicode data as
+/// [`convert_case!`](crate::convert_case) and [`convert_ascii_case!`](crate::convert_ascii_case).
+///
+/// The identifier takes the span of the first identifier in the input,
+/// or the first literal if there is none,
+/// so an identifier passed into a `macro_rules!` resolves where the caller wrote it.
+///
+/// The compilation fails if the result is not a valid identifier.
+///
+/// The output can be used where a macro call is allowed, such as expressions, types and patterns.
+///
+/// If the input contains `ident!(...)` calls, the calls are replaced by their identifiers
+/// and the other tokens are kept as they are.
+/// This form can name new items, for example in the output of a `macro_rules!`.
+/// The nested calls must be written as `ident!(...)`, without a path.
+///
+/// # Examples
+///
+/// ```
+/// use const_str::ident;
+///
+/// struct Config {
+///     max_size: usize,
+/// }
+///
+/// fn get_max_size(cfg: &Config) -> usize {
+///     cfg.max_size
+/// }
+///
+/// macro_rules! get {
+///     ($cfg: expr, $name: ident) => {
+///         ident!(concat!("get_", convert_case!(snake, $name)))(&$cfg)
+///     };
+/// }
+///
+/// let cfg = Config { max_size: 64 };
+/// assert_eq!(get!(cfg, MaxSize), 64);
+///
+/// type Alias = ident!(replace!("ConfigBuilder", "Builder", ""));
+/// let cfg: Alias = Config { max_size: 8 };
+/// assert_eq!(cfg.max_size, 8);
+/// ```
+///
+/// Defining new items:
+///
+/// ```
+/// use const_str::ident;
+///
+/// macro_rules! getters {
+///     ($ty: ident { $($field: ident: $field_ty: ty),* }) => {
+///         ident! {
+///             impl $ty {
+///                 $(
+///                     pub fn ident!(concat!("get_", $field))(&self) -> &$field_ty {
+///                         &self.$field
+///                     }
+///                 )*
+///             }
+///         }
+///     };
+/// }
+///
+/// struct Config {
+///     name: String,
+///     max_size: usize,
+/// }
+///
+/// getters!(Config { name: String, max_size: usize });
+///
+/// let cfg = Config { name: "app".into(), max_size: 64 };
+/// assert_eq!(cfg.get_name(), "app");
+/// assert_eq!(*cfg.get_max_size(), 64);
+/// ```
+///
+/// ```compile_fail
+/// // `1st_item` is not a valid identifier
+/// let x = const_str::ident!(concat!(1, "st_item"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "ident")))]
+#[macro_export]
+macro_rules! ident {
+    ($($args: tt)*) => {
+        $crate::__proc::ident! { $($args)* }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_ident() {
+        const MAX_SIZE: usize = 64;
+        const HTTP_SERVER: &str = "http";
+        fn get_max_size() -> usize {
+            MAX_SIZE
+        }
+
+        assert_eq!(ident!(MAX_SIZE), 64);
+        assert_eq!(ident!("MAX_SIZE"), 64);
+        assert_eq!(ident!(concat!("MAX", '_', "SIZE")), 64);
+        assert_eq!(ident!(convert_case!(shouty_snake, "maxSize")), 64);
+        assert_eq!(ident!(convert_ascii_case!(shouty_snake, maxSize)), 64);
+        assert_eq!(ident!(const_str::convert_case!(upper, "max_size")), 64);
+        assert_eq!(ident!(replace!("MAX-SIZE", "-", "_")), 64);
+        assert_eq!(ident!(replace!(MAX_SIZE, MAX_, MAX_)), 64);
+        assert_eq!(ident!(concat!(convert_case!(upper, r#http), "_SERVER")), "http");
+        assert_eq!(ident!(HTTP_SERVER,), "http");
+
+        macro_rules! call {
+            ($prefix: literal, $name: ident) => {
+                ident!(concat!($prefix, convert_case!(snake, $name)))()
+            };
+        }
+        assert_eq!(call!("get_", MaxSize), 64);
+
+        let straße_größe = 3;
+        assert_eq!(ident!(convert_case!(snake, "StraßeGröße")), 3);
+        assert_eq!(ident!(convert_ascii_case!(snake, "straßeGröße")), 3);
+
+        let r#v2 = 2;
+        assert_eq!(ident!(concat!("v", 2)), 2);
+        assert_eq!(ident!(concat!(r#v, 2)), 2);
+    }
+
+    #[test]
+    fn test_ident_items() {
+        macro_rules! define {
+            ($name: ident, $value: expr) => {
+                ident! {
+                    const ident!(convert_case!(shouty_snake, $name)): usize = $value;
+
+                    fn ident!(concat!("get_", convert_case!(snake, $name)))() -> usize {
+                        ident!(convert_case!(shouty_snake, $name))
+                    }
+                }
+            };
+        }
+
+        define!(MaxSize, 64);
+        define!(minSize, 1);
+
+        assert_eq!(MAX_SIZE, 64);
+        assert_eq!(get_max_size(), 64);
+        assert_eq!(get_min_size(), 1);
+
+        ident! {
+            struct ident!(concat!("Point", 2, 'D')) {
+                x: i32,
+                ident!(replace!("why", "wh", "")): i32,
+            }
+        }
+        let p = Point2D { x: 1, y: 2 };
+        assert_eq!(p.x + p.y, 3);
+
+        let v = ident!([p.x, p.ident!(y)]);
+        assert_eq!(v, [1, 2]);
+    }
+}
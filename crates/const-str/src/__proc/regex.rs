@@ -1,4 +1,6 @@
-pub use const_str_proc_macro::{regex_assert_match, verified_regex};
+pub use const_str_proc_macro::{
+    regex_assert_match, regex_captures, regex_dfa_parts, regex_replace, verified_regex,
+};
 
 /// Returns a compile-time verified regex string literal.
 ///
@@ -31,3 +33,300 @@ macro_rules! regex_assert_match {
         $crate::__proc::regex_assert_match!($re, $text)
     };
 }
+
+/// Returns the capture groups of the first match in the string literal.
+///
+/// If the pattern has no named groups, the output is a tuple
+/// with one element for each capture group (excluding the whole match).
+///
+/// If the pattern has named groups, the output is a struct
+/// with one field for each group. All groups must be named in this case.
+/// The struct is defined inside the macro output and cannot be named,
+/// so it cannot be the type of a `const` or `static`.
+/// Bind it with `let`, or access its fields directly.
+///
+/// A group is [`Option<&'static str>`](Option) if it may not participate in a match,
+/// e.g. `(a)?` or `(a)|b`, and is [`None`] if it does not participate in this match.
+/// Other groups are [`&'static str`](str).
+///
+/// The compilation fails if the string literal does not match the pattern.
+///
+/// # Examples
+///
+/// ```
+/// use const_str::regex_captures;
+///
+/// const VERSION: (&str, &str) = regex_captures!(r"^v(\d+)\.(\d+)$", "v1.22");
+/// assert_eq!(VERSION, ("1", "22"));
+///
+/// const PRE: (&str, Option<&str>) = regex_captures!(r"^(\d+)(?:-(\w+))?$", "1");
+/// assert_eq!(PRE, ("1", None));
+///
+/// let date = regex_captures!(r"^(?P<y>\d{4})-(?P<m>\d{2})-(?P<d>\d{2})$", "2014-01-02");
+/// assert_eq!((date.y, date.m, date.d), ("2014", "01", "02"));
+///
+/// const YEAR: &str = regex_captures!(r"^(?P<y>\d{4})-(?P<m>\d{2})$", "2014-01").y;
+/// assert_eq!(YEAR, "2014");
+/// ```
+///
+/// Unnamed groups are not allowed along with named groups:
+///
+/// ```compile_fail
+/// let caps = const_str::regex_captures!(r"^(?P<name>\w+)@(\w+)$", "user@host");
+/// ```
+///
+/// Constants are not accepted:
+///
+/// ```compile_fail
+/// const TEXT: &str = "v1.22";
+/// const VERSION: (&str, &str) = const_str::regex_captures!(r"^v(\d+)\.(\d+)$", TEXT);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+#[macro_export]
+macro_rules! regex_captures {
+    ($($args: tt)*) => {
+        $crate::__proc::regex_captures!($($args)*)
+    };
+}
+
+/// Replaces all matches of the pattern in the string literal.
+///
+/// The replacement string supports `$1` and `${name}` expansion.
+///
+/// See [`Regex::replace_all`](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace_all).
+///
+/// All arguments must be string literals.
+///
+/// # Examples
+///
+/// ```
+/// use const_str::regex_replace;
+///
+/// const DATE: &str = regex_replace!(r"(\d{4})-(\d{2})-(\d{2})", "2014-01-02", "$2/$3/$1");
+/// assert_eq!(DATE, "01/02/2014");
+///
+/// const NAME: &str = regex_replace!(r"(?P<first>\w+)\s+(?P<last>\w+)", "Springsteen Bruce", "${last} ${first}");
+/// assert_eq!(NAME, "Bruce Springsteen");
+/// ```
+///
+/// Constants are not accepted:
+///
+/// ```compile_fail
+/// const TEXT: &str = "abc";
+/// const OUTPUT: &str = const_str::regex_replace!("b", TEXT, "x");
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+#[macro_export]
+macro_rules! regex_replace {
+    ($($args: tt)*) => {
+        $crate::__proc::regex_replace!($($args)*)
+    };
+}
+
+/// A regex compiled into a DFA table by the proc macro.
+///
+/// The start state is always `0`.
+pub struct RegexDfa<'a> {
+    pub classes: &'a [u8; 256],
+    pub stride: usize,
+    pub trans: &'a [u32],
+    pub eoi: &'a [u32],
+    pub accept: &'a [bool],
+}
+
+impl RegexDfa<'_> {
+    pub const fn is_match(&self, haystack: &[u8]) -> bool {
+        let mut state = 0;
+        let mut i = 0;
+        while i < haystack.len() {
+            let class = self.classes[haystack[i] as usize] as usize;
+            state = self.trans[state * self.stride + class] as usize;
+            if self.accept[state] {
+                return true;
+            }
+            i += 1;
+        }
+        state = self.eoi[state] as usize;
+        self.accept[state]
+    }
+}
+
+pub struct RegexIsMatch<'a, T>(pub &'a RegexDfa<'a>, pub T);
+
+impl RegexIsMatch<'_, &str> {
+    pub const fn const_eval(&self) -> bool {
+        self.0.is_match(self.1.as_bytes())
+    }
+}
+
+impl RegexIsMatch<'_, &[u8]> {
+    pub const fn const_eval(&self) -> bool {
+        self.0.is_match(self.1)
+    }
+}
+
+impl<const N: usize> RegexIsMatch<'_, &[u8; N]> {
+    pub const fn const_eval(&self) -> bool {
+        self.0.is_match(self.1.as_slice())
+    }
+}
+
+/// Returns [`true`] if the pattern matches a sub-slice of the input.
+///
+/// The pattern must be a string literal.
+/// It is compiled into a DFA table at compile time,
+/// so the matching does not depend on the `regex` crate at runtime.
+///
+/// The input type must be one of:
+/// + [`&str`](str)
+/// + [`&[u8]`](slice)
+/// + [`&[u8; N]`](array)
+///
+/// Unicode word boundaries (`\b`) are not supported. Use `(?-u:\b)` instead.
+///
+/// This macro is [const-fn compatible](./index.html#const-fn-compatible).
+///
+/// # Examples
+///
+/// ```
+/// use const_str::{concat, regex_is_match};
+///
+/// const VERSION: &str = concat!("1", ".", "22");
+/// const _: () = assert!(regex_is_match!(r"^\d+\.\d+$", VERSION));
+///
+/// const fn is_date(s: &str) -> bool {
+///     regex_is_match!(r"^\d{4}-\d{2}-\d{2}$", s)
+/// }
+///
+/// assert!(is_date("2014-01-01"));
+/// assert!(!is_date("2014/01/01"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+#[macro_export]
+macro_rules! regex_is_match {
+    ($re: literal, $text: expr) => {{
+        const DFA: $crate::__proc::RegexDfa<'static> = {
+            let (classes, stride, trans, eoi, accept) = $crate::__proc::regex_dfa_parts!($re);
+            $crate::__proc::RegexDfa {
+                classes,
+                stride,
+                trans,
+                eoi,
+                accept,
+            }
+        };
+        $crate::__proc::RegexIsMatch(&DFA, $text).const_eval()
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_regex_captures() {
+        const V: (&str, &str) = regex_captures!(r"^v(\d+)\.(\d+)$", "v1.22");
+        assert_eq!(V, ("1", "22"));
+
+        const ONE: (&str,) = regex_captures!(r"([a-z]+)\d", "12ab3");
+        assert_eq!(ONE, ("ab",));
+
+        const NONE: () = regex_captures!(r"\d+", "12ab3");
+        assert_eq!(NONE, ());
+
+        const OPT: (Option<&str>, &str) = regex_captures!(r"^(a)?(b)$", "b");
+        assert_eq!(OPT, (None, "b"));
+
+        const ALT: (Option<&str>, Option<&str>, &str) =
+            regex_captures!(r"^(?:(\d+)|(\w+))(!)$", "ab!");
+        assert_eq!(ALT, (None, Some("ab"), "!"));
+
+        const REP: (Option<&str>, Option<&str>) = regex_captures!(r"^(?:(a)(b+))*$", "abab");
+        assert_eq!(REP, (Some("a"), Some("b")));
+
+        let opt = regex_captures!(r"^(a)?(b)$", "ab");
+        assert_eq!(opt, (Some("a"), "b"));
+
+        let named = regex_captures!(r"^(?P<name>[a-z]+)@(?<host>\w+)\.(?<tld>[a-z]+)?$", "user@host.");
+        assert_eq!(named.name, "user");
+        assert_eq!(named.host, "host");
+        assert_eq!(named.tld, None);
+
+        const HOST: &str = regex_captures!(r"^(?P<name>[a-z]+)@(?<host>\w+)$", "user@host").host;
+        assert_eq!(HOST, "host");
+    }
+
+    #[test]
+    fn test_regex_replace() {
+        macro_rules! testcase {
+            ($re: literal, $text: literal, $rep: literal) => {{
+                const OUTPUT: &str = regex_replace!($re, $text, $rep);
+                let re = regex::Regex::new($re).unwrap();
+                let ans = re.replace_all($text, $rep);
+                assert_eq!(OUTPUT, ans);
+            }};
+        }
+
+        testcase!("", "", "");
+        testcase!("a", "banana", "o");
+        testcase!("x", "banana", "o");
+        testcase!(r"\s+", "  a  b  ", " ");
+        testcase!(r"(\w+)@(\w+)", "me@host you@there", "$2@$1");
+        testcase!(r"(?P<k>\w+)=(?P<v>\w+)", "a=1,b=2", "${v}=${k}");
+        testcase!(r"(\d)", "a1b2", "$$1");
+        testcase!(r"我", "我和你", "他");
+    }
+
+    #[test]
+    fn test_regex_is_match() {
+        macro_rules! testcase {
+            ($re: literal, $text: expr) => {{
+                const TEXT: &str = $text;
+                const OUTPUT: bool = regex_is_match!($re, TEXT);
+                let ans = regex::Regex::new($re).unwrap().is_match(TEXT);
+                assert_eq!(OUTPUT, ans, "re = {:?}, text = {:?}", $re, TEXT);
+            }};
+        }
+
+        testcase!("", "");
+        testcase!("", "abc");
+        testcase!("^$", "");
+        testcase!("^$", "a");
+        testcase!(r"^\d+$", "12345");
+        testcase!(r"^\d+$", "123a45");
+        testcase!(r"^\d+$", "");
+        testcase!(r"\d", "abc1");
+        testcase!(r"\d", "abc");
+        testcase!(r"^\d{4}-\d{2}-\d{2}$", "2014-01-01");
+        testcase!(r"^\d{4}-\d{2}-\d{2}$", "2014-01-01x");
+        testcase!(r"b+c$", "abbbc");
+        testcase!(r"b+c$", "abbbcd");
+        testcase!(r"(?i)hello", "say HeLLo");
+        testcase!(r"^[a-z_][a-z0-9_]*$", "snake_case_1");
+        testcase!(r"^[a-z_][a-z0-9_]*$", "1snake");
+        testcase!(r"\p{Han}+", "abc你好");
+        testcase!(r"^.{2}$", "我好");
+        testcase!(r"^.{2}$", "我好呀");
+        testcase!(r"(?-u:\b)foo(?-u:\b)", "a foo b");
+        testcase!(r"(?-u:\b)foo(?-u:\b)", "afoob");
+        testcase!(r"(?m)^b$", "a\nb\nc");
+    }
+
+    #[test]
+    fn test_regex_is_match_bytes() {
+        const B1: &[u8] = b"\x01\x02abc";
+        const B2: &[u8; 3] = b"xyz";
+        let r1 = regex_is_match!("abc$", B1);
+        let r2 = regex_is_match!("abc$", B2);
+        assert!(r1);
+        assert!(!r2);
+    }
+
+    #[test]
+    fn test_regex_is_match_const_fn() {
+        const fn is_ident(s: &str) -> bool {
+            regex_is_match!(r"^[A-Za-z_][A-Za-z0-9_]*$", s)
+        }
+        assert!(is_ident("_foo1"));
+        assert!(!is_ident("1foo"));
+        assert!(!is_ident(""));
+    }
+}
@@ -0,0 +1,298 @@
+// The tables are generated by `just gen-unicode-case`, see `tools/gen-unicode-case`.
+
+mod tables;
+
+use self::tables::*;
+
+use crate::slice::advance;
+use crate::utf8::next_char;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphemeBreak {
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndicConjunctBreak {
+    Consonant,
+    Extend,
+    Linker,
+    None,
+}
+
+const fn find_value<T: Copy>(table: &[(u32, u32, T)], ch: char) -> Option<T> {
+    let x = ch as u32;
+
+    let mut lo = 0;
+    let mut hi = table.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (start, end, value) = table[mid];
+        if x < start {
+            hi = mid;
+        } else if x > end {
+            lo = mid + 1;
+        } else {
+            return Some(value);
+        }
+    }
+    None
+}
+
+const fn find_range(table: &[(u32, u32)], ch: char) -> bool {
+    let x = ch as u32;
+
+    let mut lo = 0;
+    let mut hi = table.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (start, end) = table[mid];
+        if x < start {
+            hi = mid;
+        } else if x > end {
+            lo = mid + 1;
+        } else {
+            return true;
+        }
+    }
+    false
+}
+
+pub const fn grapheme_break(ch: char) -> GraphemeBreak {
+    // the Hangul syllables are not stored in the table
+    if matches!(ch, '\u{ac00}'..='\u{d7a3}') {
+        return if (ch as u32 - 0xac00) % 28 == 0 {
+            GraphemeBreak::Lv
+        } else {
+            GraphemeBreak::Lvt
+        };
+    }
+    match find_value(GRAPHEME_CLUSTER_BREAK, ch) {
+        Some(value) => value,
+        None => GraphemeBreak::Other,
+    }
+}
+
+pub const fn indic_conjunct_break(ch: char) -> IndicConjunctBreak {
+    match find_value(INDIC_CONJUNCT_BREAK, ch) {
+        Some(value) => value,
+        None => IndicConjunctBreak::None,
+    }
+}
+
+pub const fn is_extended_pictographic(ch: char) -> bool {
+    find_range(EXTENDED_PICTOGRAPHIC, ch)
+}
+
+/// The context of the rules GB9c, GB11 and GB12/GB13,
+/// which look further back than the previous character.
+#[derive(Clone, Copy)]
+struct State {
+    prev: GraphemeBreak,
+    /// `Some` after `\p{InCB=Consonant} [\p{InCB=Extend}\p{InCB=Linker}]*`,
+    /// and `Some(true)` if the sequence contains a linker.
+    conjunct: Option<bool>,
+    /// `Some` after `\p{Extended_Pictographic} Extend*`,
+    /// and `Some(true)` after `\p{Extended_Pictographic} Extend* ZWJ`.
+    pictographic: Option<bool>,
+    /// The number of regional indicators before the current character.
+    regional_indicators: usize,
+}
+
+impl State {
+    const fn new(ch: char) -> Self {
+        let this = Self {
+            prev: GraphemeBreak::Other,
+            conjunct: None,
+            pictographic: None,
+            regional_indicators: 0,
+        };
+        this.push(ch)
+    }
+
+    const fn push(self, ch: char) -> Self {
+        use GraphemeBreak as G;
+        use IndicConjunctBreak as I;
+
+        let gcb = grapheme_break(ch);
+
+        let conjunct = match (self.conjunct, indic_conjunct_break(ch)) {
+            (_, I::Consonant) => Some(false),
+            (Some(linked), I::Extend) => Some(linked),
+            (Some(_), I::Linker) => Some(true),
+            _ => None,
+        };
+
+        let pictographic = if is_extended_pictographic(ch) {
+            Some(false)
+        } else {
+            match (self.pictographic, gcb) {
+                (Some(false), G::Extend) => Some(false),
+                (Some(false), G::Zwj) => Some(true),
+                _ => None,
+            }
+        };
+
+        let regional_indicators = match gcb {
+            G::RegionalIndicator => self.regional_indicators + 1,
+            _ => 0,
+        };
+
+        Self {
+            prev: gcb,
+            conjunct,
+            pictographic,
+            regional_indicators,
+        }
+    }
+
+    /// Returns true if there is no grapheme cluster boundary before `ch`.
+    const fn extends(&self, ch: char) -> bool {
+        use GraphemeBreak as G;
+
+        let gcb = grapheme_break(ch);
+        match (self.prev, gcb) {
+            (G::Cr, G::Lf) => true,                       // GB3
+            (G::Cr | G::Lf | G::Control, _) => false,     // GB4
+            (_, G::Cr | G::Lf | G::Control) => false,     // GB5
+            (G::L, G::L | G::V | G::Lv | G::Lvt) => true, // GB6
+            (G::Lv | G::V, G::V | G::T) => true,          // GB7
+            (G::Lvt | G::T, G::T) => true,                // GB8
+            (_, G::Extend | G::Zwj) => true,              // GB9
+            (_, G::SpacingMark) => true,                  // GB9a
+            (G::Prepend, _) => true,                      // GB9b
+            (G::RegionalIndicator, G::RegionalIndicator) => {
+                self.regional_indicators % 2 == 1 // GB12, GB13
+            }
+            _ => {
+                let conjunct = matches!(self.conjunct, Some(true))
+                    && matches!(indic_conjunct_break(ch), IndicConjunctBreak::Consonant); // GB9c
+                let emoji = matches!(self.pictographic, Some(true)) && is_extended_pictographic(ch); // GB11
+                conjunct || emoji
+            }
+        }
+    }
+}
+
+/// Returns the byte length of the next extended grapheme cluster,
+/// as defined by [UAX #29](https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundary_Rules).
+pub const fn next_grapheme(bytes: &[u8]) -> Option<usize> {
+    let (first, mut len) = match next_char(bytes) {
+        Some(x) => x,
+        None => return None,
+    };
+
+    let mut state = State::new(first);
+    while let Some((ch, count)) = next_char(advance(bytes, len)) {
+        if !state.extends(ch) {
+            break;
+        }
+        len += count;
+        state = state.push(ch);
+    }
+
+    Some(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grapheme_break() {
+        assert_eq!(grapheme_break('a'), GraphemeBreak::Other);
+        assert_eq!(grapheme_break('\r'), GraphemeBreak::Cr);
+        assert_eq!(grapheme_break('\u{0301}'), GraphemeBreak::Extend);
+        assert_eq!(grapheme_break('\u{1f3fd}'), GraphemeBreak::Extend);
+        assert_eq!(grapheme_break('\u{200d}'), GraphemeBreak::Zwj);
+        assert_eq!(grapheme_break('\u{0903}'), GraphemeBreak::SpacingMark);
+        assert_eq!(grapheme_break('\u{0600}'), GraphemeBreak::Prepend);
+        assert_eq!(grapheme_break('\u{1100}'), GraphemeBreak::L);
+        assert_eq!(grapheme_break('\u{1161}'), GraphemeBreak::V);
+        assert_eq!(grapheme_break('\u{11a8}'), GraphemeBreak::T);
+        assert_eq!(grapheme_break('가'), GraphemeBreak::Lv);
+        assert_eq!(grapheme_break('각'), GraphemeBreak::Lvt);
+        assert_eq!(grapheme_break('힣'), GraphemeBreak::Lvt);
+
+        assert_eq!(indic_conjunct_break('क'), IndicConjunctBreak::Consonant);
+        assert_eq!(indic_conjunct_break('\u{094d}'), IndicConjunctBreak::Linker);
+        assert_eq!(indic_conjunct_break('a'), IndicConjunctBreak::None);
+
+        assert!(is_extended_pictographic('👍'));
+        assert!(is_extended_pictographic('©'));
+        assert!(!is_extended_pictographic('a'));
+    }
+
+    #[test]
+    fn test_next_grapheme() {
+        fn graphemes(s: &str) -> Vec<&str> {
+            let mut ans = Vec::new();
+            let mut i = 0;
+            while let Some(len) = next_grapheme(&s.as_bytes()[i..]) {
+                ans.push(&s[i..i + len]);
+                i += len;
+            }
+            ans
+        }
+
+        assert_eq!(graphemes(""), Vec::<&str>::new());
+        assert_eq!(graphemes("abc"), ["a", "b", "c"]);
+        assert_eq!(graphemes("e\u{301}x"), ["e\u{301}", "x"]);
+        assert_eq!(graphemes("\u{301}a"), ["\u{301}", "a"]);
+        assert_eq!(graphemes("a\r\nb\n\r"), ["a", "\r\n", "b", "\n", "\r"]);
+        assert_eq!(graphemes("\r\u{301}"), ["\r", "\u{301}"]);
+        assert_eq!(graphemes("👍🏽!"), ["👍🏽", "!"]);
+        assert_eq!(
+            graphemes("👨\u{200d}👩\u{200d}👧x"),
+            ["👨\u{200d}👩\u{200d}👧", "x"]
+        );
+        assert_eq!(graphemes("🇺🇸🇨🇳🇯"), ["🇺🇸", "🇨🇳", "🇯"]);
+        assert_eq!(graphemes("\u{200d}a"), ["\u{200d}", "a"]);
+
+        // GB6, GB7, GB8
+        assert_eq!(graphemes("\u{1100}\u{1161}"), ["\u{1100}\u{1161}"]);
+        assert_eq!(
+            graphemes("\u{1100}\u{1161}\u{11a8}\u{1100}"),
+            ["\u{1100}\u{1161}\u{11a8}", "\u{1100}"]
+        );
+        assert_eq!(
+            graphemes("가\u{11a8}각\u{11a8}"),
+            ["가\u{11a8}", "각\u{11a8}"]
+        );
+        assert_eq!(
+            graphemes("\u{1100}가\u{1161}\u{1100}"),
+            ["\u{1100}가\u{1161}", "\u{1100}"]
+        );
+
+        // GB9a, GB9b
+        assert_eq!(graphemes("क\u{0903}a"), ["क\u{0903}", "a"]);
+        assert_eq!(graphemes("\u{0600}1a"), ["\u{0600}1", "a"]);
+
+        // GB9c
+        assert_eq!(graphemes("क\u{094d}षa"), ["क\u{094d}ष", "a"]);
+        assert_eq!(graphemes("क\u{094d}\u{200d}ष"), ["क\u{094d}\u{200d}ष"]);
+        assert_eq!(graphemes("कष"), ["क", "ष"]);
+
+        // GB11
+        assert_eq!(graphemes("a\u{200d}b"), ["a\u{200d}", "b"]);
+        assert_eq!(graphemes("a\u{200d}👍"), ["a\u{200d}", "👍"]);
+        assert_eq!(
+            graphemes("👍\u{1f3fd}\u{200d}👍"),
+            ["👍\u{1f3fd}\u{200d}👍"]
+        );
+        assert_eq!(graphemes("👍\u{200d}a"), ["👍\u{200d}", "a"]);
+    }
+}
@@ -0,0 +1,591 @@
+// DO NOT EDIT THIS FILE. It is generated by `just gen-unicode-case`.
+//
+// Unicode version: 17.0.0, the same as `char::UNICODE_VERSION` of the generating toolchain.
+//
+// + Grapheme_Cluster_Break, except Other and the Hangul syllables
+// + Indic_Conjunct_Break, except None
+// + Extended_Pictographic
+
+use super::{GraphemeBreak, IndicConjunctBreak};
+
+#[rustfmt::skip]
+pub const GRAPHEME_CLUSTER_BREAK: &[(u32, u32, GraphemeBreak)] = &[
+    (0x00000, 0x00009, GraphemeBreak::Control), (0x0000a, 0x0000a, GraphemeBreak::Lf),
+    (0x0000b, 0x0000c, GraphemeBreak::Control), (0x0000d, 0x0000d, GraphemeBreak::Cr),
+    (0x0000e, 0x0001f, GraphemeBreak::Control), (0x0007f, 0x0009f, GraphemeBreak::Control),
+    (0x000ad, 0x000ad, GraphemeBreak::Control), (0x00300, 0x0036f, GraphemeBreak::Extend),
+    (0x00483, 0x00489, GraphemeBreak::Extend), (0x00591, 0x005bd, GraphemeBreak::Extend),
+    (0x005bf, 0x005bf, GraphemeBreak::Extend), (0x005c1, 0x005c2, GraphemeBreak::Extend),
+    (0x005c4, 0x005c5, GraphemeBreak::Extend), (0x005c7, 0x005c7, GraphemeBreak::Extend),
+    (0x00600, 0x00605, GraphemeBreak::Prepend), (0x00610, 0x0061a, GraphemeBreak::Extend),
+    (0x0061c, 0x0061c, GraphemeBreak::Control), (0x0064b, 0x0065f, GraphemeBreak::Extend),
+    (0x00670, 0x00670, GraphemeBreak::Extend), (0x006d6, 0x006dc, GraphemeBreak::Extend),
+    (0x006dd, 0x006dd, GraphemeBreak::Prepend), (0x006df, 0x006e4, GraphemeBreak::Extend),
+    (0x006e7, 0x006e8, GraphemeBreak::Extend), (0x006ea, 0x006ed, GraphemeBreak::Extend),
+    (0x0070f, 0x0070f, GraphemeBreak::Prepend), (0x00711, 0x00711, GraphemeBreak::Extend),
+    (0x00730, 0x0074a, GraphemeBreak::Extend), (0x007a6, 0x007b0, GraphemeBreak::Extend),
+    (0x007eb, 0x007f3, GraphemeBreak::Extend), (0x007fd, 0x007fd, GraphemeBreak::Extend),
+    (0x00816, 0x00819, GraphemeBreak::Extend), (0x0081b, 0x00823, GraphemeBreak::Extend),
+    (0x00825, 0x00827, GraphemeBreak::Extend), (0x00829, 0x0082d, GraphemeBreak::Extend),
+    (0x00859, 0x0085b, GraphemeBreak::Extend), (0x00890, 0x00891, GraphemeBreak::Prepend),
+    (0x00897, 0x0089f, GraphemeBreak::Extend), (0x008ca, 0x008e1, GraphemeBreak::Extend),
+    (0x008e2, 0x008e2, GraphemeBreak::Prepend), (0x008e3, 0x00902, GraphemeBreak::Extend),
+    (0x00903, 0x00903, GraphemeBreak::SpacingMark), (0x0093a, 0x0093a, GraphemeBreak::Extend),
+    (0x0093b, 0x0093b, GraphemeBreak::SpacingMark), (0x0093c, 0x0093c, GraphemeBreak::Extend),
+    (0x0093e, 0x00940, GraphemeBreak::SpacingMark), (0x00941, 0x00948, GraphemeBreak::Extend),
+    (0x00949, 0x0094c, GraphemeBreak::SpacingMark), (0x0094d, 0x0094d, GraphemeBreak::Extend),
+    (0x0094e, 0x0094f, GraphemeBreak::SpacingMark), (0x00951, 0x00957, GraphemeBreak::Extend),
+    (0x00962, 0x00963, GraphemeBreak::Extend), (0x00981, 0x00981, GraphemeBreak::Extend),
+    (0x00982, 0x00983, GraphemeBreak::SpacingMark), (0x009bc, 0x009bc, GraphemeBreak::Extend),
+    (0x009be, 0x009be, GraphemeBreak::Extend), (0x009bf, 0x009c0, GraphemeBreak::SpacingMark),
+    (0x009c1, 0x009c4, GraphemeBreak::Extend), (0x009c7, 0x009c8, GraphemeBreak::SpacingMark),
+    (0x009cb, 0x009cc, GraphemeBreak::SpacingMark), (0x009cd, 0x009cd, GraphemeBreak::Extend),
+    (0x009d7, 0x009d7, GraphemeBreak::Extend), (0x009e2, 0x009e3, GraphemeBreak::Extend),
+    (0x009fe, 0x009fe, GraphemeBreak::Extend), (0x00a01, 0x00a02, GraphemeBreak::Extend),
+    (0x00a03, 0x00a03, GraphemeBreak::SpacingMark), (0x00a3c, 0x00a3c, GraphemeBreak::Extend),
+    (0x00a3e, 0x00a40, GraphemeBreak::SpacingMark), (0x00a41, 0x00a42, GraphemeBreak::Extend),
+    (0x00a47, 0x00a48, GraphemeBreak::Extend), (0x00a4b, 0x00a4d, GraphemeBreak::Extend),
+    (0x00a51, 0x00a51, GraphemeBreak::Extend), (0x00a70, 0x00a71, GraphemeBreak::Extend),
+    (0x00a75, 0x00a75, GraphemeBreak::Extend), (0x00a81, 0x00a82, GraphemeBreak::Extend),
+    (0x00a83, 0x00a83, GraphemeBreak::SpacingMark), (0x00abc, 0x00abc, GraphemeBreak::Extend),
+    (0x00abe, 0x00ac0, GraphemeBreak::SpacingMark), (0x00ac1, 0x00ac5, GraphemeBreak::Extend),
+    (0x00ac7, 0x00ac8, GraphemeBreak::Extend), (0x00ac9, 0x00ac9, GraphemeBreak::SpacingMark),
+    (0x00acb, 0x00acc, GraphemeBreak::SpacingMark), (0x00acd, 0x00acd, GraphemeBreak::Extend),
+    (0x00ae2, 0x00ae3, GraphemeBreak::Extend), (0x00afa, 0x00aff, GraphemeBreak::Extend),
+    (0x00b01, 0x00b01, GraphemeBreak::Extend), (0x00b02, 0x00b03, GraphemeBreak::SpacingMark),
+    (0x00b3c, 0x00b3c, GraphemeBreak::Extend), (0x00b3e, 0x00b3f, GraphemeBreak::Extend),
+    (0x00b40, 0x00b40, GraphemeBreak::SpacingMark), (0x00b41, 0x00b44, GraphemeBreak::Extend),
+    (0x00b47, 0x00b48, GraphemeBreak::SpacingMark), (0x00b4b, 0x00b4c, GraphemeBreak::SpacingMark),
+    (0x00b4d, 0x00b4d, GraphemeBreak::Extend), (0x00b55, 0x00b57, GraphemeBreak::Extend),
+    (0x00b62, 0x00b63, GraphemeBreak::Extend), (0x00b82, 0x00b82, GraphemeBreak::Extend),
+    (0x00bbe, 0x00bbe, GraphemeBreak::Extend), (0x00bbf, 0x00bbf, GraphemeBreak::SpacingMark),
+    (0x00bc0, 0x00bc0, GraphemeBreak::Extend), (0x00bc1, 0x00bc2, GraphemeBreak::SpacingMark),
+    (0x00bc6, 0x00bc8, GraphemeBreak::SpacingMark), (0x00bca, 0x00bcc, GraphemeBreak::SpacingMark),
+    (0x00bcd, 0x00bcd, GraphemeBreak::Extend), (0x00bd7, 0x00bd7, GraphemeBreak::Extend),
+    (0x00c00, 0x00c00, GraphemeBreak::Extend), (0x00c01, 0x00c03, GraphemeBreak::SpacingMark),
+    (0x00c04, 0x00c04, GraphemeBreak::Extend), (0x00c3c, 0x00c3c, GraphemeBreak::Extend),
+    (0x00c3e, 0x00c40, GraphemeBreak::Extend), (0x00c41, 0x00c44, GraphemeBreak::SpacingMark),
+    (0x00c46, 0x00c48, GraphemeBreak::Extend), (0x00c4a, 0x00c4d, GraphemeBreak::Extend),
+    (0x00c55, 0x00c56, GraphemeBreak::Extend), (0x00c62, 0x00c63, GraphemeBreak::Extend),
+    (0x00c81, 0x00c81, GraphemeBreak::Extend), (0x00c82, 0x00c83, GraphemeBreak::SpacingMark),
+    (0x00cbc, 0x00cbc, GraphemeBreak::Extend), (0x00cbe, 0x00cbe, GraphemeBreak::SpacingMark),
+    (0x00cbf, 0x00cc0, GraphemeBreak::Extend), (0x00cc1, 0x00cc1, GraphemeBreak::SpacingMark),
+    (0x00cc2, 0x00cc2, GraphemeBreak::Extend), (0x00cc3, 0x00cc4, GraphemeBreak::SpacingMark),
+    (0x00cc6, 0x00cc8, GraphemeBreak::Extend), (0x00cca, 0x00ccd, GraphemeBreak::Extend),
+    (0x00cd5, 0x00cd6, GraphemeBreak::Extend), (0x00ce2, 0x00ce3, GraphemeBreak::Extend),
+    (0x00cf3, 0x00cf3, GraphemeBreak::SpacingMark), (0x00d00, 0x00d01, GraphemeBreak::Extend),
+    (0x00d02, 0x00d03, GraphemeBreak::SpacingMark), (0x00d3b, 0x00d3c, GraphemeBreak::Extend),
+    (0x00d3e, 0x00d3e, GraphemeBreak::Extend), (0x00d3f, 0x00d40, GraphemeBreak::SpacingMark),
+    (0x00d41, 0x00d44, GraphemeBreak::Extend), (0x00d46, 0x00d48, GraphemeBreak::SpacingMark),
+    (0x00d4a, 0x00d4c, GraphemeBreak::SpacingMark), (0x00d4d, 0x00d4d, GraphemeBreak::Extend),
+    (0x00d4e, 0x00d4e, GraphemeBreak::Prepend), (0x00d57, 0x00d57, GraphemeBreak::Extend),
+    (0x00d62, 0x00d63, GraphemeBreak::Extend), (0x00d81, 0x00d81, GraphemeBreak::Extend),
+    (0x00d82, 0x00d83, GraphemeBreak::SpacingMark), (0x00dca, 0x00dca, GraphemeBreak::Extend),
+    (0x00dcf, 0x00dcf, GraphemeBreak::Extend), (0x00dd0, 0x00dd1, GraphemeBreak::SpacingMark),
+    (0x00dd2, 0x00dd4, GraphemeBreak::Extend), (0x00dd6, 0x00dd6, GraphemeBreak::Extend),
+    (0x00dd8, 0x00dde, GraphemeBreak::SpacingMark), (0x00ddf, 0x00ddf, GraphemeBreak::Extend),
+    (0x00df2, 0x00df3, GraphemeBreak::SpacingMark), (0x00e31, 0x00e31, GraphemeBreak::Extend),
+    (0x00e33, 0x00e33, GraphemeBreak::SpacingMark), (0x00e34, 0x00e3a, GraphemeBreak::Extend),
+    (0x00e47, 0x00e4e, GraphemeBreak::Extend), (0x00eb1, 0x00eb1, GraphemeBreak::Extend),
+    (0x00eb3, 0x00eb3, GraphemeBreak::SpacingMark), (0x00eb4, 0x00ebc, GraphemeBreak::Extend),
+    (0x00ec8, 0x00ece, GraphemeBreak::Extend), (0x00f18, 0x00f19, GraphemeBreak::Extend),
+    (0x00f35, 0x00f35, GraphemeBreak::Extend), (0x00f37, 0x00f37, GraphemeBreak::Extend),
+    (0x00f39, 0x00f39, GraphemeBreak::Extend), (0x00f3e, 0x00f3f, GraphemeBreak::SpacingMark),
+    (0x00f71, 0x00f7e, GraphemeBreak::Extend), (0x00f7f, 0x00f7f, GraphemeBreak::SpacingMark),
+    (0x00f80, 0x00f84, GraphemeBreak::Extend), (0x00f86, 0x00f87, GraphemeBreak::Extend),
+    (0x00f8d, 0x00f97, GraphemeBreak::Extend), (0x00f99, 0x00fbc, GraphemeBreak::Extend),
+    (0x00fc6, 0x00fc6, GraphemeBreak::Extend), (0x0102d, 0x01030, GraphemeBreak::Extend),
+    (0x01031, 0x01031, GraphemeBreak::SpacingMark), (0x01032, 0x01037, GraphemeBreak::Extend),
+    (0x01039, 0x0103a, GraphemeBreak::Extend), (0x0103b, 0x0103c, GraphemeBreak::SpacingMark),
+    (0x0103d, 0x0103e, GraphemeBreak::Extend), (0x01056, 0x01057, GraphemeBreak::SpacingMark),
+    (0x01058, 0x01059, GraphemeBreak::Extend), (0x0105e, 0x01060, GraphemeBreak::Extend),
+    (0x01071, 0x01074, GraphemeBreak::Extend), (0x01082, 0x01082, GraphemeBreak::Extend),
+    (0x01084, 0x01084, GraphemeBreak::SpacingMark), (0x01085, 0x01086, GraphemeBreak::Extend),
+    (0x0108d, 0x0108d, GraphemeBreak::Extend), (0x0109d, 0x0109d, GraphemeBreak::Extend),
+    (0x01100, 0x0115f, GraphemeBreak::L), (0x01160, 0x011a7, GraphemeBreak::V),
+    (0x011a8, 0x011ff, GraphemeBreak::T), (0x0135d, 0x0135f, GraphemeBreak::Extend),
+    (0x01712, 0x01715, GraphemeBreak::Extend), (0x01732, 0x01734, GraphemeBreak::Extend),
+    (0x01752, 0x01753, GraphemeBreak::Extend), (0x01772, 0x01773, GraphemeBreak::Extend),
+    (0x017b4, 0x017b5, GraphemeBreak::Extend), (0x017b6, 0x017b6, GraphemeBreak::SpacingMark),
+    (0x017b7, 0x017bd, GraphemeBreak::Extend), (0x017be, 0x017c5, GraphemeBreak::SpacingMark),
+    (0x017c6, 0x017c6, GraphemeBreak::Extend), (0x017c7, 0x017c8, GraphemeBreak::SpacingMark),
+    (0x017c9, 0x017d3, GraphemeBreak::Extend), (0x017dd, 0x017dd, GraphemeBreak::Extend),
+    (0x0180b, 0x0180d, GraphemeBreak::Extend), (0x0180e, 0x0180e, GraphemeBreak::Control),
+    (0x0180f, 0x0180f, GraphemeBreak::Extend), (0x01885, 0x01886, GraphemeBreak::Extend),
+    (0x018a9, 0x018a9, GraphemeBreak::Extend), (0x01920, 0x01922, GraphemeBreak::Extend),
+    (0x01923, 0x01926, GraphemeBreak::SpacingMark), (0x01927, 0x01928, GraphemeBreak::Extend),
+    (0x01929, 0x0192b, GraphemeBreak::SpacingMark), (0x01930, 0x01931, GraphemeBreak::SpacingMark),
+    (0x01932, 0x01932, GraphemeBreak::Extend), (0x01933, 0x01938, GraphemeBreak::SpacingMark),
+    (0x01939, 0x0193b, GraphemeBreak::Extend), (0x01a17, 0x01a18, GraphemeBreak::Extend),
+    (0x01a19, 0x01a1a, GraphemeBreak::SpacingMark), (0x01a1b, 0x01a1b, GraphemeBreak::Extend),
+    (0x01a55, 0x01a55, GraphemeBreak::SpacingMark), (0x01a56, 0x01a56, GraphemeBreak::Extend),
+    (0x01a57, 0x01a57, GraphemeBreak::SpacingMark), (0x01a58, 0x01a5e, GraphemeBreak::Extend),
+    (0x01a60, 0x01a60, GraphemeBreak::Extend), (0x01a62, 0x01a62, GraphemeBreak::Extend),
+    (0x01a65, 0x01a6c, GraphemeBreak::Extend), (0x01a6d, 0x01a72, GraphemeBreak::SpacingMark),
+    (0x01a73, 0x01a7c, GraphemeBreak::Extend), (0x01a7f, 0x01a7f, GraphemeBreak::Extend),
+    (0x01ab0, 0x01add, GraphemeBreak::Extend), (0x01ae0, 0x01aeb, GraphemeBreak::Extend),
+    (0x01b00, 0x01b03, GraphemeBreak::Extend), (0x01b04, 0x01b04, GraphemeBreak::SpacingMark),
+    (0x01b34, 0x01b3d, GraphemeBreak::Extend), (0x01b3e, 0x01b41, GraphemeBreak::SpacingMark),
+    (0x01b42, 0x01b44, GraphemeBreak::Extend), (0x01b6b, 0x01b73, GraphemeBreak::Extend),
+    (0x01b80, 0x01b81, GraphemeBreak::Extend), (0x01b82, 0x01b82, GraphemeBreak::SpacingMark),
+    (0x01ba1, 0x01ba1, GraphemeBreak::SpacingMark), (0x01ba2, 0x01ba5, GraphemeBreak::Extend),
+    (0x01ba6, 0x01ba7, GraphemeBreak::SpacingMark), (0x01ba8, 0x01bad, GraphemeBreak::Extend),
+    (0x01be6, 0x01be6, GraphemeBreak::Extend), (0x01be7, 0x01be7, GraphemeBreak::SpacingMark),
+    (0x01be8, 0x01be9, GraphemeBreak::Extend), (0x01bea, 0x01bec, GraphemeBreak::SpacingMark),
+    (0x01bed, 0x01bed, GraphemeBreak::Extend), (0x01bee, 0x01bee, GraphemeBreak::SpacingMark),
+    (0x01bef, 0x01bf3, GraphemeBreak::Extend), (0x01c24, 0x01c2b, GraphemeBreak::SpacingMark),
+    (0x01c2c, 0x01c33, GraphemeBreak::Extend), (0x01c34, 0x01c35, GraphemeBreak::SpacingMark),
+    (0x01c36, 0x01c37, GraphemeBreak::Extend), (0x01cd0, 0x01cd2, GraphemeBreak::Extend),
+    (0x01cd4, 0x01ce0, GraphemeBreak::Extend), (0x01ce1, 0x01ce1, GraphemeBreak::SpacingMark),
+    (0x01ce2, 0x01ce8, GraphemeBreak::Extend), (0x01ced, 0x01ced, GraphemeBreak::Extend),
+    (0x01cf4, 0x01cf4, GraphemeBreak::Extend), (0x01cf7, 0x01cf7, GraphemeBreak::SpacingMark),
+    (0x01cf8, 0x01cf9, GraphemeBreak::Extend), (0x01dc0, 0x01dff, GraphemeBreak::Extend),
+    (0x0200b, 0x0200b, GraphemeBreak::Control), (0x0200c, 0x0200c, GraphemeBreak::Extend),
+    (0x0200d, 0x0200d, GraphemeBreak::Zwj), (0x0200e, 0x0200f, GraphemeBreak::Control),
+    (0x02028, 0x0202e, GraphemeBreak::Control), (0x02060, 0x0206f, GraphemeBreak::Control),
+    (0x020d0, 0x020f0, GraphemeBreak::Extend), (0x02cef, 0x02cf1, GraphemeBreak::Extend),
+    (0x02d7f, 0x02d7f, GraphemeBreak::Extend), (0x02de0, 0x02dff, GraphemeBreak::Extend),
+    (0x0302a, 0x0302f, GraphemeBreak::Extend), (0x03099, 0x0309a, GraphemeBreak::Extend),
+    (0x0a66f, 0x0a672, GraphemeBreak::Extend), (0x0a674, 0x0a67d, GraphemeBreak::Extend),
+    (0x0a69e, 0x0a69f, GraphemeBreak::Extend), (0x0a6f0, 0x0a6f1, GraphemeBreak::Extend),
+    (0x0a802, 0x0a802, GraphemeBreak::Extend), (0x0a806, 0x0a806, GraphemeBreak::Extend),
+    (0x0a80b, 0x0a80b, GraphemeBreak::Extend), (0x0a823, 0x0a824, GraphemeBreak::SpacingMark),
+    (0x0a825, 0x0a826, GraphemeBreak::Extend), (0x0a827, 0x0a827, GraphemeBreak::SpacingMark),
+    (0x0a82c, 0x0a82c, GraphemeBreak::Extend), (0x0a880, 0x0a881, GraphemeBreak::SpacingMark),
+    (0x0a8b4, 0x0a8c3, GraphemeBreak::SpacingMark), (0x0a8c4, 0x0a8c5, GraphemeBreak::Extend),
+    (0x0a8e0, 0x0a8f1, GraphemeBreak::Extend), (0x0a8ff, 0x0a8ff, GraphemeBreak::Extend),
+    (0x0a926, 0x0a92d, GraphemeBreak::Extend), (0x0a947, 0x0a951, GraphemeBreak::Extend),
+    (0x0a952, 0x0a952, GraphemeBreak::SpacingMark), (0x0a953, 0x0a953, GraphemeBreak::Extend),
+    (0x0a960, 0x0a97c, GraphemeBreak::L), (0x0a980, 0x0a982, GraphemeBreak::Extend),
+    (0x0a983, 0x0a983, GraphemeBreak::SpacingMark), (0x0a9b3, 0x0a9b3, GraphemeBreak::Extend),
+    (0x0a9b4, 0x0a9b5, GraphemeBreak::SpacingMark), (0x0a9b6, 0x0a9b9, GraphemeBreak::Extend),
+    (0x0a9ba, 0x0a9bb, GraphemeBreak::SpacingMark), (0x0a9bc, 0x0a9bd, GraphemeBreak::Extend),
+    (0x0a9be, 0x0a9bf, GraphemeBreak::SpacingMark), (0x0a9c0, 0x0a9c0, GraphemeBreak::Extend),
+    (0x0a9e5, 0x0a9e5, GraphemeBreak::Extend), (0x0aa29, 0x0aa2e, GraphemeBreak::Extend),
+    (0x0aa2f, 0x0aa30, GraphemeBreak::SpacingMark), (0x0aa31, 0x0aa32, GraphemeBreak::Extend),
+    (0x0aa33, 0x0aa34, GraphemeBreak::SpacingMark), (0x0aa35, 0x0aa36, GraphemeBreak::Extend),
+    (0x0aa43, 0x0aa43, GraphemeBreak::Extend), (0x0aa4c, 0x0aa4c, GraphemeBreak::Extend),
+    (0x0aa4d, 0x0aa4d, GraphemeBreak::SpacingMark), (0x0aa7c, 0x0aa7c, GraphemeBreak::Extend),
+    (0x0aab0, 0x0aab0, GraphemeBreak::Extend), (0x0aab2, 0x0aab4, GraphemeBreak::Extend),
+    (0x0aab7, 0x0aab8, GraphemeBreak::Extend), (0x0aabe, 0x0aabf, GraphemeBreak::Extend),
+    (0x0aac1, 0x0aac1, GraphemeBreak::Extend), (0x0aaeb, 0x0aaeb, GraphemeBreak::SpacingMark),
+    (0x0aaec, 0x0aaed, GraphemeBreak::Extend), (0x0aaee, 0x0aaef, GraphemeBreak::SpacingMark),
+    (0x0aaf5, 0x0aaf5, GraphemeBreak::SpacingMark), (0x0aaf6, 0x0aaf6, GraphemeBreak::Extend),
+    (0x0abe3, 0x0abe4, GraphemeBreak::SpacingMark), (0x0abe5, 0x0abe5, GraphemeBreak::Extend),
+    (0x0abe6, 0x0abe7, GraphemeBreak::SpacingMark), (0x0abe8, 0x0abe8, GraphemeBreak::Extend),
+    (0x0abe9, 0x0abea, GraphemeBreak::SpacingMark), (0x0abec, 0x0abec, GraphemeBreak::SpacingMark),
+    (0x0abed, 0x0abed, GraphemeBreak::Extend), (0x0d7b0, 0x0d7c6, GraphemeBreak::V),
+    (0x0d7cb, 0x0d7fb, GraphemeBreak::T), (0x0fb1e, 0x0fb1e, GraphemeBreak::Extend),
+    (0x0fe00, 0x0fe0f, GraphemeBreak::Extend), (0x0fe20, 0x0fe2f, GraphemeBreak::Extend),
+    (0x0feff, 0x0feff, GraphemeBreak::Control), (0x0ff9e, 0x0ff9f, GraphemeBreak::Extend),
+    (0x0fff0, 0x0fffb, GraphemeBreak::Control), (0x101fd, 0x101fd, GraphemeBreak::Extend),
+    (0x102e0, 0x102e0, GraphemeBreak::Extend), (0x10376, 0x1037a, GraphemeBreak::Extend),
+    (0x10a01, 0x10a03, GraphemeBreak::Extend), (0x10a05, 0x10a06, GraphemeBreak::Extend),
+    (0x10a0c, 0x10a0f, GraphemeBreak::Extend), (0x10a38, 0x10a3a, GraphemeBreak::Extend),
+    (0x10a3f, 0x10a3f, GraphemeBreak::Extend), (0x10ae5, 0x10ae6, GraphemeBreak::Extend),
+    (0x10d24, 0x10d27, GraphemeBreak::Extend), (0x10d69, 0x10d6d, GraphemeBreak::Extend),
+    (0x10eab, 0x10eac, GraphemeBreak::Extend), (0x10efa, 0x10eff, GraphemeBreak::Extend),
+    (0x10f46, 0x10f50, GraphemeBreak::Extend), (0x10f82, 0x10f85, GraphemeBreak::Extend),
+    (0x11000, 0x11000, GraphemeBreak::SpacingMark), (0x11001, 0x11001, GraphemeBreak::Extend),
+    (0x11002, 0x11002, GraphemeBreak::SpacingMark), (0x11038, 0x11046, GraphemeBreak::Extend),
+    (0x11070, 0x11070, GraphemeBreak::Extend), (0x11073, 0x11074, GraphemeBreak::Extend),
+    (0x1107f, 0x11081, GraphemeBreak::Extend), (0x11082, 0x11082, GraphemeBreak::SpacingMark),
+    (0x110b0, 0x110b2, GraphemeBreak::SpacingMark), (0x110b3, 0x110b6, GraphemeBreak::Extend),
+    (0x110b7, 0x110b8, GraphemeBreak::SpacingMark), (0x110b9, 0x110ba, GraphemeBreak::Extend),
+    (0x110bd, 0x110bd, GraphemeBreak::Prepend), (0x110c2, 0x110c2, GraphemeBreak::Extend),
+    (0x110cd, 0x110cd, GraphemeBreak::Prepend), (0x11100, 0x11102, GraphemeBreak::Extend),
+    (0x11127, 0x1112b, GraphemeBreak::Extend), (0x1112c, 0x1112c, GraphemeBreak::SpacingMark),
+    (0x1112d, 0x11134, GraphemeBreak::Extend), (0x11145, 0x11146, GraphemeBreak::SpacingMark),
+    (0x11173, 0x11173, GraphemeBreak::Extend), (0x11180, 0x11181, GraphemeBreak::Extend),
+    (0x11182, 0x11182, GraphemeBreak::SpacingMark), (0x111b3, 0x111b5, GraphemeBreak::SpacingMark),
+    (0x111b6, 0x111be, GraphemeBreak::Extend), (0x111bf, 0x111bf, GraphemeBreak::SpacingMark),
+    (0x111c0, 0x111c0, GraphemeBreak::Extend), (0x111c2, 0x111c3, GraphemeBreak::Prepend),
+    (0x111c9, 0x111cc, GraphemeBreak::Extend), (0x111ce, 0x111ce, GraphemeBreak::SpacingMark),
+    (0x111cf, 0x111cf, GraphemeBreak::Extend), (0x1122c, 0x1122e, GraphemeBreak::SpacingMark),
+    (0x1122f, 0x11231, GraphemeBreak::Extend), (0x11232, 0x11233, GraphemeBreak::SpacingMark),
+    (0x11234, 0x11237, GraphemeBreak::Extend), (0x1123e, 0x1123e, GraphemeBreak::Extend),
+    (0x11241, 0x11241, GraphemeBreak::Extend), (0x112df, 0x112df, GraphemeBreak::Extend),
+    (0x112e0, 0x112e2, GraphemeBreak::SpacingMark), (0x112e3, 0x112ea, GraphemeBreak::Extend),
+    (0x11300, 0x11301, GraphemeBreak::Extend), (0x11302, 0x11303, GraphemeBreak::SpacingMark),
+    (0x1133b, 0x1133c, GraphemeBreak::Extend), (0x1133e, 0x1133e, GraphemeBreak::Extend),
+    (0x1133f, 0x1133f, GraphemeBreak::SpacingMark), (0x11340, 0x11340, GraphemeBreak::Extend),
+    (0x11341, 0x11344, GraphemeBreak::SpacingMark), (0x11347, 0x11348, GraphemeBreak::SpacingMark),
+    (0x1134b, 0x1134c, GraphemeBreak::SpacingMark), (0x1134d, 0x1134d, GraphemeBreak::Extend),
+    (0x11357, 0x11357, GraphemeBreak::Extend), (0x11362, 0x11363, GraphemeBreak::SpacingMark),
+    (0x11366, 0x1136c, GraphemeBreak::Extend), (0x11370, 0x11374, GraphemeBreak::Extend),
+    (0x113b8, 0x113b8, GraphemeBreak::Extend), (0x113b9, 0x113ba, GraphemeBreak::SpacingMark),
+    (0x113bb, 0x113c0, GraphemeBreak::Extend), (0x113c2, 0x113c2, GraphemeBreak::Extend),
+    (0x113c5, 0x113c5, GraphemeBreak::Extend), (0x113c7, 0x113c9, GraphemeBreak::Extend),
+    (0x113ca, 0x113ca, GraphemeBreak::SpacingMark), (0x113cc, 0x113cd, GraphemeBreak::SpacingMark),
+    (0x113ce, 0x113d0, GraphemeBreak::Extend), (0x113d1, 0x113d1, GraphemeBreak::Prepend),
+    (0x113d2, 0x113d2, GraphemeBreak::Extend), (0x113e1, 0x113e2, GraphemeBreak::Extend),
+    (0x11435, 0x11437, GraphemeBreak::SpacingMark), (0x11438, 0x1143f, GraphemeBreak::Extend),
+    (0x11440, 0x11441, GraphemeBreak::SpacingMark), (0x11442, 0x11444, GraphemeBreak::Extend),
+    (0x11445, 0x11445, GraphemeBreak::SpacingMark), (0x11446, 0x11446, GraphemeBreak::Extend),
+    (0x1145e, 0x1145e, GraphemeBreak::Extend), (0x114b0, 0x114b0, GraphemeBreak::Extend),
+    (0x114b1, 0x114b2, GraphemeBreak::SpacingMark), (0x114b3, 0x114b8, GraphemeBreak::Extend),
+    (0x114b9, 0x114b9, GraphemeBreak::SpacingMark), (0x114ba, 0x114ba, GraphemeBreak::Extend),
+    (0x114bb, 0x114bc, GraphemeBreak::SpacingMark), (0x114bd, 0x114bd, GraphemeBreak::Extend),
+    (0x114be, 0x114be, GraphemeBreak::SpacingMark), (0x114bf, 0x114c0, GraphemeBreak::Extend),
+    (0x114c1, 0x114c1, GraphemeBreak::SpacingMark), (0x114c2, 0x114c3, GraphemeBreak::Extend),
+    (0x115af, 0x115af, GraphemeBreak::Extend), (0x115b0, 0x115b1, GraphemeBreak::SpacingMark),
+    (0x115b2, 0x115b5, GraphemeBreak::Extend), (0x115b8, 0x115bb, GraphemeBreak::SpacingMark),
+    (0x115bc, 0x115bd, GraphemeBreak::Extend), (0x115be, 0x115be, GraphemeBreak::SpacingMark),
+    (0x115bf, 0x115c0, GraphemeBreak::Extend), (0x115dc, 0x115dd, GraphemeBreak::Extend),
+    (0x11630, 0x11632, GraphemeBreak::SpacingMark), (0x11633, 0x1163a, GraphemeBreak::Extend),
+    (0x1163b, 0x1163c, GraphemeBreak::SpacingMark), (0x1163d, 0x1163d, GraphemeBreak::Extend),
+    (0x1163e, 0x1163e, GraphemeBreak::SpacingMark), (0x1163f, 0x11640, GraphemeBreak::Extend),
+    (0x116ab, 0x116ab, GraphemeBreak::Extend), (0x116ac, 0x116ac, GraphemeBreak::SpacingMark),
+    (0x116ad, 0x116ad, GraphemeBreak::Extend), (0x116ae, 0x116af, GraphemeBreak::SpacingMark),
+    (0x116b0, 0x116b7, GraphemeBreak::Extend), (0x1171d, 0x1171d, GraphemeBreak::Extend),
+    (0x1171e, 0x1171e, GraphemeBreak::SpacingMark), (0x1171f, 0x1171f, GraphemeBreak::Extend),
+    (0x11722, 0x11725, GraphemeBreak::Extend), (0x11726, 0x11726, GraphemeBreak::SpacingMark),
+    (0x11727, 0x1172b, GraphemeBreak::Extend), (0x1182c, 0x1182e, GraphemeBreak::SpacingMark),
+    (0x1182f, 0x11837, GraphemeBreak::Extend), (0x11838, 0x11838, GraphemeBreak::SpacingMark),
+    (0x11839, 0x1183a, GraphemeBreak::Extend), (0x11930, 0x11930, GraphemeBreak::Extend),
+    (0x11931, 0x11935, GraphemeBreak::SpacingMark), (0x11937, 0x11938, GraphemeBreak::SpacingMark),
+    (0x1193b, 0x1193e, GraphemeBreak::Extend), (0x1193f, 0x1193f, GraphemeBreak::Prepend),
+    (0x11940, 0x11940, GraphemeBreak::SpacingMark), (0x11941, 0x11941, GraphemeBreak::Prepend),
+    (0x11942, 0x11942, GraphemeBreak::SpacingMark), (0x11943, 0x11943, GraphemeBreak::Extend),
+    (0x119d1, 0x119d3, GraphemeBreak::SpacingMark), (0x119d4, 0x119d7, GraphemeBreak::Extend),
+    (0x119da, 0x119db, GraphemeBreak::Extend), (0x119dc, 0x119df, GraphemeBreak::SpacingMark),
+    (0x119e0, 0x119e0, GraphemeBreak::Extend), (0x119e4, 0x119e4, GraphemeBreak::SpacingMark),
+    (0x11a01, 0x11a0a, GraphemeBreak::Extend), (0x11a33, 0x11a38, GraphemeBreak::Extend),
+    (0x11a39, 0x11a39, GraphemeBreak::SpacingMark), (0x11a3b, 0x11a3e, GraphemeBreak::Extend),
+    (0x11a47, 0x11a47, GraphemeBreak::Extend), (0x11a51, 0x11a56, GraphemeBreak::Extend),
+    (0x11a57, 0x11a58, GraphemeBreak::SpacingMark), (0x11a59, 0x11a5b, GraphemeBreak::Extend),
+    (0x11a84, 0x11a89, GraphemeBreak::Prepend), (0x11a8a, 0x11a96, GraphemeBreak::Extend),
+    (0x11a97, 0x11a97, GraphemeBreak::SpacingMark), (0x11a98, 0x11a99, GraphemeBreak::Extend),
+    (0x11b60, 0x11b60, GraphemeBreak::Extend), (0x11b61, 0x11b61, GraphemeBreak::SpacingMark),
+    (0x11b62, 0x11b64, GraphemeBreak::Extend), (0x11b65, 0x11b65, GraphemeBreak::SpacingMark),
+    (0x11b66, 0x11b66, GraphemeBreak::Extend), (0x11b67, 0x11b67, GraphemeBreak::SpacingMark),
+    (0x11c2f, 0x11c2f, GraphemeBreak::SpacingMark), (0x11c30, 0x11c36, GraphemeBreak::Extend),
+    (0x11c38, 0x11c3d, GraphemeBreak::Extend), (0x11c3e, 0x11c3e, GraphemeBreak::SpacingMark),
+    (0x11c3f, 0x11c3f, GraphemeBreak::Extend), (0x11c92, 0x11ca7, GraphemeBreak::Extend),
+    (0x11ca9, 0x11ca9, GraphemeBreak::SpacingMark), (0x11caa, 0x11cb0, GraphemeBreak::Extend),
+    (0x11cb1, 0x11cb1, GraphemeBreak::SpacingMark), (0x11cb2, 0x11cb3, GraphemeBreak::Extend),
+    (0x11cb4, 0x11cb4, GraphemeBreak::SpacingMark), (0x11cb5, 0x11cb6, GraphemeBreak::Extend),
+    (0x11d31, 0x11d36, GraphemeBreak::Extend), (0x11d3a, 0x11d3a, GraphemeBreak::Extend),
+    (0x11d3c, 0x11d3d, GraphemeBreak::Extend), (0x11d3f, 0x11d45, GraphemeBreak::Extend),
+    (0x11d46, 0x11d46, GraphemeBreak::Prepend), (0x11d47, 0x11d47, GraphemeBreak::Extend),
+    (0x11d8a, 0x11d8e, GraphemeBreak::SpacingMark), (0x11d90, 0x11d91, GraphemeBreak::Extend),
+    (0x11d93, 0x11d94, GraphemeBreak::SpacingMark), (0x11d95, 0x11d95, GraphemeBreak::Extend),
+    (0x11d96, 0x11d96, GraphemeBreak::SpacingMark), (0x11d97, 0x11d97, GraphemeBreak::Extend),
+    (0x11ef3, 0x11ef4, GraphemeBreak::Extend), (0x11ef5, 0x11ef6, GraphemeBreak::SpacingMark),
+    (0x11f00, 0x11f01, GraphemeBreak::Extend), (0x11f02, 0x11f02, GraphemeBreak::Prepend),
+    (0x11f03, 0x11f03, GraphemeBreak::SpacingMark), (0x11f34, 0x11f35, GraphemeBreak::SpacingMark),
+    (0x11f36, 0x11f3a, GraphemeBreak::Extend), (0x11f3e, 0x11f3f, GraphemeBreak::SpacingMark),
+    (0x11f40, 0x11f42, GraphemeBreak::Extend), (0x11f5a, 0x11f5a, GraphemeBreak::Extend),
+    (0x13430, 0x1343f, GraphemeBreak::Control), (0x13440, 0x13440, GraphemeBreak::Extend),
+    (0x13447, 0x13455, GraphemeBreak::Extend), (0x1611e, 0x16129, GraphemeBreak::Extend),
+    (0x1612a, 0x1612c, GraphemeBreak::SpacingMark), (0x1612d, 0x1612f, GraphemeBreak::Extend),
+    (0x16af0, 0x16af4, GraphemeBreak::Extend), (0x16b30, 0x16b36, GraphemeBreak::Extend),
+    (0x16d63, 0x16d63, GraphemeBreak::V), (0x16d67, 0x16d6a, GraphemeBreak::V),
+    (0x16f4f, 0x16f4f, GraphemeBreak::Extend), (0x16f51, 0x16f87, GraphemeBreak::SpacingMark),
+    (0x16f8f, 0x16f92, GraphemeBreak::Extend), (0x16fe4, 0x16fe4, GraphemeBreak::Extend),
+    (0x16ff0, 0x16ff1, GraphemeBreak::Extend), (0x1bc9d, 0x1bc9e, GraphemeBreak::Extend),
+    (0x1bca0, 0x1bca3, GraphemeBreak::Control), (0x1cf00, 0x1cf2d, GraphemeBreak::Extend),
+    (0x1cf30, 0x1cf46, GraphemeBreak::Extend), (0x1d165, 0x1d169, GraphemeBreak::Extend),
+    (0x1d16d, 0x1d172, GraphemeBreak::Extend), (0x1d173, 0x1d17a, GraphemeBreak::Control),
+    (0x1d17b, 0x1d182, GraphemeBreak::Extend), (0x1d185, 0x1d18b, GraphemeBreak::Extend),
+    (0x1d1aa, 0x1d1ad, GraphemeBreak::Extend), (0x1d242, 0x1d244, GraphemeBreak::Extend),
+    (0x1da00, 0x1da36, GraphemeBreak::Extend), (0x1da3b, 0x1da6c, GraphemeBreak::Extend),
+    (0x1da75, 0x1da75, GraphemeBreak::Extend), (0x1da84, 0x1da84, GraphemeBreak::Extend),
+    (0x1da9b, 0x1da9f, GraphemeBreak::Extend), (0x1daa1, 0x1daaf, GraphemeBreak::Extend),
+    (0x1e000, 0x1e006, GraphemeBreak::Extend), (0x1e008, 0x1e018, GraphemeBreak::Extend),
+    (0x1e01b, 0x1e021, GraphemeBreak::Extend), (0x1e023, 0x1e024, GraphemeBreak::Extend),
+    (0x1e026, 0x1e02a, GraphemeBreak::Extend), (0x1e08f, 0x1e08f, GraphemeBreak::Extend),
+    (0x1e130, 0x1e136, GraphemeBreak::Extend), (0x1e2ae, 0x1e2ae, GraphemeBreak::Extend),
+    (0x1e2ec, 0x1e2ef, GraphemeBreak::Extend), (0x1e4ec, 0x1e4ef, GraphemeBreak::Extend),
+    (0x1e5ee, 0x1e5ef, GraphemeBreak::Extend), (0x1e6e3, 0x1e6e3, GraphemeBreak::Extend),
+    (0x1e6e6, 0x1e6e6, GraphemeBreak::Extend), (0x1e6ee, 0x1e6ef, GraphemeBreak::Extend),
+    (0x1e6f5, 0x1e6f5, GraphemeBreak::Extend), (0x1e8d0, 0x1e8d6, GraphemeBreak::Extend),
+    (0x1e944, 0x1e94a, GraphemeBreak::Extend), (0x1f1e6, 0x1f1ff, GraphemeBreak::RegionalIndicator),
+    (0x1f3fb, 0x1f3ff, GraphemeBreak::Extend), (0xe0000, 0xe001f, GraphemeBreak::Control),
+    (0xe0020, 0xe007f, GraphemeBreak::Extend), (0xe0080, 0xe00ff, GraphemeBreak::Control),
+    (0xe0100, 0xe01ef, GraphemeBreak::Extend), (0xe01f0, 0xe0fff, GraphemeBreak::Control),
+];
+
+#[rustfmt::skip]
+pub const INDIC_CONJUNCT_BREAK: &[(u32, u32, IndicConjunctBreak)] = &[
+    (0x00300, 0x0036f, IndicConjunctBreak::Extend), (0x00483, 0x00489, IndicConjunctBreak::Extend),
+    (0x00591, 0x005bd, IndicConjunctBreak::Extend), (0x005bf, 0x005bf, IndicConjunctBreak::Extend),
+    (0x005c1, 0x005c2, IndicConjunctBreak::Extend), (0x005c4, 0x005c5, IndicConjunctBreak::Extend),
+    (0x005c7, 0x005c7, IndicConjunctBreak::Extend), (0x00610, 0x0061a, IndicConjunctBreak::Extend),
+    (0x0064b, 0x0065f, IndicConjunctBreak::Extend), (0x00670, 0x00670, IndicConjunctBreak::Extend),
+    (0x006d6, 0x006dc, IndicConjunctBreak::Extend), (0x006df, 0x006e4, IndicConjunctBreak::Extend),
+    (0x006e7, 0x006e8, IndicConjunctBreak::Extend), (0x006ea, 0x006ed, IndicConjunctBreak::Extend),
+    (0x00711, 0x00711, IndicConjunctBreak::Extend), (0x00730, 0x0074a, IndicConjunctBreak::Extend),
+    (0x007a6, 0x007b0, IndicConjunctBreak::Extend), (0x007eb, 0x007f3, IndicConjunctBreak::Extend),
+    (0x007fd, 0x007fd, IndicConjunctBreak::Extend), (0x00816, 0x00819, IndicConjunctBreak::Extend),
+    (0x0081b, 0x00823, IndicConjunctBreak::Extend), (0x00825, 0x00827, IndicConjunctBreak::Extend),
+    (0x00829, 0x0082d, IndicConjunctBreak::Extend), (0x00859, 0x0085b, IndicConjunctBreak::Extend),
+    (0x00897, 0x0089f, IndicConjunctBreak::Extend), (0x008ca, 0x008e1, IndicConjunctBreak::Extend),
+    (0x008e3, 0x00902, IndicConjunctBreak::Extend), (0x00915, 0x00939, IndicConjunctBreak::Consonant),
+    (0x0093a, 0x0093a, IndicConjunctBreak::Extend), (0x0093c, 0x0093c, IndicConjunctBreak::Extend),
+    (0x00941, 0x00948, IndicConjunctBreak::Extend), (0x0094d, 0x0094d, IndicConjunctBreak::Linker),
+    (0x00951, 0x00957, IndicConjunctBreak::Extend), (0x00958, 0x0095f, IndicConjunctBreak::Consonant),
+    (0x00962, 0x00963, IndicConjunctBreak::Extend), (0x00978, 0x0097f, IndicConjunctBreak::Consonant),
+    (0x00981, 0x00981, IndicConjunctBreak::Extend), (0x00995, 0x009a8, IndicConjunctBreak::Consonant),
+    (0x009aa, 0x009b0, IndicConjunctBreak::Consonant), (0x009b2, 0x009b2, IndicConjunctBreak::Consonant),
+    (0x009b6, 0x009b9, IndicConjunctBreak::Consonant), (0x009bc, 0x009bc, IndicConjunctBreak::Extend),
+    (0x009be, 0x009be, IndicConjunctBreak::Extend), (0x009c1, 0x009c4, IndicConjunctBreak::Extend),
+    (0x009cd, 0x009cd, IndicConjunctBreak::Linker), (0x009d7, 0x009d7, IndicConjunctBreak::Extend),
+    (0x009dc, 0x009dd, IndicConjunctBreak::Consonant), (0x009df, 0x009df, IndicConjunctBreak::Consonant),
+    (0x009e2, 0x009e3, IndicConjunctBreak::Extend), (0x009f0, 0x009f1, IndicConjunctBreak::Consonant),
+    (0x009fe, 0x009fe, IndicConjunctBreak::Extend), (0x00a01, 0x00a02, IndicConjunctBreak::Extend),
+    (0x00a3c, 0x00a3c, IndicConjunctBreak::Extend), (0x00a41, 0x00a42, IndicConjunctBreak::Extend),
+    (0x00a47, 0x00a48, IndicConjunctBreak::Extend), (0x00a4b, 0x00a4d, IndicConjunctBreak::Extend),
+    (0x00a51, 0x00a51, IndicConjunctBreak::Extend), (0x00a70, 0x00a71, IndicConjunctBreak::Extend),
+    (0x00a75, 0x00a75, IndicConjunctBreak::Extend), (0x00a81, 0x00a82, IndicConjunctBreak::Extend),
+    (0x00a95, 0x00aa8, IndicConjunctBreak::Consonant), (0x00aaa, 0x00ab0, IndicConjunctBreak::Consonant),
+    (0x00ab2, 0x00ab3, IndicConjunctBreak::Consonant), (0x00ab5, 0x00ab9, IndicConjunctBreak::Consonant),
+    (0x00abc, 0x00abc, IndicConjunctBreak::Extend), (0x00ac1, 0x00ac5, IndicConjunctBreak::Extend),
+    (0x00ac7, 0x00ac8, IndicConjunctBreak::Extend), (0x00acd, 0x00acd, IndicConjunctBreak::Linker),
+    (0x00ae2, 0x00ae3, IndicConjunctBreak::Extend), (0x00af9, 0x00af9, IndicConjunctBreak::Consonant),
+    (0x00afa, 0x00aff, IndicConjunctBreak::Extend), (0x00b01, 0x00b01, IndicConjunctBreak::Extend),
+    (0x00b15, 0x00b28, IndicConjunctBreak::Consonant), (0x00b2a, 0x00b30, IndicConjunctBreak::Consonant),
+    (0x00b32, 0x00b33, IndicConjunctBreak::Consonant), (0x00b35, 0x00b39, IndicConjunctBreak::Consonant),
+    (0x00b3c, 0x00b3c, IndicConjunctBreak::Extend), (0x00b3e, 0x00b3f, IndicConjunctBreak::Extend),
+    (0x00b41, 0x00b44, IndicConjunctBreak::Extend), (0x00b4d, 0x00b4d, IndicConjunctBreak::Linker),
+    (0x00b55, 0x00b57, IndicConjunctBreak::Extend), (0x00b5c, 0x00b5d, IndicConjunctBreak::Consonant),
+    (0x00b5f, 0x00b5f, IndicConjunctBreak::Consonant), (0x00b62, 0x00b63, IndicConjunctBreak::Extend),
+    (0x00b71, 0x00b71, IndicConjunctBreak::Consonant), (0x00b82, 0x00b82, IndicConjunctBreak::Extend),
+    (0x00bbe, 0x00bbe, IndicConjunctBreak::Extend), (0x00bc0, 0x00bc0, IndicConjunctBreak::Extend),
+    (0x00bcd, 0x00bcd, IndicConjunctBreak::Extend), (0x00bd7, 0x00bd7, IndicConjunctBreak::Extend),
+    (0x00c00, 0x00c00, IndicConjunctBreak::Extend), (0x00c04, 0x00c04, IndicConjunctBreak::Extend),
+    (0x00c15, 0x00c28, IndicConjunctBreak::Consonant), (0x00c2a, 0x00c39, IndicConjunctBreak::Consonant),
+    (0x00c3c, 0x00c3c, IndicConjunctBreak::Extend), (0x00c3e, 0x00c40, IndicConjunctBreak::Extend),
+    (0x00c46, 0x00c48, IndicConjunctBreak::Extend), (0x00c4a, 0x00c4c, IndicConjunctBreak::Extend),
+    (0x00c4d, 0x00c4d, IndicConjunctBreak::Linker), (0x00c55, 0x00c56, IndicConjunctBreak::Extend),
+    (0x00c58, 0x00c5a, IndicConjunctBreak::Consonant), (0x00c62, 0x00c63, IndicConjunctBreak::Extend),
+    (0x00c81, 0x00c81, IndicConjunctBreak::Extend), (0x00cbc, 0x00cbc, IndicConjunctBreak::Extend),
+    (0x00cbf, 0x00cc0, IndicConjunctBreak::Extend), (0x00cc2, 0x00cc2, IndicConjunctBreak::Extend),
+    (0x00cc6, 0x00cc8, IndicConjunctBreak::Extend), (0x00cca, 0x00ccd, IndicConjunctBreak::Extend),
+    (0x00cd5, 0x00cd6, IndicConjunctBreak::Extend), (0x00ce2, 0x00ce3, IndicConjunctBreak::Extend),
+    (0x00d00, 0x00d01, IndicConjunctBreak::Extend), (0x00d15, 0x00d3a, IndicConjunctBreak::Consonant),
+    (0x00d3b, 0x00d3c, IndicConjunctBreak::Extend), (0x00d3e, 0x00d3e, IndicConjunctBreak::Extend),
+    (0x00d41, 0x00d44, IndicConjunctBreak::Extend), (0x00d4d, 0x00d4d, IndicConjunctBreak::Linker),
+    (0x00d57, 0x00d57, IndicConjunctBreak::Extend), (0x00d62, 0x00d63, IndicConjunctBreak::Extend),
+    (0x00d81, 0x00d81, IndicConjunctBreak::Extend), (0x00dca, 0x00dca, IndicConjunctBreak::Extend),
+    (0x00dcf, 0x00dcf, IndicConjunctBreak::Extend), (0x00dd2, 0x00dd4, IndicConjunctBreak::Extend),
+    (0x00dd6, 0x00dd6, IndicConjunctBreak::Extend), (0x00ddf, 0x00ddf, IndicConjunctBreak::Extend),
+    (0x00e31, 0x00e31, IndicConjunctBreak::Extend), (0x00e34, 0x00e3a, IndicConjunctBreak::Extend),
+    (0x00e47, 0x00e4e, IndicConjunctBreak::Extend), (0x00eb1, 0x00eb1, IndicConjunctBreak::Extend),
+    (0x00eb4, 0x00ebc, IndicConjunctBreak::Extend), (0x00ec8, 0x00ece, IndicConjunctBreak::Extend),
+    (0x00f18, 0x00f19, IndicConjunctBreak::Extend), (0x00f35, 0x00f35, IndicConjunctBreak::Extend),
+    (0x00f37, 0x00f37, IndicConjunctBreak::Extend), (0x00f39, 0x00f39, IndicConjunctBreak::Extend),
+    (0x00f71, 0x00f7e, IndicConjunctBreak::Extend), (0x00f80, 0x00f84, IndicConjunctBreak::Extend),
+    (0x00f86, 0x00f87, IndicConjunctBreak::Extend), (0x00f8d, 0x00f97, IndicConjunctBreak::Extend),
+    (0x00f99, 0x00fbc, IndicConjunctBreak::Extend), (0x00fc6, 0x00fc6, IndicConjunctBreak::Extend),
+    (0x01000, 0x0102a, IndicConjunctBreak::Consonant), (0x0102d, 0x01030, IndicConjunctBreak::Extend),
+    (0x01032, 0x01037, IndicConjunctBreak::Extend), (0x01039, 0x01039, IndicConjunctBreak::Linker),
+    (0x0103a, 0x0103a, IndicConjunctBreak::Extend), (0x0103d, 0x0103e, IndicConjunctBreak::Extend),
+    (0x0103f, 0x0103f, IndicConjunctBreak::Consonant), (0x01050, 0x01055, IndicConjunctBreak::Consonant),
+    (0x01058, 0x01059, IndicConjunctBreak::Extend), (0x0105a, 0x0105d, IndicConjunctBreak::Consonant),
+    (0x0105e, 0x01060, IndicConjunctBreak::Extend), (0x01061, 0x01061, IndicConjunctBreak::Consonant),
+    (0x01065, 0x01066, IndicConjunctBreak::Consonant), (0x0106e, 0x01070, IndicConjunctBreak::Consonant),
+    (0x01071, 0x01074, IndicConjunctBreak::Extend), (0x01075, 0x01081, IndicConjunctBreak::Consonant),
+    (0x01082, 0x01082, IndicConjunctBreak::Extend), (0x01085, 0x01086, IndicConjunctBreak::Extend),
+    (0x0108d, 0x0108d, IndicConjunctBreak::Extend), (0x0108e, 0x0108e, IndicConjunctBreak::Consonant),
+    (0x0109d, 0x0109d, IndicConjunctBreak::Extend), (0x0135d, 0x0135f, IndicConjunctBreak::Extend),
+    (0x01712, 0x01715, IndicConjunctBreak::Extend), (0x01732, 0x01734, IndicConjunctBreak::Extend),
+    (0x01752, 0x01753, IndicConjunctBreak::Extend), (0x01772, 0x01773, IndicConjunctBreak::Extend),
+    (0x01780, 0x017b3, IndicConjunctBreak::Consonant), (0x017b4, 0x017b5, IndicConjunctBreak::Extend),
+    (0x017b7, 0x017bd, IndicConjunctBreak::Extend), (0x017c6, 0x017c6, IndicConjunctBreak::Extend),
+    (0x017c9, 0x017d1, IndicConjunctBreak::Extend), (0x017d2, 0x017d2, IndicConjunctBreak::Linker),
+    (0x017d3, 0x017d3, IndicConjunctBreak::Extend), (0x017dd, 0x017dd, IndicConjunctBreak::Extend),
+    (0x0180b, 0x0180d, IndicConjunctBreak::Extend), (0x0180f, 0x0180f, IndicConjunctBreak::Extend),
+    (0x01885, 0x01886, IndicConjunctBreak::Extend), (0x018a9, 0x018a9, IndicConjunctBreak::Extend),
+    (0x01920, 0x01922, IndicConjunctBreak::Extend), (0x01927, 0x01928, IndicConjunctBreak::Extend),
+    (0x01932, 0x01932, IndicConjunctBreak::Extend), (0x01939, 0x0193b, IndicConjunctBreak::Extend),
+    (0x01a17, 0x01a18, IndicConjunctBreak::Extend), (0x01a1b, 0x01a1b, IndicConjunctBreak::Extend),
+    (0x01a20, 0x01a54, IndicConjunctBreak::Consonant), (0x01a56, 0x01a56, IndicConjunctBreak::Extend),
+    (0x01a58, 0x01a5e, IndicConjunctBreak::Extend), (0x01a60, 0x01a60, IndicConjunctBreak::Linker),
+    (0x01a62, 0x01a62, IndicConjunctBreak::Extend), (0x01a65, 0x01a6c, IndicConjunctBreak::Extend),
+    (0x01a73, 0x01a7c, IndicConjunctBreak::Extend), (0x01a7f, 0x01a7f, IndicConjunctBreak::Extend),
+    (0x01ab0, 0x01add, IndicConjunctBreak::Extend), (0x01ae0, 0x01aeb, IndicConjunctBreak::Extend),
+    (0x01b00, 0x01b03, IndicConjunctBreak::Extend), (0x01b0b, 0x01b0c, IndicConjunctBreak::Consonant),
+    (0x01b13, 0x01b33, IndicConjunctBreak::Consonant), (0x01b34, 0x01b3d, IndicConjunctBreak::Extend),
+    (0x01b42, 0x01b43, IndicConjunctBreak::Extend), (0x01b44, 0x01b44, IndicConjunctBreak::Linker),
+    (0x01b45, 0x01b4c, IndicConjunctBreak::Consonant), (0x01b6b, 0x01b73, IndicConjunctBreak::Extend),
+    (0x01b80, 0x01b81, IndicConjunctBreak::Extend), (0x01b83, 0x01ba0, IndicConjunctBreak::Consonant),
+    (0x01ba2, 0x01ba5, IndicConjunctBreak::Extend), (0x01ba8, 0x01baa, IndicConjunctBreak::Extend),
+    (0x01bab, 0x01bab, IndicConjunctBreak::Linker), (0x01bac, 0x01bad, IndicConjunctBreak::Extend),
+    (0x01bae, 0x01baf, IndicConjunctBreak::Consonant), (0x01bbb, 0x01bbd, IndicConjunctBreak::Consonant),
+    (0x01be6, 0x01be6, IndicConjunctBreak::Extend), (0x01be8, 0x01be9, IndicConjunctBreak::Extend),
+    (0x01bed, 0x01bed, IndicConjunctBreak::Extend), (0x01bef, 0x01bf3, IndicConjunctBreak::Extend),
+    (0x01c2c, 0x01c33, IndicConjunctBreak::Extend), (0x01c36, 0x01c37, IndicConjunctBreak::Extend),
+    (0x01cd0, 0x01cd2, IndicConjunctBreak::Extend), (0x01cd4, 0x01ce0, IndicConjunctBreak::Extend),
+    (0x01ce2, 0x01ce8, IndicConjunctBreak::Extend), (0x01ced, 0x01ced, IndicConjunctBreak::Extend),
+    (0x01cf4, 0x01cf4, IndicConjunctBreak::Extend), (0x01cf8, 0x01cf9, IndicConjunctBreak::Extend),
+    (0x01dc0, 0x01dff, IndicConjunctBreak::Extend), (0x0200d, 0x0200d, IndicConjunctBreak::Extend),
+    (0x020d0, 0x020f0, IndicConjunctBreak::Extend), (0x02cef, 0x02cf1, IndicConjunctBreak::Extend),
+    (0x02d7f, 0x02d7f, IndicConjunctBreak::Extend), (0x02de0, 0x02dff, IndicConjunctBreak::Extend),
+    (0x0302a, 0x0302f, IndicConjunctBreak::Extend), (0x03099, 0x0309a, IndicConjunctBreak::Extend),
+    (0x0a66f, 0x0a672, IndicConjunctBreak::Extend), (0x0a674, 0x0a67d, IndicConjunctBreak::Extend),
+    (0x0a69e, 0x0a69f, IndicConjunctBreak::Extend), (0x0a6f0, 0x0a6f1, IndicConjunctBreak::Extend),
+    (0x0a802, 0x0a802, IndicConjunctBreak::Extend), (0x0a806, 0x0a806, IndicConjunctBreak::Extend),
+    (0x0a80b, 0x0a80b, IndicConjunctBreak::Extend), (0x0a825, 0x0a826, IndicConjunctBreak::Extend),
+    (0x0a82c, 0x0a82c, IndicConjunctBreak::Extend), (0x0a8c4, 0x0a8c5, IndicConjunctBreak::Extend),
+    (0x0a8e0, 0x0a8f1, IndicConjunctBreak::Extend), (0x0a8ff, 0x0a8ff, IndicConjunctBreak::Extend),
+    (0x0a926, 0x0a92d, IndicConjunctBreak::Extend), (0x0a947, 0x0a951, IndicConjunctBreak::Extend),
+    (0x0a953, 0x0a953, IndicConjunctBreak::Extend), (0x0a980, 0x0a982, IndicConjunctBreak::Extend),
+    (0x0a989, 0x0a98b, IndicConjunctBreak::Consonant), (0x0a98f, 0x0a9b2, IndicConjunctBreak::Consonant),
+    (0x0a9b3, 0x0a9b3, IndicConjunctBreak::Extend), (0x0a9b6, 0x0a9b9, IndicConjunctBreak::Extend),
+    (0x0a9bc, 0x0a9bd, IndicConjunctBreak::Extend), (0x0a9c0, 0x0a9c0, IndicConjunctBreak::Linker),
+    (0x0a9e0, 0x0a9e4, IndicConjunctBreak::Consonant), (0x0a9e5, 0x0a9e5, IndicConjunctBreak::Extend),
+    (0x0a9e7, 0x0a9ef, IndicConjunctBreak::Consonant), (0x0a9fa, 0x0a9fe, IndicConjunctBreak::Consonant),
+    (0x0aa29, 0x0aa2e, IndicConjunctBreak::Extend), (0x0aa31, 0x0aa32, IndicConjunctBreak::Extend),
+    (0x0aa35, 0x0aa36, IndicConjunctBreak::Extend), (0x0aa43, 0x0aa43, IndicConjunctBreak::Extend),
+    (0x0aa4c, 0x0aa4c, IndicConjunctBreak::Extend), (0x0aa60, 0x0aa6f, IndicConjunctBreak::Consonant),
+    (0x0aa71, 0x0aa73, IndicConjunctBreak::Consonant), (0x0aa7a, 0x0aa7a, IndicConjunctBreak::Consonant),
+    (0x0aa7c, 0x0aa7c, IndicConjunctBreak::Extend), (0x0aa7e, 0x0aa7f, IndicConjunctBreak::Consonant),
+    (0x0aab0, 0x0aab0, IndicConjunctBreak::Extend), (0x0aab2, 0x0aab4, IndicConjunctBreak::Extend),
+    (0x0aab7, 0x0aab8, IndicConjunctBreak::Extend), (0x0aabe, 0x0aabf, IndicConjunctBreak::Extend),
+    (0x0aac1, 0x0aac1, IndicConjunctBreak::Extend), (0x0aae0, 0x0aaea, IndicConjunctBreak::Consonant),
+    (0x0aaec, 0x0aaed, IndicConjunctBreak::Extend), (0x0aaf6, 0x0aaf6, IndicConjunctBreak::Linker),
+    (0x0abc0, 0x0abda, IndicConjunctBreak::Consonant), (0x0abe5, 0x0abe5, IndicConjunctBreak::Extend),
+    (0x0abe8, 0x0abe8, IndicConjunctBreak::Extend), (0x0abed, 0x0abed, IndicConjunctBreak::Extend),
+    (0x0fb1e, 0x0fb1e, IndicConjunctBreak::Extend), (0x0fe00, 0x0fe0f, IndicConjunctBreak::Extend),
+    (0x0fe20, 0x0fe2f, IndicConjunctBreak::Extend), (0x0ff9e, 0x0ff9f, IndicConjunctBreak::Extend),
+    (0x101fd, 0x101fd, IndicConjunctBreak::Extend), (0x102e0, 0x102e0, IndicConjunctBreak::Extend),
+    (0x10376, 0x1037a, IndicConjunctBreak::Extend), (0x10a00, 0x10a00, IndicConjunctBreak::Consonant),
+    (0x10a01, 0x10a03, IndicConjunctBreak::Extend), (0x10a05, 0x10a06, IndicConjunctBreak::Extend),
+    (0x10a0c, 0x10a0f, IndicConjunctBreak::Extend), (0x10a10, 0x10a13, IndicConjunctBreak::Consonant),
+    (0x10a15, 0x10a17, IndicConjunctBreak::Consonant), (0x10a19, 0x10a35, IndicConjunctBreak::Consonant),
+    (0x10a38, 0x10a3a, IndicConjunctBreak::Extend), (0x10a3f, 0x10a3f, IndicConjunctBreak::Linker),
+    (0x10ae5, 0x10ae6, IndicConjunctBreak::Extend), (0x10d24, 0x10d27, IndicConjunctBreak::Extend),
+    (0x10d69, 0x10d6d, IndicConjunctBreak::Extend), (0x10eab, 0x10eac, IndicConjunctBreak::Extend),
+    (0x10efa, 0x10eff, IndicConjunctBreak::Extend), (0x10f46, 0x10f50, IndicConjunctBreak::Extend),
+    (0x10f82, 0x10f85, IndicConjunctBreak::Extend), (0x11001, 0x11001, IndicConjunctBreak::Extend),
+    (0x11038, 0x11046, IndicConjunctBreak::Extend), (0x11070, 0x11070, IndicConjunctBreak::Extend),
+    (0x11073, 0x11074, IndicConjunctBreak::Extend), (0x1107f, 0x11081, IndicConjunctBreak::Extend),
+    (0x110b3, 0x110b6, IndicConjunctBreak::Extend), (0x110b9, 0x110ba, IndicConjunctBreak::Extend),
+    (0x110c2, 0x110c2, IndicConjunctBreak::Extend), (0x11100, 0x11102, IndicConjunctBreak::Extend),
+    (0x11103, 0x11126, IndicConjunctBreak::Consonant), (0x11127, 0x1112b, IndicConjunctBreak::Extend),
+    (0x1112d, 0x11132, IndicConjunctBreak::Extend), (0x11133, 0x11133, IndicConjunctBreak::Linker),
+    (0x11134, 0x11134, IndicConjunctBreak::Extend), (0x11144, 0x11144, IndicConjunctBreak::Consonant),
+    (0x11147, 0x11147, IndicConjunctBreak::Consonant), (0x11173, 0x11173, IndicConjunctBreak::Extend),
+    (0x11180, 0x11181, IndicConjunctBreak::Extend), (0x111b6, 0x111be, IndicConjunctBreak::Extend),
+    (0x111c0, 0x111c0, IndicConjunctBreak::Extend), (0x111c9, 0x111cc, IndicConjunctBreak::Extend),
+    (0x111cf, 0x111cf, IndicConjunctBreak::Extend), (0x1122f, 0x11231, IndicConjunctBreak::Extend),
+    (0x11234, 0x11237, IndicConjunctBreak::Extend), (0x1123e, 0x1123e, IndicConjunctBreak::Extend),
+    (0x11241, 0x11241, IndicConjunctBreak::Extend), (0x112df, 0x112df, IndicConjunctBreak::Extend),
+    (0x112e3, 0x112ea, IndicConjunctBreak::Extend), (0x11300, 0x11301, IndicConjunctBreak::Extend),
+    (0x1133b, 0x1133c, IndicConjunctBreak::Extend), (0x1133e, 0x1133e, IndicConjunctBreak::Extend),
+    (0x11340, 0x11340, IndicConjunctBreak::Extend), (0x1134d, 0x1134d, IndicConjunctBreak::Extend),
+    (0x11357, 0x11357, IndicConjunctBreak::Extend), (0x11366, 0x1136c, IndicConjunctBreak::Extend),
+    (0x11370, 0x11374, IndicConjunctBreak::Extend), (0x11380, 0x11389, IndicConjunctBreak::Consonant),
+    (0x1138b, 0x1138b, IndicConjunctBreak::Consonant), (0x1138e, 0x1138e, IndicConjunctBreak::Consonant),
+    (0x11390, 0x113b5, IndicConjunctBreak::Consonant), (0x113b8, 0x113b8, IndicConjunctBreak::Extend),
+    (0x113bb, 0x113c0, IndicConjunctBreak::Extend), (0x113c2, 0x113c2, IndicConjunctBreak::Extend),
+    (0x113c5, 0x113c5, IndicConjunctBreak::Extend), (0x113c7, 0x113c9, IndicConjunctBreak::Extend),
+    (0x113ce, 0x113cf, IndicConjunctBreak::Extend), (0x113d0, 0x113d0, IndicConjunctBreak::Linker),
+    (0x113d2, 0x113d2, IndicConjunctBreak::Extend), (0x113e1, 0x113e2, IndicConjunctBreak::Extend),
+    (0x11438, 0x1143f, IndicConjunctBreak::Extend), (0x11442, 0x11444, IndicConjunctBreak::Extend),
+    (0x11446, 0x11446, IndicConjunctBreak::Extend), (0x1145e, 0x1145e, IndicConjunctBreak::Extend),
+    (0x114b0, 0x114b0, IndicConjunctBreak::Extend), (0x114b3, 0x114b8, IndicConjunctBreak::Extend),
+    (0x114ba, 0x114ba, IndicConjunctBreak::Extend), (0x114bd, 0x114bd, IndicConjunctBreak::Extend),
+    (0x114bf, 0x114c0, IndicConjunctBreak::Extend), (0x114c2, 0x114c3, IndicConjunctBreak::Extend),
+    (0x115af, 0x115af, IndicConjunctBreak::Extend), (0x115b2, 0x115b5, IndicConjunctBreak::Extend),
+    (0x115bc, 0x115bd, IndicConjunctBreak::Extend), (0x115bf, 0x115c0, IndicConjunctBreak::Extend),
+    (0x115dc, 0x115dd, IndicConjunctBreak::Extend), (0x11633, 0x1163a, IndicConjunctBreak::Extend),
+    (0x1163d, 0x1163d, IndicConjunctBreak::Extend), (0x1163f, 0x11640, IndicConjunctBreak::Extend),
+    (0x116ab, 0x116ab, IndicConjunctBreak::Extend), (0x116ad, 0x116ad, IndicConjunctBreak::Extend),
+    (0x116b0, 0x116b7, IndicConjunctBreak::Extend), (0x1171d, 0x1171d, IndicConjunctBreak::Extend),
+    (0x1171f, 0x1171f, IndicConjunctBreak::Extend), (0x11722, 0x11725, IndicConjunctBreak::Extend),
+    (0x11727, 0x1172b, IndicConjunctBreak::Extend), (0x1182f, 0x11837, IndicConjunctBreak::Extend),
+    (0x11839, 0x1183a, IndicConjunctBreak::Extend), (0x11900, 0x11906, IndicConjunctBreak::Consonant),
+    (0x11909, 0x11909, IndicConjunctBreak::Consonant), (0x1190c, 0x11913, IndicConjunctBreak::Consonant),
+    (0x11915, 0x11916, IndicConjunctBreak::Consonant), (0x11918, 0x1192f, IndicConjunctBreak::Consonant),
+    (0x11930, 0x11930, IndicConjunctBreak::Extend), (0x1193b, 0x1193d, IndicConjunctBreak::Extend),
+    (0x1193e, 0x1193e, IndicConjunctBreak::Linker), (0x11943, 0x11943, IndicConjunctBreak::Extend),
+    (0x119d4, 0x119d7, IndicConjunctBreak::Extend), (0x119da, 0x119db, IndicConjunctBreak::Extend),
+    (0x119e0, 0x119e0, IndicConjunctBreak::Extend), (0x11a00, 0x11a00, IndicConjunctBreak::Consonant),
+    (0x11a01, 0x11a0a, IndicConjunctBreak::Extend), (0x11a0b, 0x11a32, IndicConjunctBreak::Consonant),
+    (0x11a33, 0x11a38, IndicConjunctBreak::Extend), (0x11a3b, 0x11a3e, IndicConjunctBreak::Extend),
+    (0x11a47, 0x11a47, IndicConjunctBreak::Linker), (0x11a50, 0x11a50, IndicConjunctBreak::Consonant),
+    (0x11a51, 0x11a56, IndicConjunctBreak::Extend), (0x11a59, 0x11a5b, IndicConjunctBreak::Extend),
+    (0x11a5c, 0x11a83, IndicConjunctBreak::Consonant), (0x11a8a, 0x11a96, IndicConjunctBreak::Extend),
+    (0x11a98, 0x11a98, IndicConjunctBreak::Extend), (0x11a99, 0x11a99, IndicConjunctBreak::Linker),
+    (0x11b60, 0x11b60, IndicConjunctBreak::Extend), (0x11b62, 0x11b64, IndicConjunctBreak::Extend),
+    (0x11b66, 0x11b66, IndicConjunctBreak::Extend), (0x11c30, 0x11c36, IndicConjunctBreak::Extend),
+    (0x11c38, 0x11c3d, IndicConjunctBreak::Extend), (0x11c3f, 0x11c3f, IndicConjunctBreak::Extend),
+    (0x11c92, 0x11ca7, IndicConjunctBreak::Extend), (0x11caa, 0x11cb0, IndicConjunctBreak::Extend),
+    (0x11cb2, 0x11cb3, IndicConjunctBreak::Extend), (0x11cb5, 0x11cb6, IndicConjunctBreak::Extend),
+    (0x11d31, 0x11d36, IndicConjunctBreak::Extend), (0x11d3a, 0x11d3a, IndicConjunctBreak::Extend),
+    (0x11d3c, 0x11d3d, IndicConjunctBreak::Extend), (0x11d3f, 0x11d45, IndicConjunctBreak::Extend),
+    (0x11d47, 0x11d47, IndicConjunctBreak::Extend), (0x11d90, 0x11d91, IndicConjunctBreak::Extend),
+    (0x11d95, 0x11d95, IndicConjunctBreak::Extend), (0x11d97, 0x11d97, IndicConjunctBreak::Extend),
+    (0x11ef3, 0x11ef4, IndicConjunctBreak::Extend), (0x11f00, 0x11f01, IndicConjunctBreak::Extend),
+    (0x11f04, 0x11f10, IndicConjunctBreak::Consonant), (0x11f12, 0x11f33, IndicConjunctBreak::Consonant),
+    (0x11f36, 0x11f3a, IndicConjunctBreak::Extend), (0x11f40, 0x11f41, IndicConjunctBreak::Extend),
+    (0x11f42, 0x11f42, IndicConjunctBreak::Linker), (0x11f5a, 0x11f5a, IndicConjunctBreak::Extend),
+    (0x13440, 0x13440, IndicConjunctBreak::Extend), (0x13447, 0x13455, IndicConjunctBreak::Extend),
+    (0x1611e, 0x16129, IndicConjunctBreak::Extend), (0x1612d, 0x1612f, IndicConjunctBreak::Extend),
+    (0x16af0, 0x16af4, IndicConjunctBreak::Extend), (0x16b30, 0x16b36, IndicConjunctBreak::Extend),
+    (0x16f4f, 0x16f4f, IndicConjunctBreak::Extend), (0x16f8f, 0x16f92, IndicConjunctBreak::Extend),
+    (0x16fe4, 0x16fe4, IndicConjunctBreak::Extend), (0x16ff0, 0x16ff1, IndicConjunctBreak::Extend),
+    (0x1bc9d, 0x1bc9e, IndicConjunctBreak::Extend), (0x1cf00, 0x1cf2d, IndicConjunctBreak::Extend),
+    (0x1cf30, 0x1cf46, IndicConjunctBreak::Extend), (0x1d165, 0x1d169, IndicConjunctBreak::Extend),
+    (0x1d16d, 0x1d172, IndicConjunctBreak::Extend), (0x1d17b, 0x1d182, IndicConjunctBreak::Extend),
+    (0x1d185, 0x1d18b, IndicConjunctBreak::Extend), (0x1d1aa, 0x1d1ad, IndicConjunctBreak::Extend),
+    (0x1d242, 0x1d244, IndicConjunctBreak::Extend), (0x1da00, 0x1da36, IndicConjunctBreak::Extend),
+    (0x1da3b, 0x1da6c, IndicConjunctBreak::Extend), (0x1da75, 0x1da75, IndicConjunctBreak::Extend),
+    (0x1da84, 0x1da84, IndicConjunctBreak::Extend), (0x1da9b, 0x1da9f, IndicConjunctBreak::Extend),
+    (0x1daa1, 0x1daaf, IndicConjunctBreak::Extend), (0x1e000, 0x1e006, IndicConjunctBreak::Extend),
+    (0x1e008, 0x1e018, IndicConjunctBreak::Extend), (0x1e01b, 0x1e021, IndicConjunctBreak::Extend),
+    (0x1e023, 0x1e024, IndicConjunctBreak::Extend), (0x1e026, 0x1e02a, IndicConjunctBreak::Extend),
+    (0x1e08f, 0x1e08f, IndicConjunctBreak::Extend), (0x1e130, 0x1e136, IndicConjunctBreak::Extend),
+    (0x1e2ae, 0x1e2ae, IndicConjunctBreak::Extend), (0x1e2ec, 0x1e2ef, IndicConjunctBreak::Extend),
+    (0x1e4ec, 0x1e4ef, IndicConjunctBreak::Extend), (0x1e5ee, 0x1e5ef, IndicConjunctBreak::Extend),
+    (0x1e6e3, 0x1e6e3, IndicConjunctBreak::Extend), (0x1e6e6, 0x1e6e6, IndicConjunctBreak::Extend),
+    (0x1e6ee, 0x1e6ef, IndicConjunctBreak::Extend), (0x1e6f5, 0x1e6f5, IndicConjunctBreak::Extend),
+    (0x1e8d0, 0x1e8d6, IndicConjunctBreak::Extend), (0x1e944, 0x1e94a, IndicConjunctBreak::Extend),
+    (0x1f3fb, 0x1f3ff, IndicConjunctBreak::Extend), (0xe0020, 0xe007f, IndicConjunctBreak::Extend),
+    (0xe0100, 0xe01ef, IndicConjunctBreak::Extend),
+];
+
+#[rustfmt::skip]
+pub const EXTENDED_PICTOGRAPHIC: &[(u32, u32)] = &[
+    (0x000a9, 0x000a9), (0x000ae, 0x000ae), (0x0203c, 0x0203c), (0x02049, 0x02049),
+    (0x02122, 0x02122), (0x02139, 0x02139), (0x02194, 0x02199), (0x021a9, 0x021aa),
+    (0x0231a, 0x0231b), (0x02328, 0x02328), (0x023cf, 0x023cf), (0x023e9, 0x023f3),
+    (0x023f8, 0x023fa), (0x024c2, 0x024c2), (0x025aa, 0x025ab), (0x025b6, 0x025b6),
+    (0x025c0, 0x025c0), (0x025fb, 0x025fe), (0x02600, 0x02604), (0x0260e, 0x0260e),
+    (0x02611, 0x02611), (0x02614, 0x02615), (0x02618, 0x02618), (0x0261d, 0x0261d),
+    (0x02620, 0x02620), (0x02622, 0x02623), (0x02626, 0x02626), (0x0262a, 0x0262a),
+    (0x0262e, 0x0262f), (0x02638, 0x0263a), (0x02640, 0x02640), (0x02642, 0x02642),
+    (0x02648, 0x02653), (0x0265f, 0x02660), (0x02663, 0x02663), (0x02665, 0x02666),
+    (0x02668, 0x02668), (0x0267b, 0x0267b), (0x0267e, 0x0267f), (0x02692, 0x02697),
+    (0x02699, 0x02699), (0x0269b, 0x0269c), (0x026a0, 0x026a1), (0x026a7, 0x026a7),
+    (0x026aa, 0x026ab), (0x026b0, 0x026b1), (0x026bd, 0x026be), (0x026c4, 0x026c5),
+    (0x026c8, 0x026c8), (0x026ce, 0x026cf), (0x026d1, 0x026d1), (0x026d3, 0x026d4),
+    (0x026e9, 0x026ea), (0x026f0, 0x026f5), (0x026f7, 0x026fa), (0x026fd, 0x026fd),
+    (0x02702, 0x02702), (0x02705, 0x02705), (0x02708, 0x0270d), (0x0270f, 0x0270f),
+    (0x02712, 0x02712), (0x02714, 0x02714), (0x02716, 0x02716), (0x0271d, 0x0271d),
+    (0x02721, 0x02721), (0x02728, 0x02728), (0x02733, 0x02734), (0x02744, 0x02744),
+    (0x02747, 0x02747), (0x0274c, 0x0274c), (0x0274e, 0x0274e), (0x02753, 0x02755),
+    (0x02757, 0x02757), (0x02763, 0x02764), (0x02795, 0x02797), (0x027a1, 0x027a1),
+    (0x027b0, 0x027b0), (0x027bf, 0x027bf), (0x02934, 0x02935), (0x02b05, 0x02b07),
+    (0x02b1b, 0x02b1c), (0x02b50, 0x02b50), (0x02b55, 0x02b55), (0x03030, 0x03030),
+    (0x0303d, 0x0303d), (0x03297, 0x03297), (0x03299, 0x03299), (0x1f004, 0x1f004),
+    (0x1f02c, 0x1f02f), (0x1f094, 0x1f09f), (0x1f0af, 0x1f0b0), (0x1f0c0, 0x1f0c0),
+    (0x1f0cf, 0x1f0d0), (0x1f0f6, 0x1f0ff), (0x1f170, 0x1f171), (0x1f17e, 0x1f17f),
+    (0x1f18e, 0x1f18e), (0x1f191, 0x1f19a), (0x1f1ae, 0x1f1e5), (0x1f201, 0x1f20f),
+    (0x1f21a, 0x1f21a), (0x1f22f, 0x1f22f), (0x1f232, 0x1f23a), (0x1f23c, 0x1f23f),
+    (0x1f249, 0x1f25f), (0x1f266, 0x1f321), (0x1f324, 0x1f393), (0x1f396, 0x1f397),
+    (0x1f399, 0x1f39b), (0x1f39e, 0x1f3f0), (0x1f3f3, 0x1f3f5), (0x1f3f7, 0x1f3fa),
+    (0x1f400, 0x1f4fd), (0x1f4ff, 0x1f53d), (0x1f549, 0x1f54e), (0x1f550, 0x1f567),
+    (0x1f56f, 0x1f570), (0x1f573, 0x1f57a), (0x1f587, 0x1f587), (0x1f58a, 0x1f58d),
+    (0x1f590, 0x1f590), (0x1f595, 0x1f596), (0x1f5a4, 0x1f5a5), (0x1f5a8, 0x1f5a8),
+    (0x1f5b1, 0x1f5b2), (0x1f5bc, 0x1f5bc), (0x1f5c2, 0x1f5c4), (0x1f5d1, 0x1f5d3),
+    (0x1f5dc, 0x1f5de), (0x1f5e1, 0x1f5e1), (0x1f5e3, 0x1f5e3), (0x1f5e8, 0x1f5e8),
+    (0x1f5ef, 0x1f5ef), (0x1f5f3, 0x1f5f3), (0x1f5fa, 0x1f64f), (0x1f680, 0x1f6c5),
+    (0x1f6cb, 0x1f6d2), (0x1f6d5, 0x1f6e5), (0x1f6e9, 0x1f6e9), (0x1f6eb, 0x1f6f0),
+    (0x1f6f3, 0x1f6ff), (0x1f7da, 0x1f7ff), (0x1f80c, 0x1f80f), (0x1f848, 0x1f84f),
+    (0x1f85a, 0x1f85f), (0x1f888, 0x1f88f), (0x1f8ae, 0x1f8af), (0x1f8bc, 0x1f8bf),
+    (0x1f8c2, 0x1f8cf), (0x1f8d9, 0x1f8ff), (0x1f90c, 0x1f93a), (0x1f93c, 0x1f945),
+    (0x1f947, 0x1f9ff), (0x1fa58, 0x1fa5f), (0x1fa6e, 0x1faff), (0x1fc00, 0x1fffd),
+];
@@ -100,23 +100,55 @@ macro_rules! cfg_group {
     }
 }
 
+#[macro_use]
+mod panic;
+
 mod ascii;
 mod bytes;
+mod grapheme;
 mod printable;
 mod slice;
 mod str;
+mod unicode_case;
 mod utf16;
 mod utf8;
 
+pub mod ascii_case {
+    //! Const-fn ASCII case conversion.
+    //!
+    //! See also [`convert_ascii_case!`](crate::convert_ascii_case).
+
+    pub use crate::__ctfe::{AsciiCase, Boundaries, CaseBuf, CaseOptions};
+}
+
+pub mod ffi {
+    //! Wide C strings.
+    //!
+    //! See also [`wcstr!`](crate::wcstr).
+
+    pub use crate::__ctfe::WStr;
+}
+
+pub mod phf {
+    //! Compile-time perfect hash maps.
+    //!
+    //! See also [`phf_map!`](crate::phf_map).
+
+    pub use crate::__ctfe::PhfMap as Map;
+}
+
 #[doc(hidden)]
 #[cfg(feature = "proc")]
 pub mod __proc {
-    mod case;
-    pub use self::case::*;
-
     mod fmt;
     pub use self::fmt::*;
 
+    #[cfg(feature = "ident")]
+    cfg_group! {
+        mod ident;
+        pub use self::ident::*;
+    }
+
     #[cfg(feature = "http")]
     cfg_group! {
         mod http;
@@ -135,6 +167,9 @@ pub mod __ctfe {
     mod ascii_case;
     pub use self::ascii_case::*;
 
+    mod case;
+    pub use self::case::*;
+
     mod chain;
     // pub use self::chain::*;
 
@@ -150,6 +185,9 @@ pub mod __ctfe {
     mod cstr;
     pub use self::cstr::*;
 
+    mod decode;
+    pub use self::decode::*;
+
     mod encode;
     pub use self::encode::*;
 
@@ -165,6 +203,30 @@ pub mod __ctfe {
     mod hex;
     pub use self::hex::*;
 
+    mod base64;
+    pub use self::base64::*;
+
+    mod base32;
+    pub use self::base32::*;
+
+    mod base58;
+    pub use self::base58::*;
+
+    mod bech32;
+    pub use self::bech32::*;
+
+    mod percent;
+    pub use self::percent::*;
+
+    mod escape;
+    pub use self::escape::*;
+
+    mod unescape;
+    pub use self::unescape::*;
+
+    mod punycode;
+    pub use self::punycode::*;
+
     mod net;
     pub use self::net::*;
 
@@ -177,9 +239,15 @@ pub mod __ctfe {
     mod replace;
     pub use self::replace::*;
 
+    mod reverse;
+    pub use self::reverse::*;
+
     mod str;
     pub use self::str::*;
 
+    mod substr;
+    pub use self::substr::*;
+
     mod to_byte_array;
     pub use self::to_byte_array::*;
 
@@ -192,6 +260,15 @@ pub mod __ctfe {
     mod sorted;
     pub use self::sorted::*;
 
+    mod dedup;
+    pub use self::dedup::*;
+
+    mod binary_search;
+    pub use self::binary_search::*;
+
+    mod phf;
+    pub use self::phf::*;
+
     mod split;
     pub use self::split::*;
 
@@ -201,9 +278,15 @@ pub mod __ctfe {
     mod is_ascii;
     pub use self::is_ascii::*;
 
+    mod is_case;
+    pub use self::is_case::*;
+
     mod eq_ignore_ascii_case;
     pub use self::eq_ignore_ascii_case::*;
 
+    mod eq_ignore_case;
+    pub use self::eq_ignore_case::*;
+
     mod unwrap;
     pub use self::unwrap::*;
 
@@ -0,0 +1,103 @@
+#![allow(unsafe_code)]
+
+/// A fixed-capacity message buffer for panics with runtime values in const fns.
+///
+/// Const panics only accept `panic!("{}", s)` with `s: &str`,
+/// so the message has to be built before panicking.
+pub struct PanicMsg {
+    buf: [u8; 256],
+    len: usize,
+}
+
+impl PanicMsg {
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; 256],
+            len: 0,
+        }
+    }
+
+    /// Appends a string slice. It is dropped if there is not enough room.
+    pub const fn str(mut self, s: &str) -> Self {
+        let bytes = s.as_bytes();
+        if self.len + bytes.len() > self.buf.len() {
+            return self;
+        }
+        let mut i = 0;
+        while i < bytes.len() {
+            self.buf[self.len] = bytes[i];
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    pub const fn usize(self, x: usize) -> Self {
+        let mut digits = [0; 20];
+        let mut pos = digits.len();
+        let mut x = x;
+        loop {
+            pos -= 1;
+            digits[pos] = b'0' + (x % 10) as u8;
+            x /= 10;
+            if x == 0 {
+                break;
+            }
+        }
+        let digits = crate::slice::advance(&digits, pos);
+        self.str(unsafe { core::str::from_utf8_unchecked(digits) })
+    }
+
+    pub const fn as_str(&self) -> &str {
+        let bytes = crate::slice::subslice(&self.buf, 0..self.len);
+        unsafe { core::str::from_utf8_unchecked(bytes) }
+    }
+}
+
+/// Panics with a message built from string slices and `usize` values.
+///
+/// ```txt
+/// const_panic!("index ", usize: i, " is out of bounds");
+/// ```
+macro_rules! const_panic {
+    (@push $msg: expr, usize: $x: expr $(, $($tt:tt)+)?) => {
+        const_panic!(@push $msg.usize($x) $(, $($tt)+)?)
+    };
+    (@push $msg: expr, $s: expr $(, $($tt:tt)+)?) => {
+        const_panic!(@push $msg.str($s) $(, $($tt)+)?)
+    };
+    (@push $msg: expr) => {
+        $msg
+    };
+    ($($tt:tt)+) => {{
+        let msg = const_panic!(@push $crate::panic::PanicMsg::new(), $($tt)+);
+        panic!("{}", msg.as_str())
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_panic_msg() {
+        const MSG: PanicMsg = PanicMsg::new()
+            .str("index ")
+            .usize(42)
+            .str(" of 我")
+            .usize(0)
+            .usize(usize::MAX);
+        assert_eq!(MSG.as_str(), "index 42 of 我018446744073709551615");
+
+        let long = "x".repeat(300);
+        let msg = PanicMsg::new().str("a").str(&long).str("b");
+        assert_eq!(msg.as_str(), "ab");
+    }
+
+    #[test]
+    #[should_panic = "index 3 is out of bounds"]
+    fn test_const_panic() {
+        let i = 3;
+        const_panic!("index ", usize: i, " is out of bounds");
+    }
+}
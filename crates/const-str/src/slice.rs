@@ -1,5 +1,6 @@
 #![allow(unsafe_code)]
 
+use core::mem::MaybeUninit;
 use core::ops::Range;
 use core::slice;
 
@@ -19,6 +20,35 @@ pub const fn subslice<T>(s: &[T], range: Range<usize>) -> &[T] {
     unsafe { slice::from_raw_parts(base.add(range.start), range.end - range.start) }
 }
 
+pub const fn to_array<T: Copy, const N: usize>(s: &[T]) -> [T; N] {
+    assert!(s.len() == N);
+
+    let mut buf = [MaybeUninit::<T>::uninit(); N];
+    let mut i = 0;
+    while i < N {
+        buf[i] = MaybeUninit::new(s[i]);
+        i += 1;
+    }
+
+    union Cast<T: Copy, const N: usize> {
+        src: [MaybeUninit<T>; N],
+        dst: [T; N],
+    }
+    // SAFETY: all elements are initialized
+    unsafe { Cast { src: buf }.dst }
+}
+
+/// Returns `[s[idx[0]], s[idx[1]], ..., s[idx[N - 1]]]`.
+pub const fn select<T: Copy, const N: usize>(s: &[T], idx: &[usize; N]) -> [T; N] {
+    let mut buf: [T; N] = to_array(subslice(s, 0..N));
+    let mut i = 0;
+    while i < N {
+        buf[i] = s[idx[i]];
+        i += 1;
+    }
+    buf
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -34,4 +64,21 @@ mod tests {
 
         assert_eq!(subslice::<u8>(&[], 0..0), &[]);
     }
+
+    #[test]
+    fn test_to_array() {
+        assert_eq!(to_array::<_, 3>(b"abc"), *b"abc");
+        assert_eq!(to_array::<&str, 0>(&[]), [""; 0]);
+        assert_eq!(
+            to_array::<_, 2>(&[("a", 1), ("b", 2)]),
+            [("a", 1), ("b", 2)]
+        );
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(select(b"abc", &[2, 0, 1]), *b"cab");
+        assert_eq!(select(b"abc", &[1]), *b"b");
+        assert_eq!(select::<u8, 0>(b"abc", &[]), [0; 0]);
+    }
 }
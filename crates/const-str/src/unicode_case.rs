@@ -0,0 +1,231 @@
+// The tables are generated by `just gen-unicode-case`, see `tools/gen-unicode-case`.
+
+mod tables;
+
+use self::tables::*;
+
+const fn find_map(table: &[(char, char)], ch: char) -> Option<char> {
+    let mut lo = 0;
+    let mut hi = table.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (key, value) = table[mid];
+        if ch < key {
+            hi = mid;
+        } else if ch > key {
+            lo = mid + 1;
+        } else {
+            return Some(value);
+        }
+    }
+    None
+}
+
+const fn find_multi(table: &[(char, [char; 3])], ch: char) -> Option<[char; 3]> {
+    let mut lo = 0;
+    let mut hi = table.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (key, value) = table[mid];
+        if ch < key {
+            hi = mid;
+        } else if ch > key {
+            lo = mid + 1;
+        } else {
+            return Some(value);
+        }
+    }
+    None
+}
+
+const fn find_range(table: &[(u32, u32)], ch: char) -> bool {
+    let x = ch as u32;
+
+    let mut lo = 0;
+    let mut hi = table.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (start, end) = table[mid];
+        if x < start {
+            hi = mid;
+        } else if x > end {
+            lo = mid + 1;
+        } else {
+            return true;
+        }
+    }
+    false
+}
+
+const fn mapping(single: &[(char, char)], multi: &[(char, [char; 3])], ch: char) -> [char; 3] {
+    if let Some(c) = find_map(single, ch) {
+        return [c, '\0', '\0'];
+    }
+    if let Some(cs) = find_multi(multi, ch) {
+        return cs;
+    }
+    [ch, '\0', '\0']
+}
+
+/// Returns the full lowercase mapping of `ch`, padded with `'\0'`.
+///
+/// The context-dependent mapping of `'Σ'` is not applied.
+pub const fn to_lower(ch: char) -> [char; 3] {
+    mapping(TO_LOWER, TO_LOWER_MULTI, ch)
+}
+
+/// Returns the full uppercase mapping of `ch`, padded with `'\0'`.
+pub const fn to_upper(ch: char) -> [char; 3] {
+    mapping(TO_UPPER, TO_UPPER_MULTI, ch)
+}
+
+/// Returns the full titlecase mapping of `ch`, padded with `'\0'`.
+pub const fn to_title(ch: char) -> [char; 3] {
+    if let Some(c) = find_map(TO_TITLE, ch) {
+        return [c, '\0', '\0'];
+    }
+    if let Some(cs) = find_multi(TO_TITLE_MULTI, ch) {
+        return cs;
+    }
+    to_upper(ch)
+}
+
+/// Returns the simple case folding of `ch`.
+///
+/// The Turkic mappings of `'I'` and `'İ'` are not applied.
+pub const fn fold(ch: char) -> char {
+    match find_map(FOLD, ch) {
+        Some(c) => c,
+        None => ch,
+    }
+}
+
+pub const fn is_lowercase(ch: char) -> bool {
+    find_range(LOWERCASE, ch)
+}
+
+/// Returns true if `ch` is uppercase or titlecase.
+pub const fn is_uppercase(ch: char) -> bool {
+    find_range(UPPERCASE, ch) || find_range(TITLECASE, ch)
+}
+
+pub const fn is_cased(ch: char) -> bool {
+    is_lowercase(ch) || is_uppercase(ch)
+}
+
+pub const fn is_case_ignorable(ch: char) -> bool {
+    find_range(CASE_IGNORABLE, ch)
+}
+
+/// Returns true if `ch` can be a part of a word.
+pub const fn is_word(ch: char) -> bool {
+    find_range(WORD, ch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(cs: [char; 3]) -> String {
+        cs.iter().filter(|&&c| c != '\0').collect()
+    }
+
+    #[test]
+    fn test_case_mapping() {
+        let chars = "aZ09_ßİﬁŉǅǆΣσςΐЖжԱաᾳᾼ我ǰẞ";
+        for ch in chars.chars() {
+            assert_eq!(collect(to_lower(ch)), ch.to_lowercase().to_string(), "{ch}");
+            assert_eq!(collect(to_upper(ch)), ch.to_uppercase().to_string(), "{ch}");
+        }
+
+        assert_eq!(collect(to_title('ǆ')), "ǅ");
+        assert_eq!(collect(to_title('ß')), "Ss");
+        assert_eq!(collect(to_title('ﬁ')), "Fi");
+        assert_eq!(collect(to_title('ᾳ')), "ᾼ");
+        assert_eq!(collect(to_title('a')), "A");
+        assert_eq!(collect(to_title('我')), "我");
+    }
+
+    #[test]
+    fn test_case_mapping_std() {
+        // the tables follow a fixed Unicode version, which may differ from the toolchain
+        if char::UNICODE_VERSION != UNICODE_VERSION {
+            return;
+        }
+        for ch in (1..=char::MAX as u32).filter_map(char::from_u32) {
+            assert_eq!(
+                collect(to_lower(ch)),
+                ch.to_lowercase().to_string(),
+                "{ch:?}"
+            );
+            assert_eq!(
+                collect(to_upper(ch)),
+                ch.to_uppercase().to_string(),
+                "{ch:?}"
+            );
+            assert_eq!(is_lowercase(ch), ch.is_lowercase(), "{ch:?}");
+            assert_eq!(find_range(UPPERCASE, ch), ch.is_uppercase(), "{ch:?}");
+        }
+    }
+
+    #[test]
+    fn test_case_folding() {
+        let cases = [
+            ('A', 'a'),
+            ('a', 'a'),
+            ('ſ', 's'),
+            ('K', 'k'),
+            ('µ', 'μ'),
+            ('ς', 'σ'),
+            ('Σ', 'σ'),
+            ('ẞ', 'ß'),
+            ('ß', 'ß'),
+            ('ᾈ', 'ᾀ'),
+            ('İ', 'İ'),
+            ('ı', 'ı'),
+            ('ꭰ', 'Ꭰ'),
+            ('Ꭰ', 'Ꭰ'),
+            ('ǅ', 'ǆ'),
+            ('\u{1c89}', '\u{1c8a}'),
+            ('\u{a7cb}', '\u{264}'),
+            ('\u{10d50}', '\u{10d70}'),
+            ('我', '我'),
+        ];
+        for (ch, ans) in cases {
+            assert_eq!(fold(ch), ans, "{ch}");
+        }
+    }
+
+    #[test]
+    fn test_case_folding_std() {
+        if char::UNICODE_VERSION != UNICODE_VERSION {
+            return;
+        }
+        for ch in (0..=char::MAX as u32).filter_map(char::from_u32) {
+            let f = fold(ch);
+            assert_eq!(fold(f), f, "{ch:?}");
+
+            // a char and its single-char lowercase mapping fold to the same char
+            let mut lower = ch.to_lowercase();
+            if let (Some(l), None) = (lower.next(), lower.next()) {
+                assert_eq!(fold(l), f, "{ch:?}");
+            }
+
+            // the folded chars are lowercase, except Cherokee which folds to uppercase,
+            // and 'İ' which has no simple case folding
+            let is_exception = matches!(f, '\u{13a0}'..='\u{13f5}' | 'İ');
+            assert!(is_exception || f.to_lowercase().eq([f]), "{ch:?}");
+        }
+    }
+
+    #[test]
+    fn test_case_properties() {
+        assert!(is_lowercase('a') && is_lowercase('ß') && is_lowercase('ª'));
+        assert!(is_uppercase('A') && is_uppercase('Ж') && is_uppercase('ǅ'));
+        assert!(!is_cased('我') && !is_cased('1') && !is_cased('_'));
+        assert!(is_case_ignorable('\'') && is_case_ignorable('\u{301}'));
+        assert!(!is_case_ignorable('a'));
+        assert!(is_word('我') && is_word('٣') && is_word('\u{301}'));
+        assert!(!is_word('—') && !is_word('\u{3000}') && !is_word('…'));
+    }
+}
@@ -255,6 +255,23 @@ pub const fn str_count_chars(s: &str) -> usize {
     ans
 }
 
+/// Returns the byte offset of the `idx`-th character.
+///
+/// `idx == str_count_chars(s)` is allowed and returns `s.len()`.
+pub const fn str_char_offset(s: &str, idx: usize) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let mut offset = 0;
+    let mut i = 0;
+    while i < idx {
+        match next_char(advance(bytes, offset)) {
+            Some((_, count)) => offset += count,
+            None => return None,
+        }
+        i += 1;
+    }
+    Some(offset)
+}
+
 pub const fn str_chars<const N: usize>(s: &str) -> [char; N] {
     let mut s = s.as_bytes();
     let mut buf: [char; N] = ['\0'; N];
@@ -366,6 +383,17 @@ mod tests {
         assert_eq!(COUNT4, 3);
     }
 
+    #[test]
+    fn test_str_char_offset() {
+        assert_eq!(str_char_offset("", 0), Some(0));
+        assert_eq!(str_char_offset("", 1), None);
+        assert_eq!(str_char_offset("a我b", 0), Some(0));
+        assert_eq!(str_char_offset("a我b", 1), Some(1));
+        assert_eq!(str_char_offset("a我b", 2), Some(4));
+        assert_eq!(str_char_offset("a我b", 3), Some(5));
+        assert_eq!(str_char_offset("a我b", 4), None);
+    }
+
     #[test]
     fn test_char_escape_debug_args() {
         // Test with escape_single_quote only
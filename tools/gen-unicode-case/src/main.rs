@@ -0,0 +1,352 @@
+//! Generates the Unicode tables of const-str:
+//!
+//! + `case`: `crates/const-str/src/unicode_case/tables.rs`,
+//!   which is also copied to `crates/const-str-proc-macro` for `ident!`.
+//! + `grapheme`: `crates/const-str/src/grapheme/tables.rs`.
+//!
+//! The data comes from ICU4X, which is checked against the case mappings and
+//! case properties of the running toolchain's `std` before anything is generated,
+//! so the tables always follow the Unicode version of `std`.
+//!
+//! Usage: `just gen-unicode-case`
+
+use std::fmt::Write as _;
+use std::ops::RangeInclusive;
+
+use icu_casemap::options::{LeadingAdjustment, TitlecaseOptions, TrailingCase};
+use icu_casemap::CaseMapper;
+use icu_locale_core::LanguageIdentifier;
+use icu_properties::props::{
+    Alphabetic, CaseIgnorable, Cased, ExtendedPictographic, GeneralCategory, GeneralCategoryGroup,
+    GraphemeClusterBreak, IndicConjunctBreak, Lowercase, Uppercase,
+};
+use icu_properties::{CodePointMapData, CodePointSetData};
+
+fn all_chars() -> impl Iterator<Item = char> {
+    (0..=char::MAX as u32).filter_map(char::from_u32)
+}
+
+fn escape(ch: char) -> String {
+    if ch.is_ascii_alphanumeric() {
+        format!("'{ch}'")
+    } else {
+        format!("'\\u{{{:x}}}'", ch as u32)
+    }
+}
+
+fn ranges(pred: impl Fn(char) -> bool) -> Vec<RangeInclusive<u32>> {
+    let mut ans: Vec<RangeInclusive<u32>> = Vec::new();
+    for ch in all_chars().filter(|&ch| pred(ch)) {
+        let x = ch as u32;
+        match ans.last_mut() {
+            Some(last) if *last.end() + 1 == x => *last = *last.start()..=x,
+            _ => ans.push(x..=x),
+        }
+    }
+    ans
+}
+
+fn write_items(out: &mut String, header: &str, items: &[String], per_line: usize) {
+    writeln!(out, "#[rustfmt::skip]").unwrap();
+    writeln!(out, "{header} = &[").unwrap();
+    for line in items.chunks(per_line) {
+        writeln!(out, "    {},", line.join(", ")).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(out).unwrap();
+}
+
+/// Returns the ranges of the chars which have the same value, skipping `None`.
+fn value_ranges<T: Copy + PartialEq>(f: impl Fn(char) -> Option<T>) -> Vec<(u32, u32, T)> {
+    let mut ans: Vec<(u32, u32, T)> = Vec::new();
+    for ch in all_chars() {
+        let Some(value) = f(ch) else { continue };
+        let x = ch as u32;
+        match ans.last_mut() {
+            Some(last) if last.1 + 1 == x && last.2 == value => last.1 = x,
+            _ => ans.push((x, x, value)),
+        }
+    }
+    ans
+}
+
+fn write_value_ranges<T: Copy + PartialEq>(
+    out: &mut String,
+    name: &str,
+    ty: &str,
+    f: impl Fn(char) -> Option<T>,
+    value_name: impl Fn(T) -> &'static str,
+) {
+    let items: Vec<String> = value_ranges(f)
+        .into_iter()
+        .map(|(start, end, value)| {
+            format!("(0x{start:05x}, 0x{end:05x}, {ty}::{})", value_name(value))
+        })
+        .collect();
+    write_items(
+        out,
+        &format!("pub const {name}: &[(u32, u32, {ty})]"),
+        &items,
+        2,
+    );
+}
+
+fn write_ranges(out: &mut String, name: &str, pred: impl Fn(char) -> bool) {
+    let items: Vec<String> = ranges(pred)
+        .into_iter()
+        .map(|r| format!("(0x{:05x}, 0x{:05x})", r.start(), r.end()))
+        .collect();
+    write_items(out, &format!("pub const {name}: &[(u32, u32)]"), &items, 4);
+}
+
+/// Writes the mappings that differ from `default` as a single-char table and a multi-char table.
+fn write_mapping(
+    out: &mut String,
+    name: &str,
+    map: impl Fn(char) -> String,
+    default: impl Fn(char) -> String,
+) {
+    let mut single = Vec::new();
+    let mut multi = Vec::new();
+    for ch in all_chars() {
+        let s = map(ch);
+        if s == default(ch) {
+            continue;
+        }
+        let cs: Vec<char> = s.chars().collect();
+        match cs.len() {
+            1 => single.push(format!("({}, {})", escape(ch), escape(cs[0]))),
+            2 | 3 => {
+                let mut padded = ['\0'; 3];
+                padded[..cs.len()].copy_from_slice(&cs);
+                let padded: Vec<String> = padded.iter().map(|&c| escape(c)).collect();
+                multi.push(format!("({}, [{}])", escape(ch), padded.join(", ")));
+            }
+            n => panic!("U+{:04X} maps to {n} chars", ch as u32),
+        }
+    }
+    write_items(
+        out,
+        &format!("pub const {name}: &[(char, char)]"),
+        &single,
+        4,
+    );
+    write_items(
+        out,
+        &format!("pub const {name}_MULTI: &[(char, [char; 3])]"),
+        &multi,
+        2,
+    );
+}
+
+fn write_header(out: &mut String, contents: &[&str]) {
+    let (major, minor, update) = char::UNICODE_VERSION;
+    let version = format!(
+        "// Unicode version: {major}.{minor}.{update}, \
+        the same as `char::UNICODE_VERSION` of the generating toolchain."
+    );
+    let header = [
+        "// DO NOT EDIT THIS FILE. It is generated by `just gen-unicode-case`.",
+        "//",
+        &version,
+        "//",
+    ];
+    for line in header.iter().chain(contents) {
+        writeln!(out, "{line}").unwrap();
+    }
+    writeln!(out).unwrap();
+}
+
+fn main() {
+    let out = match std::env::args().nth(1).as_deref() {
+        Some("case") => case_tables(),
+        Some("grapheme") => grapheme_tables(),
+        _ => panic!("usage: gen-unicode-case <case|grapheme>"),
+    };
+    print!("{}", out.trim_end());
+    println!();
+}
+
+fn case_tables() -> String {
+    let cm = CaseMapper::new();
+    let root = LanguageIdentifier::UNKNOWN;
+
+    let lowercase = CodePointSetData::new::<Lowercase>();
+    let uppercase = CodePointSetData::new::<Uppercase>();
+    let cased = CodePointSetData::new::<Cased>();
+    let case_ignorable = CodePointSetData::new::<CaseIgnorable>();
+    let alphabetic = CodePointSetData::new::<Alphabetic>();
+    let gc = CodePointMapData::<GeneralCategory>::new();
+
+    let to_lower = |ch: char| {
+        cm.lowercase_to_string(ch.encode_utf8(&mut [0; 4]), &root)
+            .into_owned()
+    };
+    let to_upper = |ch: char| {
+        cm.uppercase_to_string(ch.encode_utf8(&mut [0; 4]), &root)
+            .into_owned()
+    };
+    let to_title = |ch: char| {
+        let mut options = TitlecaseOptions::default();
+        options.leading_adjustment = Some(LeadingAdjustment::None);
+        options.trailing_case = Some(TrailingCase::Unchanged);
+        cm.titlecase_segment_with_only_case_data_to_string(
+            ch.encode_utf8(&mut [0; 4]),
+            &root,
+            options,
+        )
+        .into_owned()
+    };
+
+    for ch in all_chars() {
+        let check = |what: &str, ok: bool| {
+            assert!(
+                ok,
+                "ICU4X disagrees with std on {what} of U+{:04X}",
+                ch as u32
+            );
+        };
+        check(
+            "to_lowercase",
+            to_lower(ch) == ch.to_lowercase().to_string(),
+        );
+        check(
+            "to_uppercase",
+            to_upper(ch) == ch.to_uppercase().to_string(),
+        );
+        check("is_lowercase", lowercase.contains(ch) == ch.is_lowercase());
+        check("is_uppercase", uppercase.contains(ch) == ch.is_uppercase());
+    }
+
+    let (major, minor, update) = char::UNICODE_VERSION;
+
+    let mut out = String::new();
+    write_header(
+        &mut out,
+        &[
+            "// + full case mappings (UnicodeData.txt and the unconditional part of SpecialCasing.txt)",
+            "// + simple case folding (the C and S entries of CaseFolding.txt)",
+            "// + the derived properties Lowercase, Uppercase and Case_Ignorable",
+            "// + General_Category Lt",
+            "// + the word characters (alphabetic, numeric, cased or combining marks)",
+        ],
+    );
+    writeln!(out, "#[cfg(test)]").unwrap();
+    writeln!(
+        out,
+        "pub const UNICODE_VERSION: (u8, u8, u8) = ({major}, {minor}, {update});"
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+
+    let id = |ch: char| ch.to_string();
+    write_mapping(&mut out, "TO_LOWER", to_lower, id);
+    write_mapping(&mut out, "TO_UPPER", to_upper, id);
+    // only the titlecase mappings that differ from the uppercase mappings
+    write_mapping(&mut out, "TO_TITLE", to_title, to_upper);
+
+    let fold: Vec<String> = all_chars()
+        .filter_map(|ch| {
+            let f = cm.simple_fold(ch);
+            (f != ch).then(|| format!("({}, {})", escape(ch), escape(f)))
+        })
+        .collect();
+    write_items(&mut out, "pub const FOLD: &[(char, char)]", &fold, 4);
+
+    let number = GeneralCategoryGroup::Number;
+    let mark = GeneralCategoryGroup::Mark;
+    write_ranges(&mut out, "LOWERCASE", |ch| lowercase.contains(ch));
+    write_ranges(&mut out, "UPPERCASE", |ch| uppercase.contains(ch));
+    write_ranges(&mut out, "TITLECASE", |ch| {
+        gc.get(ch) == GeneralCategory::TitlecaseLetter
+    });
+    write_ranges(&mut out, "CASE_IGNORABLE", |ch| case_ignorable.contains(ch));
+    write_ranges(&mut out, "WORD", |ch| {
+        let gc = gc.get(ch);
+        alphabetic.contains(ch) || number.contains(gc) || cased.contains(ch) || mark.contains(gc)
+    });
+
+    out
+}
+
+/// Checks that the Hangul syllables are LV or LVT by the Hangul syllable decomposition,
+/// which is computed instead of being stored in the table.
+fn is_hangul_syllable(ch: char, gcb: GraphemeClusterBreak) -> bool {
+    let x = ch as u32;
+    if !(0xac00..=0xd7a3).contains(&x) {
+        return false;
+    }
+    let expected = if (x - 0xac00) % 28 == 0 {
+        GraphemeClusterBreak::LV
+    } else {
+        GraphemeClusterBreak::LVT
+    };
+    assert_eq!(gcb, expected, "U+{x:04X}");
+    true
+}
+
+fn grapheme_tables() -> String {
+    let gcb = CodePointMapData::<GraphemeClusterBreak>::new();
+    let incb = CodePointMapData::<IndicConjunctBreak>::new();
+    let ext_pict = CodePointSetData::new::<ExtendedPictographic>();
+
+    let mut out = String::new();
+    write_header(
+        &mut out,
+        &[
+            "// + Grapheme_Cluster_Break, except Other and the Hangul syllables",
+            "// + Indic_Conjunct_Break, except None",
+            "// + Extended_Pictographic",
+        ],
+    );
+    writeln!(out, "use super::{{GraphemeBreak, IndicConjunctBreak}};").unwrap();
+    writeln!(out).unwrap();
+
+    write_value_ranges(
+        &mut out,
+        "GRAPHEME_CLUSTER_BREAK",
+        "GraphemeBreak",
+        |ch| {
+            let value = gcb.get(ch);
+            let skip = value == GraphemeClusterBreak::Other || is_hangul_syllable(ch, value);
+            (!skip).then_some(value)
+        },
+        |value| match value {
+            GraphemeClusterBreak::CR => "Cr",
+            GraphemeClusterBreak::LF => "Lf",
+            GraphemeClusterBreak::Control => "Control",
+            GraphemeClusterBreak::Extend => "Extend",
+            GraphemeClusterBreak::ZWJ => "Zwj",
+            GraphemeClusterBreak::RegionalIndicator => "RegionalIndicator",
+            GraphemeClusterBreak::Prepend => "Prepend",
+            GraphemeClusterBreak::SpacingMark => "SpacingMark",
+            GraphemeClusterBreak::L => "L",
+            GraphemeClusterBreak::V => "V",
+            GraphemeClusterBreak::T => "T",
+            GraphemeClusterBreak::LV => "Lv",
+            GraphemeClusterBreak::LVT => "Lvt",
+            // E_Base, E_Modifier, Glue_After_Zwj and E_Base_GAZ are unused since Unicode 11.0
+            _ => panic!("unexpected Grapheme_Cluster_Break {value:?}"),
+        },
+    );
+    write_value_ranges(
+        &mut out,
+        "INDIC_CONJUNCT_BREAK",
+        "IndicConjunctBreak",
+        |ch| {
+            let value = incb.get(ch);
+            (value != IndicConjunctBreak::None).then_some(value)
+        },
+        |value| match value {
+            IndicConjunctBreak::Consonant => "Consonant",
+            IndicConjunctBreak::Extend => "Extend",
+            IndicConjunctBreak::Linker => "Linker",
+            _ => panic!("unexpected Indic_Conjunct_Break {value:?}"),
+        },
+    );
+    write_ranges(&mut out, "EXTENDED_PICTOGRAPHIC", |ch| {
+        ext_pict.contains(ch)
+    });
+
+    out
+}